@@ -13,6 +13,20 @@ pub struct Model2GroupApp {
     pub model_path: String,
     #[arg(help = "Path to output directory", required = true)]
     pub output_path: String,
+    #[arg(long, default_value = "30", value_parser = parse_positive_f32, help = "Frame rate used for animation keys and clip samples")]
+    pub fps: f32,
+    #[arg(long, default_value = "120", value_parser = parse_positive_f32, help = "Tempo used to map clip samples to beats")]
+    pub bpm: f32,
+    #[arg(long, default_value = "2", help = "Char clip sample compression (0-3)")]
+    pub compression: u32,
+}
+
+fn parse_positive_f32(value: &str) -> Result<f32, String> {
+    match value.parse::<f32>() {
+        Ok(v) if v.is_finite() && v > 0.0 => Ok(v),
+        Ok(v) => Err(format!("must be greater than 0 (got {v})")),
+        Err(e) => Err(e.to_string())
+    }
 }
 
 // TODO: Get from args
@@ -24,7 +38,13 @@ const SYSTEM_INFO: SystemInfo = SystemInfo {
 
 impl SubApp for Model2GroupApp {
     fn process(&mut self) -> Result<(), Box<dyn Error>> {
-        let asset_man = open_model(&self.model_path, SYSTEM_INFO)?;
+        let settings = GltfImportSettings {
+            anim_fps: self.fps,
+            beats_per_sec: self.bpm / 60.0,
+            clip_compression: self.compression,
+        };
+
+        let asset_man = open_model_with_settings(&self.model_path, SYSTEM_INFO, settings)?;
         asset_man.dump_to_directory(&self.output_path)
     }
 }
@@ -290,6 +290,7 @@ fn load_midi(project_dir: &Path, is_gdrb: bool) -> Option<Object> {
         type2: String::from("song_anim"),
         note: format!("Generated by {} ({})", super::PKG_NAME, super::VERSION),
         keys: prop_keys,
+        version: Some(11), // TBRB
         ..Default::default()
     }))
 }
@@ -0,0 +1,87 @@
+use crate::apps::{GameOptions, SubApp};
+use clap::Parser;
+use std::error::Error;
+use std::path::Path;
+use thiserror::Error;
+
+use grim::{Platform, SystemInfo};
+use grim::io::*;
+use grim::midi::MidiFile;
+use grim::scene::{LipSyncGenerator, MiloObject, Object};
+
+#[derive(Debug, Error)]
+pub enum Mid2LipSyncError {
+    #[error("Unable to open midi file \"{path}\"")]
+    MidiNotLoaded {
+        path: String
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct Mid2LipSyncApp {
+    #[arg(help = "Path to input midi file w/ vocals", required = true)]
+    pub midi_path: String,
+    #[arg(help = "Path to output lipsync file", required = true)]
+    pub output_path: String,
+    #[arg(short, long, default_value = "PART VOCALS", help = "Name of vocals track")]
+    pub track: String,
+    #[arg(long, default_value = "25", help = "Milo archive version (24, 25, 28)")]
+    pub milo_version: u32,
+    #[arg(long, help = "Use big endian serialization")]
+    pub big_endian: bool,
+    #[arg(long, default_value = "x360", help = "Platform (ps2, ps3, wii, x360)")]
+    pub platform: String,
+}
+
+impl GameOptions for Mid2LipSyncApp {
+    fn get_system_info(&self) -> SystemInfo {
+        SystemInfo {
+            version: self.milo_version,
+            platform: match self.platform.to_lowercase().as_str() {
+                "ps2" => Platform::PS2,
+                "ps3" => Platform::PS3,
+                "wii" => Platform::Wii,
+                _ => Platform::X360
+            },
+            endian: match self.big_endian {
+                true => IOEndian::Big,
+                _ => IOEndian::Little
+            }
+        }
+    }
+}
+
+impl SubApp for Mid2LipSyncApp {
+    fn process(&mut self) -> Result<(), Box<dyn Error>> {
+        let output_path = Path::new(&self.output_path);
+        let info = self.get_system_info();
+
+        // Open midi
+        let Some(mid) = MidiFile::from_path(&self.midi_path) else {
+            return Err(Box::new(Mid2LipSyncError::MidiNotLoaded {
+                path: self.midi_path.to_owned()
+            }));
+        };
+
+        let generator = LipSyncGenerator::default();
+        let mut lipsync = generator.generate_from_midi(&mid, &self.track)?;
+
+        let file_name = output_path
+            .file_name()
+            .and_then(|f| f.to_str())
+            .unwrap_or("song.lipsync");
+
+        lipsync.set_name(file_name.to_owned());
+        println!("Generated {} frames from \"{}\"", lipsync.frames_count, &self.track);
+
+        let Object::Packed(packed) = Object::CharLipSync(lipsync).pack(&info)? else {
+            return Ok(()); // Shouldn't be reached
+        };
+
+        let mut stream = FileStream::from_path_as_read_write_create(output_path)?;
+        stream.write_bytes(packed.data.as_slice())?;
+
+        println!("Successfully wrote {file_name}");
+        Ok(())
+    }
+}
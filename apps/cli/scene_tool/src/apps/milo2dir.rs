@@ -104,7 +104,7 @@ fn extract_contents(milo_dir: &ObjectDir, output_path: &Path, convert_texures: b
 
         // First try parsing object
         if convert_texures && obj.get_type() == "Tex" {
-            if let Some(unpacked) = obj.unpack(info) {
+            if let Ok(unpacked) = obj.unpack(info) {
                 match &unpacked {
                     Object::Tex(tex) => {
                         if tex.bitmap.is_some() && extract_tex_object(tex, &entry_dir, info).is_ok() {
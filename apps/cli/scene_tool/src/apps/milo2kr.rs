@@ -156,7 +156,7 @@ fn get_lipsync_files(obj_dir: &ObjectDir, info: &SystemInfo) -> Vec<CharLipSync>
         .iter()
         .filter_map(|e| match e.get_type() {
             "CharLipSync" => match e.unpack(info) {
-                Some(Object::CharLipSync(cls)) => Some(cls),
+                Ok(Object::CharLipSync(cls)) => Some(cls),
                 _ => panic!("Unable to open {}", e.get_name())
             },
             _ => None
@@ -4,10 +4,12 @@ use std::error::Error;
 use grim::SystemInfo;
 
 mod dir2milo;
+mod mid2lipsync;
 mod milo2dir;
 mod milo2kr;
 mod savemilo;
 pub use self::dir2milo::*;
+pub use self::mid2lipsync::*;
 pub use self::milo2dir::*;
 pub use self::milo2kr::*;
 pub use self::savemilo::*;
@@ -31,6 +33,8 @@ struct Options {
 enum SubCommand {
     #[command(name = "dir2milo", about = "Creates milo scene from input directory")]
     Dir2Milo(Dir2MiloApp),
+    #[command(name = "mid2lipsync", about = "Generates lipsync from MIDI vocals")]
+    Mid2LipSync(Mid2LipSyncApp),
     #[command(name = "milo2dir", about = "Extracts content of milo scene to directory")]
     Milo2Dir(Milo2DirApp),
     #[command(name = "milo2kr", about = "Converts RB milo lipsync to KR rnd")]
@@ -54,6 +58,7 @@ impl SceneTool {
     pub fn run(&mut self) -> Result<(), Box<dyn Error>> {
         match &mut self.options.commands {
             SubCommand::Dir2Milo(app) => app.process(),
+            SubCommand::Mid2LipSync(app) => app.process(),
             SubCommand::Milo2Dir(app) => app.process(),
             SubCommand::Milo2Kr(app) => app.process(),
             SubCommand::SaveMilo(app) => app.process(),
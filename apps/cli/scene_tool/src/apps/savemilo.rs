@@ -9,7 +9,7 @@ use thiserror::Error;
 use grim::{Platform, SystemInfo};
 use grim::io::*;
 use grim::scene::{Object, ObjectDir, ObjectReadWrite, PackedObject, Tex};
-use grim::texture::{Bitmap, Image, swap_image_bytes, write_rgba_to_file};
+use grim::texture::{Bitmap, Image, write_rgba_to_file};
 
 
 #[derive(Parser, Debug)]
@@ -82,7 +82,7 @@ impl SubApp for SaveMiloApp {
         if in_sys_info.platform.ne(&out_sys_info.platform) {
             println!("Converting platform from {:?} to {:?}", in_sys_info.platform, out_sys_info.platform);
 
-            convert_textures(&mut obj_dir, &in_sys_info, &out_sys_info);
+            obj_dir.convert_textures(&in_sys_info, &out_sys_info, &Default::default())?;
         }
 
         if in_sys_info.version.ne(&out_sys_info.version) {
@@ -125,45 +125,8 @@ fn unpack_entries(milo_dir: &mut ObjectDir, info: &SystemInfo, all: bool) {
             continue
         }
 
-        if let Some(new_entry) = entry.unpack(info) {
+        if let Ok(new_entry) = entry.unpack(info) {
             *entry = new_entry;
         }
     }
 }
-
-fn convert_textures(milo_dir: &mut ObjectDir, in_sys_info: &SystemInfo, out_sys_info: &SystemInfo) {
-    for obj in milo_dir.get_entries_mut() {
-        if let Object::Tex(tex) = obj {
-            transcode_texture(tex, in_sys_info, out_sys_info);
-        }
-    }
-}
-
-fn transcode_texture(tex: &mut Tex, in_sys_info: &SystemInfo, out_sys_info: &SystemInfo) {
-    if let Some(bitmap) = &mut tex.bitmap {
-        match (bitmap.encoding, &in_sys_info.platform, &out_sys_info.platform) {
-            (32, &Platform::X360, _) => {},
-            (_, &Platform::PS3, &Platform::X360) | (_, &Platform::X360, &Platform::PS3) => {
-                // Just swap bytes to save time
-                if !bitmap.raw_data.is_empty() {
-                    swap_image_bytes(&mut bitmap.raw_data);
-                    println!("Swapped bytes for \"{}\"", tex.name.as_str());
-                }
-                return;
-            },
-            _ => {}
-        }
-
-        // Decode proper
-        if let Some(rgba) = bitmap.unpack_rgba(in_sys_info).ok() {
-            println!("Successfully decoded \"{}\"", tex.name.as_str());
-
-            *bitmap = Bitmap::from_image(Image::FromRGBA {
-                rgba: rgba.as_slice(),
-                width: bitmap.width,
-                height: bitmap.height,
-                mips: 0,
-            }, out_sys_info);
-        }
-    }
-}
\ No newline at end of file
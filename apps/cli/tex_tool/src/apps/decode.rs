@@ -0,0 +1,93 @@
+use crate::apps::{find_files_in_dir, get_output_path, is_texture_path, open_texture, parse_platform, SubApp, TexToolError};
+use clap::Parser;
+use rayon::prelude::*;
+use std::error::Error;
+use std::path::Path;
+
+use grim::Platform;
+use grim::io::create_missing_dirs;
+use grim::texture::write_rgba_to_file;
+
+#[derive(Parser, Debug)]
+pub struct DecodeApp {
+    #[arg(help = "Path to input texture (.png_xbox, .png_ps3, .png_wii, .bmp_ps2, Tex) or directory", required = true)]
+    pub input_path: String,
+    #[arg(help = "Path to output image (.png, .dds) or directory", required = true)]
+    pub output_path: String,
+    #[arg(long, help = "Platform (ps2, ps3, wii, x360), guessed from extension if not set")]
+    pub platform: Option<String>,
+    #[arg(short = 'f', long, default_value = "png", help = "Output image format when decoding directory (png, dds)")]
+    pub format: String,
+}
+
+impl SubApp for DecodeApp {
+    fn process(&mut self) -> Result<(), Box<dyn Error>> {
+        let input_path = Path::new(&self.input_path);
+        let output_path = Path::new(&self.output_path);
+
+        let platform = match &self.platform {
+            Some(p) => Some(parse_platform(p)?),
+            None => None,
+        };
+
+        if !input_path.is_dir() {
+            decode_texture(input_path, output_path, platform)?;
+            println!("Wrote output to \"{}\"", output_path.to_str().unwrap_or_default());
+
+            return Ok(());
+        }
+
+        let files = find_files_in_dir(input_path, is_texture_path)?;
+        println!("Found {} textures", files.len());
+
+        let failed_count = files
+            .par_iter()
+            .map(|file_path| {
+                let out_path = get_output_path(file_path, input_path, output_path, &self.format);
+
+                match decode_texture(file_path, &out_path, platform) {
+                    Ok(_) => {
+                        println!("Wrote \"{}\"", out_path.to_str().unwrap_or_default());
+                        0
+                    },
+                    Err(err) => {
+                        println!("Unable to decode \"{}\": {err}", file_path.to_str().unwrap_or_default());
+                        1
+                    }
+                }
+            })
+            .sum::<usize>();
+
+        println!("Decoded {} of {} textures", files.len() - failed_count, files.len());
+        Ok(())
+    }
+}
+
+fn decode_texture(input_path: &Path, output_path: &Path, platform: Option<Platform>) -> Result<(), Box<dyn Error>> {
+    let (bitmap, info) = open_texture(input_path, platform)?;
+
+    let output_ext = output_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    create_missing_dirs(output_path)?;
+
+    match output_ext.as_str() {
+        "png" => {
+            // Only write base image
+            let rgba = bitmap.unpack_rgba(&info)?;
+            let base_size = (bitmap.width as usize) * (bitmap.height as usize) * 4;
+
+            write_rgba_to_file(bitmap.width as u32, bitmap.height as u32, &rgba[..base_size], output_path)
+        },
+        "dds" => {
+            let dds = bitmap.to_dds(&info)?;
+            std::fs::write(output_path, dds).map_err(|e| e.into())
+        },
+        _ => Err(Box::new(TexToolError::UnsupportedOutputFormat {
+            format: output_ext
+        }))
+    }
+}
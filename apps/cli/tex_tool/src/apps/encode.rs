@@ -0,0 +1,149 @@
+use crate::apps::{find_files_in_dir, get_default_endian, get_output_path, get_platform_ext, guess_platform_from_ext, parse_endian, parse_platform, SubApp};
+use clap::Parser;
+use rayon::prelude::*;
+use std::error::Error;
+use std::path::Path;
+use thiserror::Error as ThisError;
+
+use grim::{Platform, SystemInfo};
+use grim::io::{create_missing_dirs, FileStream};
+use grim::scene::ObjectReadWrite;
+use grim::texture::{Bitmap, BitmapEncodeOptions, BitmapEncoding, read_rgba_from_file};
+
+#[derive(Debug, ThisError)]
+pub enum EncodeError {
+    #[error("Unsupported encoding \"{encoding}\"")]
+    UnsupportedEncoding {
+        encoding: String
+    },
+    #[error("Image resolution of {width}x{height} is too large")]
+    UnsupportedResolution {
+        width: u32,
+        height: u32
+    },
+}
+
+#[derive(Parser, Debug)]
+pub struct EncodeApp {
+    #[arg(help = "Path to input image (.png, .dds) or directory", required = true)]
+    pub input_path: String,
+    #[arg(help = "Path to output texture (.png_xbox, .png_ps3, .bmp_ps2) or directory", required = true)]
+    pub output_path: String,
+    #[arg(long, help = "Platform (ps2, ps3, x360), guessed from output extension if not set")]
+    pub platform: Option<String>,
+    #[arg(short = 'e', long, default_value = "auto", help = "Encoding (auto, dxt1, dxt5, ati2, rgb, rgba)")]
+    pub encoding: String,
+    #[arg(short = 'm', long, default_value = "0", help = "Additional mip maps to generate")]
+    pub mip_maps: u8,
+    #[arg(long, help = "Header endian (big, little), defaults to little for ps2/x360 and big otherwise")]
+    pub endian: Option<String>,
+}
+
+impl SubApp for EncodeApp {
+    fn process(&mut self) -> Result<(), Box<dyn Error>> {
+        let input_path = Path::new(&self.input_path);
+        let output_path = Path::new(&self.output_path);
+
+        let platform = match &self.platform {
+            Some(p) => parse_platform(p)?,
+            None => guess_platform_from_ext(output_path).unwrap_or(Platform::X360),
+        };
+
+        let endian = match &self.endian {
+            Some(e) => parse_endian(e)?,
+            None => get_default_endian(platform),
+        };
+
+        let info = SystemInfo {
+            platform,
+            endian,
+            ..Default::default()
+        };
+
+        let opts = BitmapEncodeOptions {
+            encoding: parse_encoding(&self.encoding)?,
+            mip_maps: self.mip_maps,
+        };
+
+        if !input_path.is_dir() {
+            encode_texture(input_path, output_path, &info, &opts)?;
+            println!("Wrote output to \"{}\"", output_path.to_str().unwrap_or_default());
+
+            return Ok(());
+        }
+
+        let files = find_files_in_dir(input_path, is_image_path)?;
+        println!("Found {} images", files.len());
+
+        let output_ext = get_platform_ext(platform);
+
+        let failed_count = files
+            .par_iter()
+            .map(|file_path| {
+                let out_path = get_output_path(file_path, input_path, output_path, output_ext);
+
+                match encode_texture(file_path, &out_path, &info, &opts) {
+                    Ok(_) => {
+                        println!("Wrote \"{}\"", out_path.to_str().unwrap_or_default());
+                        0
+                    },
+                    Err(err) => {
+                        println!("Unable to encode \"{}\": {err}", file_path.to_str().unwrap_or_default());
+                        1
+                    }
+                }
+            })
+            .sum::<usize>();
+
+        println!("Encoded {} of {} images", files.len() - failed_count, files.len());
+        Ok(())
+    }
+}
+
+fn parse_encoding(encoding: &str) -> Result<Option<BitmapEncoding>, Box<dyn Error>> {
+    match encoding.to_lowercase().as_str() {
+        "auto" => Ok(None),
+        "dxt1" | "bc1" => Ok(Some(BitmapEncoding::DXT1)),
+        "dxt5" | "bc3" => Ok(Some(BitmapEncoding::DXT5)),
+        "ati2" | "bc5" => Ok(Some(BitmapEncoding::ATI2)),
+        "rgb" => Ok(Some(BitmapEncoding::RGB)),
+        "rgba" => Ok(Some(BitmapEncoding::RGBA)),
+        _ => Err(Box::new(EncodeError::UnsupportedEncoding {
+            encoding: encoding.to_owned()
+        }))
+    }
+}
+
+fn is_image_path(path: &Path) -> bool {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some(ext) => ext.eq_ignore_ascii_case("png") || ext.eq_ignore_ascii_case("dds"),
+        None => false
+    }
+}
+
+fn encode_texture(input_path: &Path, output_path: &Path, info: &SystemInfo, opts: &BitmapEncodeOptions) -> Result<(), Box<dyn Error>> {
+    let is_dds = input_path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.eq_ignore_ascii_case("dds"))
+        .unwrap_or_default();
+
+    let bitmap = if is_dds {
+        // Copy blocks as-is
+        let data = std::fs::read(input_path)?;
+        Bitmap::from_dds(&data, info)?
+    } else {
+        let (width, height, rgba) = read_rgba_from_file(input_path)?;
+
+        if width > u16::MAX as u32 || height > u16::MAX as u32 {
+            return Err(Box::new(EncodeError::UnsupportedResolution { width, height }));
+        }
+
+        Bitmap::from_rgba(&rgba, width as u16, height as u16, info, opts)?
+    };
+
+    create_missing_dirs(output_path)?;
+
+    let mut stream = FileStream::from_path_as_write_create(output_path)?;
+    bitmap.save(&mut stream, info)
+}
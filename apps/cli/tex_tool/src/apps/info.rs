@@ -0,0 +1,64 @@
+use crate::apps::{find_files_in_dir, get_encoding_name, is_texture_path, open_texture, parse_platform, SubApp};
+use clap::Parser;
+use rayon::prelude::*;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+
+use grim::Platform;
+
+#[derive(Parser, Debug)]
+pub struct InfoApp {
+    #[arg(help = "Path to input texture (.png_xbox, .png_ps3, .png_wii, .bmp_ps2, Tex) or directory", required = true)]
+    pub input_path: String,
+    #[arg(long, help = "Platform (ps2, ps3, wii, x360), guessed from extension if not set")]
+    pub platform: Option<String>,
+}
+
+impl SubApp for InfoApp {
+    fn process(&mut self) -> Result<(), Box<dyn Error>> {
+        let input_path = Path::new(&self.input_path);
+
+        let platform = match &self.platform {
+            Some(p) => Some(parse_platform(p)?),
+            None => None,
+        };
+
+        let files = match input_path.is_dir() {
+            true => find_files_in_dir(input_path, is_texture_path)?,
+            false => vec![input_path.to_path_buf()],
+        };
+
+        // Read in parallel but print in order
+        let infos = files
+            .par_iter()
+            .map(|file_path| get_texture_info(file_path, platform))
+            .collect::<Vec<_>>();
+
+        for info in infos {
+            println!("{info}");
+        }
+
+        Ok(())
+    }
+}
+
+fn get_texture_info(file_path: &PathBuf, platform: Option<Platform>) -> String {
+    let file_name = file_path.to_str().unwrap_or_default();
+
+    match open_texture(file_path, platform) {
+        Ok((bitmap, info)) => format!(
+            "{file_name}\n\tPlatform: {:?}\n\tEndian: {:?}\n\tResolution: {}x{}\n\tBpp: {}\n\tEncoding: {} ({})\n\tMip maps: {}\n\tBpl: {}\n\tData size: {}",
+            info.platform,
+            info.endian,
+            bitmap.width,
+            bitmap.height,
+            bitmap.bpp,
+            get_encoding_name(&bitmap),
+            bitmap.encoding,
+            bitmap.mip_maps,
+            bitmap.bpl,
+            bitmap.raw_data.len()
+        ),
+        Err(err) => format!("{file_name}\n\t{err}")
+    }
+}
@@ -0,0 +1,248 @@
+mod decode;
+mod encode;
+mod info;
+
+use clap::{Parser, Subcommand};
+use decode::*;
+use encode::*;
+use info::*;
+use std::error::Error;
+use std::path::{Path, PathBuf};
+use thiserror::Error as ThisError;
+
+use grim::{Platform, SystemInfo};
+use grim::io::{FileSearchDepth, IOEndian, MemoryStream, PathFinder};
+use grim::scene::Tex;
+use grim::texture::Bitmap;
+
+// From Cargo.toml
+const PKG_NAME: &str = env!("CARGO_PKG_NAME");
+const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+// Milo versions to try when opening textures as Tex objects
+const TEX_VERSIONS: [u32; 4] = [25, 28, 24, 10];
+
+pub(crate) trait SubApp {
+    fn process(&mut self) -> Result<(), Box<dyn Error>>;
+}
+
+#[derive(Debug, ThisError)]
+pub enum TexToolError {
+    #[error("Unsupported platform \"{platform}\"")]
+    UnsupportedPlatform {
+        platform: String
+    },
+    #[error("Unsupported endian \"{endian}\"")]
+    UnsupportedEndian {
+        endian: String
+    },
+    #[error("Unable to open \"{path}\" as texture")]
+    UnsupportedTexture {
+        path: String
+    },
+    #[error("Unsupported output image format \"{format}\"")]
+    UnsupportedOutputFormat {
+        format: String
+    },
+}
+
+#[derive(Parser, Debug)]
+#[command(name = PKG_NAME, version = VERSION, about = "Texture tool for milo games")]
+struct Options {
+    #[command(subcommand)]
+    commands: SubCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum SubCommand {
+    #[command(name = "decode", about = "Decode platform texture to image")]
+    Decode(DecodeApp),
+    #[command(name = "encode", about = "Encode image to platform texture")]
+    Encode(EncodeApp),
+    #[command(name = "info", about = "Print texture info")]
+    Info(InfoApp),
+}
+
+#[derive(Debug)]
+pub struct TexTool {
+    options: Options,
+}
+
+impl TexTool {
+    pub fn new() -> TexTool {
+        TexTool {
+            options: Options::parse()
+        }
+    }
+
+    pub fn run(&mut self) -> Result<(), Box<dyn Error>> {
+        match &mut self.options.commands {
+            SubCommand::Decode(app) => app.process(),
+            SubCommand::Encode(app) => app.process(),
+            SubCommand::Info(app) => app.process(),
+        }
+    }
+}
+
+pub(crate) fn parse_platform(platform: &str) -> Result<Platform, Box<dyn Error>> {
+    match platform.to_lowercase().as_str() {
+        "ps2" => Ok(Platform::PS2),
+        "ps3" => Ok(Platform::PS3),
+        "wii" => Ok(Platform::Wii),
+        "xbox 360" | "xbox360" | "x360" | "360" | "xbox" => Ok(Platform::X360),
+        _ => Err(Box::new(TexToolError::UnsupportedPlatform {
+            platform: platform.to_owned()
+        }))
+    }
+}
+
+pub(crate) fn parse_endian(endian: &str) -> Result<IOEndian, Box<dyn Error>> {
+    match endian.to_lowercase().as_str() {
+        "big" | "be" => Ok(IOEndian::Big),
+        "little" | "le" => Ok(IOEndian::Little),
+        _ => Err(Box::new(TexToolError::UnsupportedEndian {
+            endian: endian.to_owned()
+        }))
+    }
+}
+
+pub(crate) fn guess_platform_from_ext(path: &Path) -> Option<Platform> {
+    let ext = path.extension().and_then(|e| e.to_str())?.to_ascii_lowercase();
+
+    match ext.as_str() {
+        "png_xbox" | "bmp_xbox" => Some(Platform::X360),
+        "png_ps3" | "bmp_ps3" => Some(Platform::PS3),
+        "png_wii" | "bmp_wii" => Some(Platform::Wii),
+        "png_ps2" | "bmp_ps2" => Some(Platform::PS2),
+        _ => None
+    }
+}
+
+pub(crate) fn get_platform_ext(platform: Platform) -> &'static str {
+    match platform {
+        Platform::PS2 => "bmp_ps2",
+        Platform::PS3 => "png_ps3",
+        Platform::Wii => "png_wii",
+        Platform::X360 => "png_xbox",
+    }
+}
+
+pub(crate) fn get_default_endian(platform: Platform) -> IOEndian {
+    // Standalone ps2/360 textures use little endian headers
+    match platform {
+        Platform::PS2 | Platform::X360 => IOEndian::Little,
+        Platform::PS3 | Platform::Wii => IOEndian::Big,
+    }
+}
+
+pub(crate) fn is_texture_path(path: &Path) -> bool {
+    let is_tex = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(|e| e.eq_ignore_ascii_case("tex"))
+        .unwrap_or_default();
+
+    is_tex || guess_platform_from_ext(path).is_some()
+}
+
+pub(crate) fn find_files_in_dir<T: Fn(&Path) -> bool>(dir: &Path, filter: T) -> Result<Vec<PathBuf>, Box<dyn Error>> {
+    let files = dir
+        .find_files_with_depth(FileSearchDepth::Unlimited)?
+        .into_iter()
+        .filter(|f| filter(f.as_path()))
+        .collect();
+
+    Ok(files)
+}
+
+pub(crate) fn get_output_path(input_file: &Path, input_dir: &Path, output_dir: &Path, ext: &str) -> PathBuf {
+    let relative_path = input_file
+        .strip_prefix(input_dir)
+        .unwrap_or(input_file);
+
+    output_dir.join(relative_path).with_extension(ext)
+}
+
+pub(crate) fn open_texture(path: &Path, platform: Option<Platform>) -> Result<(Bitmap, SystemInfo), Box<dyn Error>> {
+    let data = std::fs::read(path)?;
+
+    let platform = platform
+        .or_else(|| guess_platform_from_ext(path))
+        .unwrap_or(Platform::X360);
+
+    let default_endian = get_default_endian(platform);
+    let endians = match default_endian {
+        IOEndian::Little => [IOEndian::Little, IOEndian::Big],
+        IOEndian::Big => [IOEndian::Big, IOEndian::Little],
+    };
+
+    // First try as bitmap
+    for endian in endians {
+        let info = SystemInfo {
+            platform,
+            endian,
+            ..Default::default()
+        };
+
+        let mut stream = MemoryStream::from_slice_as_read(&data);
+        match Bitmap::from_stream(&mut stream, &info) {
+            Ok(bitmap) if is_bitmap_valid(&bitmap) => return Ok((bitmap, info)),
+            _ => continue,
+        }
+    }
+
+    // Then try as tex object
+    for version in TEX_VERSIONS {
+        for endian in endians {
+            let info = SystemInfo {
+                version,
+                platform,
+                endian,
+            };
+
+            let mut stream = MemoryStream::from_slice_as_read(&data);
+            if let Ok(Tex { bitmap: Some(bitmap), .. }) = Tex::from_stream(&mut stream, &info) {
+                if is_bitmap_valid(&bitmap) {
+                    return Ok((bitmap, info));
+                }
+            }
+        }
+    }
+
+    Err(Box::new(TexToolError::UnsupportedTexture {
+        path: path.to_str().unwrap_or_default().to_owned()
+    }))
+}
+
+pub(crate) fn get_encoding_name(bitmap: &Bitmap) -> &'static str {
+    match (bitmap.encoding, bitmap.bpp) {
+        (1, _) => "ARGB",
+        (3, 4 | 8) => "Palette",
+        (3, 24) => "RGB",
+        (3, _) => "RGBA",
+        (8, _) => "DXT1",
+        (24, _) => "DXT5",
+        (32, _) => "ATI2",
+        (72, _) => "CMP",
+        (328, _) => "CMP (Alpha)",
+        _ => "Unknown",
+    }
+}
+
+fn is_bitmap_valid(bitmap: &Bitmap) -> bool {
+    if bitmap.width == 0 || bitmap.height == 0 || get_encoding_name(bitmap).eq("Unknown") {
+        return false;
+    }
+
+    // Palette is stored before image data
+    let palette_size = match (bitmap.encoding, bitmap.bpp) {
+        (3, 4 | 8) => 1 << (bitmap.bpp + 2),
+        _ => 0,
+    };
+
+    let image_size = (0..=bitmap.mip_maps)
+        .map(|i| ((bitmap.width as usize) >> i) * ((bitmap.height as usize) >> i) * (bitmap.bpp as usize) / 8)
+        .sum::<usize>();
+
+    bitmap.raw_data.len() >= palette_size + image_size
+}
@@ -0,0 +1,7 @@
+mod apps;
+use apps::TexTool;
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let mut tool = TexTool::new();
+    tool.run()
+}
@@ -399,8 +399,13 @@ impl MiloArchive {
             // Hacky way to write directory entry
             let dir_entry = dir_entry_op.unwrap();
 
-            if let Object::Packed(packed) = dir_entry {
-                writer.write_bytes(&packed.data.as_slice())?;
+            match dir_entry {
+                Object::Packed(packed) => writer.write_bytes(&packed.data.as_slice())?,
+                _ => {
+                    if let Object::Packed(packed) = dir_entry.pack(info)? {
+                        writer.write_bytes(packed.data.as_slice())?;
+                    }
+                }
             }
 
             writer.write_bytes(&ADDE_PADDING)?;
@@ -425,23 +430,16 @@ impl MiloArchive {
                 },
                 _ => {
                     // Pack entry
-                    let data = entry
-                        .pack(info)
-                        .and_then(|o| match o {
-                            Object::Packed(p) => Some(p.data),
-                            _ => None
-                        });
-
-                    if let Some(data) = &data {
-                        // Write to stream
-                        writer.write_bytes(&data[..])?;
-                        writer.write_bytes(&ADDE_PADDING)?;
-
-                        // Update block size
-                        current_size += data.len() + 4;
-                    } else {
+                    let Object::Packed(PackedObject { data, .. }) = entry.pack(info)? else {
                         continue;
-                    }
+                    };
+
+                    // Write to stream
+                    writer.write_bytes(&data[..])?;
+                    writer.write_bytes(&ADDE_PADDING)?;
+
+                    // Update block size
+                    current_size += data.len() + 4;
                 }
             };
 
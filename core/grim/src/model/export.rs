@@ -13,6 +13,7 @@ use std::error::Error;
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::rc::Rc;
+use super::{get_sample_rotation, Skeleton};
 
 //type TransObject = dyn Trans + MiloObject;
 
@@ -21,27 +22,29 @@ pub struct BoneNode<'a> {
     pub children: Vec<BoneNode<'a>>
 }
 
-fn get_child_nodes<'a>(parent_name: &str, bone_map: &HashMap<&str, &'a dyn Trans>, child_map: &HashMap<&str, Vec<&dyn Trans>>) -> Vec<BoneNode<'a>> {
-    let Some(children) = child_map.get(parent_name) else {
-        return Vec::new();
-    };
-
-    children
+fn get_bone_nodes<'a>(skeleton: &Skeleton, indices: &[usize], trans_map: &HashMap<&str, &'a dyn Trans>) -> Vec<BoneNode<'a>> {
+    indices
         .iter()
-        .sorted_by(|a, b| a.get_name().cmp(b.get_name())) // Sort by name
-        .map(|c| BoneNode {
-            object: *bone_map.get(c.get_name().as_str()).unwrap(),
-            children: get_child_nodes(c.get_name().as_str(), bone_map, child_map)
+        .flat_map(|i| {
+            let bone = &skeleton.get_bones()[*i];
+            let children = get_bone_nodes(skeleton, &bone.children, trans_map);
+
+            match trans_map.get(bone.name.as_str()) {
+                Some(object) => vec![BoneNode {
+                    object: *object,
+                    children
+                }],
+                // Mesh only bone, move children up
+                None => children
+            }
         })
         .collect()
 }
 
 pub fn find_bones<'a>(obj_dir: &'a ObjectDir) -> Vec<BoneNode<'a>> {
-    let dir_name = match obj_dir {
-        ObjectDir::ObjectDir(base) => base.name.as_str(),
-    };
+    let skeleton = Skeleton::from_object_dir(obj_dir);
 
-    let bones = obj_dir
+    let trans_map = obj_dir
         .get_entries()
         .iter()
         .filter_map(|o| match o {
@@ -53,32 +56,7 @@ pub fn find_bones<'a>(obj_dir: &'a ObjectDir) -> Vec<BoneNode<'a>> {
         .map(|b| (b.get_name().as_str(), b))
         .collect::<HashMap<_, _>>();
 
-    // Map parent to children
-    let child_map = bones
-        .iter()
-        .fold(HashMap::new(), |mut acc: HashMap<&str, Vec<&'a dyn Trans>>, (_, b)| {
-            if b.get_parent().eq(b.get_name()) {
-                // If bone references self, ignore
-                return acc;
-            }
-
-            acc
-                .entry(b.get_parent().as_str())
-                .and_modify(|e| e.push(*b))
-                .or_insert(vec![*b]);
-
-            acc
-        });
-
-    let mut root_nodes = Vec::new();
-
-    // Add bones that belong to object dir
-    let mut dir_nodes = get_child_nodes(dir_name, &bones, &child_map);
-    root_nodes.append(&mut dir_nodes);
-
-    // TODO: Add unparented bones
-
-    root_nodes
+    get_bone_nodes(&skeleton, &skeleton.get_root_indices(), &trans_map)
 }
 
 fn map_bones_to_nodes(dir_name: &str, bones: &Vec<BoneNode>) -> Vec<gltf_json::Node> {
@@ -346,6 +324,8 @@ pub struct GltfExporter {
     groups: HashMap<String, MappedObject<GroupObject>>,
     materials: HashMap<String, MappedObject<MatObject>>,
     meshes: HashMap<String, MappedObject<MeshObject>>,
+    mesh_anims: HashMap<String, MappedObject<MeshAnim>>,
+    morphs: HashMap<String, MappedObject<Morph>>,
     transforms: HashMap<String, MappedObject<TransObject>>,
     trans_anims: HashMap<String, MappedObject<TransAnim>>,
     textures: HashMap<String, MappedObject<Tex>>,
@@ -421,6 +401,8 @@ impl GltfExporter {
         self.groups.clear();
         self.materials.clear();
         self.meshes.clear();
+        self.mesh_anims.clear();
+        self.morphs.clear();
         self.transforms.clear();
         self.textures.clear();
 
@@ -476,6 +458,18 @@ impl GltfExporter {
                             MappedObject::new(mesh, parent.clone())
                         );
                     },
+                    Object::MeshAnim(mesh_anim) => {
+                        self.mesh_anims.insert(
+                            name,
+                            MappedObject::new(mesh_anim, parent.clone())
+                        );
+                    },
+                    Object::Morph(morph) => {
+                        self.morphs.insert(
+                            name,
+                            MappedObject::new(morph, parent.clone())
+                        );
+                    },
                     Object::Tex(tex) => {
                         self.textures.insert(
                             name,
@@ -590,6 +584,16 @@ impl GltfExporter {
             .map(|m| &m.object)
     }
 
+    fn get_mesh_anim<'a>(&'a self, mesh: &MeshObject) -> Option<&'a MeshAnim> {
+        // Vertex keys are used as morph targets
+        self.mesh_anims
+            .values()
+            .map(|m| &m.object)
+            .filter(|m| m.mesh.eq(mesh.get_name()) && !m.vert_point_keys.is_empty())
+            .sorted_by(|a, b| a.get_name().cmp(b.get_name()))
+            .find(|m| m.vert_point_keys.iter().all(|k| k.value.len() == mesh.get_vertices().len()))
+    }
+
     fn process_node<'a>(&'a self, gltf: &mut json::Root, name: &'a str, child_map: &HashMap<&'a str, Vec<&'a str>>, depth: usize) -> usize {
         let node_index = gltf.nodes.len();
 
@@ -772,7 +776,6 @@ impl GltfExporter {
         (gltf.images, gltf.textures) = self.textures
             .values()
             .sorted_by(|a, b| a.object.get_name().cmp(b.object.get_name()))
-            .filter(|t| t.object.bpp != 24) // TODO: Support 24bpp textures...
             .enumerate()
             .map(|(i, mt)| {
                 let t = &mt.object;
@@ -1308,6 +1311,22 @@ impl GltfExporter {
                 mesh.get_faces().iter().map(|f| f.to_owned()).flatten()
             );
 
+            // Add morph targets from vertex anim keys
+            // Note: Tex coord + color keys aren't supported by morph targets
+            let morph_targets = self
+                .get_mesh_anim(mesh)
+                .map(|mesh_anim| get_morph_target_deltas(mesh, mesh_anim)
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, deltas)| json::mesh::MorphTarget {
+                        positions: acc_builder
+                            .add_array(format!("{}_target_{i}_pos", mesh.get_name()), deltas)
+                            .map(|idx| json::Index::new(idx as u32)),
+                        normals: None,
+                        tangents: None,
+                    })
+                    .collect::<Vec<_>>());
+
             let mesh_idx = meshes.len();
 
             meshes.push(json::Mesh {
@@ -1373,12 +1392,14 @@ impl GltfExporter {
                             .get(&mesh.mat)
                             .map(|idx| json::Index::new(*idx as u32)),
                         mode: json::validation::Checked::Valid(gltf::mesh::Mode::Triangles),
-                        targets: None,
+                        targets: morph_targets.clone(),
                         extras: Default::default(),
                         extensions: None
                     },
                 ],
-                weights: None,
+                weights: morph_targets
+                    .as_ref()
+                    .map(|t| vec![0.0; t.len()]),
                 extras: Default::default(),
                 extensions: None
             });
@@ -1819,6 +1840,47 @@ impl GltfExporter {
             });
         }
 
+        // Get morph target weight anims
+        let mut mesh_anims = self
+            .mesh_anims
+            .values()
+            .map(|m| &m.object)
+            .collect::<Vec<_>>();
+
+        // Sort mesh anims by name
+        mesh_anims.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+
+        for mesh_anim in mesh_anims {
+            let Some((node_idx, target_count)) = get_morph_target_node(gltf, &node_map, &mesh_anim.mesh) else {
+                continue;
+            };
+
+            let (times, weights) = get_mesh_anim_weights(mesh_anim, target_count);
+            if let Some(animation) = create_weights_animation(acc_builder, mesh_anim.get_name(), node_idx, times, weights) {
+                animations.push(animation);
+            }
+        }
+
+        let mut morphs = self
+            .morphs
+            .values()
+            .map(|m| &m.object)
+            .collect::<Vec<_>>();
+
+        // Sort morphs by name
+        morphs.sort_by(|a, b| a.get_name().cmp(b.get_name()));
+
+        for morph in morphs {
+            let Some((node_idx, target_count)) = get_morph_target_node(gltf, &node_map, &morph.target) else {
+                continue;
+            };
+
+            let (times, weights) = get_morph_weights(morph, target_count);
+            if let Some(animation) = create_weights_animation(acc_builder, morph.get_name(), node_idx, times, weights) {
+                animations.push(animation);
+            }
+        }
+
         // Get char clip anims
         let mut char_clips = self
             .char_clip_samples
@@ -1829,133 +1891,78 @@ impl GltfExporter {
         // Sort clips by name
         char_clips.sort_by(|(a, _), (b, _)| a.get_name().cmp(b.get_name()));
 
-        /*let char_clip_samples = char_clips
-            .into_iter()
-            .flat_map(|(ccs, info)| [&ccs.full, &ccs.one]
-                .into_iter()
-                .flat_map(|cbs| cbs
-                    .decode_samples(info)
-                    .into_inter()
-                )
-            )
-            .collect::<Vec<_>>();*/
-
-        let default_frames = vec![0.0];
-
         for (char_clip, info) in char_clips {
-            let clip_name = char_clip.get_name();
+            if let Some(animation) = self.process_char_clip(gltf, acc_builder, &node_map, char_clip, info) {
+                animations.push(animation);
+            }
+        }
 
-            let mut channels = Vec::new();
-            let mut samplers = Vec::new();
+        gltf.animations = animations;
+    }
 
-            // TODO: Delete after testing
-            let _filtered_bone_names = HashSet::from([
-                //"bone_neck.mesh"
-                "bone_spine1.mesh",
-                "bone_spine2.mesh",
-                "bone_spine3.mesh",
-            ]);
+    fn process_char_clip(&self, gltf: &json::Root, acc_builder: &mut AccessorBuilder, node_map: &HashMap<String, usize>, char_clip: &CharClipSamples, info: &SystemInfo) -> Option<json::Animation> {
+        let clip_name = char_clip.get_name();
 
-            // TODO: Decode at earlier step...
-            let bone_samples = [&char_clip.full, &char_clip.one]
-                .iter()
-                .flat_map(|cbs| cbs.decode_samples(info)
-                    .into_iter()
-                    .map(|s| (s, if !cbs.frames.is_empty() { &cbs.frames } else { &default_frames })))
-                //.filter(|(b, _)| filtered_bone_names.contains(b.symbol.as_str()))
-                .collect::<Vec<_>>();
+        // Full samples take priority over single pose samples
+        let mut bone_anims: BTreeMap<String, (usize, CharClipBoneAnim)> = BTreeMap::new();
 
-            for (mut bone, _frames) in bone_samples {
-                let bone_name = bone.symbol.as_str();
-                let Some(node_idx) = node_map.get(bone_name).map(|i| *i) else {
+        for cbs in [&char_clip.full, &char_clip.one] {
+            let bone_samples = match cbs.decode_samples(info) {
+                Ok(samples) => samples,
+                Err(err) => {
+                    log::warn!("Unable to decode samples for \"{clip_name}\": {err}");
                     continue;
-                };
-
-                // Get existing matrix for node
-                let node = &gltf.nodes[node_idx];
+                }
+            };
 
-                let node_trans = node.translation
-                    .map(na::Vector3::from)
-                    .unwrap_or_else(na::Vector3::zeros);
+            for bone in bone_samples {
+                let Some(node_idx) = node_map.get(&bone.symbol).copied() else {
+                    continue;
+                };
 
-                let node_rotate = node.rotation
+                let bind_rotation = gltf.nodes[node_idx].rotation
                     .map(|json::scene::UnitQuaternion([x, y, z, w])|
                         na::UnitQuaternion::from_quaternion(
                             na::Quaternion::new(w, x, y, z)
                         )
                     )
-                    .unwrap_or_else(|| na::UnitQuaternion::identity());
-
-                // TODO: Add scaling transform samples...
-                let _node_scale = node.scale
-                    .map(na::Vector3::from)
-                    .unwrap_or_else(|| na::Vector3::from_element(1.0));
-
-                // Compute samples as matrices
-                //let translate_samples = bone.pos.take().map(|(pw, p)| p.into_iter().map(|v| na::Matrix4::new_translation(&na::Vector3::new(v.x, v.y, v.z))));
-                //let translate_samples = bone.quat.take().map(|(qw, q)| q.into_iter().map(|v| na::Quaternion::new(v.x, v.y, v.z, v.w)));
-                //let mat = na::Matrix4::new_translation(&na::Vector3::new(1.0, 1.0, 1.0));
-
-                /*let mut samples = Vec::new();
-
-                // Process translations (.pos)
-                if let Some((w, positions)) = bone.pos.take() {
-                    for (i, v) in positions.into_iter().enumerate() {
-                        let mat = match samples.get_mut(i) {
-                            Some(m) => m,
-                            _ => {
-                                samples.push(node_matrix);
-                                samples.last_mut().unwrap()
-                            }
-                        };
+                    .unwrap_or_else(na::UnitQuaternion::identity);
 
-                        mat.append_translation_mut(&na::Vector3::new(v.x * w, v.y * w, v.z * w));
+                let anim = CharClipBoneAnim::from_sample(char_clip, &cbs.frames, &bone, bind_rotation);
+
+                match bone_anims.get_mut(&bone.symbol) {
+                    Some((_, existing)) => existing.merge(anim),
+                    None => {
+                        bone_anims.insert(bone.symbol.to_owned(), (node_idx, anim));
                     }
                 }
+            }
+        }
 
-                // Process rotations (.quat)
-
-                // Process rotations (.rotz)
+        let mut channels = Vec::new();
+        let mut samplers = Vec::new();
 
-                // Add matrix samples
+        for (bone_name, (node_idx, anim)) in bone_anims {
+            let mut add_channel = |acc_builder: &mut AccessorBuilder, path: &str, property: json::animation::Property, times: Vec<f32>, output_idx: Option<usize>| {
                 let input_idx = acc_builder.add_scalar(
-                    format!("{}_{}_matrix_input", clip_name, bone_name),
-                    //frames.iter().map(|f| *f)
-                    samples.iter().enumerate().map(|(i, _)| i as f32)
-                ).unwrap();
-
-                let output_idx = acc_builder.add_array(
-                    format!("{}_{}_matrix_output", clip_name, bone_name),
-                    samples.into_iter().map(|m| [
-                        m[0],
-                        m[1],
-                        m[2],
-                        m[3],
-                        m[4],
-                        m[5],
-                        m[6],
-                        m[7],
-                        m[8],
-                        m[9],
-                        m[10],
-                        m[11],
-                        m[12],
-                        m[13],
-                        m[14],
-                        m[15],
-                    ])
-                ).unwrap();
+                    format!("{clip_name}_{bone_name}_{path}_input"),
+                    times
+                );
+
+                let (Some(input_idx), Some(output_idx)) = (input_idx, output_idx) else {
+                    return;
+                };
 
                 channels.push(json::animation::Channel {
                     sampler: json::Index::new(samplers.len() as u32),
                     target: json::animation::Target {
                         node: json::Index::new(node_idx as u32),
-                        path: json::validation::Checked::Valid(json::animation::Property::Translation),
+                        path: json::validation::Checked::Valid(property),
                         extensions: None,
                         extras: Default::default()
                     },
                     extensions: None,
-                        extras: Default::default()
+                    extras: Default::default()
                 });
 
                 samplers.push(json::animation::Sampler {
@@ -1964,230 +1971,242 @@ impl GltfExporter {
                     interpolation: json::validation::Checked::Valid(json::animation::Interpolation::Linear),
                     extensions: None,
                     extras: Default::default()
-                });*/
+                });
+            };
 
-                const FPS: f32 = 1. / 30.;
-
-                // Add translations (.pos)
-                if let Some((w, samples)) = bone.pos.take() {
-                    let input_idx = acc_builder.add_scalar(
-                        format!("{}_{}_translation_input", clip_name, bone_name),
-                        //frames.iter().map(|f| *f)
-                        samples.iter().enumerate().map(|(i, _)| (i as f32) * FPS)
-                    ).unwrap();
-
-                    let output_idx = acc_builder.add_array(
-                        format!("{}_{}_translation_output", clip_name, bone_name),
-                        samples.into_iter().map(|s| {
-                            let v = na::Vector3::new(s.x * w, s.y * w, s.z * w);
-
-                            [v.x, v.y, v.z]
-                        })
-                    ).unwrap();
-
-                    channels.push(json::animation::Channel {
-                        sampler: json::Index::new(samplers.len() as u32),
-                        target: json::animation::Target {
-                            node: json::Index::new(node_idx as u32),
-                            path: json::validation::Checked::Valid(json::animation::Property::Translation),
-                            extensions: None,
-                            extras: Default::default()
-                        },
-                        extensions: None,
-                            extras: Default::default()
-                    });
-    
-                    samplers.push(json::animation::Sampler {
-                        input: json::Index::new(input_idx as u32),
-                        output: json::Index::new(output_idx as u32),
-                        interpolation: json::validation::Checked::Valid(json::animation::Interpolation::Linear),
-                        extensions: None,
-                        extras: Default::default()
-                    });
-                } else {
-                    // Add empty pos sample
-                    let input_idx = acc_builder.add_scalar(
-                        format!("{}_{}_translation_input", clip_name, bone_name),
-                        //frames.iter().map(|f| *f)
-                        vec![0.0]
-                    ).unwrap();
-
-                    let output_idx = acc_builder.add_array(
-                        format!("{}_{}_translation_output", clip_name, bone_name),
-                        {
-                            let v = node_trans;
-                            vec![[v.x, v.y, v.z]]
-                        }
-                    ).unwrap();
+            if let Some((times, values)) = anim.translations {
+                let output_idx = acc_builder.add_array(format!("{clip_name}_{bone_name}_translation_output"), values);
+                add_channel(acc_builder, "translation", json::animation::Property::Translation, times, output_idx);
+            }
 
-                    channels.push(json::animation::Channel {
-                        sampler: json::Index::new(samplers.len() as u32),
-                        target: json::animation::Target {
-                            node: json::Index::new(node_idx as u32),
-                            path: json::validation::Checked::Valid(json::animation::Property::Translation),
-                            extensions: None,
-                            extras: Default::default()
-                        },
-                        extensions: None,
-                            extras: Default::default()
-                    });
-    
-                    samplers.push(json::animation::Sampler {
-                        input: json::Index::new(input_idx as u32),
-                        output: json::Index::new(output_idx as u32),
-                        interpolation: json::validation::Checked::Valid(json::animation::Interpolation::Linear),
-                        extensions: None,
-                        extras: Default::default()
-                    });
-                }
+            if let Some((times, values)) = anim.rotations {
+                let output_idx = acc_builder.add_array(format!("{clip_name}_{bone_name}_rotation_output"), values);
+                add_channel(acc_builder, "rotation", json::animation::Property::Rotation, times, output_idx);
+            }
 
-                /*let mut rotation_samples = [
-                    bone.quat
-                        .take()
-                        .map(|(w, samples)| samples.into_iter().map(|s| {
-                            na::UnitQuaternion::from_quaternion(
-                                na::Quaternion::new(
-                                    s.x * w,
-                                    s.y * w,
-                                    s.z * w,
-                                    s.w * w,
-                            ))
-                        })
-                        .by_ref()
-                        .collect::<Vec<_>>()
-                    ).unwrap_or_default(),
-                    bone.rotz
-                        .take()
-                        .map(|(w, samples)| samples.into_iter().map(|s| {
-                            na::UnitQuaternion::from_axis_angle(
-                                &na::Vector3::z_axis(),
-                                std::f32::consts::PI * (s * w)
-                            )
-                        })
-                        .collect::<Vec<_>>()
-                    )
-                    .unwrap_or_default()
-                ];*/
+            if let Some((times, values)) = anim.scales {
+                let output_idx = acc_builder.add_array(format!("{clip_name}_{bone_name}_scale_output"), values);
+                add_channel(acc_builder, "scale", json::animation::Property::Scale, times, output_idx);
+            }
+        }
 
-                /*let rotation_sample_count = match (bone.quat.as_ref().map(|(_, s)| s.len()), bone.rotz.as_ref().map(|(_, s)| s.len())) {
-                    (Some(a), Some(b)) => a.max(b),
-                    (Some(a), _) => a,
-                    (_, Some(b)) => b,
-                    _ => bone.pos.as_ref().map(|(_, s)| s.len()).unwrap_or_default()
-                };*/
+        if samplers.is_empty() || channels.is_empty() {
+            // Don't add if no anims found
+            return None;
+        }
 
-                let rotation_sample_count = [
-                    bone.quat.as_ref().map(|(_, s)| s.len()),
-                    bone.rotz.as_ref().map(|(_, s)| s.len()),
-                    bone.pos.as_ref().map(|(_, s)| s.len())
-                ]
-                .into_iter()
-                .filter_map(|f| f)
-                .max()
-                .unwrap_or_default();
-
-                // Combined rotations
-                let mut rotation_samples = (0..rotation_sample_count)
-                    .map(|_| node_rotate)
-                    /*.map(|_| {
-                        let q = rotation.as_vector();
-                        na::Quaternion::new(q[3], q[0], q[1], q[2])
-                    })*/
-                    .collect::<Vec<_>>();
+        Some(json::Animation {
+            name: Some(clip_name.to_owned()),
+            channels,
+            samplers,
+            extensions: None,
+            extras: Default::default()
+        })
+    }
+}
 
-                // Add rotations (.quat)
-                if let Some((w, samples)) = bone.quat.take() {
-                    for (i, s) in samples.into_iter().enumerate() {
-                        let rot =  &mut rotation_samples[i];
+const DEFAULT_CLIP_FPS: f32 = 30.0;
 
-                        let q = na::Quaternion::new(
-                            s.w * w,
-                            s.x * w,
-                            s.y * w,
-                            s.z * w,
-                        );
+// Sampled channels for single bone, each w/ own key times
+#[derive(Default)]
+struct CharClipBoneAnim {
+    translations: Option<(Vec<f32>, Vec<[f32; 3]>)>,
+    rotations: Option<(Vec<f32>, Vec<[f32; 4]>)>,
+    scales: Option<(Vec<f32>, Vec<[f32; 3]>)>,
+}
 
-                        //let q = na::UnitQuaternion::from
+impl CharClipBoneAnim {
+    fn from_sample(char_clip: &CharClipSamples, frames: &[f32], sample: &CharBoneSample, bind_rotation: na::UnitQuaternion<f32>) -> CharClipBoneAnim {
+        let translations = sample.pos
+            .as_ref()
+            .map(|(_, p)| (
+                get_char_clip_sample_times(char_clip, frames, p.len()),
+                p.iter().map(|v| [v.x, v.y, v.z]).collect()
+            ));
 
-                        //*rot = *rot * q;
+        let scales = sample.scale
+            .as_ref()
+            .map(|(_, s)| (
+                get_char_clip_sample_times(char_clip, frames, s.len()),
+                s.iter().map(|v| [v.x, v.y, v.z]).collect()
+            ));
 
-                        //*rot = rot.rotation_to(&na::UnitQuaternion::from_quaternion(q));
-                        *rot = na::UnitQuaternion::from_quaternion(q);
-                        //*rot = na::UnitQuaternion::from_quaternion(rot.normalize());
-                    }
-                }
+        let rotation_count = [&sample.rotx, &sample.roty, &sample.rotz]
+            .iter()
+            .filter_map(|r| r.as_ref().map(|(_, r)| r.len()))
+            .chain(sample.quat.as_ref().map(|(_, q)| q.len()))
+            .max();
+
+        let rotations = rotation_count.map(|count| {
+            let values = (0..count)
+                .map(|i| {
+                    let rotation = get_sample_rotation(sample, i, bind_rotation);
+                    [rotation[0], rotation[1], rotation[2], rotation[3]]
+                })
+                .collect();
 
-                // Add rotations (.rotz)
-                if let Some((w, samples)) = bone.rotz.take() {
-                    for (i, z) in samples.into_iter().enumerate() {
-                        let rot =  &mut rotation_samples[i];
+            (get_char_clip_sample_times(char_clip, frames, count), values)
+        });
 
-                        let q = na::UnitQuaternion::from_axis_angle(
-                            &na::Vector3::z_axis(),
-                            std::f32::consts::PI * (z * w)
-                        );
+        CharClipBoneAnim {
+            translations,
+            rotations,
+            scales,
+        }
+    }
 
-                        *rot *= q;
-                    }
-                }
+    fn merge(&mut self, other: CharClipBoneAnim) {
+        if self.translations.is_none() {
+            self.translations = other.translations;
+        }
 
-                // Add all rotations
-                // TODO: Add empty rotation sample?
-                if rotation_samples.len() > 0 {
-                    let input_idx = acc_builder.add_scalar(
-                        format!("{}_{}_rotation_input", clip_name, bone_name),
-                        //frames.iter().map(|f| *f)
-                        rotation_samples.iter().enumerate().map(|(i, _)| (i as f32) * FPS)
-                    ).unwrap();
-
-                    let output_idx = acc_builder.add_array(
-                        format!("{}_{}_rotation_output", clip_name, bone_name),
-                        rotation_samples.into_iter().map(|s| [s[0], s[1], s[2], s[3]])
-                    ).unwrap();
-
-                    channels.push(json::animation::Channel {
-                        sampler: json::Index::new(samplers.len() as u32),
-                        target: json::animation::Target {
-                            node: json::Index::new(node_idx as u32),
-                            path: json::validation::Checked::Valid(json::animation::Property::Rotation),
-                            extensions: None,
-                            extras: Default::default()
-                        },
-                        extensions: None,
-                            extras: Default::default()
-                    });
+        if self.rotations.is_none() {
+            self.rotations = other.rotations;
+        }
 
-                    samplers.push(json::animation::Sampler {
-                        input: json::Index::new(input_idx as u32),
-                        output: json::Index::new(output_idx as u32),
-                        interpolation: json::validation::Checked::Valid(json::animation::Interpolation::Linear),
-                        extensions: None,
-                        extras: Default::default()
-                    });
-                }
+        if self.scales.is_none() {
+            self.scales = other.scales;
+        }
+    }
+}
 
-                // Add scales (.scale)
-            }
+fn get_char_clip_sample_times(char_clip: &CharClipSamples, frames: &[f32], count: usize) -> Vec<f32> {
+    let bps = char_clip.beats_per_sec;
+    let beat_length = char_clip.end_beat - char_clip.start_beat;
+
+    if bps > 0.0 && count > 1 && frames.len() == count {
+        // Frames are beat positions of each sample
+        let first = frames[0];
+        frames.iter().map(|f| (f - first) / bps).collect()
+    } else if bps > 0.0 && count > 1 && beat_length > 0.0 {
+        // Spread evenly across clip
+        let step = beat_length / ((count - 1) as f32);
+        (0..count).map(|i| (i as f32) * step / bps).collect()
+    } else {
+        (0..count).map(|i| (i as f32) / DEFAULT_CLIP_FPS).collect()
+    }
+}
 
-            if samplers.is_empty() || channels.is_empty() {
-                // Don't add if no anims found
-                continue;
-            }
+fn get_morph_target_deltas(mesh: &MeshObject, mesh_anim: &MeshAnim) -> Vec<Vec<[f32; 3]>> {
+    mesh_anim
+        .vert_point_keys
+        .iter()
+        .map(|k| k.value
+            .iter()
+            .zip(mesh.get_vertices())
+            .map(|(p, v)| [p.x - v.pos.x, p.y - v.pos.y, p.z - v.pos.z])
+            .collect())
+        .collect()
+}
 
-            animations.push(json::Animation {
-                name: Some(clip_name.to_owned()),
-                channels,
-                samplers,
-                extensions: None,
-                extras: Default::default()
-            });
-        }
+fn get_morph_target_node(gltf: &json::Root, node_map: &HashMap<String, usize>, mesh_name: &str) -> Option<(usize, usize)> {
+    let node_idx = node_map.get(mesh_name).copied()?;
 
-        gltf.animations = animations;
+    let target_count = gltf.meshes
+        .iter()
+        .find(|m| m.name.as_deref() == Some(mesh_name))
+        .and_then(|m| m.primitives.first())
+        .and_then(|p| p.targets.as_ref())
+        .map(|t| t.len())
+        .filter(|c| *c > 0)?;
+
+    Some((node_idx, target_count))
+}
+
+fn get_mesh_anim_weights(mesh_anim: &MeshAnim, target_count: usize) -> (Vec<f32>, Vec<f32>) {
+    let timeline = Timeline::new(mesh_anim.rate);
+
+    // Fully weight target of each key so linear interpolation blends between keys
+    let times = mesh_anim
+        .vert_point_keys
+        .iter()
+        .map(|k| timeline.frame_to_seconds(k.pos))
+        .collect::<Vec<_>>();
+
+    let weights = (0..times.len())
+        .flat_map(|i| (0..target_count).map(move |t| if i == t { 1.0 } else { 0.0 }))
+        .collect();
+
+    (times, weights)
+}
+
+fn get_morph_weights(morph: &Morph, target_count: usize) -> (Vec<f32>, Vec<f32>) {
+    let mut timeline = Timeline::from(morph);
+
+    // Sample all poses at same key positions
+    let mut positions = timeline
+        .channels
+        .iter()
+        .flat_map(|c| c.keys.positions())
+        .collect::<Vec<_>>();
+    positions.sort_by(|a, b| a.total_cmp(b));
+    positions.dedup();
+
+    for channel in timeline.channels.iter_mut() {
+        channel.keys.resample(&positions, channel.interpolation);
     }
+
+    let pose_weights = timeline
+        .channels
+        .iter()
+        .map(|c| match &c.keys {
+            ChannelKeys::Float(keys) => keys.iter().map(|k| k.value).collect(),
+            _ => Vec::new(),
+        })
+        .collect::<Vec<Vec<f32>>>();
+
+    let weights = (0..positions.len())
+        .flat_map(|i| (0..target_count)
+            .map(|t| pose_weights
+                .get(t)
+                .and_then(|w| w.get(i))
+                .copied()
+                .unwrap_or_default())
+            .collect::<Vec<_>>())
+        .collect();
+
+    let times = positions
+        .iter()
+        .map(|p| timeline.frame_to_seconds(*p))
+        .collect();
+
+    (times, weights)
+}
+
+fn create_weights_animation(acc_builder: &mut AccessorBuilder, name: &str, node_idx: usize, times: Vec<f32>, weights: Vec<f32>) -> Option<json::Animation> {
+    let input_idx = acc_builder.add_scalar(format!("{name}_weights_input"), times)?;
+    let output_idx = acc_builder.add_scalar(format!("{name}_weights_output"), weights)?;
+
+    Some(json::Animation {
+        name: Some(name.to_owned()),
+        channels: vec![
+            json::animation::Channel {
+                sampler: json::Index::new(0),
+                target: json::animation::Target {
+                    node: json::Index::new(node_idx as u32),
+                    path: json::validation::Checked::Valid(json::animation::Property::MorphTargetWeights),
+                    extensions: None,
+                    extras: Default::default()
+                },
+                extensions: None,
+                extras: Default::default()
+            }
+        ],
+        samplers: vec![
+            json::animation::Sampler {
+                input: json::Index::new(input_idx as u32),
+                output: json::Index::new(output_idx as u32),
+                interpolation: json::validation::Checked::Valid(json::animation::Interpolation::Linear),
+                extensions: None,
+                extras: Default::default()
+            }
+        ],
+        extensions: None,
+        extras: Default::default()
+    })
 }
 
-fn decompose_trs(mat: na::Matrix4<f32>) -> (na::Vector3<f32>, na::UnitQuaternion<f32>, na::Vector3<f32>) {
+pub(crate) fn decompose_trs(mat: na::Matrix4<f32>) -> (na::Vector3<f32>, na::UnitQuaternion<f32>, na::Vector3<f32>) {
     // Decompose matrix to T*R*S
     let translate = mat.column(3).xyz();
     let rotation = na::UnitQuaternion::from_matrix(&mat.fixed_view::<3, 3>(0, 0).into());
@@ -2340,4 +2359,122 @@ mod tests {
         assert_eq!(na::UnitQuaternion::from_quaternion(na::Quaternion::new(w, i, j, k)), rotate);
         assert_eq!(expected_scale, scale);
     }
+    #[rstest]
+    #[case(2.0, 0.0, 4.0, vec![], 5, vec![0.0, 0.5, 1.0, 1.5, 2.0])]
+    #[case(2.0, 0.0, 4.0, vec![1.0, 2.0, 5.0], 3, vec![0.0, 0.5, 2.0])]
+    #[case(0.0, 0.0, 0.0, vec![], 3, vec![0.0, 1.0 / 30.0, 2.0 / 30.0])]
+    #[case(2.0, 0.0, 4.0, vec![], 1, vec![0.0])]
+    fn get_char_clip_sample_times_test(#[case] beats_per_sec: f32, #[case] start_beat: f32, #[case] end_beat: f32, #[case] frames: Vec<f32>, #[case] count: usize, #[case] expected: Vec<f32>) {
+        let char_clip = CharClipSamples {
+            beats_per_sec,
+            start_beat,
+            end_beat,
+            ..Default::default()
+        };
+
+        assert_eq!(expected, get_char_clip_sample_times(&char_clip, &frames, count));
+    }
+
+    #[rstest]
+    fn char_clip_bone_anim_from_sample_test() {
+        let char_clip = CharClipSamples {
+            beats_per_sec: 1.0,
+            end_beat: 1.0,
+            ..Default::default()
+        };
+
+        let full_sample = CharBoneSample {
+            symbol: String::from("bone_neck.mesh"),
+            rotz: Some((1.0, vec![0.0, 0.5])),
+            ..Default::default()
+        };
+
+        let one_sample = CharBoneSample {
+            symbol: String::from("bone_neck.mesh"),
+            pos: Some((1.0, vec![Vector3 { x: 1.0, y: 2.0, z: 3.0 }])),
+            rotz: Some((1.0, vec![1.0])),
+            ..Default::default()
+        };
+
+        let mut anim = CharClipBoneAnim::from_sample(&char_clip, &[], &full_sample, na::UnitQuaternion::identity());
+        anim.merge(CharClipBoneAnim::from_sample(&char_clip, &[], &one_sample, na::UnitQuaternion::identity()));
+
+        // Rotations from full samples are kept
+        let (rot_times, rotations) = anim.rotations.unwrap();
+        assert_eq!(vec![0.0, 1.0], rot_times);
+        assert_eq!([0.0, 0.0, 0.0, 1.0], rotations[0]);
+
+        let expected = na::UnitQuaternion::from_axis_angle(&na::Vector3::z_axis(), std::f32::consts::FRAC_PI_2);
+        assert_eq!([expected[0], expected[1], expected[2], expected[3]], rotations[1]);
+
+        // Missing translations are filled from single pose
+        let (trans_times, translations) = anim.translations.unwrap();
+        assert_eq!(vec![0.0], trans_times);
+        assert_eq!(vec![[1.0, 2.0, 3.0]], translations);
+
+        assert!(anim.scales.is_none());
+    }
+
+    #[rstest]
+    fn get_morph_target_deltas_test() {
+        let mesh = MeshObject {
+            vertices: vec![
+                Vert { pos: Vector4 { x: 1.0, y: 2.0, z: 3.0, w: 1.0 }, ..Default::default() },
+            ],
+            ..Default::default()
+        };
+
+        let mesh_anim = MeshAnim {
+            vert_point_keys: vec![
+                AnimEvent { pos: 0.0, value: vec![Vector3 { x: 1.0, y: 2.0, z: 3.0 }] },
+                AnimEvent { pos: 10.0, value: vec![Vector3 { x: 2.0, y: 4.0, z: 6.0 }] },
+            ],
+            ..Default::default()
+        };
+
+        assert_eq!(vec![vec![[0.0, 0.0, 0.0]], vec![[1.0, 2.0, 3.0]]], get_morph_target_deltas(&mesh, &mesh_anim));
+    }
+
+    #[rstest]
+    fn get_mesh_anim_weights_test() {
+        let mesh_anim = MeshAnim {
+            vert_point_keys: vec![
+                AnimEvent { pos: 0.0, value: Vec::new() },
+                AnimEvent { pos: 15.0, value: Vec::new() },
+            ],
+            ..Default::default()
+        };
+
+        let (times, weights) = get_mesh_anim_weights(&mesh_anim, 2);
+        assert_eq!(vec![0.0, 0.5], times);
+        assert_eq!(vec![1.0, 0.0, 0.0, 1.0], weights);
+    }
+
+    #[rstest]
+    fn get_morph_weights_test() {
+        let morph = Morph {
+            poses: vec![
+                MorphPose {
+                    events: vec![
+                        AnimEvent { pos: 0.0, value: 0.0 },
+                        AnimEvent { pos: 30.0, value: 1.0 },
+                    ]
+                },
+                MorphPose {
+                    events: vec![
+                        AnimEvent { pos: 15.0, value: 1.0 },
+                    ]
+                },
+            ],
+            ..Default::default()
+        };
+
+        let (times, weights) = get_morph_weights(&morph, 3);
+        assert_eq!(vec![0.0, 0.5, 1.0], times);
+        assert_eq!(vec![
+            0.0, 1.0, 0.0,
+            0.5, 1.0, 0.0,
+            1.0, 1.0, 0.0,
+        ], weights);
+    }
 }
\ No newline at end of file
@@ -1,6 +1,7 @@
 use crate::{SystemInfo, io::*};
 use crate::model::{Draw, GroupObject, MatObject, MeshObject, TexPath, Trans, Vert};
-use crate::scene::{AnimEvent, TransAnim};
+use crate::scene::{AnimEvent, CharBoneSample, CharClipSamples, MeshAnim, Morph, MorphPose, TransAnim};
+use gltf::animation::{Interpolation, Property};
 use gltf::animation::util::ReadOutputs;
 use gltf::buffer::Data as BufferData;
 use gltf::{Document, Gltf, Mesh, Primitive, Scene};
@@ -13,32 +14,69 @@ use gltf::scene::Node;
 use grim_traits::scene::{Blend, Color3, MiloObject, Quat, UV, Vector3, Vector4, ZMode};
 use itertools::{Itertools, izip};
 use nalgebra as na;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::error::Error;
 use std::path::{Path, PathBuf};
+use thiserror::Error as ThisError;
 
 use crate::model::AssetManagager;
 
+#[derive(Debug, ThisError)]
+pub enum GltfImportError {
+    #[error("Frame rate must be greater than 0 (got {fps})")]
+    InvalidFrameRate {
+        fps: f32
+    },
+    #[error("Beats per second must be greater than 0 (got {bps})")]
+    InvalidBeatsPerSecond {
+        bps: f32
+    },
+}
+
+pub struct GltfImportSettings {
+    pub anim_fps: f32, // Frames per second for TransAnim keys + CharClip samples
+    pub beats_per_sec: f32,
+    pub clip_compression: u32,
+}
+
+impl Default for GltfImportSettings {
+    fn default() -> GltfImportSettings {
+        GltfImportSettings {
+            anim_fps: 30.0,
+            beats_per_sec: 2.0, // 120 bpm
+            clip_compression: 2,
+        }
+    }
+}
+
 pub struct GLTFImporter {
     model_path: PathBuf,
+    settings: GltfImportSettings,
     document: Option<Document>,
     buffers: Vec<BufferData>,
     images: Vec<ImageData>,
     mats: Vec<MatObject>,
     node_names: HashMap<usize, String>,
+    morph_targets: HashMap<String, Vec<Vec<Vert>>>, // Full vertex positions of each target
 }
 
 impl GLTFImporter {
     pub fn new<T>(model_path: T) -> Result<GLTFImporter, Box<dyn Error>> where T: AsRef<Path> {
+        GLTFImporter::with_settings(model_path, GltfImportSettings::default())
+    }
+
+    pub fn with_settings<T>(model_path: T, settings: GltfImportSettings) -> Result<GLTFImporter, Box<dyn Error>> where T: AsRef<Path> {
         let (document, buffers, images) = gltf::import(&model_path)?;
 
         Ok(GLTFImporter {
             model_path: model_path.as_ref().to_owned(),
+            settings,
             document: Some(document),
             buffers,
             images,
             mats: Vec::new(),
             node_names: HashMap::new(),
+            morph_targets: HashMap::new(),
         })
     }
 
@@ -98,6 +136,11 @@ impl GLTFImporter {
                 while !meshes.is_empty() {
                     let mut mesh = meshes.remove(0);
                     transform_verts(&mut mesh.vertices); // Update to DX coordinates
+                    mesh.recompute_sphere();
+
+                    for target in self.morph_targets.get_mut(&mesh.name).into_iter().flatten() {
+                        transform_verts(target);
+                    }
 
                     asset_manager.add_mesh(mesh);
                 }
@@ -111,6 +154,15 @@ impl GLTFImporter {
             .map(|n| n.name())
             .collect::<Vec<_>>();
 
+        // Nodes used as joints in skins get animated w/ char clips
+        let joint_nodes = document
+            .skins()
+            .flat_map(|s| s.joints().map(|j| j.index()))
+            .collect::<HashSet<_>>();
+
+        // Key times of vertex anims (in seconds)
+        let mut mesh_anim_times: HashMap<String, Vec<f32>> = HashMap::new();
+
         // Process anims
         for anim in document.animations() {
             let name = anim // .tnm
@@ -133,12 +185,77 @@ impl GLTFImporter {
                 });
 
             let mut anim_count = 0;
+            let mut morph_count = 0;
+            let mut joint_curves = Vec::new();
+
+            for (node_idx, channels) in group_channels.into_iter().sorted_by_key(|(i, _)| *i) {
+                // Morph target weights are handled separately
+                let (weight_channels, channels): (Vec<_>, Vec<_>) = channels
+                    .into_iter()
+                    .partition(|ch| ch.target().property() == Property::MorphTargetWeights);
+
+                for channel in weight_channels {
+                    let Some(mesh_name) = self.node_names.get(&node_idx) else {
+                        continue;
+                    };
+
+                    let target_count = self.morph_targets.get(mesh_name).map(|t| t.len()).unwrap_or_default();
+                    let Some((times, weights)) = self.read_channel_weights(channel, target_count) else {
+                        continue;
+                    };
+
+                    // Vertex anim keys get exported as one-hot weights
+                    if let (Some(key_times), false) = (get_one_hot_key_times(&times, &weights), mesh_anim_times.contains_key(mesh_name)) {
+                        mesh_anim_times.insert(mesh_name.to_owned(), key_times);
+                        continue;
+                    }
+
+                    let morph_name = if morph_count == 0 {
+                        format!("{name}.mrf")
+                    } else {
+                        format!("{name}_{morph_count}.mrf")
+                    };
+                    morph_count += 1;
+
+                    let fps = self.settings.anim_fps;
+                    let morph = Morph {
+                        name: morph_name,
+                        target: mesh_name.to_owned(),
+                        poses: (0..target_count)
+                            .map(|t| MorphPose {
+                                events: times
+                                    .iter()
+                                    .zip(weights.iter())
+                                    .map(|(time, w)| AnimEvent {
+                                        pos: time * fps,
+                                        value: w[t]
+                                    })
+                                    .collect()
+                            })
+                            .collect(),
+                        ..Default::default()
+                    };
+
+                    asset_manager.add_morph(morph);
+                }
 
-            for (node_idx, channels) in group_channels {
-                // Ignore if node doesn't have associated name
-                /*let Some(target_name) = self.node_names.get(&node_idx) else {
+                if channels.is_empty() {
                     continue;
-                };*/
+                }
+
+                if joint_nodes.contains(&node_idx) {
+                    let Some(joint_name) = *all_node_names.get(node_idx).unwrap() else {
+                        continue;
+                    };
+
+                    let curves = channels
+                        .iter()
+                        .map(|ch| self.read_channel_curves(ch))
+                        .fold(NodeCurves::default(), |acc, c| acc.merge(c));
+
+                    joint_curves.push((joint_name.to_owned(), curves));
+                    continue;
+                }
 
                 // Fallback on actual node name if mesh not found
                 let Some(target_name) = self.node_names.get(&node_idx).map(|n| n.as_str()).or_else(|| *all_node_names.get(node_idx).unwrap()) else {
@@ -152,55 +269,96 @@ impl GLTFImporter {
                 };
                 anim_count += 1;
 
-                //println!("Found {} anim channels for {}", channels.len(), target_name);
-
                 let mut trans_anim = TransAnim {
                     name: anim_name.to_owned(),
                     trans_object: target_name.to_owned(),
                     trans_anim_owner: anim_name,
-                    //trans_spline: true,
-                    //repeat_trans: true,
-                    //scale_spline: true,
-                    //rot_slerp: true,
                     ..Default::default()
                 };
 
-                for channel in channels {
-                    let reader = channel.reader(|buffer| Some(&self.buffers[buffer.index()]));
-                    let inputs = reader.read_inputs().unwrap().collect::<Vec<_>>();
-
-                    match reader.read_outputs().unwrap() {
-                        ReadOutputs::Translations(trans) => {
-                            trans_anim.trans_keys = izip!(inputs.iter(), trans)
-                                .map(|(t, [x, z, y])| AnimEvent {
-                                    pos: (*t * 30.) - 1.0,
-                                    value: Vector3 { x, y, z }
-                                })
-                                .collect();
-                        },
-                        ReadOutputs::Rotations(rots) => {
-                            trans_anim.rot_keys = izip!(inputs.iter(), rots.into_f32())
-                                .map(|(t, [x, z, y, w])| AnimEvent {
-                                    pos: (*t * 30.) - 1.0,
-                                    value: Quat { x, y, z, w }
-                                })
-                                .collect();
-                        },
-                        ReadOutputs::Scales(scales) => {
-                            trans_anim.scale_keys = izip!(inputs.iter(), scales)
-                                .map(|(t, [x, z, y])| AnimEvent {
-                                    pos: (*t * 30.) - 1.0,
-                                    value: Vector3 { x, y, z }
-                                })
-                                .collect();
-                        }
-                        _ => continue
-                    }
+                let fps = self.settings.anim_fps;
+                let curves = channels
+                    .iter()
+                    .map(|ch| self.read_channel_curves(ch))
+                    .fold(NodeCurves::default(), |acc, c| acc.merge(c));
+
+                // Cubic spline tangents are dropped and game interpolates w/ splines instead
+                if let Some(trans) = curves.translation {
+                    trans_anim.trans_spline = trans.interpolation == Interpolation::CubicSpline;
+                    trans_anim.trans_keys = trans
+                        .get_keys()
+                        .map(|(t, [x, z, y])| AnimEvent {
+                            pos: (t * fps) - 1.0,
+                            value: Vector3 { x, y, z }
+                        })
+                        .collect();
+                }
+
+                if let Some(rots) = curves.rotation {
+                    trans_anim.rot_spline = rots.interpolation == Interpolation::CubicSpline;
+                    trans_anim.rot_keys = rots
+                        .get_keys()
+                        .map(|(t, [x, z, y, w])| AnimEvent {
+                            pos: (t * fps) - 1.0,
+                            value: Quat { x, y, z, w }
+                        })
+                        .collect();
+                }
+
+                if let Some(scales) = curves.scale {
+                    trans_anim.scale_spline = scales.interpolation == Interpolation::CubicSpline;
+                    trans_anim.scale_keys = scales
+                        .get_keys()
+                        .map(|(t, [x, z, y])| AnimEvent {
+                            pos: (t * fps) - 1.0,
+                            value: Vector3 { x, y, z }
+                        })
+                        .collect();
                 }
 
                 // Add anim
                 asset_manager.add_trans_anim(trans_anim);
             }
+
+            if !joint_curves.is_empty() {
+                let char_clip = self.create_char_clip(format!("{name}.clp"), joint_curves, &info)?;
+                asset_manager.add_char_clip(char_clip);
+            }
+        }
+
+        // Add morph targets as vertex anims
+        for (mesh_name, targets) in self.morph_targets.drain().sorted_by(|(a, _), (b, _)| a.cmp(b)) {
+            // Other weights are already imported as morphs
+            let Some(key_times) = mesh_anim_times.get(&mesh_name) else {
+                continue;
+            };
+
+            let fps = self.settings.anim_fps;
+
+            let mesh_anim_name = format!("{}.msnm", mesh_name.replace(".mesh", ""));
+
+            let mesh_anim = MeshAnim {
+                name: mesh_anim_name.to_owned(),
+                mesh: mesh_name,
+                vert_point_keys: targets
+                    .into_iter()
+                    .enumerate()
+                    .map(|(i, verts)| AnimEvent {
+                        pos: key_times
+                            .get(i)
+                            .map(|t| t * fps)
+                            .unwrap_or(i as f32),
+                        value: verts
+                            .iter()
+                            .map(|v| Vector3 { x: v.pos.x, y: v.pos.y, z: v.pos.z })
+                            .collect()
+                    })
+                    .collect(),
+                keys_owner: mesh_anim_name,
+                ..Default::default()
+            };
+
+            asset_manager.add_mesh_anim(mesh_anim);
         }
 
         // Add materials to asset manager
@@ -212,6 +370,130 @@ impl GLTFImporter {
         Ok(asset_manager)
     }
 
+    fn read_channel_curves(&self, channel: &gltf::animation::Channel) -> NodeCurves {
+        let reader = channel.reader(|buffer| Some(&self.buffers[buffer.index()]));
+        let interpolation = channel.sampler().interpolation();
+
+        let Some(times) = reader.read_inputs().map(|i| i.collect::<Vec<_>>()) else {
+            return NodeCurves::default();
+        };
+
+        match reader.read_outputs() {
+            Some(ReadOutputs::Translations(trans)) => NodeCurves {
+                translation: Some(ChannelCurve { times, values: trans.collect(), interpolation }),
+                ..Default::default()
+            },
+            Some(ReadOutputs::Rotations(rots)) => NodeCurves {
+                rotation: Some(ChannelCurve { times, values: rots.into_f32().collect(), interpolation }),
+                ..Default::default()
+            },
+            Some(ReadOutputs::Scales(scales)) => NodeCurves {
+                scale: Some(ChannelCurve { times, values: scales.collect(), interpolation }),
+                ..Default::default()
+            },
+            _ => NodeCurves::default()
+        }
+    }
+
+    fn read_channel_weights(&self, channel: &gltf::animation::Channel, target_count: usize) -> Option<(Vec<f32>, Vec<Vec<f32>>)> {
+        let reader = channel.reader(|buffer| Some(&self.buffers[buffer.index()]));
+
+        let times = reader.read_inputs()?.collect::<Vec<_>>();
+        let Some(ReadOutputs::MorphTargetWeights(weights)) = reader.read_outputs() else {
+            return None;
+        };
+
+        let weights = weights.into_f32().collect::<Vec<_>>();
+
+        // Cubic splines store in-tangent, value, out-tangent for each key
+        let (stride, offset) = match channel.sampler().interpolation() {
+            Interpolation::CubicSpline => (target_count * 3, target_count),
+            _ => (target_count, 0),
+        };
+
+        if target_count == 0 || weights.len() != times.len() * stride {
+            return None;
+        }
+
+        let weights = weights
+            .chunks_exact(stride)
+            .map(|w| w[offset..(offset + target_count)].to_vec())
+            .collect();
+
+        Some((times, weights))
+    }
+
+    fn create_char_clip(&self, name: String, joint_curves: Vec<(String, NodeCurves)>, info: &SystemInfo) -> Result<CharClipSamples, Box<dyn Error>> {
+        let fps = self.settings.anim_fps;
+        let bps = self.settings.beats_per_sec;
+
+        if !fps.is_finite() || fps <= 0.0 {
+            return Err(Box::new(GltfImportError::InvalidFrameRate { fps }));
+        }
+
+        if !bps.is_finite() || bps <= 0.0 {
+            return Err(Box::new(GltfImportError::InvalidBeatsPerSecond { bps }));
+        }
+
+        let duration = joint_curves
+            .iter()
+            .flat_map(|(_, c)| [
+                c.translation.as_ref().map(|c| c.get_duration()),
+                c.rotation.as_ref().map(|c| c.get_duration()),
+                c.scale.as_ref().map(|c| c.get_duration()),
+            ])
+            .flatten()
+            .fold(0.0f32, |acc, d| acc.max(d));
+
+        // Resample all curves at fixed rate
+        let sample_count = (duration * fps).round() as usize + 1;
+        let sample_times = (0..sample_count)
+            .map(|i| (i as f32) / fps)
+            .collect::<Vec<_>>();
+
+        let bone_samples = joint_curves
+            .into_iter()
+            .map(|(joint_name, curves)| CharBoneSample {
+                symbol: joint_name,
+                pos: curves.translation.map(|c| (1.0, sample_times
+                    .iter()
+                    .map(|t| {
+                        let [x, y, z] = c.sample(*t);
+                        Vector3 { x, y, z }
+                    })
+                    .collect())),
+                quat: curves.rotation.map(|c| (1.0, sample_times
+                    .iter()
+                    .map(|t| {
+                        let [x, y, z, w] = c.sample(*t);
+                        let q = na::Quaternion::new(w, x, y, z).normalize();
+                        Quat { x: q.i, y: q.j, z: q.k, w: q.w }
+                    })
+                    .collect())),
+                scale: curves.scale.map(|c| (1.0, sample_times
+                    .iter()
+                    .map(|t| {
+                        let [x, y, z] = c.sample(*t);
+                        Vector3 { x, y, z }
+                    })
+                    .collect())),
+                ..Default::default()
+            })
+            .collect::<Vec<_>>();
+
+        let mut char_clip = CharClipSamples {
+            name,
+            start_beat: 0.0,
+            end_beat: duration * bps,
+            beats_per_sec: bps,
+            ..Default::default()
+        };
+
+        char_clip.full.encode_samples(&bone_samples, self.settings.clip_compression, info)?;
+
+        Ok(char_clip)
+    }
+
     pub fn process_materials(&mut self, document: &mut Document, asset_manager: &mut AssetManagager) {
         for doc_mat in document.materials() {
             // Create mat name
@@ -319,6 +601,10 @@ impl GLTFImporter {
 
         for mesh in meshes.iter_mut() {
             transform_verts_with_mat(&mut mesh.vertices, &matrix);
+
+            for target in self.morph_targets.get_mut(&mesh.name).into_iter().flatten() {
+                transform_verts_with_mat(target, &matrix);
+            }
         };
 
         Ok(meshes)
@@ -364,9 +650,11 @@ impl GLTFImporter {
             ])
             .collect();
 
-        // TODO: Figure out what happens if normals are missing...
         let positions = reader.read_positions().unwrap();
-        let normals = reader.read_normals().unwrap();
+        let has_normals = reader.read_normals().is_some();
+        let normals = reader.read_normals()
+            .map(|n| n.collect::<Vec<_>>())
+            .unwrap_or_else(|| (0..positions.len()).map(|_| Default::default()).collect::<Vec<_>>());
         let uvs = reader.read_tex_coords(0)
             .map(|tc| tc.into_f32()
             .collect::<Vec<_>>())
@@ -380,7 +668,7 @@ impl GLTFImporter {
 
         println!("Found {} verts!", verts_interleaved.len());
 
-        let verts = verts_interleaved
+        let mut verts = verts_interleaved
             .map(|(pos, norm, uv)| Vert {
                 pos: Vector4 {
                     x: match pos.get(0) {
@@ -444,7 +732,43 @@ impl GLTFImporter {
             _ => format!("{}_{}.mesh", mesh_name_prefix, prim.index()),
         };
 
-        MeshObject {
+        // Read morph targets as full vertex positions
+        let targets = reader
+            .read_morph_targets()
+            .filter_map(|(positions, _, _)| positions)
+            .map(|deltas| reader
+                .read_positions()
+                .unwrap()
+                .zip(deltas)
+                .map(|(p, d)| Vert {
+                    pos: Vector4 {
+                        x: p[0] + d[0],
+                        y: p[1] + d[1],
+                        z: p[2] + d[2],
+                        ..Vector4::default()
+                    },
+                    ..Vert::default()
+                })
+                .collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+
+        if !targets.is_empty() {
+            self.morph_targets.insert(mesh_name.to_owned(), targets);
+        }
+
+        // Use tangents from file if available
+        let has_tangents = match reader.read_tangents() {
+            Some(tangents) => {
+                for (vert, t) in verts.iter_mut().zip(tangents) {
+                    vert.tangent = Vector4 { x: t[0], y: t[1], z: t[2], w: t[3] };
+                }
+
+                true
+            },
+            None => false,
+        };
+
+        let mut mesh = MeshObject {
             name: mesh_name.to_owned(),
             vertices: verts,
             faces,
@@ -452,38 +776,456 @@ impl GLTFImporter {
             geom_owner: mesh_name,
             parent: String::default(),
             ..MeshObject::default()
+        };
+
+        if !has_normals {
+            mesh.recompute_normals();
         }
+
+        if !has_tangents {
+            mesh.recompute_tangents();
+        }
+
+        mesh
+    }
+}
+
+#[derive(Default)]
+struct NodeCurves {
+    translation: Option<ChannelCurve<3>>,
+    rotation: Option<ChannelCurve<4>>,
+    scale: Option<ChannelCurve<3>>,
+}
+
+impl NodeCurves {
+    fn merge(self, other: NodeCurves) -> NodeCurves {
+        NodeCurves {
+            translation: self.translation.or(other.translation),
+            rotation: self.rotation.or(other.rotation),
+            scale: self.scale.or(other.scale),
+        }
+    }
+}
+
+struct ChannelCurve<const N: usize> {
+    times: Vec<f32>,
+    values: Vec<[f32; N]>, // Cubic splines store in-tangent, value, out-tangent for each key
+    interpolation: Interpolation,
+}
+
+impl<const N: usize> ChannelCurve<N> {
+    fn get_duration(&self) -> f32 {
+        self.times.last().copied().unwrap_or_default()
+    }
+
+    fn get_value(&self, i: usize) -> [f32; N] {
+        match self.interpolation {
+            Interpolation::CubicSpline => self.values[i * 3 + 1],
+            _ => self.values[i],
+        }
+    }
+
+    fn get_keys(&self) -> impl Iterator<Item = (f32, [f32; N])> + '_ {
+        self.times
+            .iter()
+            .enumerate()
+            .map(|(i, t)| (*t, self.get_value(i)))
+    }
+
+    fn sample(&self, time: f32) -> [f32; N] {
+        let (Some(first), Some(last)) = (self.times.first(), self.times.last()) else {
+            return [0.0; N];
+        };
+
+        if time <= *first {
+            return self.get_value(0);
+        } else if time >= *last {
+            return self.get_value(self.times.len() - 1);
+        }
+
+        let i = self.times.partition_point(|t| *t <= time) - 1;
+        let (t0, t1) = (self.times[i], self.times[i + 1]);
+
+        let dt = t1 - t0;
+        let s = (time - t0) / dt;
+
+        let v0 = self.get_value(i);
+        let v1 = self.get_value(i + 1);
+
+        match self.interpolation {
+            Interpolation::Step => v0,
+            Interpolation::Linear => std::array::from_fn(|c| v0[c] + (v1[c] - v0[c]) * s),
+            Interpolation::CubicSpline => {
+                // Hermite spline w/ out-tangent of current key and in-tangent of next key
+                let b0 = self.values[i * 3 + 2];
+                let a1 = self.values[(i + 1) * 3];
+
+                let s2 = s * s;
+                let s3 = s2 * s;
+
+                std::array::from_fn(|c| ((2. * s3) - (3. * s2) + 1.) * v0[c]
+                    + (s3 - (2. * s2) + s) * dt * b0[c]
+                    + ((-2. * s3) + (3. * s2)) * v1[c]
+                    + (s3 - s2) * dt * a1[c])
+            }
+        }
+    }
+}
+
+fn get_one_hot_key_times(times: &[f32], weights: &[Vec<f32>]) -> Option<Vec<f32>> {
+    // Each key fully weights target of same index
+    let is_one_hot = times.len() == weights.len()
+        && weights.iter().all(|w| w.len() == weights.len())
+        && weights
+            .iter()
+            .enumerate()
+            .all(|(k, w)| w
+                .iter()
+                .enumerate()
+                .all(|(t, v)| (v - if k == t { 1.0 } else { 0.0 }).abs() <= f32::EPSILON));
+
+    match is_one_hot {
+        true => Some(times.to_vec()),
+        false => None,
     }
 }
 
 pub(crate) fn transform_verts(verts: &mut Vec<Vert>) {
     let rotate_on_z = na::Matrix4::from_axis_angle(&na::Vector3::z_axis(), std::f32::consts::PI);
+    apply_transform(verts, &(rotate_on_z * super::MILOSPACE_TO_GLSPACE));
+}
+
+fn transform_verts_with_mat(verts: &mut Vec<Vert>, matrix: &[[f32; 4]; 4]) {
+    apply_transform(verts, &na::Matrix4::from(matrix.to_owned()));
+}
+
+fn apply_transform(verts: &mut [Vert], mat: &na::Matrix4<f32>) {
+    let linear = mat.fixed_view::<3, 3>(0, 0).into_owned();
+    let normal_mat = get_normal_matrix(&linear);
 
     for vert in verts.iter_mut() {
+        // Update position
         let Vector4 { x, y, z, .. } = &mut vert.pos;
+        let pos = mat.transform_vector(&na::Vector3::new(*x, *y, *z));
+        (*x, *y, *z) = (pos.x, pos.y, pos.z);
 
-        // Update position
-        let pos = super::MILOSPACE_TO_GLSPACE.transform_vector(&na::Vector3::new(*x, *y, *z));
+        // Update normals + tangents (w is preserved)
+        for (Vector4 { x, y, z, .. }, dir_mat) in [(&mut vert.normals, &normal_mat), (&mut vert.tangent, &linear)] {
+            let dir = dir_mat * na::Vector3::new(*x, *y, *z);
+            let dir = dir.try_normalize(f32::EPSILON).unwrap_or(dir);
+            (*x, *y, *z) = (dir.x, dir.y, dir.z);
+        }
+    }
+}
 
-        // Rotate
-        let pos = rotate_on_z.transform_vector(&pos);
+fn get_normal_matrix(linear: &na::Matrix3<f32>) -> na::Matrix3<f32> {
+    // Non-uniform scale needs inverse-transpose to keep normals perpendicular
+    let scales = linear.column_iter().map(|c| c.norm()).collect::<Vec<_>>();
+    let is_uniform = scales.iter().all(|s| (s - scales[0]).abs() <= 1e-5 * scales[0].max(1.0));
 
-        *x = *pos.get(0).unwrap();
-        *y = *pos.get(1).unwrap();
-        *z = *pos.get(2).unwrap();
+    if is_uniform {
+        return *linear;
     }
+
+    linear
+        .try_inverse()
+        .map(|m| m.transpose())
+        .unwrap_or(*linear)
 }
 
-fn transform_verts_with_mat(verts: &mut Vec<Vert>, matrix: &[[f32; 4]; 4]) {
-    let mat = na::Matrix4::from(matrix.to_owned());
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::Platform;
+    use crate::model::{open_model, open_model_with_settings};
 
-    for vert in verts.iter_mut() {
-        let Vector4 { x, y, z, .. } = &mut vert.pos;
+    fn write_test_gltf(name: &str, json: &str, buffer: &[u8]) -> PathBuf {
+        let dir = std::env::temp_dir().join("grim_gltf_tests");
+        std::fs::create_dir_all(&dir).unwrap();
 
-        // Update position
-        let pos = mat.transform_vector(&na::Vector3::new(*x, *y, *z));
-        *x = *pos.get(0).unwrap();
-        *y = *pos.get(1).unwrap();
-        *z = *pos.get(2).unwrap();
+        std::fs::write(dir.join(format!("{name}.bin")), buffer).unwrap();
+
+        let gltf_path = dir.join(format!("{name}.gltf"));
+        std::fs::write(&gltf_path, json.replace("{BIN}", &format!("{name}.bin"))).unwrap();
+
+        gltf_path
+    }
+
+    fn to_bytes(values: &[f32]) -> Vec<u8> {
+        values.iter().flat_map(|v| v.to_le_bytes()).collect()
+    }
+
+    #[rstest]
+    #[case(true)]
+    #[case(false)]
+    fn import_mesh_tangents(#[case] has_tangents: bool) {
+        let mut buffer = to_bytes(&[
+            0.0, 0.0, 0.0,  1.0, 0.0, 0.0,  0.0, 1.0, 0.0, // Positions
+            0.0, 0.0, 1.0,  0.0, 0.0, 1.0,  0.0, 0.0, 1.0, // Normals
+            0.0, 0.0,  1.0, 0.0,  0.0, 1.0,                // UVs
+            0.0, 1.0, 0.0, -1.0,  0.0, 1.0, 0.0, -1.0,  0.0, 1.0, 0.0, -1.0, // Tangents
+        ]);
+        buffer.extend([0u16, 1, 2, 0].iter().flat_map(|i| i.to_le_bytes()));
+
+        let tangent_attribute = match has_tangents {
+            true => r#", "TANGENT": 3"#,
+            false => "",
+        };
+
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [ { "name": "test", "nodes": [ 0 ] } ],
+            "nodes": [ { "name": "tri", "mesh": 0 } ],
+            "meshes": [ { "name": "tri", "primitives": [ { "attributes": { "POSITION": 0, "NORMAL": 1, "TEXCOORD_0": 2{TANGENT} }, "indices": 4 } ] } ],
+            "buffers": [ { "uri": "{BIN}", "byteLength": 152 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 36, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 72, "byteLength": 24 },
+                { "buffer": 0, "byteOffset": 96, "byteLength": 48 },
+                { "buffer": 0, "byteOffset": 144, "byteLength": 8 }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [ 0.0, 0.0, 0.0 ], "max": [ 1.0, 1.0, 0.0 ] },
+                { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3" },
+                { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC2" },
+                { "bufferView": 3, "componentType": 5126, "count": 3, "type": "VEC4" },
+                { "bufferView": 4, "componentType": 5123, "count": 3, "type": "SCALAR" }
+            ]
+        }"#.replace("{TANGENT}", tangent_attribute);
+
+        let gltf_path = write_test_gltf(&format!("tangents_{has_tangents}"), &json, &buffer);
+        let assets = open_model(&gltf_path, SystemInfo::default()).unwrap();
+        let mesh = assets.get_mesh("tri.mesh").unwrap();
+
+        // Tangents from file are kept, otherwise computed from uvs (both converted to milo space)
+        let expected = match has_tangents {
+            true => [0.0, 0.0, 1.0, -1.0],
+            false => [1.0, 0.0, 0.0, 1.0],
+        };
+
+        for vert in mesh.vertices.iter() {
+            let Vector4 { x, y, z, w } = vert.tangent;
+            assert_vec_eq(&expected, &[x, y, z, w]);
+
+            let Vector4 { x, y, z, .. } = vert.normals;
+            assert_vec_eq(&[0.0, -1.0, 0.0], &[x, y, z]);
+        }
+    }
+
+    #[rstest]
+    #[case([1.0, 0.0, 0.0, 1.0], true)]
+    #[case([1.0, 0.0, 0.5, 0.5], false)]
+    fn import_morph_target_anims(#[case] weights: [f32; 4], #[case] is_mesh_anim: bool) {
+        let mut buffer = to_bytes(&[
+            0.0, 0.0, 0.0,  1.0, 0.0, 0.0,  0.0, 1.0, 0.0, // Positions
+            0.0, 0.0, 1.0,  0.0, 0.0, 1.0,  0.0, 0.0, 1.0, // Target 0
+            0.0, 0.0, 2.0,  0.0, 0.0, 2.0,  0.0, 0.0, 2.0, // Target 1
+            0.0, 1.0, // Times
+        ]);
+        buffer.extend(to_bytes(&weights));
+        buffer.extend([0u16, 1, 2, 0].iter().flat_map(|i| i.to_le_bytes()));
+
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [ { "name": "test", "nodes": [ 0 ] } ],
+            "nodes": [ { "name": "tri", "mesh": 0 } ],
+            "meshes": [ { "name": "tri", "primitives": [ { "attributes": { "POSITION": 0 }, "targets": [ { "POSITION": 1 }, { "POSITION": 2 } ], "indices": 5 } ] } ],
+            "animations": [ {
+                "name": "face",
+                "channels": [ { "sampler": 0, "target": { "node": 0, "path": "weights" } } ],
+                "samplers": [ { "input": 3, "output": 4, "interpolation": "LINEAR" } ]
+            } ],
+            "buffers": [ { "uri": "{BIN}", "byteLength": 140 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 36, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 72, "byteLength": 36 },
+                { "buffer": 0, "byteOffset": 108, "byteLength": 8 },
+                { "buffer": 0, "byteOffset": 116, "byteLength": 16 },
+                { "buffer": 0, "byteOffset": 132, "byteLength": 8 }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 3, "type": "VEC3", "min": [ 0.0, 0.0, 0.0 ], "max": [ 1.0, 1.0, 0.0 ] },
+                { "bufferView": 1, "componentType": 5126, "count": 3, "type": "VEC3", "min": [ 0.0, 0.0, 1.0 ], "max": [ 0.0, 0.0, 1.0 ] },
+                { "bufferView": 2, "componentType": 5126, "count": 3, "type": "VEC3", "min": [ 0.0, 0.0, 2.0 ], "max": [ 0.0, 0.0, 2.0 ] },
+                { "bufferView": 3, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [ 0.0 ], "max": [ 1.0 ] },
+                { "bufferView": 4, "componentType": 5126, "count": 4, "type": "SCALAR" },
+                { "bufferView": 5, "componentType": 5123, "count": 3, "type": "SCALAR" }
+            ]
+        }"#;
+
+        let gltf_path = write_test_gltf(&format!("morph_targets_{is_mesh_anim}"), json, &buffer);
+        let assets = open_model(&gltf_path, SystemInfo::default()).unwrap();
+
+        // Blended weights are imported as morph only
+        assert_eq!(is_mesh_anim, assets.get_mesh_anim("tri.msnm").is_some());
+        assert_eq!(!is_mesh_anim, assets.get_morph("face.mrf").is_some());
+    }
+
+    fn assert_vec_eq(expected: &[f32], actual: &[f32]) {
+        for (e, a) in expected.iter().zip(actual) {
+            assert!((e - a).abs() < 1e-5, "expected {expected:?}, got {actual:?}");
+        }
+    }
+
+    #[rstest]
+    #[case([1.0, 1.0, 1.0], [0.6, 0.8, 0.0])]
+    #[case([2.0, 2.0, 2.0], [0.6, 0.8, 0.0])]
+    #[case([1.0, 4.0, 1.0], [0.9486833, 0.31622776, 0.0])]
+    fn transform_verts_with_mat_normals(#[case] scale: [f32; 3], #[case] expected_normal: [f32; 3]) {
+        let matrix = [
+            [scale[0], 0.0, 0.0, 0.0],
+            [0.0, scale[1], 0.0, 0.0],
+            [0.0, 0.0, scale[2], 0.0],
+            [0.0, 0.0, 0.0, 1.0],
+        ];
+
+        let mut verts = vec![Vert {
+            pos: Vector4 { x: 1.0, y: 1.0, z: 1.0, w: 1.0 },
+            normals: Vector4 { x: 0.6, y: 0.8, z: 0.0, w: 0.0 },
+            tangent: Vector4 { x: 1.0, y: 0.0, z: 0.0, w: -1.0 },
+            ..Default::default()
+        }];
+
+        transform_verts_with_mat(&mut verts, &matrix);
+
+        let Vert { pos, normals, tangent, .. } = &verts[0];
+        assert_vec_eq(&scale, &[pos.x, pos.y, pos.z]);
+        assert_vec_eq(&expected_normal, &[normals.x, normals.y, normals.z]);
+        assert_vec_eq(&[1.0, 0.0, 0.0, -1.0], &[tangent.x, tangent.y, tangent.z, tangent.w]);
+    }
+
+    fn write_skinned_anim_gltf(name: &str) -> PathBuf {
+        let buffer = to_bytes(&[
+            0.0, 1.0,                     // Key times
+            0.0, 0.0, 0.0,  3.0, 6.0, 9.0, // Translations
+        ]);
+
+        let json = r#"{
+            "asset": { "version": "2.0" },
+            "scenes": [ { "name": "test", "nodes": [ 0 ] } ],
+            "nodes": [ { "name": "bone_pelvis.mesh" } ],
+            "skins": [ { "joints": [ 0 ] } ],
+            "animations": [ {
+                "name": "walk",
+                "channels": [ { "sampler": 0, "target": { "node": 0, "path": "translation" } } ],
+                "samplers": [ { "input": 0, "output": 1, "interpolation": "LINEAR" } ]
+            } ],
+            "buffers": [ { "uri": "{BIN}", "byteLength": 32 } ],
+            "bufferViews": [
+                { "buffer": 0, "byteOffset": 0, "byteLength": 8 },
+                { "buffer": 0, "byteOffset": 8, "byteLength": 24 }
+            ],
+            "accessors": [
+                { "bufferView": 0, "componentType": 5126, "count": 2, "type": "SCALAR", "min": [ 0.0 ], "max": [ 1.0 ] },
+                { "bufferView": 1, "componentType": 5126, "count": 2, "type": "VEC3" }
+            ]
+        }"#;
+
+        write_test_gltf(name, json, &buffer)
+    }
+
+    #[rstest]
+    fn import_skinned_anim_char_clip() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::PS3,
+            endian: IOEndian::Big,
+        };
+
+        let settings = GltfImportSettings {
+            anim_fps: 10.0,
+            beats_per_sec: 2.0,
+            clip_compression: 2,
+        };
+
+        let gltf_path = write_skinned_anim_gltf("skinned_anim");
+        let assets = open_model_with_settings(&gltf_path, info, settings).unwrap();
+        let char_clip = assets.get_char_clip("walk.clp").unwrap();
+
+        assert_eq!(0.0, char_clip.start_beat);
+        assert_eq!(2.0, char_clip.end_beat);
+        assert_eq!(2.0, char_clip.beats_per_sec);
+        assert!(assets.get_trans_anim("walk.tnm").is_none());
+
+        let decoded = char_clip.full.decode_samples(&info).unwrap();
+        assert_eq!(1, decoded.len());
+        assert_eq!("bone_pelvis.mesh", decoded[0].symbol);
+        assert!(decoded[0].quat.is_none());
+        assert!(decoded[0].scale.is_none());
+
+        // Resampled at 10 fps over 1 second
+        let (_, positions) = decoded[0].pos.as_ref().unwrap();
+        assert_eq!(11, positions.len());
+
+        for (i, pos) in positions.iter().enumerate() {
+            let t = i as f32 / 10.0;
+
+            for (actual, expected) in [(pos.x, t * 3.0), (pos.y, t * 6.0), (pos.z, t * 9.0)] {
+                assert!((actual - expected).abs() <= 0.05, "{actual} != {expected}");
+            }
+        }
     }
-}
\ No newline at end of file
+
+    #[rstest]
+    #[case(0.0, 2.0)]
+    #[case(-30.0, 2.0)]
+    #[case(30.0, 0.0)]
+    #[case(30.0, -2.0)]
+    fn import_skinned_anim_invalid_rate(#[case] anim_fps: f32, #[case] beats_per_sec: f32) {
+        let settings = GltfImportSettings {
+            anim_fps,
+            beats_per_sec,
+            clip_compression: 2,
+        };
+
+        let gltf_path = write_skinned_anim_gltf(&format!("skinned_anim_{anim_fps}_{beats_per_sec}"));
+        let result = open_model_with_settings(&gltf_path, SystemInfo::default(), settings);
+        assert!(result.is_err_and(|e| e.is::<GltfImportError>()));
+    }
+
+    #[rstest]
+    #[case(vec![0.0, 1.0], vec![vec![1.0, 0.0], vec![0.0, 1.0]], Some(vec![0.0, 1.0]))]
+    #[case(vec![0.0, 1.0], vec![vec![1.0, 0.0], vec![0.5, 0.5]], None)]
+    #[case(vec![0.0, 1.0, 2.0], vec![vec![1.0, 0.0], vec![0.0, 1.0], vec![0.0, 0.0]], None)]
+    fn get_one_hot_key_times_test(#[case] times: Vec<f32>, #[case] weights: Vec<Vec<f32>>, #[case] expected: Option<Vec<f32>>) {
+        assert_eq!(expected, get_one_hot_key_times(&times, &weights));
+    }
+
+    #[rstest]
+    #[case(Interpolation::Linear, -1.0, [0.0])]
+    #[case(Interpolation::Linear, 0.5, [1.0])]
+    #[case(Interpolation::Linear, 1.5, [3.0])]
+    #[case(Interpolation::Linear, 3.0, [4.0])]
+    #[case(Interpolation::Step, 0.5, [0.0])]
+    #[case(Interpolation::Step, 1.5, [2.0])]
+    fn channel_curve_sample(#[case] interpolation: Interpolation, #[case] time: f32, #[case] expected: [f32; 1]) {
+        let curve = ChannelCurve {
+            times: vec![0.0, 1.0, 2.0],
+            values: vec![[0.0], [2.0], [4.0]],
+            interpolation,
+        };
+
+        assert_eq!(expected, curve.sample(time));
+    }
+
+    #[rstest]
+    fn channel_curve_sample_cubic_spline() {
+        // (in-tangent, value, out-tangent) per key
+        let curve = ChannelCurve {
+            times: vec![0.0, 2.0],
+            values: vec![
+                [0.0], [0.0], [1.0],
+                [1.0], [2.0], [0.0],
+            ],
+            interpolation: Interpolation::CubicSpline,
+        };
+
+        assert_eq!(vec![(0.0, [0.0]), (2.0, [2.0])], curve.get_keys().collect::<Vec<_>>());
+        assert_eq!([0.0], curve.sample(0.0));
+        assert_eq!([1.0], curve.sample(1.0));
+        assert_eq!([2.0], curve.sample(2.0));
+    }
+}
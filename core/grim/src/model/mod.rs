@@ -5,6 +5,7 @@ mod gltf;
 //mod group;
 //mod mat;
 //mod mesh;
+mod skeleton;
 mod tex_path;
 //mod trans;
 
@@ -14,7 +15,9 @@ use std::{error::Error, fs::copy, path::Path};
 pub use self::export::*; // TODO: Remove later
 use crate::SystemInfo;
 use crate::scene::*;
+pub use self::gltf::GltfImportSettings;
 pub(crate) use self::gltf::*;
+pub use self::skeleton::*;
 pub use self::tex_path::*;
 
 pub(crate) const MILOSPACE_TO_GLSPACE: nalgebra::Matrix4<f32> = nalgebra::Matrix4::new(
@@ -26,8 +29,11 @@ pub(crate) const MILOSPACE_TO_GLSPACE: nalgebra::Matrix4<f32> = nalgebra::Matrix
 
 pub struct AssetManagager {
     info: SystemInfo,
+    char_clips: Vec<CharClipSamples>,
     groups: Vec<GroupObject>,
     meshes: Vec<MeshObject>,
+    mesh_anims: Vec<MeshAnim>,
+    morphs: Vec<Morph>,
     materials: Vec<MatObject>,
     textures: Vec<TexPath>,
     trans_anims: Vec<TransAnim>,
@@ -37,14 +43,21 @@ impl AssetManagager {
     pub fn new(info: SystemInfo) -> AssetManagager {
         AssetManagager {
             info,
+            char_clips: Vec::new(),
             groups: Vec::new(),
             meshes: Vec::new(),
+            mesh_anims: Vec::new(),
+            morphs: Vec::new(),
             materials: Vec::new(),
             textures: Vec::new(),
             trans_anims: Vec::new(),
         }
     }
 
+    pub fn get_char_clip(&self, name: &str) -> Option<&CharClipSamples> {
+        self.char_clips.iter().find(|c| c.name.eq(name))
+    }
+
     pub fn get_group(&self, name: &str) -> Option<&GroupObject> {
         self.groups.iter().find(|g| g.name.eq(name))
     }
@@ -53,6 +66,14 @@ impl AssetManagager {
         self.meshes.iter().find(|m| m.name.eq(name))
     }
 
+    pub fn get_mesh_anim(&self, name: &str) -> Option<&MeshAnim> {
+        self.mesh_anims.iter().find(|m| m.name.eq(name))
+    }
+
+    pub fn get_morph(&self, name: &str) -> Option<&Morph> {
+        self.morphs.iter().find(|m| m.name.eq(name))
+    }
+
     pub fn get_material(&self, name: &str) -> Option<&MatObject> {
         self.materials.iter().find(|m| m.name.eq(name))
     }
@@ -65,6 +86,10 @@ impl AssetManagager {
         self.trans_anims.iter().find(|t| t.name.eq(name))
     }
 
+    pub fn add_char_clip(&mut self, char_clip: CharClipSamples) {
+        self.char_clips.push(char_clip);
+    }
+
     pub fn add_group(&mut self, group: GroupObject) {
         self.groups.push(group);
     }
@@ -73,6 +98,14 @@ impl AssetManagager {
         self.meshes.push(mesh);
     }
 
+    pub fn add_mesh_anim(&mut self, mesh_anim: MeshAnim) {
+        self.mesh_anims.push(mesh_anim);
+    }
+
+    pub fn add_morph(&mut self, morph: Morph) {
+        self.morphs.push(morph);
+    }
+
     pub fn add_material(&mut self, mat: MatObject) {
         self.materials.push(mat);
     }
@@ -129,6 +162,30 @@ impl AssetManagager {
             println!("Wrote {}", &trans_anim.name);
         }
 
+        // Iterate mesh anims
+        for mesh_anim in self.mesh_anims.iter() {
+            // Write mesh anim
+            let mesh_anim_path = out_dir.as_ref().join(&mesh_anim.name);
+            save_to_file(mesh_anim, &mesh_anim_path, &self.info)?;
+            println!("Wrote {}", &mesh_anim.name);
+        }
+
+        // Iterate morphs
+        for morph in self.morphs.iter() {
+            // Write morph
+            let morph_path = out_dir.as_ref().join(&morph.name);
+            save_to_file(morph, &morph_path, &self.info)?;
+            println!("Wrote {}", &morph.name);
+        }
+
+        // Iterate char clips
+        for char_clip in self.char_clips.iter() {
+            // Write char clip
+            let char_clip_path = out_dir.as_ref().join(&char_clip.name);
+            save_to_file(char_clip, &char_clip_path, &self.info)?;
+            println!("Wrote {}", &char_clip.name);
+        }
+
         Ok(())
     }
 }
@@ -145,10 +202,14 @@ pub(crate) fn create_dir_if_not_exists<T>(dir_path: T) -> Result<(), Box<dyn Err
 }
 
 pub fn open_model<T>(model_path: T, info: SystemInfo) -> Result<AssetManagager, Box<dyn Error>> where T: AsRef<Path> {
+    open_model_with_settings(model_path, info, GltfImportSettings::default())
+}
+
+pub fn open_model_with_settings<T>(model_path: T, info: SystemInfo, settings: GltfImportSettings) -> Result<AssetManagager, Box<dyn Error>> where T: AsRef<Path> {
     // Check if path exists first
     verify_path_exists(&model_path, Some("model_path"))?;
 
-    let mut gltf_importer = GLTFImporter::new(&model_path)?;
+    let mut gltf_importer = GLTFImporter::with_settings(&model_path, settings)?;
     gltf_importer.process(info)
 }
 
@@ -0,0 +1,442 @@
+use crate::scene::*;
+use nalgebra as na;
+use std::collections::{HashMap, HashSet};
+
+pub struct SkeletonBone {
+    pub name: String,
+    pub parent: Option<usize>,
+    pub children: Vec<usize>,
+    pub has_object: bool, // False if only referenced by mesh
+    pub local_bind: na::Matrix4<f32>,
+    pub world_bind: na::Matrix4<f32>,
+    pub inverse_bind: na::Matrix4<f32>,
+    pub local_pose: na::Matrix4<f32>,
+    pub world_pose: na::Matrix4<f32>,
+}
+
+// Bones are stored flat w/ parents always before children
+#[derive(Default)]
+pub struct Skeleton {
+    pub name: String,
+    bones: Vec<SkeletonBone>,
+    bone_map: HashMap<String, usize>,
+}
+
+struct BoneInfo<'a> {
+    parent: &'a str,
+    local_xfm: Option<&'a Matrix>,
+}
+
+pub(crate) fn matrix_to_na(m: &Matrix) -> na::Matrix4<f32> {
+    na::Matrix4::new(
+        // Column-major order...
+        m.m11, m.m21, m.m31, m.m41,
+        m.m12, m.m22, m.m32, m.m42,
+        m.m13, m.m23, m.m33, m.m43,
+        m.m14, m.m24, m.m34, m.m44
+    )
+}
+
+fn compose_trs(translate: &na::Vector3<f32>, rotation: &na::UnitQuaternion<f32>, scale: &na::Vector3<f32>) -> na::Matrix4<f32> {
+    na::Matrix4::new_translation(translate)
+        * rotation.to_homogeneous()
+        * na::Matrix4::new_nonuniform_scaling(scale)
+}
+
+fn get_sample_value<T>(values: &Option<(f32, Vec<T>)>, i: usize) -> Option<&T> {
+    // Use last sample if out of range
+    values
+        .as_ref()
+        .and_then(|(_, v)| v.get(i).or_else(|| v.last()))
+}
+
+pub fn get_sample_rotation(sample: &CharBoneSample, i: usize, base_rotation: na::UnitQuaternion<f32>) -> na::UnitQuaternion<f32> {
+    let mut rotation = get_sample_value(&sample.quat, i)
+        .map(|q| na::UnitQuaternion::from_quaternion(na::Quaternion::new(q.w, q.x, q.y, q.z)))
+        .unwrap_or(base_rotation);
+
+    // Euler rotations are applied on top of quat (or base rotation if no quat)
+    let euler_rots = [
+        (na::Vector3::x_axis(), &sample.rotx),
+        (na::Vector3::y_axis(), &sample.roty),
+        (na::Vector3::z_axis(), &sample.rotz),
+    ];
+
+    for (axis, rot) in euler_rots.iter() {
+        if let Some(r) = get_sample_value(rot, i) {
+            rotation *= na::UnitQuaternion::from_axis_angle(axis, std::f32::consts::PI * r);
+        }
+    }
+
+    rotation
+}
+
+pub fn get_sample_transform(sample: &CharBoneSample, i: usize, local_bind: na::Matrix4<f32>) -> na::Matrix4<f32> {
+    let (mut translate, rotation, mut scale) = super::export::decompose_trs(local_bind);
+
+    if let Some(p) = get_sample_value(&sample.pos, i) {
+        translate = na::Vector3::new(p.x, p.y, p.z);
+    }
+
+    if let Some(s) = get_sample_value(&sample.scale, i) {
+        scale = na::Vector3::new(s.x, s.y, s.z);
+    }
+
+    let rotation = get_sample_rotation(sample, i, rotation);
+    compose_trs(&translate, &rotation, &scale)
+}
+
+impl Skeleton {
+    pub fn from_object_dir(obj_dir: &ObjectDir) -> Skeleton {
+        let dir_name = match obj_dir {
+            ObjectDir::ObjectDir(base) => base.name.as_str(),
+        };
+
+        let mut bone_infos = HashMap::new();
+        let mut inverse_binds = HashMap::new();
+
+        for obj in obj_dir.get_entries() {
+            let trans = match obj {
+                Object::Mesh(m) if m.faces.is_empty() // GH1 bones
+                    => m as &dyn Trans,
+                Object::Trans(t) => t as &dyn Trans,
+                Object::Mesh(m) => {
+                    // First mesh offset found is used as inverse bind
+                    for bone in m.bones.iter().filter(|b| !b.name.is_empty()) {
+                        inverse_binds
+                            .entry(bone.name.as_str())
+                            .or_insert(&bone.trans);
+                    }
+
+                    continue;
+                },
+                _ => continue
+            };
+
+            bone_infos.insert(trans.get_name().as_str(), BoneInfo {
+                parent: trans.get_parent().as_str(),
+                local_xfm: Some(trans.get_local_xfm()),
+            });
+        }
+
+        // Add bones only referenced by meshes
+        for name in inverse_binds.keys() {
+            bone_infos
+                .entry(*name)
+                .or_insert(BoneInfo {
+                    parent: "",
+                    local_xfm: None,
+                });
+        }
+
+        // Map parent to children
+        let mut child_map: HashMap<&str, Vec<&str>> = HashMap::new();
+        let mut root_names = Vec::new();
+
+        for (name, info) in bone_infos.iter() {
+            if info.parent.is_empty()
+                || info.parent.eq(*name)
+                || info.parent.eq(dir_name)
+                || !bone_infos.contains_key(info.parent) {
+                root_names.push(*name);
+                continue;
+            }
+
+            child_map
+                .entry(info.parent)
+                .or_default()
+                .push(*name);
+        }
+
+        for children in child_map.values_mut() {
+            children.sort();
+        }
+
+        root_names.sort();
+
+        let mut skeleton = Skeleton {
+            name: dir_name.to_owned(),
+            ..Default::default()
+        };
+
+        let mut visited = HashSet::new();
+
+        for root_name in root_names {
+            skeleton.add_bone_hierarchy(root_name, None, &bone_infos, &child_map, &mut visited);
+        }
+
+        // Bones in cyclic hierarchies are never reached from roots
+        let mut remaining = bone_infos
+            .keys()
+            .filter(|n| !visited.contains(*n))
+            .copied()
+            .collect::<Vec<_>>();
+
+        remaining.sort();
+
+        for name in remaining {
+            if !visited.contains(name) {
+                skeleton.add_bone_hierarchy(name, None, &bone_infos, &child_map, &mut visited);
+            }
+        }
+
+        // Compute bind transforms
+        for i in 0..skeleton.bones.len() {
+            let parent_world = skeleton.bones[i].parent
+                .map(|p| skeleton.bones[p].world_bind)
+                .unwrap_or_else(na::Matrix4::identity);
+
+            let bone = &mut skeleton.bones[i];
+            bone.world_bind = parent_world * bone.local_bind;
+            bone.world_pose = bone.world_bind;
+
+            bone.inverse_bind = inverse_binds
+                .get(bone.name.as_str())
+                .map(|m| matrix_to_na(m))
+                .or_else(|| bone.world_bind.try_inverse())
+                .unwrap_or_else(na::Matrix4::identity);
+        }
+
+        skeleton
+    }
+
+    fn add_bone_hierarchy<'a>(&mut self, name: &'a str, parent: Option<usize>, bone_infos: &HashMap<&'a str, BoneInfo>, child_map: &HashMap<&str, Vec<&'a str>>, visited: &mut HashSet<&'a str>) {
+        if !visited.insert(name) {
+            return;
+        }
+
+        let local_xfm = bone_infos
+            .get(name)
+            .and_then(|b| b.local_xfm);
+
+        let local_bind = local_xfm
+            .map(matrix_to_na)
+            .unwrap_or_else(na::Matrix4::identity);
+
+        let index = self.bones.len();
+        self.bones.push(SkeletonBone {
+            name: name.to_owned(),
+            parent,
+            children: Vec::new(),
+            has_object: local_xfm.is_some(),
+            local_bind,
+            world_bind: na::Matrix4::identity(),
+            inverse_bind: na::Matrix4::identity(),
+            local_pose: local_bind,
+            world_pose: na::Matrix4::identity(),
+        });
+        self.bone_map.insert(name.to_owned(), index);
+
+        if let Some(parent) = parent {
+            self.bones[parent].children.push(index);
+        }
+
+        for child_name in child_map.get(name).into_iter().flatten() {
+            self.add_bone_hierarchy(child_name, Some(index), bone_infos, child_map, visited);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bones.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bones.is_empty()
+    }
+
+    pub fn get_bones(&self) -> &[SkeletonBone] {
+        &self.bones
+    }
+
+    pub fn get_bone(&self, name: &str) -> Option<&SkeletonBone> {
+        self.get_bone_index(name).map(|i| &self.bones[i])
+    }
+
+    pub fn get_bone_index(&self, name: &str) -> Option<usize> {
+        self.bone_map.get(name).copied()
+    }
+
+    pub fn get_root_indices(&self) -> Vec<usize> {
+        self.bones
+            .iter()
+            .enumerate()
+            .filter(|(_, b)| b.parent.is_none())
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    pub fn get_world_transform(&self, name: &str) -> Option<na::Matrix4<f32>> {
+        self.get_bone(name).map(|b| b.world_pose)
+    }
+
+    pub fn get_skinning_transform(&self, name: &str) -> Option<na::Matrix4<f32>> {
+        // Transforms mesh space verts from bind pose to current pose
+        self.get_bone(name).map(|b| b.world_pose * b.inverse_bind)
+    }
+
+    pub fn compute_world_transforms(&mut self) {
+        // Parents always come before children so single pass is enough
+        for i in 0..self.bones.len() {
+            let parent_world = self.bones[i].parent
+                .map(|p| self.bones[p].world_pose)
+                .unwrap_or_else(na::Matrix4::identity);
+
+            let bone = &mut self.bones[i];
+            bone.world_pose = parent_world * bone.local_pose;
+        }
+    }
+
+    pub fn reset_pose(&mut self) {
+        for bone in self.bones.iter_mut() {
+            bone.local_pose = bone.local_bind;
+        }
+
+        self.compute_world_transforms();
+    }
+
+    pub fn set_local_pose(&mut self, name: &str, local_pose: na::Matrix4<f32>) -> bool {
+        let Some(index) = self.get_bone_index(name) else {
+            return false;
+        };
+
+        self.bones[index].local_pose = local_pose;
+        true
+    }
+
+    pub fn apply_samples(&mut self, samples: &[CharBoneSample], i: usize) {
+        for bone in self.bones.iter_mut() {
+            bone.local_pose = bone.local_bind;
+        }
+
+        for sample in samples {
+            let Some(index) = self.get_bone_index(&sample.symbol) else {
+                continue;
+            };
+
+            let bone = &mut self.bones[index];
+            bone.local_pose = get_sample_transform(sample, i, bone.local_bind);
+        }
+
+        self.compute_world_transforms();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    fn create_trans(name: &str, parent: &str, pos: [f32; 3]) -> Object {
+        let mut trans = TransObject {
+            name: name.to_owned(),
+            parent: parent.to_owned(),
+            ..Default::default()
+        };
+
+        let [x, y, z] = pos;
+        trans.local_xfm.m41 = x;
+        trans.local_xfm.m42 = y;
+        trans.local_xfm.m43 = z;
+
+        Object::Trans(trans)
+    }
+
+    fn create_skeleton() -> Skeleton {
+        let mut obj_dir = ObjectDir::ObjectDir(ObjectDirBase {
+            name: String::from("char"),
+            ..ObjectDirBase::new()
+        });
+
+        let entries = obj_dir.get_entries_mut();
+        entries.push(create_trans("bone_spine.mesh", "bone_pelvis.mesh", [0.0, 0.0, 10.0]));
+        entries.push(create_trans("bone_pelvis.mesh", "char", [0.0, 0.0, 5.0]));
+        entries.push(create_trans("bone_head.mesh", "bone_spine.mesh", [0.0, 2.0, 0.0]));
+        entries.push(create_trans("bone_prop.mesh", "", [1.0, 0.0, 0.0]));
+
+        let mut mesh = MeshObject {
+            name: String::from("body.mesh"),
+            faces: vec![[0, 1, 2]],
+            ..Default::default()
+        };
+
+        mesh.bones.push(BoneTrans {
+            name: String::from("bone_extra.mesh"),
+            trans: Matrix::identity(),
+        });
+
+        entries.push(Object::Mesh(mesh));
+
+        Skeleton::from_object_dir(&obj_dir)
+    }
+
+    #[rstest]
+    fn skeleton_from_object_dir() {
+        let skeleton = create_skeleton();
+        assert_eq!(5, skeleton.len());
+
+        // Parents always before children
+        for (i, bone) in skeleton.get_bones().iter().enumerate() {
+            if let Some(parent) = bone.parent {
+                assert!(parent < i);
+            }
+        }
+
+        let roots = skeleton
+            .get_root_indices()
+            .into_iter()
+            .map(|i| skeleton.get_bones()[i].name.as_str())
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec!["bone_extra.mesh", "bone_pelvis.mesh", "bone_prop.mesh"], roots);
+
+        let extra = skeleton.get_bone("bone_extra.mesh").unwrap();
+        assert!(!extra.has_object);
+    }
+
+    #[rstest]
+    #[case("bone_pelvis.mesh", [0.0, 0.0, 5.0])]
+    #[case("bone_spine.mesh", [0.0, 0.0, 15.0])]
+    #[case("bone_head.mesh", [0.0, 2.0, 15.0])]
+    #[case("bone_prop.mesh", [1.0, 0.0, 0.0])]
+    fn skeleton_world_bind(#[case] name: &str, #[case] expected: [f32; 3]) {
+        let skeleton = create_skeleton();
+        let world = skeleton.get_world_transform(name).unwrap();
+
+        assert_eq!(na::Vector3::from(expected), world.column(3).xyz());
+
+        // Skinning transform should be identity in bind pose
+        let skin = skeleton.get_skinning_transform(name).unwrap();
+        assert!(skin.is_identity(1e-5));
+    }
+
+    #[rstest]
+    fn skeleton_apply_samples() {
+        let mut skeleton = create_skeleton();
+
+        let samples = vec![
+            CharBoneSample {
+                symbol: String::from("bone_spine.mesh"),
+                pos: Some((1.0, vec![
+                    Vector3 { x: 0.0, y: 0.0, z: 10.0 },
+                    Vector3 { x: 0.0, y: 0.0, z: 20.0 },
+                ])),
+                rotz: Some((1.0, vec![0.0, 0.5])),
+                ..Default::default()
+            },
+        ];
+
+        skeleton.apply_samples(&samples, 1);
+
+        let head = skeleton.get_world_transform("bone_head.mesh").unwrap().column(3).xyz();
+        assert!((head - na::Vector3::new(-2.0, 0.0, 25.0)).magnitude() < 1e-5);
+
+        // Out of range index uses last sample
+        skeleton.apply_samples(&samples, 5);
+        let spine = skeleton.get_world_transform("bone_spine.mesh").unwrap().column(3).xyz();
+        assert_eq!(na::Vector3::new(0.0, 0.0, 25.0), spine);
+
+        skeleton.reset_pose();
+        let spine = skeleton.get_world_transform("bone_spine.mesh").unwrap().column(3).xyz();
+        assert_eq!(na::Vector3::new(0.0, 0.0, 15.0), spine);
+    }
+}
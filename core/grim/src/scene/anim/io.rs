@@ -3,6 +3,15 @@ use crate::scene::*;
 use crate::SystemInfo;
 use grim_traits::scene::*;
 use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum AnimLoadError {
+    #[error("Anim version {version} is not supported")]
+    AnimVersionNotSupported {
+        version: u32
+    },
+}
 
 fn is_version_supported(version: u32) -> bool {
     match version {
@@ -20,16 +29,20 @@ impl ObjectReadWrite for AnimObject {
         Ok(())
     }
 
-    fn save(&self, _stream: &mut dyn Stream, _info: &SystemInfo) -> Result<(), Box<dyn Error>> {
-        todo!()
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        save_anim(self, &mut writer, info, true)?;
+        Ok(())
     }
 }
 
 pub(crate) fn load_anim<T: Anim>(anim: &mut T, reader: &mut Box<BinaryStream>, info: &SystemInfo, read_meta: bool) -> Result<(), Box<dyn Error>> {
     let version = reader.read_uint32()?;
     if !is_version_supported(version) {
-        // TODO: Switch to custom error
-        panic!("Anim version \"{}\" is not supported!", version);
+        return Err(Box::new(AnimLoadError::AnimVersionNotSupported {
+            version
+        }));
     }
 
     if read_meta {
@@ -85,4 +98,116 @@ pub(crate) fn save_anim<T: Anim>(anim: &T, writer: &mut Box<BinaryStream>, info:
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(10, Platform::PS2)]
+    #[case(24, Platform::PS2)]
+    #[case(25, Platform::X360)]
+    fn anim_round_trip(#[case] version: u32, #[case] platform: Platform) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Little,
+        };
+
+        let anim = AnimObject {
+            name: String::from("test.anim"),
+            anim_objects: match version {
+                10 => vec![String::from("a.tnm"), String::from("b.tnm")],
+                _ => Vec::new(),
+            },
+            frame: match version {
+                10 => 0.0,
+                _ => 30.0,
+            },
+            rate: AnimRate::k30_fps_ui,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        anim.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = AnimObject::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(anim.anim_objects, result.anim_objects);
+        assert_eq!(anim.frame, result.frame);
+
+        if version != 10 {
+            assert_eq!(anim.rate as u32, result.rate as u32);
+        }
+    }
+
+    #[rstest]
+    fn load_gh1_anim_bytes() {
+        let info = SystemInfo {
+            version: 10,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x00, // Version (0)
+            0x00, 0x00, 0x00, 0x00, // Anim entry count
+            0x02, 0x00, 0x00, 0x00, // Anim object count
+            0x05, 0x00, 0x00, 0x00, b'a', b'.', b't', b'n', b'm',
+            0x05, 0x00, 0x00, 0x00, b'b', b'.', b't', b'n', b'm',
+        ];
+
+        let anim: AnimObject = assert_round_trip_bytes(&data, &info);
+        assert_eq!(vec!["a.tnm", "b.tnm"], anim.anim_objects);
+    }
+
+    #[rstest]
+    fn load_anim_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x04, // Version (4)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x41, 0xF0, 0x00, 0x00, // Frame (30.0)
+            0x00, 0x00, 0x00, 0x03, // Rate (k1_fpb)
+        ];
+
+        let anim: AnimObject = assert_round_trip_bytes(&data, &info);
+        assert_eq!(30.0, anim.frame);
+        assert_eq!(AnimRate::k1_fpb as u32, anim.rate as u32);
+        assert!(anim.anim_objects.is_empty());
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(5)]
+    fn anim_unsupported_version(#[case] version: u32) {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data = version.to_be_bytes().to_vec();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut anim = AnimObject::default();
+        let result = anim.load(&mut stream, &info);
+
+        assert!(result.is_err_and(|e| matches!(e.downcast_ref(), Some(AnimLoadError::AnimVersionNotSupported { version: v }) if *v == version)));
+    }
+}
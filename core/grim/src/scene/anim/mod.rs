@@ -1,8 +1,10 @@
 mod io;
+mod timeline;
 
 use grim_macros::*;
 use grim_traits::scene::*;
 pub use io::*;
+pub use timeline::*;
 
 #[milo(Anim)]
 pub struct AnimObject {}
@@ -0,0 +1,703 @@
+use super::*;
+use crate::dta::DataArray;
+use crate::scene::*;
+use crate::SystemInfo;
+
+const TRANS_POSITION: &str = "position";
+const TRANS_ROTATION: &str = "rotation";
+const TRANS_SCALE: &str = "scale";
+
+const MESH_POINTS: &str = "points";
+const MESH_TEX: &str = "tex";
+const MESH_COLORS: &str = "colors";
+
+const CLIP_TRANSFORMS: [&str; 6] = ["pos", "scale", "quat", "rotx", "roty", "rotz"];
+
+fn to_keys<T, S>(events: &[S], get_key: impl Fn(&S) -> (f32, T)) -> Vec<TimelineKey<T>> {
+    events
+        .iter()
+        .map(|ev| {
+            let (pos, value) = get_key(ev);
+            TimelineKey { pos, value }
+        })
+        .collect()
+}
+
+fn from_keys<T, S>(keys: &[TimelineKey<T>], create_event: impl Fn(f32, &T) -> S) -> Vec<S> {
+    keys
+        .iter()
+        .map(|k| create_event(k.pos, &k.value))
+        .collect()
+}
+
+fn vector3_keys(events: &[AnimEvent<Vector3>]) -> ChannelKeys {
+    ChannelKeys::Vector3(to_keys(events, |ev| (ev.pos, [ev.value.x, ev.value.y, ev.value.z])))
+}
+
+fn vector3_events(keys: &[TimelineKey<[f32; 3]>]) -> Vec<AnimEvent<Vector3>> {
+    from_keys(keys, |pos, [x, y, z]| AnimEvent { pos, value: Vector3 { x: *x, y: *y, z: *z } })
+}
+
+fn quat_events(keys: &[TimelineKey<[f32; 4]>]) -> Vec<AnimEvent<Quat>> {
+    from_keys(keys, |pos, [x, y, z, w]| AnimEvent { pos, value: Quat { x: *x, y: *y, z: *z, w: *w } })
+}
+
+fn sample_positions<T>(positions: &[f32], sample: impl Fn(f32) -> Option<T>) -> Vec<TimelineKey<T>> {
+    positions
+        .iter()
+        .filter_map(|pos| sample(*pos).map(|value| TimelineKey { pos: *pos, value }))
+        .collect()
+}
+
+fn sample_prop_keys(keys: &ChannelKeys, positions: &[f32], interpolation: PropInterpolation) -> Option<ChannelKeys> {
+    let prop_keys = PropKeys {
+        interpolation: interpolation as u32,
+        events: Option::<PropKeysEvents>::from(keys)?,
+        ..Default::default()
+    };
+
+    let sampled = match keys {
+        ChannelKeys::Float(_) => ChannelKeys::Float(sample_positions(positions, |pos| match prop_keys.sample(pos)? {
+            PropKeysValue::Float(v) => Some(v),
+            _ => None,
+        })),
+        ChannelKeys::Vector3(_) => ChannelKeys::Vector3(sample_positions(positions, |pos| match prop_keys.sample(pos)? {
+            PropKeysValue::Vector3(v) => Some([v.x, v.y, v.z]),
+            _ => None,
+        })),
+        ChannelKeys::Quat(_) => ChannelKeys::Quat(sample_positions(positions, |pos| match prop_keys.sample(pos)? {
+            PropKeysValue::Quat(q) => Some([q.x, q.y, q.z, q.w]),
+            _ => None,
+        })),
+        ChannelKeys::Color(_) => ChannelKeys::Color(sample_positions(positions, |pos| match prop_keys.sample(pos)? {
+            PropKeysValue::Color(c) => Some([c.r, c.g, c.b, c.a]),
+            _ => None,
+        })),
+        ChannelKeys::Bool(_) => ChannelKeys::Bool(sample_positions(positions, |pos| match prop_keys.sample(pos)? {
+            PropKeysValue::Bool(v) => Some(v),
+            _ => None,
+        })),
+        ChannelKeys::Symbol(_) => ChannelKeys::Symbol(sample_positions(positions, |pos| match prop_keys.sample(pos)? {
+            PropKeysValue::Symbol(text) => Some(text),
+            _ => None,
+        })),
+        ChannelKeys::Object(_) => ChannelKeys::Object(sample_positions(positions, |pos| match prop_keys.sample(pos)? {
+            PropKeysValue::Object(text1, text2) => Some((text1, text2)),
+            _ => None,
+        })),
+        ChannelKeys::Array(_) => return None,
+    };
+
+    Some(sampled)
+}
+
+// Samples w/ evaluator of source anim type, if any
+pub(super) fn sample_source_keys(keys: &ChannelKeys, positions: &[f32], interpolation: ChannelInterpolation) -> Option<ChannelKeys> {
+    match (keys, interpolation) {
+        (ChannelKeys::Vector3(keys), ChannelInterpolation::Trans { spline, .. }) => {
+            let trans_anim = TransAnim {
+                trans_keys: vector3_events(keys),
+                trans_spline: spline,
+                ..Default::default()
+            };
+
+            Some(ChannelKeys::Vector3(sample_positions(positions, |pos| trans_anim.sample_trans(pos).map(|v| [v.x, v.y, v.z]))))
+        },
+        (ChannelKeys::Quat(keys), ChannelInterpolation::Trans { spline, slerp }) => {
+            let trans_anim = TransAnim {
+                rot_keys: quat_events(keys),
+                rot_spline: spline,
+                rot_slerp: slerp,
+                ..Default::default()
+            };
+
+            Some(ChannelKeys::Quat(sample_positions(positions, |pos| trans_anim.sample_rot(pos).map(|q| [q.x, q.y, q.z, q.w]))))
+        },
+        (_, ChannelInterpolation::Prop(interpolation)) => sample_prop_keys(keys, positions, interpolation),
+        _ => None,
+    }
+}
+
+fn new_timeline<T: Anim>(anim: &T) -> Timeline {
+    Timeline::new(*anim.get_rate())
+}
+
+impl From<&TransAnim> for Timeline {
+    fn from(trans_anim: &TransAnim) -> Timeline {
+        let target = trans_anim.trans_object.as_str();
+        let mut timeline = new_timeline(trans_anim);
+
+        timeline.channels = vec![
+            TimelineChannel {
+                interpolation: ChannelInterpolation::Trans { spline: trans_anim.trans_spline, slerp: false },
+                ..TimelineChannel::new(target, TRANS_POSITION, vector3_keys(&trans_anim.trans_keys))
+            },
+            TimelineChannel {
+                interpolation: ChannelInterpolation::Trans { spline: trans_anim.rot_spline, slerp: trans_anim.rot_slerp },
+                ..TimelineChannel::new(target, TRANS_ROTATION, ChannelKeys::Quat(to_keys(&trans_anim.rot_keys, |ev| (ev.pos, [ev.value.x, ev.value.y, ev.value.z, ev.value.w]))))
+            },
+            TimelineChannel {
+                interpolation: ChannelInterpolation::Trans { spline: trans_anim.scale_spline, slerp: false },
+                ..TimelineChannel::new(target, TRANS_SCALE, vector3_keys(&trans_anim.scale_keys))
+            },
+        ];
+
+        timeline
+    }
+}
+
+impl TransAnim {
+    pub fn apply_timeline(&mut self, timeline: &Timeline) {
+        self.rate = timeline.rate;
+
+        for channel in timeline.channels.iter().filter(|c| c.target.eq(&self.trans_object)) {
+            match (channel.property.as_str(), &channel.keys) {
+                (TRANS_POSITION, ChannelKeys::Vector3(keys)) => self.trans_keys = vector3_events(keys),
+                (TRANS_ROTATION, ChannelKeys::Quat(keys)) => self.rot_keys = quat_events(keys),
+                (TRANS_SCALE, ChannelKeys::Vector3(keys)) => self.scale_keys = vector3_events(keys),
+                _ => continue,
+            }
+        }
+    }
+}
+
+pub fn get_prop_keys_property_name(property: &[DataArray]) -> String {
+    property
+        .iter()
+        .map(|p| {
+            let mut data = Vec::new();
+            p.print(&mut data).ok();
+
+            String::from_utf8_lossy(&data).trim().to_owned()
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+impl From<&PropKeys> for ChannelKeys {
+    fn from(prop_keys: &PropKeys) -> ChannelKeys {
+        match &prop_keys.events {
+            PropKeysEvents::Float(events) => ChannelKeys::Float(to_keys(events, |ev| (ev.pos, ev.value))),
+            PropKeysEvents::Color(events) => ChannelKeys::Color(to_keys(events, |ev| (ev.pos, [ev.value.r, ev.value.g, ev.value.b, ev.value.a]))),
+            PropKeysEvents::Object(events) => ChannelKeys::Object(to_keys(events, |ev| (ev.pos, (ev.text1.to_owned(), ev.text2.to_owned())))),
+            PropKeysEvents::Bool(events) => ChannelKeys::Bool(to_keys(events, |ev| (ev.pos, ev.value))),
+            PropKeysEvents::Quat(events) => ChannelKeys::Quat(to_keys(events, |ev| (ev.pos, [ev.value.x, ev.value.y, ev.value.z, ev.value.w]))),
+            PropKeysEvents::Vector3(events) => ChannelKeys::Vector3(to_keys(events, |ev| (ev.pos, [ev.value.x, ev.value.y, ev.value.z]))),
+            PropKeysEvents::Symbol(events) => ChannelKeys::Symbol(to_keys(events, |ev| (ev.pos, ev.text.to_owned()))),
+        }
+    }
+}
+
+impl From<&ChannelKeys> for Option<PropKeysEvents> {
+    fn from(keys: &ChannelKeys) -> Option<PropKeysEvents> {
+        let events = match keys {
+            ChannelKeys::Float(keys) => PropKeysEvents::Float(from_keys(keys, |pos, value| AnimEventFloat { pos, value: *value })),
+            ChannelKeys::Color(keys) => PropKeysEvents::Color(from_keys(keys, |pos, [r, g, b, a]| AnimEventColor { pos, value: Color4 { r: *r, g: *g, b: *b, a: *a } })),
+            ChannelKeys::Object(keys) => PropKeysEvents::Object(from_keys(keys, |pos, (text1, text2)| AnimEventObject { pos, text1: text1.to_owned(), text2: text2.to_owned() })),
+            ChannelKeys::Bool(keys) => PropKeysEvents::Bool(from_keys(keys, |pos, value| AnimEventBool { pos, value: *value })),
+            ChannelKeys::Quat(keys) => PropKeysEvents::Quat(from_keys(keys, |pos, [x, y, z, w]| AnimEventQuat { pos, value: Quat { x: *x, y: *y, z: *z, w: *w } })),
+            ChannelKeys::Vector3(keys) => PropKeysEvents::Vector3(from_keys(keys, |pos, [x, y, z]| AnimEventVector3 { pos, value: Vector3 { x: *x, y: *y, z: *z } })),
+            ChannelKeys::Symbol(keys) => PropKeysEvents::Symbol(from_keys(keys, |pos, text| AnimEventSymbol { pos, text: text.to_owned() })),
+            ChannelKeys::Array(_) => return None,
+        };
+
+        Some(events)
+    }
+}
+
+impl From<&PropAnim> for Timeline {
+    fn from(prop_anim: &PropAnim) -> Timeline {
+        let mut timeline = new_timeline(prop_anim);
+
+        timeline.channels = prop_anim
+            .keys
+            .iter()
+            .map(|k| TimelineChannel {
+                interpolation: ChannelInterpolation::Prop(k.get_interpolation()),
+                ..TimelineChannel::new(k.target.as_str(), get_prop_keys_property_name(&k.property), k.into())
+            })
+            .collect();
+
+        timeline
+    }
+}
+
+impl PropAnim {
+    pub fn apply_timeline(&mut self, timeline: &Timeline) {
+        self.rate = timeline.rate;
+
+        for prop_keys in self.keys.iter_mut() {
+            let property = get_prop_keys_property_name(&prop_keys.property);
+            let Some(channel) = timeline.get_channel(&prop_keys.target, &property) else {
+                continue;
+            };
+
+            // Only replace keys of same type
+            match Option::<PropKeysEvents>::from(&channel.keys) {
+                Some(events) if events.get_enum_value() == prop_keys.events.get_enum_value() => prop_keys.events = events,
+                _ => continue,
+            }
+        }
+    }
+}
+
+impl From<&MeshAnim> for Timeline {
+    fn from(mesh_anim: &MeshAnim) -> Timeline {
+        let target = mesh_anim.mesh.as_str();
+        let mut timeline = new_timeline(mesh_anim);
+
+        timeline.channels = vec![
+            TimelineChannel::new(target, MESH_POINTS, ChannelKeys::Array(to_keys(&mesh_anim.vert_point_keys, |ev| (ev.pos, ev.value.iter().flat_map(|v| [v.x, v.y, v.z]).collect())))),
+            TimelineChannel::new(target, MESH_TEX, ChannelKeys::Array(to_keys(&mesh_anim.vert_text_keys, |ev| (ev.pos, ev.value.iter().flat_map(|v| [v.x, v.y]).collect())))),
+            TimelineChannel::new(target, MESH_COLORS, ChannelKeys::Array(to_keys(&mesh_anim.vert_color_keys, |ev| (ev.pos, ev.value.iter().flat_map(|c| [c.r, c.g, c.b, c.a]).collect())))),
+        ];
+
+        timeline
+    }
+}
+
+impl MeshAnim {
+    pub fn apply_timeline(&mut self, timeline: &Timeline) {
+        self.rate = timeline.rate;
+
+        for channel in timeline.channels.iter().filter(|c| c.target.eq(&self.mesh)) {
+            let ChannelKeys::Array(keys) = &channel.keys else {
+                continue;
+            };
+
+            match channel.property.as_str() {
+                MESH_POINTS => self.vert_point_keys = from_keys(keys, |pos, values| AnimEvent {
+                    pos,
+                    value: values.chunks_exact(3).map(|v| Vector3 { x: v[0], y: v[1], z: v[2] }).collect()
+                }),
+                MESH_TEX => self.vert_text_keys = from_keys(keys, |pos, values| AnimEvent {
+                    pos,
+                    value: values.chunks_exact(2).map(|v| Vector2 { x: v[0], y: v[1] }).collect()
+                }),
+                MESH_COLORS => self.vert_color_keys = from_keys(keys, |pos, values| AnimEvent {
+                    pos,
+                    value: values.chunks_exact(4).map(|c| Color4 { r: c[0], g: c[1], b: c[2], a: c[3] }).collect()
+                }),
+                _ => continue,
+            }
+        }
+    }
+}
+
+fn get_morph_pose_name(index: usize) -> String {
+    format!("pose{index}")
+}
+
+impl From<&Morph> for Timeline {
+    fn from(morph: &Morph) -> Timeline {
+        let mut timeline = new_timeline(morph);
+
+        timeline.channels = morph
+            .poses
+            .iter()
+            .enumerate()
+            .map(|(i, pose)| TimelineChannel::new(morph.get_name(), get_morph_pose_name(i), ChannelKeys::Float(to_keys(&pose.events, |ev| (ev.pos, ev.value)))))
+            .collect();
+
+        timeline
+    }
+}
+
+impl Morph {
+    pub fn apply_timeline(&mut self, timeline: &Timeline) {
+        self.rate = timeline.rate;
+
+        for (i, pose) in self.poses.iter_mut().enumerate() {
+            let Some(TimelineChannel { keys: ChannelKeys::Float(keys), .. }) = timeline.get_channel(&self.name, &get_morph_pose_name(i)) else {
+                continue;
+            };
+
+            pose.events = from_keys(keys, |pos, value| AnimEvent { pos, value: *value });
+        }
+    }
+}
+
+fn get_clip_sample_beats(char_clip: &CharClipSamples, frames: &[f32], count: usize) -> Vec<f32> {
+    let beat_length = char_clip.end_beat - char_clip.start_beat;
+
+    if count > 1 && frames.len() == count {
+        // Frames are beat positions of each sample
+        frames.to_vec()
+    } else if count > 1 && beat_length > 0.0 {
+        let step = beat_length / ((count - 1) as f32);
+        (0..count).map(|i| char_clip.start_beat + (i as f32) * step).collect()
+    } else {
+        vec![char_clip.start_beat; count.min(1)]
+    }
+}
+
+fn add_clip_channels(timeline: &mut Timeline, samples: &[CharBoneSample], beats: &[f32]) {
+    fn keys<T, S>(curve: &Option<(f32, Vec<S>)>, beats: &[f32], get_value: impl Fn(&S) -> T) -> Option<Vec<TimelineKey<T>>> {
+        curve.as_ref().map(|(_, values)| values
+            .iter()
+            .zip(beats.iter())
+            .map(|(v, pos)| TimelineKey { pos: *pos, value: get_value(v) })
+            .collect())
+    }
+
+    for sample in samples.iter() {
+        let curves = [
+            keys(&sample.pos, beats, |v| [v.x, v.y, v.z]).map(ChannelKeys::Vector3),
+            keys(&sample.scale, beats, |v| [v.x, v.y, v.z]).map(ChannelKeys::Vector3),
+            keys(&sample.quat, beats, |q| [q.x, q.y, q.z, q.w]).map(ChannelKeys::Quat),
+            keys(&sample.rotx, beats, |r| *r).map(ChannelKeys::Float),
+            keys(&sample.roty, beats, |r| *r).map(ChannelKeys::Float),
+            keys(&sample.rotz, beats, |r| *r).map(ChannelKeys::Float),
+        ];
+
+        for (property, keys) in CLIP_TRANSFORMS.iter().zip(curves) {
+            if let Some(keys) = keys {
+                timeline.channels.push(TimelineChannel::new(sample.symbol.as_str(), *property, keys));
+            }
+        }
+    }
+}
+
+impl Timeline {
+    pub fn from_char_clip(char_clip: &CharClipSamples, info: &SystemInfo) -> Result<Timeline, CharBonesSamplesCodecError> {
+        let mut timeline = Timeline::new(AnimRate::k1_fpb);
+
+        if char_clip.beats_per_sec > 0.0 {
+            timeline.beats_per_sec = char_clip.beats_per_sec;
+        }
+
+        // Positions are in beats
+        for set in [&char_clip.full, &char_clip.one] {
+            let samples = set.decode_samples(info)?;
+            let count = samples
+                .iter()
+                .flat_map(|s| [
+                    s.pos.as_ref().map(|(_, v)| v.len()),
+                    s.scale.as_ref().map(|(_, v)| v.len()),
+                    s.quat.as_ref().map(|(_, v)| v.len()),
+                    s.rotx.as_ref().map(|(_, v)| v.len()),
+                    s.roty.as_ref().map(|(_, v)| v.len()),
+                    s.rotz.as_ref().map(|(_, v)| v.len()),
+                ])
+                .flatten()
+                .max()
+                .unwrap_or_default();
+
+            let beats = get_clip_sample_beats(char_clip, &set.frames, count);
+            add_clip_channels(&mut timeline, &samples, &beats);
+        }
+
+        Ok(timeline)
+    }
+}
+
+impl CharClipSamples {
+    pub fn apply_timeline(&mut self, timeline: &Timeline, info: &SystemInfo) -> Result<(), CharBonesSamplesCodecError> {
+        // Existing weights are preserved
+        let weights = [&self.full, &self.one]
+            .iter()
+            .flat_map(|s| s.decode_samples(info).unwrap_or_default())
+            .flat_map(|s| {
+                let curves = [
+                    s.pos.as_ref().map(|(w, _)| *w),
+                    s.scale.as_ref().map(|(w, _)| *w),
+                    s.quat.as_ref().map(|(w, _)| *w),
+                    s.rotx.as_ref().map(|(w, _)| *w),
+                    s.roty.as_ref().map(|(w, _)| *w),
+                    s.rotz.as_ref().map(|(w, _)| *w),
+                ];
+
+                CLIP_TRANSFORMS
+                    .iter()
+                    .zip(curves)
+                    .enumerate()
+                    .filter_map(|(i, (_, w))| w.map(|w| ((s.symbol.to_owned(), i), w)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<std::collections::HashMap<_, _>>();
+
+        let channels = timeline
+            .channels
+            .iter()
+            .filter(|c| CLIP_TRANSFORMS.contains(&c.property.as_str()) && !c.keys.is_empty())
+            .collect::<Vec<_>>();
+
+        // Multi-key curves are resampled at common positions
+        let mut positions = channels
+            .iter()
+            .filter(|c| c.keys.len() > 1)
+            .flat_map(|c| c.keys.positions())
+            .collect::<Vec<_>>();
+        positions.sort_by(|a, b| a.total_cmp(b));
+        positions.dedup();
+
+        let mut full_samples: Vec<CharBoneSample> = Vec::new();
+        let mut one_samples: Vec<CharBoneSample> = Vec::new();
+
+        for channel in channels {
+            let mut keys = channel.keys.clone();
+
+            let samples = match keys.len() {
+                1 => &mut one_samples,
+                _ => {
+                    keys.resample(&positions, channel.interpolation);
+                    &mut full_samples
+                }
+            };
+
+            let idx = match samples.iter().position(|s| s.symbol.eq(&channel.target)) {
+                Some(idx) => idx,
+                None => {
+                    samples.push(CharBoneSample {
+                        symbol: channel.target.to_owned(),
+                        ..Default::default()
+                    });
+                    samples.len() - 1
+                }
+            };
+
+            let sample = &mut samples[idx];
+            let weight = CLIP_TRANSFORMS
+                .iter()
+                .position(|t| channel.property.eq(t))
+                .and_then(|i| weights.get(&(channel.target.to_owned(), i)))
+                .copied()
+                .unwrap_or(1.0);
+
+            match (channel.property.as_str(), keys) {
+                ("pos", ChannelKeys::Vector3(k)) => sample.pos = Some((weight, from_keys(&k, |_, [x, y, z]| Vector3 { x: *x, y: *y, z: *z }))),
+                ("scale", ChannelKeys::Vector3(k)) => sample.scale = Some((weight, from_keys(&k, |_, [x, y, z]| Vector3 { x: *x, y: *y, z: *z }))),
+                ("quat", ChannelKeys::Quat(k)) => sample.quat = Some((weight, from_keys(&k, |_, [x, y, z, w]| Quat { x: *x, y: *y, z: *z, w: *w }))),
+                ("rotx", ChannelKeys::Float(k)) => sample.rotx = Some((weight, from_keys(&k, |_, v| *v))),
+                ("roty", ChannelKeys::Float(k)) => sample.roty = Some((weight, from_keys(&k, |_, v| *v))),
+                ("rotz", ChannelKeys::Float(k)) => sample.rotz = Some((weight, from_keys(&k, |_, v| *v))),
+                _ => continue,
+            }
+        }
+
+        // Convert positions to beats
+        let beats = positions
+            .iter()
+            .map(|p| timeline.convert_time(*p, TimeUnit::Frames, TimeUnit::Beats))
+            .collect::<Vec<_>>();
+
+        if let Some((start, end)) = beats.first().zip(beats.last()) {
+            self.start_beat = *start;
+            self.end_beat = *end;
+        }
+
+        self.beats_per_sec = timeline.beats_per_sec;
+
+        let (full_compression, one_compression) = (self.full.compression, self.one.compression);
+        self.full.encode_samples(&full_samples, full_compression, info)?;
+        self.one.encode_samples(&one_samples, one_compression, info)?;
+        self.full.frames = beats;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::IOEndian;
+    use crate::Platform;
+
+    #[rstest]
+    fn trans_anim_timeline_round_trip() {
+        let mut trans_anim = TransAnim {
+            trans_object: String::from("box.mesh"),
+            trans_keys: vec![
+                AnimEvent { pos: 0.0, value: Vector3 { x: 0.0, y: 0.0, z: 0.0 } },
+                AnimEvent { pos: 10.0, value: Vector3 { x: 10.0, y: 0.0, z: 0.0 } },
+            ],
+            ..Default::default()
+        };
+
+        let mut timeline = Timeline::from(&trans_anim);
+        assert_eq!(3, timeline.channels.len());
+
+        timeline.scale(2.0);
+        trans_anim.apply_timeline(&timeline);
+
+        let positions = trans_anim.trans_keys.iter().map(|k| k.pos).collect::<Vec<_>>();
+        assert_eq!(vec![0.0, 20.0], positions);
+    }
+
+    #[rstest]
+    #[case(false, false)]
+    #[case(true, false)]
+    #[case(false, true)]
+    fn trans_anim_timeline_resample_interpolation(#[case] spline: bool, #[case] rot_slerp: bool) {
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+
+        let trans_anim = TransAnim {
+            trans_object: String::from("box.mesh"),
+            trans_keys: vec![
+                AnimEvent { pos: 0.0, value: Vector3 { x: 0.0, y: 0.0, z: 0.0 } },
+                AnimEvent { pos: 10.0, value: Vector3 { x: 1.0, y: 4.0, z: 0.0 } },
+                AnimEvent { pos: 20.0, value: Vector3 { x: 2.0, y: 0.0, z: 0.0 } },
+            ],
+            rot_keys: vec![
+                AnimEvent { pos: 0.0, value: Quat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 } },
+                AnimEvent { pos: 10.0, value: Quat { x: 0.0, y: 0.0, z: half, w: half } },
+                AnimEvent { pos: 20.0, value: Quat { x: 0.0, y: 0.0, z: 1.0, w: 0.0 } },
+            ],
+            trans_spline: spline,
+            rot_spline: spline,
+            rot_slerp,
+            ..Default::default()
+        };
+
+        let mut timeline = Timeline::from(&trans_anim);
+        timeline.resample(5.0);
+
+        let Some(TimelineChannel { keys: ChannelKeys::Vector3(trans_keys), .. }) = timeline.get_channel("box.mesh", TRANS_POSITION) else {
+            panic!("Expected position channel");
+        };
+
+        let Some(TimelineChannel { keys: ChannelKeys::Quat(rot_keys), .. }) = timeline.get_channel("box.mesh", TRANS_ROTATION) else {
+            panic!("Expected rotation channel");
+        };
+
+        assert_eq!(vec![0.0, 5.0, 10.0, 15.0, 20.0], trans_keys.iter().map(|k| k.pos).collect::<Vec<_>>());
+
+        // Should match sampling source anim directly
+        for (trans_key, rot_key) in trans_keys.iter().zip(rot_keys.iter()) {
+            let trans = trans_anim.sample_trans(trans_key.pos).unwrap();
+            let rot = trans_anim.sample_rot(rot_key.pos).unwrap();
+
+            assert_eq!([trans.x, trans.y, trans.z], trans_key.value);
+            assert_eq!([rot.x, rot.y, rot.z, rot.w], rot_key.value);
+        }
+
+        // Spline overshoots linear midpoint
+        assert_eq!(spline, trans_keys[3].value[1] > 2.0);
+    }
+
+    #[rstest]
+    #[case(PropInterpolation::kLinear, 0.5)]
+    #[case(PropInterpolation::kStep, 0.0)]
+    #[case(PropInterpolation::kEaseIn, 0.25)]
+    fn prop_anim_timeline_trim_interpolation(#[case] interpolation: PropInterpolation, #[case] expected: f32) {
+        let prop_anim = PropAnim {
+            keys: vec![
+                PropKeys {
+                    target: String::from("light.lit"),
+                    property: vec![DataArray::Symbol("intensity".into())],
+                    interpolation: interpolation as u32,
+                    events: PropKeysEvents::Float(vec![
+                        AnimEventFloat { pos: 0.0, value: 0.0 },
+                        AnimEventFloat { pos: 30.0, value: 1.0 },
+                    ]),
+                    ..Default::default()
+                }
+            ],
+            ..Default::default()
+        };
+
+        let mut timeline = Timeline::from(&prop_anim);
+        timeline.trim(15.0, 30.0);
+
+        let channel = timeline.get_channel("light.lit", "intensity").unwrap();
+        assert_eq!(ChannelInterpolation::Prop(interpolation), channel.interpolation);
+        assert_eq!(ChannelKeys::Float(vec![
+            TimelineKey { pos: 0.0, value: expected },
+            TimelineKey { pos: 15.0, value: 1.0 },
+        ]), channel.keys);
+    }
+
+    #[rstest]
+    fn prop_anim_timeline_round_trip() {
+        let mut prop_anim = PropAnim {
+            keys: vec![
+                PropKeys {
+                    target: String::from("light.lit"),
+                    property: vec![DataArray::Symbol("intensity".into())],
+                    interpolation: PropInterpolation::kLinear as u32,
+                    events: PropKeysEvents::Float(vec![
+                        AnimEventFloat { pos: 0.0, value: 0.0 },
+                        AnimEventFloat { pos: 30.0, value: 1.0 },
+                    ]),
+                    ..Default::default()
+                }
+            ],
+            ..Default::default()
+        };
+
+        let mut timeline = Timeline::from(&prop_anim);
+        assert!(timeline.get_channel("light.lit", "intensity").is_some());
+
+        timeline.trim(15.0, 30.0);
+        prop_anim.apply_timeline(&timeline);
+
+        let PropKeysEvents::Float(events) = &prop_anim.keys[0].events else {
+            panic!("Expected float events");
+        };
+
+        let keys = events.iter().map(|e| (e.pos, e.value)).collect::<Vec<_>>();
+        assert_eq!(vec![(0.0, 0.5), (15.0, 1.0)], keys);
+    }
+
+    #[rstest]
+    fn mesh_anim_timeline_round_trip() {
+        let mut mesh_anim = MeshAnim {
+            mesh: String::from("face.mesh"),
+            vert_point_keys: vec![
+                AnimEvent { pos: 0.0, value: vec![Vector3 { x: 0.0, y: 0.0, z: 0.0 }, Vector3 { x: 2.0, y: 2.0, z: 2.0 }] },
+                AnimEvent { pos: 2.0, value: vec![Vector3 { x: 2.0, y: 0.0, z: 0.0 }, Vector3 { x: 4.0, y: 2.0, z: 2.0 }] },
+            ],
+            ..Default::default()
+        };
+
+        let mut timeline = Timeline::from(&mesh_anim);
+        timeline.resample(1.0);
+        mesh_anim.apply_timeline(&timeline);
+
+        assert_eq!(3, mesh_anim.vert_point_keys.len());
+
+        let points = &mesh_anim.vert_point_keys[1].value;
+        assert_eq!(2, points.len());
+        assert_eq!((1.0, 3.0), (points[0].x, points[1].x));
+    }
+
+    #[rstest]
+    fn char_clip_timeline_round_trip() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut char_clip = CharClipSamples {
+            start_beat: 0.0,
+            end_beat: 2.0,
+            beats_per_sec: 2.0,
+            ..Default::default()
+        };
+
+        let bone_samples = vec![
+            CharBoneSample {
+                symbol: String::from("bone_head.mesh"),
+                rotz: Some((0.5, vec![0.0, 0.25, 0.5])),
+                ..Default::default()
+            }
+        ];
+        char_clip.full.encode_samples(&bone_samples, 0, &info).unwrap();
+
+        let mut timeline = Timeline::from_char_clip(&char_clip, &info).unwrap();
+        assert_eq!(Some((0.0, 2.0)), timeline.get_range());
+
+        // Stretch to 4 beats
+        timeline.scale(2.0);
+        char_clip.apply_timeline(&timeline, &info).unwrap();
+
+        assert_eq!((0.0, 4.0), (char_clip.start_beat, char_clip.end_beat));
+        assert_eq!(vec![0.0, 2.0, 4.0], char_clip.full.frames);
+
+        let samples = char_clip.full.decode_samples(&info).unwrap();
+        assert_eq!(1, samples.len());
+        assert_eq!(Some((0.5, vec![0.0, 0.25, 0.5])), samples[0].rotz);
+    }
+}
@@ -0,0 +1,496 @@
+mod convert;
+
+use crate::scene::trans_anim::sample::{normalize4, slerp};
+use crate::scene::PropInterpolation;
+use grim_traits::scene::AnimRate;
+pub use convert::*;
+
+const DEFAULT_BEATS_PER_SEC: f32 = 2.0; // 120 bpm
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum TimeUnit {
+    #[default]
+    Frames,
+    Seconds,
+    Beats,
+}
+
+// Interpolation of source keys, used when sampling between keys
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ChannelInterpolation {
+    #[default]
+    Linear,
+    Trans {
+        spline: bool,
+        slerp: bool,
+    },
+    Prop(PropInterpolation),
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TimelineKey<T> {
+    pub pos: f32,
+    pub value: T,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ChannelKeys {
+    Float(Vec<TimelineKey<f32>>),
+    Vector3(Vec<TimelineKey<[f32; 3]>>),
+    Quat(Vec<TimelineKey<[f32; 4]>>),
+    Color(Vec<TimelineKey<[f32; 4]>>),
+    Array(Vec<TimelineKey<Vec<f32>>>), // Flattened per-vertex values
+    Bool(Vec<TimelineKey<bool>>),
+    Symbol(Vec<TimelineKey<String>>),
+    Object(Vec<TimelineKey<(String, String)>>),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimelineChannel {
+    pub target: String,
+    pub property: String,
+    pub keys: ChannelKeys,
+    pub interpolation: ChannelInterpolation,
+}
+
+#[derive(Clone, Debug)]
+pub struct Timeline {
+    pub rate: AnimRate,
+    pub beats_per_sec: f32,
+    pub channels: Vec<TimelineChannel>,
+}
+
+// Applies expression to keys of any channel type
+macro_rules! with_keys {
+    ($keys:expr, $k:ident => $body:expr) => {
+        match $keys {
+            ChannelKeys::Float($k) => $body,
+            ChannelKeys::Vector3($k) => $body,
+            ChannelKeys::Quat($k) => $body,
+            ChannelKeys::Color($k) => $body,
+            ChannelKeys::Array($k) => $body,
+            ChannelKeys::Bool($k) => $body,
+            ChannelKeys::Symbol($k) => $body,
+            ChannelKeys::Object($k) => $body,
+        }
+    };
+}
+
+fn lerp_array<const N: usize>(a: &[f32; N], b: &[f32; N], t: f32) -> [f32; N] {
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+fn lerp_vec(a: &[f32], b: &[f32], t: f32) -> Vec<f32> {
+    // Extra values from longer key are ignored
+    a.iter()
+        .zip(b.iter())
+        .map(|(a, b)| a + (b - a) * t)
+        .collect()
+}
+
+fn sample_keys<T: Clone>(keys: &[TimelineKey<T>], pos: f32, blend: impl Fn(&T, &T, f32) -> T) -> Option<T> {
+    let (first, last) = (keys.first()?, keys.last()?);
+
+    if pos <= first.pos {
+        return Some(first.value.clone());
+    } else if pos >= last.pos {
+        return Some(last.value.clone());
+    }
+
+    let next = keys.partition_point(|k| k.pos <= pos);
+    let (a, b) = (&keys[next - 1], &keys[next]);
+
+    let length = b.pos - a.pos;
+    match length > f32::EPSILON {
+        true => Some(blend(&a.value, &b.value, (pos - a.pos) / length)),
+        false => Some(a.value.clone()),
+    }
+}
+
+fn step<T: Clone>(a: &T, _b: &T, _t: f32) -> T {
+    a.clone()
+}
+
+fn sample_keys_at<T: Clone>(keys: &[TimelineKey<T>], positions: &[f32], blend: impl Fn(&T, &T, f32) -> T) -> Vec<TimelineKey<T>> {
+    positions
+        .iter()
+        .filter_map(|pos| sample_keys(keys, *pos, &blend).map(|value| TimelineKey { pos: *pos, value }))
+        .collect()
+}
+
+impl ChannelKeys {
+    pub fn len(&self) -> usize {
+        with_keys!(self, keys => keys.len())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn positions(&self) -> Vec<f32> {
+        with_keys!(self, keys => keys.iter().map(|k| k.pos).collect())
+    }
+
+    pub fn get_range(&self) -> Option<(f32, f32)> {
+        with_keys!(self, keys => keys.first().zip(keys.last()).map(|(a, b)| (a.pos, b.pos)))
+    }
+
+    pub fn is_same_type(&self, other: &ChannelKeys) -> bool {
+        std::mem::discriminant(self) == std::mem::discriminant(other)
+    }
+
+    pub fn remap(&mut self, mut remap_fn: impl FnMut(f32) -> f32) {
+        with_keys!(self, keys => {
+            for key in keys.iter_mut() {
+                key.pos = remap_fn(key.pos);
+            }
+
+            // Remap could reverse order
+            keys.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+        })
+    }
+
+    pub fn sample(&self, positions: &[f32], interpolation: ChannelInterpolation) -> ChannelKeys {
+        if let Some(keys) = sample_source_keys(self, positions, interpolation) {
+            return keys;
+        }
+
+        match self {
+            ChannelKeys::Float(keys) => ChannelKeys::Float(sample_keys_at(keys, positions, |a, b, t| a + (b - a) * t)),
+            ChannelKeys::Vector3(keys) => ChannelKeys::Vector3(sample_keys_at(keys, positions, lerp_array)),
+            ChannelKeys::Quat(keys) => ChannelKeys::Quat(sample_keys_at(keys, positions, |a, b, t| normalize4(slerp(*a, *b, t)))),
+            ChannelKeys::Color(keys) => ChannelKeys::Color(sample_keys_at(keys, positions, lerp_array)),
+            ChannelKeys::Array(keys) => ChannelKeys::Array(sample_keys_at(keys, positions, |a, b, t| lerp_vec(a, b, t))),
+            ChannelKeys::Bool(keys) => ChannelKeys::Bool(sample_keys_at(keys, positions, step)),
+            ChannelKeys::Symbol(keys) => ChannelKeys::Symbol(sample_keys_at(keys, positions, step)),
+            ChannelKeys::Object(keys) => ChannelKeys::Object(sample_keys_at(keys, positions, step)),
+        }
+    }
+
+    pub fn resample(&mut self, positions: &[f32], interpolation: ChannelInterpolation) {
+        if self.is_empty() {
+            return;
+        }
+
+        *self = self.sample(positions, interpolation);
+    }
+
+    pub fn trim(&mut self, start: f32, end: f32, interpolation: ChannelInterpolation) {
+        if self.is_empty() {
+            return;
+        }
+
+        // Keep value at boundaries
+        let bounds = if end > start { vec![start, end] } else { vec![start] };
+        let mut trimmed = self.sample(&bounds, interpolation);
+
+        with_keys!(self, keys => keys.retain(|k| k.pos > start && k.pos < end));
+        trimmed.append(self, 0.0);
+
+        *self = trimmed;
+    }
+
+    pub fn truncate(&mut self, end: f32) {
+        with_keys!(self, keys => keys.retain(|k| k.pos < end))
+    }
+
+    pub fn append(&mut self, other: &ChannelKeys, offset: f32) -> bool {
+        macro_rules! append_keys {
+            ($($variant:ident),*) => {
+                match (self, other) {
+                    $(
+                        (ChannelKeys::$variant(keys), ChannelKeys::$variant(other_keys)) => {
+                            keys.extend(other_keys.iter().map(|k| TimelineKey { pos: k.pos + offset, value: k.value.clone() }));
+                            keys.sort_by(|a, b| a.pos.total_cmp(&b.pos));
+                        },
+                    )*
+                    _ => return false,
+                }
+            };
+        }
+
+        append_keys!(Float, Vector3, Quat, Color, Array, Bool, Symbol, Object);
+        true
+    }
+}
+
+impl TimelineChannel {
+    pub fn new<T: Into<String>, S: Into<String>>(target: T, property: S, keys: ChannelKeys) -> TimelineChannel {
+        TimelineChannel {
+            target: target.into(),
+            property: property.into(),
+            keys,
+            interpolation: ChannelInterpolation::default(),
+        }
+    }
+
+    pub fn is_same_channel(&self, other: &TimelineChannel) -> bool {
+        self.target.eq(&other.target)
+            && self.property.eq(&other.property)
+            && self.keys.is_same_type(&other.keys)
+    }
+}
+
+impl Default for Timeline {
+    fn default() -> Timeline {
+        Timeline {
+            rate: AnimRate::default(),
+            beats_per_sec: DEFAULT_BEATS_PER_SEC,
+            channels: Vec::new(),
+        }
+    }
+}
+
+impl Timeline {
+    pub fn new(rate: AnimRate) -> Timeline {
+        Timeline {
+            rate,
+            ..Default::default()
+        }
+    }
+
+    pub fn get_frames_per_second(&self) -> f32 {
+        get_frames_per_second(self.rate, self.beats_per_sec)
+    }
+
+    pub fn convert_time(&self, value: f32, from: TimeUnit, to: TimeUnit) -> f32 {
+        let fps = self.get_frames_per_second();
+
+        let seconds = match from {
+            TimeUnit::Frames => value / fps,
+            TimeUnit::Seconds => value,
+            TimeUnit::Beats => value / self.beats_per_sec,
+        };
+
+        match to {
+            TimeUnit::Frames => seconds * fps,
+            TimeUnit::Seconds => seconds,
+            TimeUnit::Beats => seconds * self.beats_per_sec,
+        }
+    }
+
+    pub fn frame_to_seconds(&self, frame: f32) -> f32 {
+        self.convert_time(frame, TimeUnit::Frames, TimeUnit::Seconds)
+    }
+
+    pub fn seconds_to_frame(&self, seconds: f32) -> f32 {
+        self.convert_time(seconds, TimeUnit::Seconds, TimeUnit::Frames)
+    }
+
+    pub fn frame_to_beat(&self, frame: f32) -> f32 {
+        self.convert_time(frame, TimeUnit::Frames, TimeUnit::Beats)
+    }
+
+    pub fn beat_to_frame(&self, beat: f32) -> f32 {
+        self.convert_time(beat, TimeUnit::Beats, TimeUnit::Frames)
+    }
+
+    pub fn get_channel(&self, target: &str, property: &str) -> Option<&TimelineChannel> {
+        self.channels
+            .iter()
+            .find(|c| c.target.eq(target) && c.property.eq(property))
+    }
+
+    pub fn get_channel_mut(&mut self, target: &str, property: &str) -> Option<&mut TimelineChannel> {
+        self.channels
+            .iter_mut()
+            .find(|c| c.target.eq(target) && c.property.eq(property))
+    }
+
+    pub fn get_range(&self) -> Option<(f32, f32)> {
+        self.channels
+            .iter()
+            .filter_map(|c| c.keys.get_range())
+            .reduce(|(s1, e1), (s2, e2)| (s1.min(s2), e1.max(e2)))
+    }
+
+    pub fn remap(&mut self, mut remap_fn: impl FnMut(f32) -> f32) {
+        for channel in self.channels.iter_mut() {
+            channel.keys.remap(&mut remap_fn);
+        }
+    }
+
+    pub fn offset(&mut self, frames: f32) {
+        self.remap(|pos| pos + frames);
+    }
+
+    pub fn scale(&mut self, factor: f32) {
+        self.remap(|pos| pos * factor);
+    }
+
+    pub fn set_rate(&mut self, rate: AnimRate) {
+        // Keep same timing in seconds
+        let factor = get_frames_per_second(rate, self.beats_per_sec) / self.get_frames_per_second();
+
+        self.rate = rate;
+        self.scale(factor);
+    }
+
+    pub fn set_beats_per_sec(&mut self, beats_per_sec: f32) {
+        // Beat based rates stay locked to beats
+        self.beats_per_sec = beats_per_sec;
+    }
+
+    pub fn resample(&mut self, frame_step: f32) {
+        let Some((start, end)) = self.get_range() else {
+            return;
+        };
+
+        if frame_step <= 0.0 {
+            return;
+        }
+
+        let count = ((end - start) / frame_step).ceil() as usize;
+        let positions = (0..=count)
+            .map(|i| (start + (i as f32) * frame_step).min(end))
+            .collect::<Vec<_>>();
+
+        for channel in self.channels.iter_mut() {
+            channel.keys.resample(&positions, channel.interpolation);
+        }
+    }
+
+    pub fn trim(&mut self, start: f32, end: f32) {
+        let end = end.max(start);
+
+        for channel in self.channels.iter_mut() {
+            channel.keys.trim(start, end, channel.interpolation);
+        }
+
+        self.offset(-start);
+    }
+
+    pub fn splice(&mut self, other: &Timeline, frame: f32) {
+        // Convert other timeline to current rate
+        let factor = self.get_frames_per_second() / other.get_frames_per_second();
+
+        for other_channel in other.channels.iter() {
+            let mut keys = other_channel.keys.clone();
+            keys.remap(|pos| pos * factor);
+
+            match self.channels.iter_mut().find(|c| c.is_same_channel(other_channel)) {
+                Some(channel) => {
+                    // Replace existing keys after splice point
+                    channel.keys.truncate(frame);
+                    channel.keys.append(&keys, frame);
+                },
+                None => {
+                    keys.remap(|pos| pos + frame);
+                    self.channels.push(TimelineChannel {
+                        keys,
+                        ..other_channel.clone()
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn get_frames_per_second(rate: AnimRate, beats_per_sec: f32) -> f32 {
+    match rate {
+        AnimRate::k30_fps | AnimRate::k30_fps_ui | AnimRate::k30_fps_tutorial => 30.0,
+        AnimRate::k480_fpb => 480.0 * beats_per_sec,
+        AnimRate::k1_fpb => beats_per_sec,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    fn create_timeline() -> Timeline {
+        Timeline {
+            channels: vec![
+                TimelineChannel::new("box.trans", "position", ChannelKeys::Vector3(vec![
+                    TimelineKey { pos: 0.0, value: [0.0, 0.0, 0.0] },
+                    TimelineKey { pos: 10.0, value: [10.0, 20.0, 30.0] },
+                ])),
+                TimelineChannel::new("box.trans", "visible", ChannelKeys::Bool(vec![
+                    TimelineKey { pos: 0.0, value: false },
+                    TimelineKey { pos: 4.0, value: true },
+                ])),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(AnimRate::k30_fps, 30.0, TimeUnit::Frames, TimeUnit::Seconds, 1.0)]
+    #[case(AnimRate::k30_fps, 1.0, TimeUnit::Seconds, TimeUnit::Beats, 2.0)]
+    #[case(AnimRate::k480_fpb, 480.0, TimeUnit::Frames, TimeUnit::Beats, 1.0)]
+    #[case(AnimRate::k480_fpb, 960.0, TimeUnit::Frames, TimeUnit::Seconds, 1.0)]
+    #[case(AnimRate::k1_fpb, 2.0, TimeUnit::Frames, TimeUnit::Seconds, 1.0)]
+    #[case(AnimRate::k1_fpb, 1.5, TimeUnit::Seconds, TimeUnit::Frames, 3.0)]
+    fn timeline_convert_time(#[case] rate: AnimRate, #[case] value: f32, #[case] from: TimeUnit, #[case] to: TimeUnit, #[case] expected: f32) {
+        let timeline = Timeline::new(rate);
+        assert_eq!(expected, timeline.convert_time(value, from, to));
+    }
+
+    #[rstest]
+    fn timeline_set_rate() {
+        let mut timeline = create_timeline();
+        timeline.set_rate(AnimRate::k1_fpb);
+
+        assert_eq!(Some((0.0, 10.0 / 15.0)), timeline.get_range());
+    }
+
+    #[rstest]
+    fn timeline_resample() {
+        let mut timeline = create_timeline();
+        timeline.resample(5.0);
+
+        let channel = timeline.get_channel("box.trans", "position").unwrap();
+        assert_eq!(ChannelKeys::Vector3(vec![
+            TimelineKey { pos: 0.0, value: [0.0, 0.0, 0.0] },
+            TimelineKey { pos: 5.0, value: [5.0, 10.0, 15.0] },
+            TimelineKey { pos: 10.0, value: [10.0, 20.0, 30.0] },
+        ]), channel.keys);
+
+        let channel = timeline.get_channel("box.trans", "visible").unwrap();
+        assert_eq!(vec![false, true, true], match &channel.keys {
+            ChannelKeys::Bool(keys) => keys.iter().map(|k| k.value).collect::<Vec<_>>(),
+            _ => Vec::new(),
+        });
+    }
+
+    #[rstest]
+    fn timeline_trim() {
+        let mut timeline = create_timeline();
+        timeline.trim(2.0, 6.0);
+
+        assert_eq!(Some((0.0, 4.0)), timeline.get_range());
+
+        let channel = timeline.get_channel("box.trans", "position").unwrap();
+        assert_eq!(ChannelKeys::Vector3(vec![
+            TimelineKey { pos: 0.0, value: [2.0, 4.0, 6.0] },
+            TimelineKey { pos: 4.0, value: [6.0, 12.0, 18.0] },
+        ]), channel.keys);
+
+        let channel = timeline.get_channel("box.trans", "visible").unwrap();
+        assert_eq!(vec![0.0, 2.0, 4.0], channel.keys.positions());
+    }
+
+    #[rstest]
+    fn timeline_splice() {
+        let mut timeline = create_timeline();
+
+        let mut other = Timeline::new(AnimRate::k1_fpb);
+        other.channels.push(TimelineChannel::new("box.trans", "position", ChannelKeys::Vector3(vec![
+            TimelineKey { pos: 0.0, value: [1.0, 1.0, 1.0] },
+            TimelineKey { pos: 1.0, value: [2.0, 2.0, 2.0] },
+        ])));
+        other.channels.push(TimelineChannel::new("box.trans", "scale", ChannelKeys::Float(vec![
+            TimelineKey { pos: 0.0, value: 1.0 },
+        ])));
+
+        timeline.splice(&other, 5.0);
+
+        // 1 beat = 15 frames
+        let channel = timeline.get_channel("box.trans", "position").unwrap();
+        assert_eq!(vec![0.0, 5.0, 20.0], channel.keys.positions());
+
+        let channel = timeline.get_channel("box.trans", "scale").unwrap();
+        assert_eq!(vec![5.0], channel.keys.positions());
+    }
+}
@@ -0,0 +1,323 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum BandCharacterLoadError {
+    #[error("BandCharacter version {version} is not supported")]
+    BandCharacterVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+        3 => true, // RB1
+        5 => true, // RB2
+        8 => true, // TBRB
+        _ => false
+    }
+}
+
+fn get_save_version(character: &BandCharacter, info: &SystemInfo) -> u32 {
+    match (info.version, character.version) {
+        (25, Some(v @ (3 | 5 | 8))) => v,
+        (25, _) => 5, // RB2
+        _ => 8,       // TBRB (RB3 not supported yet)
+    }
+}
+
+impl ObjectReadWrite for BandCharacter {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(BandCharacterLoadError::BandCharacterVersionNotSupported {
+                version
+            }));
+        }
+
+        self.version = Some(version);
+
+        load_character(self, &mut reader, info)?;
+
+        self.play_flags = reader.read_uint32()?;
+        self.tempo = reader.read_prefixed_string()?;
+
+        if version > 1 {
+            self.drum_venue = reader.read_prefixed_string()?;
+        }
+
+        if version > 2 {
+            self.test_prefab = reader.read_prefixed_string()?;
+        }
+
+        if version > 4 {
+            self.genre = reader.read_prefixed_string()?;
+        }
+
+        if version > 5 {
+            self.instrument_type = reader.read_prefixed_string()?;
+        }
+
+        if version > 6 {
+            self.in_closet = reader.read_boolean()?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+
+        stream.write_uint32(version)?;
+
+        save_character(self, &mut stream, info)?;
+
+        stream.write_uint32(self.play_flags)?;
+        stream.write_prefixed_string(&self.tempo)?;
+
+        if version > 1 {
+            stream.write_prefixed_string(&self.drum_venue)?;
+        }
+
+        if version > 2 {
+            stream.write_prefixed_string(&self.test_prefab)?;
+        }
+
+        if version > 4 {
+            stream.write_prefixed_string(&self.genre)?;
+        }
+
+        if version > 5 {
+            stream.write_prefixed_string(&self.instrument_type)?;
+        }
+
+        if version > 6 {
+            stream.write_boolean(self.in_closet)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_band_character_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x05, // Version (5)
+            0x00, 0x00, 0x00, 0x09, // Character version (9)
+            0x00, 0x00, 0x00, 0x08, // RndDir version (8)
+            0x00, 0x00, 0x00, 0x10, // ObjectDir version (16)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x00, // Viewport count
+            0x00, 0x00, 0x00, 0x00, // Current viewport
+            0x01,                   // Inline proxy
+            0x00, 0x00, 0x00, 0x00, // Proxy file
+            0x00, 0x00, 0x00, 0x00, // Sub dir count
+            0x00, 0x00, 0x00, 0x04, // Anim version (4)
+            0x00, 0x00, 0x00, 0x00, // Frame
+            0x00, 0x00, 0x00, 0x00, // Rate
+            0x00, 0x00, 0x00, 0x09, // Trans version (9)
+        ];
+        data.extend_from_slice(&[0u8; 96]); // Local + world xfm
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, // Constraint
+            0x00, 0x00, 0x00, 0x00, // Target
+            0x00,                   // Preserve scale
+            0x00, 0x00, 0x00, 0x00, // Parent
+            0x00, 0x00, 0x00, 0x03, // Draw version (3)
+            0x01,                   // Showing
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Sphere
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Draw order
+            0x00, 0x00, 0x00, 0x00, // Environ
+            0x00, 0x00, 0x00, 0x00, // LOD count
+            0x00, 0x00, 0x00, 0x00, // Shadow
+            0x00,                   // Self shadow
+            0x00, 0x00, 0x00, 0x00, // Sphere base
+            0x00, 0x00, 0x00, 0x06, // Play flags
+            0x00, 0x00, 0x00, 0x04, b'f', b'a', b's', b't', // Tempo
+            0x00, 0x00, 0x00, 0x05, b's', b'm', b'a', b'l', b'l', // Drum venue
+            0x00, 0x00, 0x00, 0x03, b'a', b'b', b'c', // Test prefab
+            0x00, 0x00, 0x00, 0x04, b'r', b'o', b'c', b'k', // Genre
+        ]);
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut character = BandCharacter::default();
+        character.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert!(character.lods.is_empty());
+        assert_eq!(6, character.play_flags);
+        assert_eq!("fast", character.tempo);
+        assert_eq!("small", character.drum_venue);
+        assert_eq!("abc", character.test_prefab);
+        assert_eq!("rock", character.genre);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        character.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    fn load_tbrb_band_character_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x08, // Version (8)
+            0x00, 0x00, 0x00, 0x0C, // Character version (12)
+            0x00, 0x00, 0x00, 0x09, // RndDir version (9)
+            0x00, 0x00, 0x00, 0x11, // ObjectDir version (17)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x00, // Viewport count
+            0x00, 0x00, 0x00, 0x00, // Current viewport
+            0x01,                   // Inline proxy
+            0x00, 0x00, 0x00, 0x00, // Proxy file
+            0x00, 0x00, 0x00, 0x00, // Sub dir count
+            0x00, 0x00, 0x00, 0x04, // Anim version (4)
+            0x00, 0x00, 0x00, 0x00, // Frame
+            0x00, 0x00, 0x00, 0x00, // Rate
+            0x00, 0x00, 0x00, 0x09, // Trans version (9)
+        ];
+        data.extend_from_slice(&[0u8; 96]); // Local + world xfm
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, // Constraint
+            0x00, 0x00, 0x00, 0x00, // Target
+            0x00,                   // Preserve scale
+            0x00, 0x00, 0x00, 0x00, // Parent
+            0x00, 0x00, 0x00, 0x03, // Draw version (3)
+            0x01,                   // Showing
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Sphere
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Draw order
+            0x00, 0x00, 0x00, 0x00, // Environ
+            0x00, 0x00, 0x00, 0x04, b't', b'e', b's', b't', // Test event
+            0x00, 0x00, 0x00, 0x01, // LOD count
+            0x3F, 0x00, 0x00, 0x00, // Screen size (0.5)
+            0x00, 0x00, 0x00, 0x01, // Group count
+            0x00, 0x00, 0x00, 0x05, b'a', b'.', b'g', b'r', b'p',
+            0x00, 0x00, 0x00, 0x01, // Translucent group count
+            0x00, 0x00, 0x00, 0x05, b'b', b'.', b'g', b'r', b'p',
+            0x00, 0x00, 0x00, 0x00, // Shadow
+            0x00,                   // Self shadow
+            0x00, 0x00, 0x00, 0x00, // Sphere base
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Bounding
+            0x00, 0x00, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00,
+            0x01,                   // Frozen
+            0x00, 0x00, 0x00, 0x01, // Min LOD
+            0x00, 0x00, 0x00, 0x06, // Play flags
+            0x00, 0x00, 0x00, 0x04, b'f', b'a', b's', b't', // Tempo
+            0x00, 0x00, 0x00, 0x05, b's', b'm', b'a', b'l', b'l', // Drum venue
+            0x00, 0x00, 0x00, 0x03, b'a', b'b', b'c', // Test prefab
+            0x00, 0x00, 0x00, 0x04, b'r', b'o', b'c', b'k', // Genre
+            0x00, 0x00, 0x00, 0x04, b'd', b'r', b'u', b'm', // Instrument type
+            0x01,                   // In closet
+        ]);
+
+        let character: BandCharacter = assert_round_trip_bytes(&data, &info);
+        assert_eq!(Some(8), character.version);
+        assert_eq!((Some(12), Some(9), Some(17)), (character.char_version, character.rnd_dir_version, character.obj_dir_version));
+
+        assert_eq!("test", character.test_event);
+        assert_eq!(vec!["b.grp"], character.lods[0].translucent_groups);
+        assert_eq!(1.0, character.bounding.r);
+        assert!(character.frozen);
+        assert_eq!(1, character.min_lod);
+        assert_eq!("drum", character.instrument_type);
+        assert!(character.in_closet);
+    }
+
+    #[rstest]
+    #[case(25, 5)]
+    #[case(28, 8)]
+    fn save_load_band_character(#[case] milo_version: u32, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version: milo_version,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let character = BandCharacter {
+            lods: vec![
+                CharLOD {
+                    screen_size: 0.5,
+                    groups: vec![String::from("lod0.grp")],
+                    ..Default::default()
+                },
+            ],
+            min_lod: 1,
+            play_flags: 2,
+            tempo: String::from("slow"),
+            drum_venue: String::from("big_club"),
+            test_prefab: String::from("prefab"),
+            genre: String::from("punk"),
+            instrument_type: String::from("drum"),
+            in_closet: true,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        character.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut reader = BinaryStream::from_stream_with_endian(&mut stream, info.endian);
+        assert_eq!(expected_version, reader.read_uint32().unwrap());
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_character = BandCharacter::default();
+        loaded_character.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(character.lods.len(), loaded_character.lods.len());
+        assert_eq!(character.play_flags, loaded_character.play_flags);
+        assert_eq!(character.tempo, loaded_character.tempo);
+        assert_eq!(character.drum_venue, loaded_character.drum_venue);
+        assert_eq!(character.test_prefab, loaded_character.test_prefab);
+        assert_eq!(character.genre, loaded_character.genre);
+
+        if expected_version > 5 {
+            // TBRB fields
+            assert_eq!(character.min_lod, loaded_character.min_lod);
+            assert_eq!(character.instrument_type, loaded_character.instrument_type);
+            assert_eq!(character.in_closet, loaded_character.in_closet);
+        }
+    }
+}
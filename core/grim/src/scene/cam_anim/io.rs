@@ -0,0 +1,164 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum CamAnimLoadError {
+    #[error("CamAnim version {version} is not supported")]
+    CamAnimVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+        1 => true, // GH1
+        2 => true, // GH2 and up
+        _ => false
+    }
+}
+
+impl ObjectReadWrite for CamAnim {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(CamAnimLoadError::CamAnimVersionNotSupported {
+                version
+            }));
+        }
+
+        load_object(self, &mut reader, info)?;
+        load_anim(self, &mut reader, info, false)?;
+
+        self.cam = reader.read_prefixed_string()?;
+
+        self.fov_keys.clear();
+        let key_count = reader.read_uint32()?;
+        for _ in 0..key_count {
+            let value = reader.read_float32()?;
+            let pos = reader.read_float32()?;
+
+            self.fov_keys.push(AnimEvent {
+                value,
+                pos
+            });
+        }
+
+        if version > 1 {
+            self.keys_owner = reader.read_prefixed_string()?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = if info.version <= 10 { 1 } else { 2 };
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+        save_anim(self, &mut stream, info, false)?;
+
+        stream.write_prefixed_string(&self.cam)?;
+
+        stream.write_uint32(self.fov_keys.len() as u32)?;
+        for key in self.fov_keys.iter() {
+            stream.write_float32(key.value)?;
+            stream.write_float32(key.pos)?;
+        }
+
+        if version > 1 {
+            stream.write_prefixed_string(&self.keys_owner)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(10, Platform::PS2)]
+    #[case(25, Platform::X360)]
+    fn save_load_cam_anim(#[case] version: u32, #[case] platform: Platform) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let cam_anim = CamAnim {
+            cam: String::from("main.cam"),
+            fov_keys: vec![
+                AnimEvent { value: 0.8, pos: 0.0 },
+                AnimEvent { value: 1.2, pos: 30.0 },
+            ],
+            keys_owner: String::from("main.cnm"),
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        cam_anim.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_cam_anim = CamAnim::default();
+        loaded_cam_anim.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(cam_anim.cam, loaded_cam_anim.cam);
+        assert_eq!(2, loaded_cam_anim.fov_keys.len());
+        assert_eq!(cam_anim.fov_keys[1].value, loaded_cam_anim.fov_keys[1].value);
+        assert_eq!(cam_anim.fov_keys[1].pos, loaded_cam_anim.fov_keys[1].pos);
+
+        if version > 10 {
+            assert_eq!(cam_anim.keys_owner, loaded_cam_anim.keys_owner);
+        }
+    }
+
+    #[rstest]
+    fn load_cam_anim_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x02, // Version (2)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x04, // Anim version (4)
+            0x00, 0x00, 0x00, 0x00, // Frame
+            0x00, 0x00, 0x00, 0x00, // Rate
+            0x00, 0x00, 0x00, 0x05, b'a', b'.', b'c', b'a', b'm', // Cam
+            0x00, 0x00, 0x00, 0x02, // Fov key count
+            0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x3F, 0x80, 0x00, 0x00, 0x41, 0xF0, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x05, b'a', b'.', b'c', b'n', b'm', // Keys owner
+        ];
+
+        let cam_anim: CamAnim = assert_round_trip_bytes(&data, &info);
+        assert_eq!("a.cam", cam_anim.cam);
+
+        let keys = cam_anim.fov_keys.iter().map(|k| (k.value, k.pos)).collect::<Vec<_>>();
+        assert_eq!(vec![(0.5, 0.0), (1.0, 30.0)], keys);
+        assert_eq!("a.cnm", cam_anim.keys_owner);
+    }
+}
@@ -0,0 +1,34 @@
+mod io;
+
+use crate::scene::AnimEvent;
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo(Anim)]
+pub struct CamAnim {
+    pub cam: String,
+    pub fov_keys: Vec<AnimEvent<f32>>,
+    pub keys_owner: String,
+}
+
+impl Default for CamAnim {
+    fn default() -> CamAnim {
+        CamAnim {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Anim object
+            anim_objects: Vec::new(),
+            frame: 0.0,
+            rate: AnimRate::default(),
+
+            // CamAnim object
+            cam: String::default(),
+            fov_keys: Vec::new(),
+            keys_owner: String::default(),
+        }
+    }
+}
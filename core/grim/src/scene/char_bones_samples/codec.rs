@@ -0,0 +1,511 @@
+use crate::io::IOEndian;
+use crate::scene::{CharBone, CharBoneSample, CharBonesSamples, EncodedSamples, Quat, Vector3};
+use crate::SystemInfo;
+use std::collections::HashMap;
+use thiserror::Error as ThisError;
+
+const MAX_COMPRESSION: u32 = 3;
+const PACKED_VECTOR_RANGE: f32 = 1345.0; // TODO: Investigate better constant
+
+#[derive(Debug, ThisError)]
+pub enum CharBonesSamplesCodecError {
+    #[error("Compression of {compression} is not supported")]
+    UnsupportedCompression {
+        compression: u32
+    },
+    #[error("Bone transform \"{symbol}\" is not supported")]
+    UnsupportedTransform {
+        symbol: String
+    },
+    #[error("Sample size of {size} is smaller than expected size of {expected}")]
+    SampleTooSmall {
+        size: usize,
+        expected: usize
+    },
+    #[error("Bone transform \"{symbol}\" has {count} samples, expected {expected}")]
+    SampleCountMismatch {
+        symbol: String,
+        count: usize,
+        expected: usize
+    },
+}
+
+impl CharBonesSamples {
+    pub fn decode_samples(&self, sys_info: &SystemInfo) -> Result<Vec<CharBoneSample>, CharBonesSamplesCodecError> {
+        let EncodedSamples::Compressed(bones, compressed_samples) = &self.samples else {
+            return Ok(Vec::new());
+        };
+
+        if self.compression > MAX_COMPRESSION {
+            return Err(CharBonesSamplesCodecError::UnsupportedCompression {
+                compression: self.compression
+            });
+        }
+
+        // Validate bone types and compute expected sample size
+        let mut sample_size = 0;
+        for bone in bones.iter() {
+            match Self::get_type_of(&bone.symbol) {
+                t @ 0..=5 => sample_size += self.get_type_size(t) as usize,
+                _ => return Err(CharBonesSamplesCodecError::UnsupportedTransform {
+                    symbol: bone.symbol.to_owned()
+                })
+            }
+        }
+
+        let endian = sys_info.endian;
+
+        // Group by bone name
+        let mut bone_map: HashMap<String, CharBoneSample> = HashMap::new();
+        for sample in compressed_samples.iter() {
+            if sample.len() < sample_size {
+                return Err(CharBonesSamplesCodecError::SampleTooSmall {
+                    size: sample.len(),
+                    expected: sample_size
+                });
+            }
+
+            let mut i = 0usize;
+
+            for bone in bones.iter() {
+                let bone_name = get_bone_name(&bone.symbol);
+                let bone_type = Self::get_type_of(&bone.symbol);
+                let size = self.get_type_size(bone_type) as usize;
+                let data = &sample[i..(i + size)];
+
+                // Get or insert bone sample w/ name
+                let bone_sample = bone_map
+                    .entry(bone_name.to_owned())
+                    .or_insert_with(|| CharBoneSample {
+                        symbol: bone_name,
+                        ..Default::default()
+                    });
+
+                match bone_type {
+                    0 => push_sample(&mut bone_sample.pos, bone.weight, read_vector(data, endian)),
+                    1 => push_sample(&mut bone_sample.scale, bone.weight, read_vector(data, endian)),
+                    2 => push_sample(&mut bone_sample.quat, bone.weight, read_quat(data, endian)),
+                    3 => push_sample(&mut bone_sample.rotx, bone.weight, read_rot(data, endian)),
+                    4 => push_sample(&mut bone_sample.roty, bone.weight, read_rot(data, endian)),
+                    _ => push_sample(&mut bone_sample.rotz, bone.weight, read_rot(data, endian)),
+                };
+
+                i += size;
+            }
+        }
+
+        let mut bone_samples = bone_map.into_values().collect::<Vec<_>>();
+
+        // Sort by name
+        bone_samples.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+        Ok(bone_samples)
+    }
+
+    pub fn encode_samples(&mut self, bone_samples: &[CharBoneSample], compression: u32, sys_info: &SystemInfo) -> Result<(), CharBonesSamplesCodecError> {
+        if compression > MAX_COMPRESSION {
+            return Err(CharBonesSamplesCodecError::UnsupportedCompression {
+                compression
+            });
+        }
+
+        self.compression = compression;
+
+        let mut sorted_samples = bone_samples.iter().collect::<Vec<_>>();
+        sorted_samples.sort_by(|a, b| a.symbol.cmp(&b.symbol));
+
+        // Group bone transforms by type (pos, scale, quat, rotx, roty, rotz)
+        let mut bones = Vec::new();
+        let mut sample_count = None;
+
+        for bone_type in 0..6u32 {
+            self.counts[bone_type as usize] = bones.len() as u32;
+
+            for bone_sample in sorted_samples.iter() {
+                let Some((weight, count)) = get_curve_info(bone_sample, bone_type) else {
+                    continue;
+                };
+
+                let symbol = get_transform_name(&bone_sample.symbol, bone_type);
+
+                // All curves should be same length
+                let expected = *sample_count.get_or_insert(count);
+                if count != expected {
+                    return Err(CharBonesSamplesCodecError::SampleCountMismatch {
+                        symbol,
+                        count,
+                        expected
+                    });
+                }
+
+                bones.push((bone_sample, bone_type, CharBone { symbol, weight }));
+            }
+        }
+
+        self.counts[6] = bones.len() as u32;
+
+        let sample_size: usize = bones
+            .iter()
+            .map(|(_, t, _)| self.get_type_size(*t) as usize)
+            .sum();
+        let aligned_size = crate::io::align_to_multiple_of_four(sample_size);
+
+        let endian = sys_info.endian;
+        let mut samples = Vec::new();
+
+        for i in 0..sample_count.unwrap_or_default() {
+            let mut data = Vec::with_capacity(aligned_size);
+
+            for (bone_sample, bone_type, _) in bones.iter() {
+                let size = self.get_type_size(*bone_type) as usize;
+
+                match bone_type {
+                    0 => write_vector(&mut data, &bone_sample.pos.as_ref().unwrap().1[i], size, endian),
+                    1 => write_vector(&mut data, &bone_sample.scale.as_ref().unwrap().1[i], size, endian),
+                    2 => write_quat(&mut data, &bone_sample.quat.as_ref().unwrap().1[i], size, endian),
+                    3 => write_rot(&mut data, bone_sample.rotx.as_ref().unwrap().1[i], size, endian),
+                    4 => write_rot(&mut data, bone_sample.roty.as_ref().unwrap().1[i], size, endian),
+                    _ => write_rot(&mut data, bone_sample.rotz.as_ref().unwrap().1[i], size, endian),
+                }
+            }
+
+            data.resize(aligned_size, 0);
+            samples.push(data.into_boxed_slice());
+        }
+
+        // Sample layout and computed sizes should come from the same type sizes
+        self.recompute_sizes();
+        assert!(samples.iter().all(|s| {
+            s.len() == crate::io::align_to_multiple_of_four(self.computed_sizes[6] as usize)
+                && s.len() <= self.computed_flags as usize
+        }));
+
+        self.samples = EncodedSamples::Compressed(
+            bones.into_iter().map(|(_, _, b)| b).collect(),
+            samples
+        );
+
+        Ok(())
+    }
+}
+
+fn get_bone_name(symbol: &str) -> String {
+    let base_name = symbol
+        .find('.')
+        .map(|i| &symbol[..i])
+        .unwrap_or(symbol);
+
+    format!("{base_name}.mesh")
+}
+
+fn get_transform_name(bone_name: &str, bone_type: u32) -> String {
+    let base_name = bone_name
+        .find('.')
+        .map(|i| &bone_name[..i])
+        .unwrap_or(bone_name);
+
+    let ext = match bone_type {
+        0 => "pos",
+        1 => "scale",
+        2 => "quat",
+        3 => "rotx",
+        4 => "roty",
+        _ => "rotz",
+    };
+
+    format!("{base_name}.{ext}")
+}
+
+fn get_curve_info(bone_sample: &CharBoneSample, bone_type: u32) -> Option<(f32, usize)> {
+    match bone_type {
+        0 => bone_sample.pos.as_ref().map(|(w, s)| (*w, s.len())),
+        1 => bone_sample.scale.as_ref().map(|(w, s)| (*w, s.len())),
+        2 => bone_sample.quat.as_ref().map(|(w, s)| (*w, s.len())),
+        3 => bone_sample.rotx.as_ref().map(|(w, s)| (*w, s.len())),
+        4 => bone_sample.roty.as_ref().map(|(w, s)| (*w, s.len())),
+        _ => bone_sample.rotz.as_ref().map(|(w, s)| (*w, s.len())),
+    }
+}
+
+fn push_sample<T>(curve: &mut Option<(f32, Vec<T>)>, weight: f32, value: T) {
+    match curve {
+        Some((_, samples)) => samples.push(value),
+        None => *curve = Some((weight, vec![value])),
+    }
+}
+
+fn read_f32(data: &[u8], endian: IOEndian) -> f32 {
+    let data = [data[0], data[1], data[2], data[3]];
+
+    match endian {
+        IOEndian::Big => f32::from_be_bytes(data),
+        IOEndian::Little => f32::from_le_bytes(data),
+    }
+}
+
+fn read_packed_i16(data: &[u8], endian: IOEndian) -> f32 {
+    let data = [data[0], data[1]];
+
+    let value = match endian {
+        IOEndian::Big => i16::from_be_bytes(data),
+        IOEndian::Little => i16::from_le_bytes(data),
+    };
+
+    ((value as f32) / 32767.0).max(-1.0)
+}
+
+fn read_packed_i8(data: u8) -> f32 {
+    ((data as i8 as f32) / 127.0).max(-1.0)
+}
+
+fn read_vector(data: &[u8], endian: IOEndian) -> Vector3 {
+    match data.len() {
+        12 => Vector3 {
+            x: read_f32(&data[0..], endian),
+            y: read_f32(&data[4..], endian),
+            z: read_f32(&data[8..], endian),
+        },
+        _ => Vector3 {
+            x: read_packed_i16(&data[0..], endian) * PACKED_VECTOR_RANGE,
+            y: read_packed_i16(&data[2..], endian) * PACKED_VECTOR_RANGE,
+            z: read_packed_i16(&data[4..], endian) * PACKED_VECTOR_RANGE,
+        }
+    }
+}
+
+fn read_quat(data: &[u8], endian: IOEndian) -> Quat {
+    match data.len() {
+        16 => Quat {
+            x: read_f32(&data[0..], endian),
+            y: read_f32(&data[4..], endian),
+            z: read_f32(&data[8..], endian),
+            w: read_f32(&data[12..], endian),
+        },
+        8 => Quat {
+            x: read_packed_i16(&data[0..], endian),
+            y: read_packed_i16(&data[2..], endian),
+            z: read_packed_i16(&data[4..], endian),
+            w: read_packed_i16(&data[6..], endian),
+        },
+        _ => Quat {
+            x: read_packed_i8(data[0]),
+            y: read_packed_i8(data[1]),
+            z: read_packed_i8(data[2]),
+            w: read_packed_i8(data[3]),
+        }
+    }
+}
+
+fn read_rot(data: &[u8], endian: IOEndian) -> f32 {
+    match data.len() {
+        4 => read_f32(data, endian),
+        _ => read_packed_i16(data, endian),
+    }
+}
+
+fn write_f32(data: &mut Vec<u8>, value: f32, endian: IOEndian) {
+    match endian {
+        IOEndian::Big => data.extend_from_slice(&value.to_be_bytes()),
+        IOEndian::Little => data.extend_from_slice(&value.to_le_bytes()),
+    }
+}
+
+fn write_packed_i16(data: &mut Vec<u8>, value: f32, endian: IOEndian) {
+    let value = (value.clamp(-1.0, 1.0) * 32767.0).round() as i16;
+
+    match endian {
+        IOEndian::Big => data.extend_from_slice(&value.to_be_bytes()),
+        IOEndian::Little => data.extend_from_slice(&value.to_le_bytes()),
+    }
+}
+
+fn write_packed_i8(data: &mut Vec<u8>, value: f32) {
+    let value = (value.clamp(-1.0, 1.0) * 127.0).round() as i8;
+    data.push(value as u8);
+}
+
+fn write_vector(data: &mut Vec<u8>, value: &Vector3, size: usize, endian: IOEndian) {
+    match size {
+        12 => {
+            write_f32(data, value.x, endian);
+            write_f32(data, value.y, endian);
+            write_f32(data, value.z, endian);
+        },
+        _ => {
+            write_packed_i16(data, value.x / PACKED_VECTOR_RANGE, endian);
+            write_packed_i16(data, value.y / PACKED_VECTOR_RANGE, endian);
+            write_packed_i16(data, value.z / PACKED_VECTOR_RANGE, endian);
+        }
+    }
+}
+
+fn write_quat(data: &mut Vec<u8>, value: &Quat, size: usize, endian: IOEndian) {
+    match size {
+        16 => {
+            write_f32(data, value.x, endian);
+            write_f32(data, value.y, endian);
+            write_f32(data, value.z, endian);
+            write_f32(data, value.w, endian);
+        },
+        8 => {
+            write_packed_i16(data, value.x, endian);
+            write_packed_i16(data, value.y, endian);
+            write_packed_i16(data, value.z, endian);
+            write_packed_i16(data, value.w, endian);
+        },
+        _ => {
+            write_packed_i8(data, value.x);
+            write_packed_i8(data, value.y);
+            write_packed_i8(data, value.z);
+            write_packed_i8(data, value.w);
+        }
+    }
+}
+
+fn write_rot(data: &mut Vec<u8>, value: f32, size: usize, endian: IOEndian) {
+    match size {
+        4 => write_f32(data, value, endian),
+        _ => write_packed_i16(data, value, endian),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::Platform;
+
+    fn get_test_samples() -> Vec<CharBoneSample> {
+        vec![
+            CharBoneSample {
+                symbol: String::from("bone_pelvis.mesh"),
+                pos: Some((1.0, vec![
+                    Vector3 { x: 0.0, y: 10.0, z: 40.0 },
+                    Vector3 { x: 1.0, y: 12.0, z: 41.0 },
+                ])),
+                quat: Some((1.0, vec![
+                    Quat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 },
+                    Quat { x: 0.0, y: 0.0, z: 0.6, w: 0.8 },
+                ])),
+                ..Default::default()
+            },
+            CharBoneSample {
+                symbol: String::from("bone_spine.mesh"),
+                scale: Some((1.0, vec![
+                    Vector3 { x: 1.0, y: 1.0, z: 1.0 },
+                    Vector3 { x: 1.5, y: 1.5, z: 1.5 },
+                ])),
+                rotx: Some((0.5, vec![0.25, -0.25])),
+                roty: Some((0.5, vec![0.5, -0.5])),
+                rotz: Some((0.5, vec![0.75, -0.75])),
+                ..Default::default()
+            },
+        ]
+    }
+
+    #[rstest]
+    #[case(0, 0.0001)]
+    #[case(1, 0.0001)]
+    #[case(2, 0.05)]
+    #[case(3, 0.05)]
+    fn char_bones_samples_encode_decode(#[case] compression: u32, #[case] epsilon: f32) {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let bone_samples = get_test_samples();
+
+        let mut char_bones_samples = CharBonesSamples::default();
+        char_bones_samples.encode_samples(&bone_samples, compression, &info).unwrap();
+
+        assert_eq!(compression, char_bones_samples.compression);
+        assert_eq!([0, 1, 2, 3, 4, 5, 6], char_bones_samples.counts);
+
+        let EncodedSamples::Compressed(bones, samples) = &char_bones_samples.samples else {
+            panic!("Samples not encoded");
+        };
+
+        assert_eq!(6, bones.len());
+        assert_eq!("bone_pelvis.pos", bones[0].symbol);
+        assert_eq!("bone_spine.rotz", bones[5].symbol);
+        assert_eq!(2, samples.len());
+        assert!(samples.iter().all(|s| s.len() % 4 == 0));
+        assert!(samples.iter().all(|s| s.len() == crate::io::align_to_multiple_of_four(char_bones_samples.computed_sizes[6] as usize)));
+        assert!(samples.iter().all(|s| s.len() <= char_bones_samples.computed_flags as usize));
+
+        let decoded = char_bones_samples.decode_samples(&info).unwrap();
+        assert_eq!(2, decoded.len());
+
+        let assert_near = |a: f32, b: f32| assert!((a - b).abs() <= epsilon, "{a} != {b}");
+
+        for (expected, actual) in bone_samples.iter().zip(decoded.iter()) {
+            assert_eq!(expected.symbol, actual.symbol);
+
+            for (curve_a, curve_b) in [(&expected.pos, &actual.pos), (&expected.scale, &actual.scale)] {
+                assert_eq!(curve_a.is_some(), curve_b.is_some());
+
+                if let (Some((_, a)), Some((_, b))) = (curve_a, curve_b) {
+                    for (a, b) in a.iter().zip(b.iter()) {
+                        assert_near(a.x, b.x);
+                        assert_near(a.y, b.y);
+                        assert_near(a.z, b.z);
+                    }
+                }
+            }
+
+            if let (Some((_, a)), Some((_, b))) = (&expected.quat, &actual.quat) {
+                for (a, b) in a.iter().zip(b.iter()) {
+                    assert_near(a.x, b.x);
+                    assert_near(a.y, b.y);
+                    assert_near(a.z, b.z);
+                    assert_near(a.w, b.w);
+                }
+            }
+
+            for (curve_a, curve_b) in [(&expected.rotx, &actual.rotx), (&expected.roty, &actual.roty), (&expected.rotz, &actual.rotz)] {
+                assert_eq!(curve_a.is_some(), curve_b.is_some());
+
+                if let (Some((wa, a)), Some((wb, b))) = (curve_a, curve_b) {
+                    assert_eq!(wa, wb);
+
+                    for (a, b) in a.iter().zip(b.iter()) {
+                        assert_near(*a, *b);
+                    }
+                }
+            }
+        }
+    }
+
+    #[rstest]
+    fn char_bones_samples_encode_count_mismatch() {
+        let bone_samples = vec![
+            CharBoneSample {
+                symbol: String::from("bone_pelvis.mesh"),
+                rotx: Some((1.0, vec![0.0, 0.1])),
+                rotz: Some((1.0, vec![0.0])),
+                ..Default::default()
+            },
+        ];
+
+        let mut char_bones_samples = CharBonesSamples::default();
+        let result = char_bones_samples.encode_samples(&bone_samples, 0, &SystemInfo::default());
+
+        assert!(matches!(result, Err(CharBonesSamplesCodecError::SampleCountMismatch { .. })));
+    }
+
+    #[rstest]
+    #[case(vec![CharBone { symbol: String::from("bone.pos"), weight: 1.0 }], vec![0u8; 6], 4)]
+    #[case(vec![CharBone { symbol: String::from("bone.pos"), weight: 1.0 }], vec![0u8; 4], 0)]
+    #[case(vec![CharBone { symbol: String::from("bone.unknown"), weight: 1.0 }], vec![0u8; 4], 0)]
+    fn char_bones_samples_decode_errors(#[case] bones: Vec<CharBone>, #[case] sample: Vec<u8>, #[case] compression: u32) {
+        let char_bones_samples = CharBonesSamples {
+            compression,
+            samples: EncodedSamples::Compressed(bones, vec![sample.into_boxed_slice()]),
+            ..Default::default()
+        };
+
+        assert!(char_bones_samples.decode_samples(&SystemInfo::default()).is_err());
+    }
+}
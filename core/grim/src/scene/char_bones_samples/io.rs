@@ -13,9 +13,16 @@ pub enum CharBonesSamplesReadError {
     },
 }
 
+#[derive(Debug, ThisError)]
+pub enum CharBonesSamplesWriteError {
+    #[error("CharBonesSamples must be encoded before writing")]
+    SamplesNotEncoded,
+}
+
 fn is_version_supported(version: u32) -> bool {
     match version {
-        16 => true, // TBRB/GDRB
+        11 | 12 => true, // RB1/RB2
+        16 => true, // TBRB/GDRB/RB3
          _ => false
     }
 }
@@ -104,7 +111,7 @@ pub(crate) fn load_char_bones_samples_data(char_bones_samples: &mut CharBonesSam
     let mut sample_size: usize = bones
         .iter()
         .filter_map(|(s, _)| match CharBonesSamples::get_type_of(s) {
-            i @ 0..=6 => Some(char_bones_samples.get_type_size2(i)),
+            i @ 0..=6 => Some(char_bones_samples.get_type_size(i) as usize),
             _ => None
         })
         .sum();
@@ -127,4 +134,172 @@ pub(crate) fn load_char_bones_samples_data(char_bones_samples: &mut CharBonesSam
     );
 
     Ok(())
-}
\ No newline at end of file
+}
+
+type EncodedSamplesRef<'a> = (&'a [CharBone], &'a [Box<[u8]>]);
+
+fn get_encoded_samples(char_bones_samples: &CharBonesSamples) -> Result<EncodedSamplesRef<'_>, Box<dyn Error>> {
+    match &char_bones_samples.samples {
+        EncodedSamples::Compressed(bones, samples) => Ok((bones, samples)),
+        EncodedSamples::Uncompressed(samples) if samples.is_empty() => Ok((&[], &[])),
+        _ => Err(Box::new(CharBonesSamplesWriteError::SamplesNotEncoded)),
+    }
+}
+
+pub(crate) fn save_char_bones_samples(char_bones_samples: &CharBonesSamples, writer: &mut Box<BinaryStream>, version: u32) -> Result<(), Box<dyn Error>> {
+    writer.write_uint32(version)?;
+
+    save_char_bones_samples_header(char_bones_samples, writer, version)?;
+    save_char_bones_samples_data(char_bones_samples, writer)?;
+
+    Ok(())
+}
+
+pub(crate) fn save_char_bones_samples_header(char_bones_samples: &CharBonesSamples, writer: &mut Box<BinaryStream>, version: u32) -> Result<(), Box<dyn Error>> {
+    let (bones, samples) = get_encoded_samples(char_bones_samples)?;
+
+    // Write symbol + weight values
+    writer.write_uint32(bones.len() as u32)?;
+    for bone in bones.iter() {
+        writer.write_prefixed_string(&bone.symbol)?;
+
+        if version > 10 {
+            writer.write_float32(bone.weight)?;
+        }
+    }
+
+    // Write offset values
+    // Earlier versions use 10 counts so repeat last offset
+    let count_size = if version > 15 { 7 } else { 10 };
+    let last_count = char_bones_samples.counts.last().copied().unwrap_or_default();
+
+    for i in 0..count_size {
+        let count = char_bones_samples.counts.get(i).copied().unwrap_or(last_count);
+        writer.write_uint32(count)?;
+    }
+
+    writer.write_uint32(char_bones_samples.compression)?;
+    writer.write_uint32(samples.len() as u32)?;
+
+    if version > 11 {
+        // Write frames
+        writer.write_uint32(char_bones_samples.frames.len() as u32)?;
+        for frame in char_bones_samples.frames.iter() {
+            writer.write_float32(*frame)?;
+        }
+    }
+
+    Ok(())
+}
+
+pub(crate) fn save_char_bones_samples_data(char_bones_samples: &CharBonesSamples, writer: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+    let (_, samples) = get_encoded_samples(char_bones_samples)?;
+
+    for sample in samples.iter() {
+        writer.write_bytes(sample)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_save_char_bones_samples_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let data = [
+            0x00, 0x00, 0x00, 0x10, // Version
+            0x00, 0x00, 0x00, 0x03, // Bone count
+            0x00, 0x00, 0x00, 0x0F, b'b', b'o', b'n', b'e', b'_', b'p', b'e', b'l', b'v', b'i', b's', b'.', b'p', b'o', b's',
+            0x3F, 0x80, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x10, b'b', b'o', b'n', b'e', b'_', b'p', b'e', b'l', b'v', b'i', b's', b'.', b'q', b'u', b'a', b't',
+            0x3F, 0x80, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x0F, b'b', b'o', b'n', b'e', b'_', b's', b'p', b'i', b'n', b'e', b'.', b'r', b'o', b't', b'z',
+            0x3F, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Counts
+            0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x01,
+            0x00, 0x00, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x02,
+            0x00, 0x00, 0x00, 0x03,
+            0x00, 0x00, 0x00, 0x01, // Compression
+            0x00, 0x00, 0x00, 0x02, // Sample count
+            0x00, 0x00, 0x00, 0x02, // Frames
+            0x00, 0x00, 0x00, 0x00,
+            0x3F, 0x80, 0x00, 0x00,
+            // Sample 0 (pos, quat, rotz + padding)
+            0x3F, 0x80, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x40, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x7F, 0xFF,
+            0x40, 0x00,
+            0x00, 0x00,
+            // Sample 1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x4C, 0xCC, 0x66, 0x66,
+            0xC0, 0x00,
+            0x00, 0x00,
+        ];
+
+        let mut input = data.to_vec();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut input);
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(&mut stream, info.endian));
+
+        let mut char_bones_samples = CharBonesSamples::default();
+        load_char_bones_samples(&mut char_bones_samples, &mut reader, &info).unwrap();
+        assert_eq!(data.len() as u64, reader.pos());
+
+        // Stride matches computed sizes (12 + 8 + 2, aligned to 4)
+        char_bones_samples.recompute_sizes();
+        assert_eq!([0, 12, 12, 20, 20, 20, 22], char_bones_samples.computed_sizes);
+        assert_eq!(32, char_bones_samples.computed_flags);
+
+        let EncodedSamples::Compressed(bones, samples) = &char_bones_samples.samples else {
+            panic!("Samples not encoded");
+        };
+
+        assert_eq!(3, bones.len());
+        assert!(samples.iter().all(|s| s.len() == 24));
+
+        let decoded = char_bones_samples.decode_samples(&info).unwrap();
+        assert_eq!(2, decoded.len());
+
+        let (_, pos) = decoded[0].pos.as_ref().unwrap();
+        assert_eq!((1.0, 2.0, 3.0), (pos[0].x, pos[0].y, pos[0].z));
+        assert_eq!((0.0, 0.0, 0.0), (pos[1].x, pos[1].y, pos[1].z));
+
+        let (_, quat) = decoded[0].quat.as_ref().unwrap();
+        assert_eq!(1.0, quat[0].w);
+        assert!((quat[1].z - 0.6).abs() < 0.0001);
+        assert!((quat[1].w - 0.8).abs() < 0.0001);
+
+        let (weight, rotz) = decoded[1].rotz.as_ref().unwrap();
+        assert_eq!(0.5, *weight);
+        assert!((rotz[0] - 0.5).abs() < 0.0001);
+        assert!((rotz[1] + 0.5).abs() < 0.0001);
+
+        // Re-encoding should give back the same bytes
+        let mut encoded = CharBonesSamples {
+            frames: char_bones_samples.frames.to_owned(),
+            ..Default::default()
+        };
+        encoded.encode_samples(&decoded, 1, &info).unwrap();
+        assert_eq!(char_bones_samples.counts, encoded.counts);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(&mut out_stream, info.endian));
+        save_char_bones_samples(&encoded, &mut writer, 16).unwrap();
+
+        assert_eq!(data.to_vec(), saved);
+    }
+}
@@ -17,12 +17,54 @@ pub enum CharClipReadError {
 fn is_version_supported(version: u32) -> bool {
     match version {
          5 => true, // GH2/GH2 360
+         7 => true, // RB1
+         9 => true, // RB2
         12 => true, // TBRB/GDRB
+        13 => true, // RB3/DC
          _ => false
     }
 }
 
-pub(crate) fn load_char_clip<T: CharClip>(char_clip: &mut T, reader: &mut Box<BinaryStream>, info: &SystemInfo, read_meta: bool) -> Result<(), Box<dyn Error>> {
+fn get_save_version(char_clip: &CharClipObject, info: &SystemInfo) -> u32 {
+    match (info.version, info.get_revision(), char_clip.version) {
+        (_, 0 | 1, _) => 5, // GH2/GH2 360
+        (25, _, Some(v @ (7 | 9 | 12))) => v, // RB1/RB2/TBRB
+        (25, _, _) => 9, // RB2
+        _ => 13, // RB3/DC
+    }
+}
+
+impl ObjectReadWrite for CharClipObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = load_char_clip(self, &mut reader, info, true)?;
+        self.version = Some(version);
+
+        if version > 12 {
+            load_char_bones_samples(&mut self.full, &mut reader, info)?;
+            load_char_bones_samples(&mut self.one, &mut reader, info)?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+        save_char_clip(self, &mut writer, info, version, true)?;
+
+        if version > 12 {
+            save_char_bones_samples(&self.full, &mut writer, 16)?;
+            save_char_bones_samples(&self.one, &mut writer, 16)?;
+        }
+
+        Ok(())
+    }
+}
+
+pub(crate) fn load_char_clip<T: CharClip>(char_clip: &mut T, reader: &mut Box<BinaryStream>, info: &SystemInfo, read_meta: bool) -> Result<u32, Box<dyn Error>> {
     let version = reader.read_uint32()?;
 
     // If not valid, return unsupported error
@@ -57,9 +99,13 @@ pub(crate) fn load_char_clip<T: CharClip>(char_clip: &mut T, reader: &mut Box<Bi
         char_clip.set_relative(reader.read_prefixed_string()?);
     }
 
-    /*if (version - 9) < 2 {
-        todo!()
-    }*/
+    if (9..=10).contains(&version) {
+        // Deprecated field
+        let unknown = reader.read_uint32()?;
+        if unknown != 0 {
+            warn!("Skipping unknown value {unknown} at offset 0x{:X} for CharClip with version {version}", reader.pos());
+        }
+    }
 
     if version > 9 {
         char_clip.set_unknown_1(reader.read_int32()?);
@@ -103,10 +149,6 @@ pub(crate) fn load_char_clip<T: CharClip>(char_clip: &mut T, reader: &mut Box<Bi
 
     char_clip.set_nodes(nodes);
 
-    if version < 3 {
-        todo!()
-    }
-
     if version < 7 {
         // Deprecated fields
         let enter_event = reader.read_prefixed_string()?;
@@ -121,21 +163,210 @@ pub(crate) fn load_char_clip<T: CharClip>(char_clip: &mut T, reader: &mut Box<Bi
         }
     }
 
-    // Read events
     let event_count = reader.read_uint32()?;
-    let mut events = Vec::new();
 
-    for _ in 0..event_count {
-        let frame = reader.read_float32()?;
-        let script = reader.read_prefixed_string()?;
+    if version > 12 {
+        // Read beat-aligned events
+        let mut beat_events = Vec::new();
 
-        events.push(FrameEvent {
-            frame,
-            script
-        });
+        for _ in 0..event_count {
+            let beat = reader.read_float32()?;
+            let script = reader.read_prefixed_string()?;
+
+            beat_events.push(BeatEvent {
+                beat,
+                script
+            });
+        }
+
+        char_clip.set_events(Vec::new());
+        char_clip.set_beat_events(beat_events);
+    } else {
+        // Read events
+        let mut events = Vec::new();
+
+        for _ in 0..event_count {
+            let frame = reader.read_float32()?;
+            let script = reader.read_prefixed_string()?;
+
+            events.push(FrameEvent {
+                frame,
+                script
+            });
+        }
+
+        char_clip.set_events(events);
+        char_clip.set_beat_events(Vec::new());
+    }
+
+    Ok(version)
+}
+
+pub(crate) fn save_char_clip<T: CharClip>(char_clip: &T, writer: &mut Box<BinaryStream>, info: &SystemInfo, version: u32, write_meta: bool) -> Result<(), Box<dyn Error>> {
+    writer.write_uint32(version)?;
+
+    if write_meta {
+        save_object(char_clip, writer, info)?;
+    }
+
+    writer.write_float32(char_clip.get_start_beat())?;
+    writer.write_float32(char_clip.get_end_beat())?;
+    writer.write_float32(char_clip.get_beats_per_sec())?;
+
+    writer.write_uint32(char_clip.get_flags())?;
+    writer.write_uint32(char_clip.get_play_flags())?;
+
+    writer.write_float32(char_clip.get_blend_width())?;
+
+    if version > 3 {
+        writer.write_float32(char_clip.get_range())?;
     }
 
-    char_clip.set_events(events);
+    if version == 5 {
+        // Unknown bool
+        writer.write_boolean(false)?;
+    } else if version > 5 {
+        writer.write_prefixed_string(char_clip.get_relative())?;
+    }
+
+    if (9..=10).contains(&version) {
+        // Deprecated field
+        writer.write_uint32(0)?;
+    }
+
+    if version > 9 {
+        writer.write_int32(char_clip.get_unknown_1())?;
+    }
+
+    if version > 11 {
+        writer.write_boolean(char_clip.get_do_not_decompress())?;
+    }
+
+    let nodes = char_clip.get_nodes();
+
+    if version >= 8 {
+        // Size of node data (symbol + count + values)
+        // TODO: Verify this is how size is calculated
+        let node_size = nodes
+            .iter()
+            .map(|n| 8 + (n.values.len() * 8))
+            .sum::<usize>();
+
+        writer.write_uint32(node_size as u32)?;
+    }
+
+    // Write nodes
+    writer.write_uint32(nodes.len() as u32)?;
+
+    for node in nodes.iter() {
+        writer.write_prefixed_string(&node.name)?;
+        writer.write_uint32(node.values.len() as u32)?;
+
+        for value in node.values.iter() {
+            writer.write_float32(value.frame)?;
+            writer.write_float32(value.weight)?;
+        }
+    }
+
+    if version < 7 {
+        // Deprecated enter/exit events
+        writer.write_prefixed_string("")?;
+        writer.write_prefixed_string("")?;
+    }
+
+    if version > 12 {
+        // Write beat-aligned events
+        let beat_events = char_clip.get_beat_events();
+        writer.write_uint32(beat_events.len() as u32)?;
+
+        for event in beat_events.iter() {
+            writer.write_float32(event.beat)?;
+            writer.write_prefixed_string(&event.script)?;
+        }
+    } else {
+        // Write events
+        let events = char_clip.get_events();
+        writer.write_uint32(events.len() as u32)?;
+
+        for event in events.iter() {
+            writer.write_float32(event.frame)?;
+            writer.write_prefixed_string(&event.script)?;
+        }
+    }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(24, Platform::PS2, None, 5)] // GH2
+    #[case(25, Platform::PS3, None, 9)] // RB2
+    #[case(25, Platform::PS3, Some(12), 12)] // TBRB
+    #[case(28, Platform::PS3, None, 13)] // RB3/DC
+    fn char_clip_round_trip(#[case] version: u32, #[case] platform: Platform, #[case] loaded_version: Option<u32>, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        // Facial clips only store single pose
+        let one = CharBonesSamples {
+            compression: 2,
+            counts: [0, 0, 0, 1, 1, 1, 1],
+            samples: EncodedSamples::Compressed(
+                vec![CharBone { symbol: String::from("bone_jaw.quat"), weight: 1.0 }],
+                vec![vec![1u8; 8].into_boxed_slice()]
+            ),
+            ..Default::default()
+        };
+
+        let clip = CharClipObject {
+            version: loaded_version,
+            start_beat: 0.0,
+            end_beat: 4.0,
+            beats_per_sec: 2.0,
+            flags: 2,
+            events: vec![FrameEvent { frame: 1.0, script: String::from("{frame_event}") }],
+            beat_events: vec![BeatEvent { beat: 2.0, script: String::from("{beat_event}") }],
+            one,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        clip.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = CharClipObject::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(expected_version), result.version);
+        assert_eq!((clip.end_beat, clip.beats_per_sec, clip.flags), (result.end_beat, result.beats_per_sec, result.flags));
+
+        if expected_version > 12 {
+            assert!(result.events.is_empty());
+            assert_eq!(1, result.beat_events.len());
+            assert_eq!((2.0, "{beat_event}"), (result.beat_events[0].beat, result.beat_events[0].script.as_str()));
+
+            let EncodedSamples::Compressed(bones, samples) = &result.one.samples else {
+                panic!("Expected compressed samples");
+            };
+
+            assert_eq!("bone_jaw.quat", bones[0].symbol);
+            assert_eq!(vec![vec![1u8; 8].into_boxed_slice()], *samples);
+            assert!(matches!(&result.full.samples, EncodedSamples::Compressed(b, s) if b.is_empty() && s.is_empty()));
+        } else {
+            assert!(result.beat_events.is_empty());
+            assert_eq!(1, result.events.len());
+            assert_eq!((1.0, "{frame_event}"), (result.events[0].frame, result.events[0].script.as_str()));
+        }
+    }
+}
@@ -2,4 +2,49 @@ mod io;
 
 use grim_macros::*;
 use grim_traits::scene::*;
-pub use io::*;
\ No newline at end of file
+pub use io::*;
+
+use super::CharBonesSamples;
+
+// RB3 and later merge CharClipSamples into CharClip
+#[milo(CharClip)]
+pub struct CharClipObject {
+    pub version: Option<u32>,
+    pub full: CharBonesSamples,
+    pub one: CharBonesSamples,
+}
+
+impl Default for CharClipObject {
+    fn default() -> CharClipObject {
+        CharClipObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // CharClip object
+            start_beat: 0.0,
+            end_beat: 0.0,
+            beats_per_sec: 0.0,
+
+            flags: 0,
+            play_flags: 0,
+
+            blend_width: 0.0,
+            range: 0.0,
+            relative: String::new(),
+
+            unknown_1: -1,
+            do_not_decompress: false,
+
+            nodes: Vec::new(),
+            events: Vec::new(),
+            beat_events: Vec::new(),
+
+            // CharClipObject
+            version: None,
+            full: CharBonesSamples::default(),
+            one: CharBonesSamples::default(),
+        }
+    }
+}
@@ -16,11 +16,40 @@ pub enum CharClipSamplesReadError {
 fn is_version_supported(version: u32) -> bool {
     match version {
         10 | 11 => true, // GH2/GH2 360
-        16 => true, // TBRB/GDRB
+        13 | 14 => true, // RB1/RB2
+        16 => true, // TBRB/GDRB/RB3/DC
          _ => false
     }
 }
 
+fn get_save_version(char_clip_samples: &CharClipSamples, info: &SystemInfo) -> u32 {
+    match (info.version, info.get_revision(), char_clip_samples.version) {
+        (_, 0, _) => 10, // GH2
+        (_, 1, _) => 11, // GH2 360
+        (25, _, Some(v @ (13 | 14 | 16))) => v, // RB1/RB2/TBRB
+        (25, _, _) => 14, // RB2
+        _ => 16, // RB3/DC
+    }
+}
+
+fn get_char_clip_version(version: u32, info: &SystemInfo) -> u32 {
+    match version {
+        10 | 11 => 5,
+        13 => 7,
+        14 => 9,
+        _ if info.version < 28 => 12, // TBRB/GDRB
+        _ => 13, // RB3/DC
+    }
+}
+
+fn get_char_bones_samples_version(version: u32) -> u32 {
+    match version {
+        13 => 11,
+        14 => 12,
+        _ => 16,
+    }
+}
+
 impl ObjectReadWrite for CharClipSamples {
     fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
@@ -34,6 +63,8 @@ impl ObjectReadWrite for CharClipSamples {
             }));
         }
 
+        self.version = Some(version);
+
         // Metadata is written for CharClip instead for some reason
         load_char_clip(self, &mut reader, info, true)?;
 
@@ -48,11 +79,18 @@ impl ObjectReadWrite for CharClipSamples {
             let (full_bones, full_sample_count) = load_char_bones_samples_header(&mut self.full, &mut reader, version)?;
             let (one_bones, one_sample_count) = load_char_bones_samples_header(&mut self.one, &mut reader, version)?;
 
+            self.raw_header.clear();
+
             if version > 7 {
-                // Read duplicate serialized data Probably milo bug
-                // TODO: Write specific function that just skips data instead of read
+                // Extra header of unknown purpose. Keep raw bytes so it can be written back as is
+                let start = reader.pos();
+
                 let mut cbs = CharBonesSamples::default();
                 load_char_bones_samples_header(&mut cbs, &mut reader, version)?;
+
+                let size = (reader.pos() - start) as usize;
+                reader.seek(SeekFrom::Start(start))?;
+                self.raw_header = reader.read_bytes(size)?;
             }
 
             // Then read data
@@ -63,21 +101,420 @@ impl ObjectReadWrite for CharClipSamples {
             load_char_bones_samples(&mut self.one, &mut reader, info)?;
         }
 
+        self.bones.clear();
+
         if version > 14 {
             // Load bones
             let bone_count = reader.read_uint32()?;
 
-            // TODO: Do something with extra bones values
             for _ in 0..bone_count {
-                let _name = reader.read_prefixed_string()?;
-                let _weight = reader.read_float32()?;
+                let symbol = reader.read_prefixed_string()?;
+                let weight = reader.read_float32()?;
+
+                self.bones.push(CharBone { symbol, weight });
             }
         }
 
         Ok(())
     }
 
-    fn save(&self, _stream: &mut dyn Stream, _info: &SystemInfo) -> Result<(), Box<dyn Error>> {
-        todo!()
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+        writer.write_uint32(version)?;
+
+        save_char_clip(self, &mut writer, info, get_char_clip_version(version, info), true)?;
+
+        if version >= 16 {
+            writer.write_boolean(self.some_bool)?;
+        }
+
+        if version < 13 {
+            // Write headers first
+            save_char_bones_samples_header(&self.full, &mut writer, version)?;
+            save_char_bones_samples_header(&self.one, &mut writer, version)?;
+
+            if version > 7 {
+                if self.raw_header.is_empty() {
+                    // Not loaded from existing clip so write empty header
+                    save_char_bones_samples_header(&CharBonesSamples::default(), &mut writer, version)?;
+                } else {
+                    writer.write_bytes(&self.raw_header)?;
+                }
+            }
+
+            // Then write data
+            save_char_bones_samples_data(&self.full, &mut writer)?;
+            save_char_bones_samples_data(&self.one, &mut writer)?;
+        } else {
+            let bones_version = get_char_bones_samples_version(version);
+
+            save_char_bones_samples(&self.full, &mut writer, bones_version)?;
+            save_char_bones_samples(&self.one, &mut writer, bones_version)?;
+        }
+
+        if version > 14 {
+            // Write bones
+            writer.write_uint32(self.bones.len() as u32)?;
+
+            for bone in self.bones.iter() {
+                writer.write_prefixed_string(&bone.symbol)?;
+                writer.write_float32(bone.weight)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    fn create_bones_samples(symbols: &[&str], sample_size: usize, sample_count: usize) -> CharBonesSamples {
+        let bones = symbols
+            .iter()
+            .map(|s| CharBone { symbol: s.to_string(), weight: 1.0 })
+            .collect();
+
+        let samples = (0..sample_count)
+            .map(|i| vec![i as u8; sample_size].into_boxed_slice())
+            .collect();
+
+        CharBonesSamples {
+            compression: 2,
+            counts: [0, 1, 1, 2, 2, 2, 2],
+            samples: EncodedSamples::Compressed(bones, samples),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(25, Platform::X360, None, 14)] // RB2
+    #[case(25, Platform::PS3, Some(16), 16)] // TBRB
+    #[case(28, Platform::PS3, None, 16)] // RB3/DC
+    fn char_clip_samples_round_trip(#[case] version: u32, #[case] platform: Platform, #[case] loaded_version: Option<u32>, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let has_bones = expected_version > 14;
+        let has_beat_events = version >= 28;
+
+        // pos (6) + quat (8) = 14 -> 16 when aligned
+        let sample_size = 16;
+
+        let clip = CharClipSamples {
+            version: loaded_version,
+            start_beat: 1.0,
+            end_beat: 5.0,
+            beats_per_sec: 2.0,
+            flags: 4,
+            play_flags: 8,
+            blend_width: 0.5,
+            range: 1.5,
+            relative: String::from("relative.clp"),
+            unknown_1: if expected_version > 14 { 3 } else { -1 },
+            do_not_decompress: expected_version > 14,
+            nodes: vec![ClipNode {
+                name: String::from("next.clp"),
+                values: vec![ClipNodeData { frame: 1.0, weight: 0.5 }],
+            }],
+            events: vec![FrameEvent { frame: 2.0, script: String::from("{do_thing}") }],
+            beat_events: vec![BeatEvent { beat: 3.0, script: String::from("{do_beat}") }],
+            full: create_bones_samples(&["bone_pelvis.pos", "bone_pelvis.quat"], sample_size, 3),
+            one: create_bones_samples(&["bone_pelvis.pos", "bone_pelvis.quat"], sample_size, 1),
+            bones: if has_bones { vec![CharBone { symbol: String::from("bone_pelvis"), weight: 0.5 }] } else { Vec::new() },
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        clip.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut reader = BinaryStream::from_stream_with_endian(&mut stream, info.endian);
+        assert_eq!(expected_version, reader.read_uint32().unwrap());
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = CharClipSamples::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(Some(expected_version), result.version);
+        assert_eq!((clip.start_beat, clip.end_beat, clip.beats_per_sec), (result.start_beat, result.end_beat, result.beats_per_sec));
+        assert_eq!((clip.flags, clip.play_flags), (result.flags, result.play_flags));
+        assert_eq!((clip.blend_width, clip.range), (result.blend_width, result.range));
+        assert_eq!(clip.relative, result.relative);
+        assert_eq!(clip.unknown_1, result.unknown_1);
+        assert_eq!(clip.do_not_decompress, result.do_not_decompress);
+
+        assert_eq!(1, result.nodes.len());
+        assert_eq!("next.clp", result.nodes[0].name);
+        assert_eq!((1.0, 0.5), (result.nodes[0].values[0].frame, result.nodes[0].values[0].weight));
+
+        if has_beat_events {
+            assert!(result.events.is_empty());
+            assert_eq!(1, result.beat_events.len());
+            assert_eq!((3.0, "{do_beat}"), (result.beat_events[0].beat, result.beat_events[0].script.as_str()));
+        } else {
+            assert!(result.beat_events.is_empty());
+            assert_eq!(1, result.events.len());
+            assert_eq!((2.0, "{do_thing}"), (result.events[0].frame, result.events[0].script.as_str()));
+        }
+
+        for (expected, actual) in [(&clip.full, &result.full), (&clip.one, &result.one)] {
+            let (EncodedSamples::Compressed(expected_bones, expected_samples), EncodedSamples::Compressed(actual_bones, actual_samples)) = (&expected.samples, &actual.samples) else {
+                panic!("Expected compressed samples");
+            };
+
+            assert_eq!(expected.compression, actual.compression);
+            assert_eq!(expected.counts, actual.counts);
+            assert_eq!(
+                expected_bones.iter().map(|b| b.symbol.as_str()).collect::<Vec<_>>(),
+                actual_bones.iter().map(|b| b.symbol.as_str()).collect::<Vec<_>>()
+            );
+            assert_eq!(expected_samples, actual_samples);
+        }
+
+        assert_eq!(
+            clip.bones.iter().map(|b| (b.symbol.as_str(), b.weight)).collect::<Vec<_>>(),
+            result.bones.iter().map(|b| (b.symbol.as_str(), b.weight)).collect::<Vec<_>>()
+        );
+    }
+
+    #[rstest]
+    fn char_clip_samples_gh2_default_raw_header() {
+        let info = SystemInfo {
+            version: 24,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let clip = CharClipSamples::default();
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        clip.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = CharClipSamples::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(10), result.version);
+
+        // Empty bone list + 10 counts + compression + sample count
+        assert_eq!(52, result.raw_header.len());
+        assert!(result.raw_header.iter().all(|b| *b == 0));
     }
-}
\ No newline at end of file
+
+    #[rstest]
+    fn load_gh2_char_clip_samples_bytes() {
+        let info = SystemInfo {
+            version: 24,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x0A, 0x00, 0x00, 0x00, // Version (10)
+            0x05, 0x00, 0x00, 0x00, // CharClip version (5)
+            0x00, 0x00, 0x00, 0x00, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x80, 0x3F, // Start beat
+            0x00, 0x00, 0x80, 0x40, // End beat
+            0x00, 0x00, 0x00, 0x40, // Beats per sec
+            0x02, 0x00, 0x00, 0x00, // Flags
+            0x00, 0x00, 0x00, 0x00, // Play flags
+            0x00, 0x00, 0x00, 0x3F, // Blend width
+            0x00, 0x00, 0x00, 0x00, // Range
+            0x00,                   // Unknown bool
+            0x00, 0x00, 0x00, 0x00, // Node count
+            0x00, 0x00, 0x00, 0x00, // Enter event
+            0x00, 0x00, 0x00, 0x00, // Exit event
+            0x00, 0x00, 0x00, 0x00, // Event count
+            // Full header
+            0x01, 0x00, 0x00, 0x00, // Bone count
+            0x0F, 0x00, 0x00, 0x00, b'b', b'o', b'n', b'e', b'_', b'p', b'e', b'l', b'v', b'i', b's', b'.', b'p', b'o', b's',
+            0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // Counts
+            0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x00, 0x00, // Compression
+            0x02, 0x00, 0x00, 0x00, // Sample count
+            // One header
+            0x00, 0x00, 0x00, 0x00, // Bone count
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Counts
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Compression
+            0x00, 0x00, 0x00, 0x00, // Sample count
+            // Extra header
+            0x00, 0x00, 0x00, 0x00, // Bone count
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Counts
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x03, 0x00, 0x00, 0x00, // Compression
+            0x00, 0x00, 0x00, 0x00, // Sample count
+            // Full samples
+            0x01, 0x02, 0x03, 0x04, 0x05, 0x06,
+            0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C,
+        ];
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut clip = CharClipSamples::default();
+        clip.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!((1.0, 4.0, 2.0), (clip.start_beat, clip.end_beat, clip.beats_per_sec));
+        assert_eq!((2, 0.5), (clip.flags, clip.blend_width));
+        assert_eq!(2, clip.full.compression);
+        assert_eq!([0, 1, 1, 1, 1, 1, 1], clip.full.counts);
+        assert_eq!(52, clip.raw_header.len());
+
+        let EncodedSamples::Compressed(bones, samples) = &clip.full.samples else {
+            panic!("Expected compressed samples");
+        };
+
+        assert_eq!(vec![("bone_pelvis.pos", 1.0)], bones.iter().map(|b| (b.symbol.as_str(), b.weight)).collect::<Vec<_>>());
+        assert_eq!(vec![vec![1u8, 2, 3, 4, 5, 6].into_boxed_slice(), vec![7u8, 8, 9, 10, 11, 12].into_boxed_slice()], *samples);
+        assert!(matches!(&clip.one.samples, EncodedSamples::Compressed(b, s) if b.is_empty() && s.is_empty()));
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        clip.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    #[case(13, vec![
+        0x00, 0x00, 0x00, 0x0D, // Version (13)
+        0x00, 0x00, 0x00, 0x07, // CharClip version (7)
+        0x00, 0x00, 0x00, 0x02, // Revision
+        0x00, 0x00, 0x00, 0x00, // Type
+        0x00,                   // Props
+        0x00, 0x00, 0x00, 0x00, // Note
+        0x3F, 0x80, 0x00, 0x00, // Start beat
+        0x40, 0xA0, 0x00, 0x00, // End beat
+        0x40, 0x00, 0x00, 0x00, // Beats per sec
+        0x00, 0x00, 0x00, 0x04, // Flags
+        0x00, 0x00, 0x00, 0x08, // Play flags
+        0x3F, 0x00, 0x00, 0x00, // Blend width
+        0x3F, 0xC0, 0x00, 0x00, // Range
+        0x00, 0x00, 0x00, 0x05, b'r', b'.', b'c', b'l', b'p', // Relative
+        0x00, 0x00, 0x00, 0x00, // Node count
+        0x00, 0x00, 0x00, 0x01, // Event count
+        0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, b'{', b'g', b'o', b'}',
+        // Full samples
+        0x00, 0x00, 0x00, 0x0B, // CharBonesSamples version (11)
+        0x00, 0x00, 0x00, 0x01, // Bone count
+        0x00, 0x00, 0x00, 0x0F, b'b', b'o', b'n', b'e', b'_', b'p', b'e', b'l', b'v', b'i', b's', b'.', b'p', b'o', b's',
+        0x3F, 0x80, 0x00, 0x00, // Weight
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, // Counts
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x02, // Compression
+        0x00, 0x00, 0x00, 0x01, // Sample count
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, // Sample
+        // One samples
+        0x00, 0x00, 0x00, 0x0B, // CharBonesSamples version (11)
+        0x00, 0x00, 0x00, 0x00, // Bone count
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Counts
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // Compression
+        0x00, 0x00, 0x00, 0x00, // Sample count
+    ], vec![], vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06])]
+    #[case(14, vec![
+        0x00, 0x00, 0x00, 0x0E, // Version (14)
+        0x00, 0x00, 0x00, 0x09, // CharClip version (9)
+        0x00, 0x00, 0x00, 0x02, // Revision
+        0x00, 0x00, 0x00, 0x00, // Type
+        0x00,                   // Props
+        0x00, 0x00, 0x00, 0x00, // Note
+        0x3F, 0x80, 0x00, 0x00, // Start beat
+        0x40, 0xA0, 0x00, 0x00, // End beat
+        0x40, 0x00, 0x00, 0x00, // Beats per sec
+        0x00, 0x00, 0x00, 0x04, // Flags
+        0x00, 0x00, 0x00, 0x08, // Play flags
+        0x3F, 0x00, 0x00, 0x00, // Blend width
+        0x3F, 0xC0, 0x00, 0x00, // Range
+        0x00, 0x00, 0x00, 0x05, b'r', b'.', b'c', b'l', b'p', // Relative
+        0x00, 0x00, 0x00, 0x00, // Deprecated
+        0x00, 0x00, 0x00, 0x00, // Node size
+        0x00, 0x00, 0x00, 0x00, // Node count
+        0x00, 0x00, 0x00, 0x01, // Event count
+        0x40, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x04, b'{', b'g', b'o', b'}',
+        // Full samples
+        0x00, 0x00, 0x00, 0x0C, // CharBonesSamples version (12)
+        0x00, 0x00, 0x00, 0x01, // Bone count
+        0x00, 0x00, 0x00, 0x0F, b'b', b'o', b'n', b'e', b'_', b'p', b'e', b'l', b'v', b'i', b's', b'.', b'p', b'o', b's',
+        0x3F, 0x80, 0x00, 0x00, // Weight
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, // Counts
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x01,
+        0x00, 0x00, 0x00, 0x02, // Compression
+        0x00, 0x00, 0x00, 0x01, // Sample count
+        0x00, 0x00, 0x00, 0x01, // Frame count
+        0x3F, 0x80, 0x00, 0x00, // Frame
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0x00, // Sample (aligned)
+        // One samples
+        0x00, 0x00, 0x00, 0x0C, // CharBonesSamples version (12)
+        0x00, 0x00, 0x00, 0x00, // Bone count
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Counts
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00, 0x00, 0x00, // Compression
+        0x00, 0x00, 0x00, 0x00, // Sample count
+        0x00, 0x00, 0x00, 0x00, // Frame count
+    ], vec![1.0], vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x00, 0x00])]
+    fn load_rb_char_clip_samples_bytes(#[case] version: u32, #[case] mut data: Vec<u8>, #[case] frames: Vec<f32>, #[case] sample: Vec<u8>) {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let expected = data.clone();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut clip = CharClipSamples::default();
+        clip.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!((1.0, 5.0, 2.0), (clip.start_beat, clip.end_beat, clip.beats_per_sec));
+        assert_eq!((4, 8), (clip.flags, clip.play_flags));
+        assert_eq!((0.5, 1.5), (clip.blend_width, clip.range));
+        assert_eq!("r.clp", clip.relative);
+        assert!(clip.nodes.is_empty());
+        assert_eq!(1, clip.events.len());
+        assert_eq!((2.0, "{go}"), (clip.events[0].frame, clip.events[0].script.as_str()));
+
+        assert_eq!(2, clip.full.compression);
+        assert_eq!([0, 1, 1, 1, 1, 1, 1], clip.full.counts);
+        assert_eq!(frames, clip.full.frames);
+
+        let EncodedSamples::Compressed(bones, samples) = &clip.full.samples else {
+            panic!("Expected compressed samples");
+        };
+
+        assert_eq!(vec![("bone_pelvis.pos", 1.0)], bones.iter().map(|b| (b.symbol.as_str(), b.weight)).collect::<Vec<_>>());
+        assert_eq!(vec![sample.into_boxed_slice()], *samples);
+        assert!(matches!(&clip.one.samples, EncodedSamples::Compressed(b, s) if b.is_empty() && s.is_empty()));
+        assert!(clip.bones.is_empty());
+
+        if version == 14 {
+            // RB2 clips are written back as is
+            let mut saved = Vec::new();
+            let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+            clip.save(&mut out_stream, &info).unwrap();
+
+            assert_eq!(expected, saved);
+        }
+    }
+}
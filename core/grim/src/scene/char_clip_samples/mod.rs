@@ -6,32 +6,15 @@ pub use io::*;
 
 use super::{CharBone, CharBonesSamples};
 
-#[milo]
+#[milo(CharClip)]
 pub struct CharClipSamples {
-    // TODO: Move CharClip fields to macros
-    // Start CharClip
-    pub start_beat: f32,
-    pub end_beat: f32,
-    pub beats_per_sec: f32,
-
-    pub flags: u32,
-    pub play_flags: u32,
-
-    pub blend_width: f32,
-    pub range: f32,
-    pub relative: String,
-
-    pub unknown_1: i32,
-    pub do_not_decompress: bool,
-
-    pub nodes: Vec<ClipNode>,
-    pub events: Vec<FrameEvent>,
-    // End CharClip
-
+    pub version: Option<u32>,
     pub some_bool: bool,
     pub full: CharBonesSamples,
     pub one: CharBonesSamples,
     pub bones: Vec<CharBone>,
+    // Extra GH2 header, purpose unknown
+    pub raw_header: Vec<u8>,
 }
 
 impl Default for CharClipSamples {
@@ -59,107 +42,15 @@ impl Default for CharClipSamples {
 
             nodes: Vec::new(),
             events: Vec::new(),
+            beat_events: Vec::new(),
 
             // CharClipSamples object
+            version: None,
             some_bool: true,
             full: CharBonesSamples::default(),
             one: CharBonesSamples::default(),
             bones: Vec::new(),
+            raw_header: Vec::new(),
         }
     }
 }
-
-impl CharClip for CharClipSamples {
-    fn get_start_beat(&self) -> f32 {
-        self.start_beat
-    }
-    fn set_start_beat(&mut self, start_beat: f32) {
-        self.start_beat = start_beat;
-    }
-
-    fn get_end_beat(&self) -> f32 {
-        self.end_beat
-    }
-    fn set_end_beat(&mut self, end_beat: f32) {
-        self.end_beat = end_beat;
-    }
-
-    fn get_beats_per_sec(&self) -> f32 {
-        self.beats_per_sec
-    }
-    fn set_beats_per_sec(&mut self, beats_per_sec: f32) {
-        self.beats_per_sec = beats_per_sec;
-    }
-
-    fn get_flags(&self) -> u32 {
-        self.flags
-    }
-    fn set_flags(&mut self, flags: u32) {
-        self.flags = flags;
-    }
-
-    fn get_play_flags(&self) -> u32 {
-        self.play_flags
-    }
-    fn set_play_flags(&mut self, play_flags: u32) {
-        self.play_flags = play_flags;
-    }
-
-    fn get_blend_width(&self) -> f32 {
-        self.blend_width
-    }
-    fn set_blend_width(&mut self, blend_width: f32) {
-        self.blend_width = blend_width;
-    }
-
-    fn get_range(&self) -> f32 {
-        self.range
-    }
-    fn set_range(&mut self, range: f32) {
-        self.range = range;
-    }
-
-    fn get_relative(&self) -> &String {
-        &self.relative
-    }
-    fn get_relative_mut(&mut self) -> &mut String {
-        &mut self.relative
-    }
-    fn set_relative(&mut self, relative: String) {
-        self.relative = relative;
-    }
-
-    fn get_unknown_1(&self) -> i32 {
-        self.unknown_1
-    }
-    fn set_unknown_1(&mut self, unknown_1: i32) {
-        self.unknown_1 = unknown_1;
-    }
-
-    fn get_do_not_decompress(&self) -> bool {
-        self.do_not_decompress
-    }
-    fn set_do_not_decompress(&mut self, do_not_decompress: bool) {
-        self.do_not_decompress = do_not_decompress;
-    }
-
-    fn get_nodes(&self) -> &Vec<ClipNode> {
-        &self.nodes
-    }
-    fn get_nodes_mut(&mut self) -> &mut Vec<ClipNode> {
-        &mut self.nodes
-    }
-    fn set_nodes(&mut self, nodes: Vec<ClipNode>) {
-        self.nodes = nodes;
-    }
-
-    fn get_events(&self) -> &Vec<FrameEvent> {
-        &self.events
-    }
-    fn get_events_mut(&mut self) -> &mut Vec<FrameEvent> {
-        &mut self.events
-    }
-    fn set_events(&mut self, events: Vec<FrameEvent>) {
-        self.events = events;
-    }
-}
\ No newline at end of file
@@ -0,0 +1,233 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum CharDriverLoadError {
+    #[error("CharDriver version {version} is not supported")]
+    CharDriverVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+         6 => true, // GH2
+         9 => true, // RB1/RB2
+        11 => true, // TBRB/GDRB
+        _ => false
+    }
+}
+
+fn get_save_version(info: &SystemInfo) -> u32 {
+    match info.version {
+        ..=24 => 6, // GH2
+        _ => 9,
+    }
+}
+
+impl ObjectReadWrite for CharDriver {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(CharDriverLoadError::CharDriverVersionNotSupported {
+                version
+            }));
+        }
+
+        load_object(self, &mut reader, info)?;
+        load_char_weightable(self, &mut reader, info, false)?;
+
+        self.bones = reader.read_prefixed_string()?;
+        self.clips = reader.read_prefixed_string()?;
+        self.first_clip = reader.read_prefixed_string()?;
+
+        if version > 3 {
+            self.starved_handler = reader.read_prefixed_string()?;
+        }
+
+        if version > 4 {
+            self.blend_width = reader.read_float32()?;
+        }
+
+        self.clip_type = reader.read_prefixed_string()?;
+
+        if version > 5 {
+            self.apply = reader.read_uint32()?.into();
+        }
+
+        if version > 6 {
+            self.real_time = reader.read_boolean()?;
+        }
+
+        if version > 8 {
+            self.play_multiple_clips = reader.read_boolean()?;
+        }
+
+        if version > 10 {
+            self.internal_bones = reader.read_prefixed_string()?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(info);
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+        save_char_weightable(self, &mut stream, info, false)?;
+
+        stream.write_prefixed_string(&self.bones)?;
+        stream.write_prefixed_string(&self.clips)?;
+        stream.write_prefixed_string(&self.first_clip)?;
+
+        if version > 3 {
+            stream.write_prefixed_string(&self.starved_handler)?;
+        }
+
+        if version > 4 {
+            stream.write_float32(self.blend_width)?;
+        }
+
+        stream.write_prefixed_string(&self.clip_type)?;
+
+        if version > 5 {
+            stream.write_uint32(self.apply as u32)?;
+        }
+
+        if version > 6 {
+            stream.write_boolean(self.real_time)?;
+        }
+
+        if version > 8 {
+            stream.write_boolean(self.play_multiple_clips)?;
+        }
+
+        if version > 10 {
+            stream.write_prefixed_string(&self.internal_bones)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_char_driver_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x09, // Version (9)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x02, // CharWeightable version (2)
+            0x3F, 0x00, 0x00, 0x00, // Weight
+            0x00, 0x00, 0x00, 0x00, // Weight owner
+            0x00, 0x00, 0x00, 0x07, b'm', b'a', b'i', b'n', b'.', b'c', b'b', // Bones
+            0x00, 0x00, 0x00, 0x05, b'c', b'l', b'i', b'p', b's', // Clips
+            0x00, 0x00, 0x00, 0x04, b'i', b'd', b'l', b'e', // First clip
+            0x00, 0x00, 0x00, 0x00, // Starved handler
+            0x40, 0x00, 0x00, 0x00, // Blend width
+            0x00, 0x00, 0x00, 0x08, b'B', b'a', b'n', b'd', b'C', b'l', b'i', b'p', // Clip type
+            0x00, 0x00, 0x00, 0x01, // Apply
+            0x01,                   // Real time
+            0x00,                   // Play multiple clips
+        ];
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut driver = CharDriver::default();
+        driver.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(0.5, driver.weight);
+        assert_eq!("", driver.weight_owner);
+        assert_eq!("main.cb", driver.bones);
+        assert_eq!("clips", driver.clips);
+        assert_eq!("idle", driver.first_clip);
+        assert_eq!(2.0, driver.blend_width);
+        assert_eq!("BandClip", driver.clip_type);
+        assert_eq!(DriverApply::kApplyAdd as u32, driver.apply as u32);
+        assert!(driver.real_time);
+        assert!(!driver.play_multiple_clips);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        driver.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    #[case(24, Platform::PS2)]
+    #[case(25, Platform::X360)]
+    fn save_load_char_driver(#[case] version: u32, #[case] platform: Platform) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let driver = CharDriver {
+            weight: 0.5,
+            weight_owner: String::from("main.drv"),
+            bones: String::from("main.cb"),
+            clips: String::from("clips"),
+            first_clip: String::from("idle"),
+            blend_width: 2.0,
+            clip_type: String::from("BandClip"),
+            apply: DriverApply::kApplyAdd,
+            real_time: true,
+            play_multiple_clips: true,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        driver.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_driver = CharDriver::default();
+        loaded_driver.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(driver.weight, loaded_driver.weight);
+        assert_eq!(driver.bones, loaded_driver.bones);
+        assert_eq!(driver.clips, loaded_driver.clips);
+        assert_eq!(driver.first_clip, loaded_driver.first_clip);
+        assert_eq!(driver.blend_width, loaded_driver.blend_width);
+        assert_eq!(driver.clip_type, loaded_driver.clip_type);
+        assert_eq!(driver.apply as u32, loaded_driver.apply as u32);
+
+        if version > 24 {
+            assert_eq!(driver.real_time, loaded_driver.real_time);
+            assert_eq!(driver.weight_owner, loaded_driver.weight_owner);
+            assert_eq!(driver.play_multiple_clips, loaded_driver.play_multiple_clips);
+        }
+    }
+}
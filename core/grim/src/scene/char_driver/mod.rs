@@ -0,0 +1,70 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo(CharWeightable)]
+pub struct CharDriver {
+    pub bones: String,
+    pub clips: String,
+    pub first_clip: String,
+    pub starved_handler: String,
+    pub blend_width: f32,
+    pub clip_type: String,
+    pub apply: DriverApply,
+    pub real_time: bool,
+    pub play_multiple_clips: bool,
+    pub internal_bones: String,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+#[repr(u32)]
+pub enum DriverApply {
+    #[default]
+    kApplyBlend,
+    kApplyAdd,
+    kApplyRotateTo,
+    kApplyBlendWeights,
+}
+
+impl From<u32> for DriverApply {
+    fn from(num: u32) -> DriverApply {
+        match num {
+            0 => DriverApply::kApplyBlend,
+            1 => DriverApply::kApplyAdd,
+            2 => DriverApply::kApplyRotateTo,
+            3 => DriverApply::kApplyBlendWeights,
+            // Default
+            _ => DriverApply::default(),
+        }
+    }
+}
+
+impl Default for CharDriver {
+    fn default() -> CharDriver {
+        CharDriver {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // CharWeightable object
+            weight: 1.0,
+            weight_owner: String::default(),
+
+            // CharDriver object
+            bones: String::default(),
+            clips: String::default(),
+            first_clip: String::default(),
+            starved_handler: String::default(),
+            blend_width: 1.0,
+            clip_type: String::default(),
+            apply: DriverApply::default(),
+            real_time: false,
+            play_multiple_clips: false,
+            internal_bones: String::default(),
+        }
+    }
+}
@@ -99,7 +99,173 @@ impl ObjectReadWrite for CharHair {
 
         save_object(self, &mut stream, info)?;
 
-        todo!("Support for writing CharHair not implemented yet");
-        //Ok(())
+        stream.write_float32(self.stiffness)?;
+        stream.write_float32(self.torsion)?;
+        stream.write_float32(self.inertia)?;
+        stream.write_float32(self.gravity)?;
+
+        stream.write_float32(self.weight)?;
+        stream.write_float32(self.friction)?;
+
+        if version >= 11 {
+            stream.write_float32(self.min_slack)?;
+            stream.write_float32(self.max_slack)?;
+        }
+
+        // Write strands
+        stream.write_uint32(self.strands.len() as u32)?;
+        for strand in self.strands.iter() {
+            stream.write_prefixed_string(&strand.root)?;
+            stream.write_float32(strand.angle)?;
+
+            // Write points
+            stream.write_uint32(strand.points.len() as u32)?;
+            for point in strand.points.iter() {
+                save_vector3(&point.unknown_floats, &mut stream)?;
+                stream.write_prefixed_string(&point.bone)?;
+
+                stream.write_float32(point.length)?;
+                stream.write_uint32(point.collide_type as u32)?;
+                stream.write_prefixed_string(&point.collision)?;
+
+                stream.write_float32(point.distance)?;
+                stream.write_float32(point.align_dist)?;
+            }
+
+            // Write rotation + scale matrices
+            save_matrix3(&strand.base_mat, &mut stream)?;
+            save_matrix3(&strand.root_mat, &mut stream)?;
+        }
+
+        stream.write_boolean(self.simulate)?;
+
+        if version >= 11 {
+            stream.write_prefixed_string(&self.wind)?;
+        }
+
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    fn char_hair_round_trip() {
+        let info = SystemInfo {
+            version: 24,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let hair = CharHair {
+            name: String::from("hair.hair"),
+            stiffness: 0.5,
+            friction: 0.75,
+            strands: vec![
+                CharHairStrand {
+                    root: String::from("bone_hair01.mesh"),
+                    angle: 1.5,
+                    points: vec![
+                        CharHairPoint {
+                            unknown_floats: Vector3 { x: 1.0, y: 2.0, z: 3.0 },
+                            bone: String::from("bone_hair02.mesh"),
+                            length: 4.0,
+                            collide_type: CollideType::kCollideSphere,
+                            collision: String::from("head.coll"),
+                            distance: 5.0,
+                            align_dist: 6.0,
+                        }
+                    ],
+                    ..Default::default()
+                }
+            ],
+            simulate: false,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        hair.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = CharHair::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(hair.stiffness, result.stiffness);
+        assert_eq!(hair.friction, result.friction);
+        assert_eq!(hair.simulate, result.simulate);
+        assert_eq!(1, result.strands.len());
+
+        let strand = &result.strands[0];
+        assert_eq!("bone_hair01.mesh", strand.root);
+        assert_eq!(1.5, strand.angle);
+        assert_eq!(1, strand.points.len());
+
+        let point = &strand.points[0];
+        assert_eq!((1.0, 2.0, 3.0), (point.unknown_floats.x, point.unknown_floats.y, point.unknown_floats.z));
+        assert_eq!("bone_hair02.mesh", point.bone);
+        assert_eq!(4.0, point.length);
+        assert_eq!(CollideType::kCollideSphere as u32, point.collide_type as u32);
+        assert_eq!("head.coll", point.collision);
+        assert_eq!((5.0, 6.0), (point.distance, point.align_dist));
+    }
+
+    #[rstest]
+    fn load_char_hair_bytes() {
+        let info = SystemInfo {
+            version: 24,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x02, 0x00, 0x00, 0x00, // Version (2)
+            0x00, 0x00, 0x00, 0x00, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x3F, // Stiffness (0.5)
+            0x00, 0x00, 0x80, 0x3F, // Torsion (1.0)
+            0x00, 0x00, 0x00, 0x40, // Inertia (2.0)
+            0x00, 0x00, 0x40, 0x40, // Gravity (3.0)
+            0x00, 0x00, 0x80, 0x40, // Weight (4.0)
+            0x00, 0x00, 0x40, 0x3F, // Friction (0.75)
+            0x01, 0x00, 0x00, 0x00, // Strand count
+            0x06, 0x00, 0x00, 0x00, b'r', b'.', b'm', b'e', b's', b'h', // Root
+            0x00, 0x00, 0xC0, 0x3F, // Angle (1.5)
+            0x01, 0x00, 0x00, 0x00, // Point count
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x40, 0x40, // Unknown floats
+            0x06, 0x00, 0x00, 0x00, b'p', b'.', b'm', b'e', b's', b'h', // Bone
+            0x00, 0x00, 0x80, 0x40, // Length (4.0)
+            0x03, 0x00, 0x00, 0x00, // Collide type (cylinder)
+            0x06, 0x00, 0x00, 0x00, b'h', b'.', b'c', b'o', b'l', b'l', // Collision
+            0x00, 0x00, 0xA0, 0x40, // Distance (5.0)
+            0x00, 0x00, 0xC0, 0x40, // Align dist (6.0)
+        ];
+        data.extend_from_slice(&[0u8; 72]); // Base + root matrices
+        data.push(0x01); // Simulate
+
+        let hair: CharHair = assert_round_trip_bytes(&data, &info);
+        assert_eq!((0.5, 1.0, 2.0, 3.0), (hair.stiffness, hair.torsion, hair.inertia, hair.gravity));
+        assert_eq!((4.0, 0.75), (hair.weight, hair.friction));
+        assert!(hair.simulate);
+        assert_eq!(1, hair.strands.len());
+
+        let strand = &hair.strands[0];
+        assert_eq!("r.mesh", strand.root);
+        assert_eq!(1.5, strand.angle);
+        assert_eq!(1, strand.points.len());
+
+        let point = &strand.points[0];
+        assert_eq!((1.0, 2.0, 3.0), (point.unknown_floats.x, point.unknown_floats.y, point.unknown_floats.z));
+        assert_eq!("p.mesh", point.bone);
+        assert_eq!(4.0, point.length);
+        assert_eq!(CollideType::kCollideCylinder as u32, point.collide_type as u32);
+        assert_eq!("h.coll", point.collision);
+        assert_eq!((5.0, 6.0), (point.distance, point.align_dist));
+    }
+}
@@ -0,0 +1,267 @@
+use super::{CharLipSync, CharLipSyncEncodeError};
+use std::ops::Range;
+
+// Dense viseme weights for every frame
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LipSyncFrames {
+    pub visemes: Vec<String>,
+    pub weights: Vec<Vec<u8>>, // Frame -> viseme weights
+}
+
+impl LipSyncFrames {
+    pub fn new(visemes: Vec<String>, frame_count: usize) -> LipSyncFrames {
+        LipSyncFrames {
+            weights: vec![vec![0; visemes.len()]; frame_count],
+            visemes,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.weights.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.weights.is_empty()
+    }
+
+    pub fn set_frame_count(&mut self, frame_count: usize) {
+        self.weights.resize(frame_count, vec![0; self.visemes.len()]);
+    }
+
+    pub fn get_viseme_index(&self, viseme: &str) -> Option<usize> {
+        self.visemes.iter().position(|v| v.eq(viseme))
+    }
+
+    pub fn add_viseme(&mut self, viseme: &str) -> usize {
+        if let Some(idx) = self.get_viseme_index(viseme) {
+            return idx;
+        }
+
+        self.visemes.push(viseme.to_owned());
+
+        for frame in self.weights.iter_mut() {
+            frame.push(0);
+        }
+
+        self.visemes.len() - 1
+    }
+
+    pub fn remove_viseme(&mut self, viseme: &str) -> bool {
+        let Some(idx) = self.get_viseme_index(viseme) else {
+            return false;
+        };
+
+        self.visemes.remove(idx);
+
+        for frame in self.weights.iter_mut() {
+            frame.remove(idx);
+        }
+
+        true
+    }
+
+    pub fn get_weight(&self, frame: usize, viseme: &str) -> u8 {
+        self.get_viseme_index(viseme)
+            .and_then(|idx| self.weights.get(frame).map(|w| w[idx]))
+            .unwrap_or_default()
+    }
+
+    pub fn set_weight(&mut self, frame: usize, viseme: &str, weight: u8) {
+        let idx = self.add_viseme(viseme);
+
+        if frame >= self.len() {
+            self.set_frame_count(frame + 1);
+        }
+
+        self.weights[frame][idx] = weight;
+    }
+
+    pub fn clear_weight(&mut self, frame: usize, viseme: &str) {
+        let Some(idx) = self.get_viseme_index(viseme) else {
+            return;
+        };
+
+        if let Some(weights) = self.weights.get_mut(frame) {
+            weights[idx] = 0;
+        }
+    }
+
+    pub fn clear_viseme(&mut self, viseme: &str) {
+        let Some(idx) = self.get_viseme_index(viseme) else {
+            return;
+        };
+
+        for frame in self.weights.iter_mut() {
+            frame[idx] = 0;
+        }
+    }
+
+    pub fn clear_frames(&mut self, range: Range<usize>) {
+        let end = range.end.min(self.len());
+        let start = range.start.min(end);
+
+        for frame in self.weights[start..end].iter_mut() {
+            frame.fill(0);
+        }
+    }
+
+    pub fn offset(&mut self, frames: isize) {
+        // Shift frames forward (insert empty frames) or backward (drop frames)
+        if frames >= 0 {
+            let empty = vec![0; self.visemes.len()];
+            self.weights.splice(0..0, std::iter::repeat_n(empty, frames as usize));
+        } else {
+            let count = frames.unsigned_abs().min(self.len());
+            self.weights.drain(..count);
+        }
+    }
+
+    pub fn retime(&mut self, factor: f32) {
+        if factor <= 0.0 || self.is_empty() {
+            return;
+        }
+
+        // Linear resample of weights
+        let last = self.len() - 1;
+        let frame_count = ((last as f32 * factor).round() as usize) + 1;
+
+        self.weights = (0..frame_count)
+            .map(|i| {
+                let pos = (i as f32 / factor).min(last as f32);
+                let prev = pos.floor() as usize;
+                let next = (prev + 1).min(last);
+                let t = pos - prev as f32;
+
+                self.weights[prev]
+                    .iter()
+                    .zip(self.weights[next].iter())
+                    .map(|(a, b)| (*a as f32 + (*b as f32 - *a as f32) * t).round() as u8)
+                    .collect()
+            })
+            .collect();
+    }
+
+    pub fn merge(&mut self, other: &LipSyncFrames, start_frame: usize) {
+        // Weights from other replace existing weights for same visemes
+        let indices = other
+            .visemes
+            .iter()
+            .map(|v| self.add_viseme(v))
+            .collect::<Vec<_>>();
+
+        if start_frame + other.len() > self.len() {
+            self.set_frame_count(start_frame + other.len());
+        }
+
+        for (frame, other_weights) in self.weights[start_frame..].iter_mut().zip(other.weights.iter()) {
+            for (idx, weight) in indices.iter().zip(other_weights.iter()) {
+                frame[*idx] = *weight;
+            }
+        }
+    }
+}
+
+impl CharLipSync {
+    pub fn decode_frames(&self) -> LipSyncFrames {
+        let mut frames = LipSyncFrames::new(self.visemes.to_owned(), 0);
+        let mut current = vec![0u8; self.visemes.len()];
+
+        // Frames only store changed weights so accumulate them
+        for frame in self.get_frames() {
+            for (viseme, weight) in frame.visemes {
+                if let Some(idx) = frames.get_viseme_index(viseme) {
+                    current[idx] = weight;
+                }
+            }
+
+            frames.weights.push(current.to_owned());
+        }
+
+        // Last weights are held if data is missing frames
+        frames.weights.resize(self.frames_count, current);
+        frames
+    }
+
+    pub fn encode_frames(&mut self, frames: &LipSyncFrames) -> Result<(), CharLipSyncEncodeError> {
+        self.set_weights(frames.visemes.to_owned(), &frames.weights)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    fn create_frames() -> LipSyncFrames {
+        let mut frames = LipSyncFrames::default();
+        frames.set_weight(0, "Eat_hi", 100);
+        frames.set_weight(1, "Eat_hi", 200);
+        frames.set_weight(2, "Bump_hi", 50);
+        frames
+    }
+
+    #[rstest]
+    fn lipsync_frames_set_clear() {
+        let mut frames = create_frames();
+        assert_eq!(3, frames.len());
+        assert_eq!(vec!["Eat_hi", "Bump_hi"], frames.visemes);
+        assert_eq!(vec![vec![100, 0], vec![200, 0], vec![0, 50]], frames.weights);
+
+        frames.clear_weight(1, "Eat_hi");
+        assert_eq!(0, frames.get_weight(1, "Eat_hi"));
+
+        frames.clear_frames(0..1);
+        assert_eq!(vec![vec![0, 0], vec![0, 0], vec![0, 50]], frames.weights);
+
+        assert!(frames.remove_viseme("Eat_hi"));
+        assert_eq!(vec![vec![0], vec![0], vec![50]], frames.weights);
+    }
+
+    #[rstest]
+    fn lipsync_frames_encode_decode() {
+        let frames = create_frames();
+
+        let mut lipsync = CharLipSync::default();
+        lipsync.encode_frames(&frames).unwrap();
+
+        assert_eq!(3, lipsync.frames_count);
+        assert_eq!(vec![1, 0, 100, 1, 0, 200, 2, 0, 0, 1, 50], lipsync.data);
+        assert_eq!(frames, lipsync.decode_frames());
+    }
+
+    #[rstest]
+    #[case(2, vec![vec![0, 0], vec![0, 0], vec![100, 0], vec![200, 0], vec![0, 50]])]
+    #[case(-2, vec![vec![0, 50]])]
+    #[case(-5, vec![])]
+    fn lipsync_frames_offset(#[case] offset: isize, #[case] expected: Vec<Vec<u8>>) {
+        let mut frames = create_frames();
+        frames.offset(offset);
+
+        assert_eq!(expected, frames.weights);
+    }
+
+    #[rstest]
+    #[case(2.0, vec![100, 150, 200, 100, 0])]
+    #[case(0.5, vec![100, 0])]
+    fn lipsync_frames_retime(#[case] factor: f32, #[case] expected: Vec<u8>) {
+        let mut frames = create_frames();
+        frames.retime(factor);
+
+        let eat = frames.weights.iter().map(|w| w[0]).collect::<Vec<_>>();
+        assert_eq!(expected, eat);
+    }
+
+    #[rstest]
+    fn lipsync_frames_merge() {
+        let mut frames = create_frames();
+
+        let mut other = LipSyncFrames::default();
+        other.set_weight(0, "Blink", 255);
+        other.set_weight(1, "Eat_hi", 10);
+
+        frames.merge(&other, 2);
+
+        assert_eq!(vec!["Eat_hi", "Bump_hi", "Blink"], frames.visemes);
+        assert_eq!(vec![vec![100, 0, 0], vec![200, 0, 0], vec![0, 50, 255], vec![10, 0, 0]], frames.weights);
+    }
+}
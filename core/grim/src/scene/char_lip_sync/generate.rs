@@ -0,0 +1,398 @@
+use super::{CharLipSync, CharLipSyncEncodeError, LipSyncFrames, Phoneme, get_word_phonemes};
+use std::collections::HashMap;
+
+pub const LIPSYNC_FPS: f32 = 30.0;
+
+const CONSONANT_LENGTH_MS: f32 = 60.0;
+const BLEND_LENGTH_MS: f32 = 2000.0 / LIPSYNC_FPS; // 2 frames
+
+#[derive(Clone, Debug, Default)]
+pub struct LyricSyllable {
+    pub text: String,
+    pub start: f32, // Milliseconds
+    pub end: f32,   // Milliseconds
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimedPhoneme {
+    pub phoneme: Phoneme,
+    pub start: f32, // Milliseconds
+    pub end: f32,   // Milliseconds
+}
+
+type PhonemeMapping<'a> = (&'a [Phoneme], &'a [(&'a str, f32)]);
+
+#[derive(Default)]
+pub struct VisemeSet {
+    pub visemes: Vec<String>,
+    phonemes: HashMap<Phoneme, Vec<(usize, f32)>>, // Viseme index, weight
+}
+
+impl VisemeSet {
+    pub fn new() -> VisemeSet {
+        VisemeSet::default()
+    }
+
+    pub fn rock_band() -> VisemeSet {
+        let mappings: [PhonemeMapping; 21] = [
+            (&[Phoneme::IY, Phoneme::Y], &[("Eat", 1.0)]),
+            (&[Phoneme::ER], &[("Earth", 1.0)]),
+            (&[Phoneme::IH, Phoneme::EH, Phoneme::AE], &[("If", 1.0)]),
+            (&[Phoneme::AA, Phoneme::AO, Phoneme::AH], &[("Ox", 1.0)]),
+            (&[Phoneme::AY], &[("Ox", 0.7), ("Eat", 0.3)]),
+            (&[Phoneme::AW], &[("Ox", 0.7), ("Wet", 0.3)]),
+            (&[Phoneme::OW], &[("Oat", 1.0)]),
+            (&[Phoneme::OY], &[("Oat", 0.7), ("Eat", 0.3)]),
+            (&[Phoneme::EY], &[("Cage", 1.0)]),
+            (&[Phoneme::W, Phoneme::UW], &[("Wet", 1.0)]),
+            (&[Phoneme::UH], &[("New", 1.0)]),
+            (&[Phoneme::S, Phoneme::Z], &[("Size", 1.0)]),
+            (&[Phoneme::CH, Phoneme::SH, Phoneme::JH, Phoneme::ZH], &[("Church", 1.0)]),
+            (&[Phoneme::F, Phoneme::V], &[("Fave", 1.0)]),
+            (&[Phoneme::TH, Phoneme::DH], &[("Though", 1.0)]),
+            (&[Phoneme::T, Phoneme::D, Phoneme::L], &[("Told", 1.0)]),
+            (&[Phoneme::N, Phoneme::NG], &[("New", 1.0)]),
+            (&[Phoneme::B, Phoneme::M, Phoneme::P], &[("Bump", 1.0)]),
+            (&[Phoneme::R], &[("Roar", 1.0)]),
+            (&[Phoneme::K, Phoneme::G], &[("Cage", 1.0)]),
+            (&[Phoneme::HH], &[("If", 0.5)]),
+        ];
+
+        let mut viseme_set = VisemeSet::new();
+
+        for (phonemes, weights) in mappings {
+            // Game blends between hi + lo visemes by singing volume
+            let weights = weights
+                .iter()
+                .flat_map(|(v, w)| [(format!("{v}_hi"), *w), (format!("{v}_lo"), *w)])
+                .collect::<Vec<_>>();
+
+            let weights = weights
+                .iter()
+                .map(|(v, w)| (v.as_str(), *w))
+                .collect::<Vec<_>>();
+
+            for phoneme in phonemes {
+                viseme_set.add_phoneme(*phoneme, &weights);
+            }
+        }
+
+        viseme_set
+    }
+
+    pub fn add_phoneme(&mut self, phoneme: Phoneme, weights: &[(&str, f32)]) {
+        let weights = weights
+            .iter()
+            .map(|(viseme, weight)| (self.get_or_add_viseme(viseme), *weight))
+            .collect();
+
+        self.phonemes.insert(phoneme, weights);
+    }
+
+    pub fn get_weights(&self, phoneme: Phoneme) -> &[(usize, f32)] {
+        self.phonemes
+            .get(&phoneme)
+            .map(|w| w.as_slice())
+            .unwrap_or_default()
+    }
+
+    fn get_or_add_viseme(&mut self, viseme: &str) -> usize {
+        match self.visemes.iter().position(|v| v.eq(viseme)) {
+            Some(idx) => idx,
+            None => {
+                self.visemes.push(viseme.to_owned());
+                self.visemes.len() - 1
+            }
+        }
+    }
+}
+
+pub struct LipSyncGenerator {
+    pub viseme_set: VisemeSet,
+    pub blend_length: f32, // Milliseconds
+}
+
+impl Default for LipSyncGenerator {
+    fn default() -> LipSyncGenerator {
+        LipSyncGenerator::new(VisemeSet::rock_band())
+    }
+}
+
+impl LipSyncGenerator {
+    pub fn new(viseme_set: VisemeSet) -> LipSyncGenerator {
+        LipSyncGenerator {
+            viseme_set,
+            blend_length: BLEND_LENGTH_MS,
+        }
+    }
+
+    pub fn generate(&self, syllables: &[LyricSyllable], length: f32) -> Result<CharLipSync, CharLipSyncEncodeError> {
+        let frames = self.generate_frames(&get_timed_phonemes(syllables), length);
+
+        let mut lipsync = CharLipSync::default();
+        lipsync.encode_frames(&frames)?;
+        Ok(lipsync)
+    }
+
+    pub fn generate_frames(&self, phonemes: &[TimedPhoneme], length: f32) -> LipSyncFrames {
+        let end = phonemes
+            .last()
+            .map(|p| p.end + self.blend_length)
+            .unwrap_or_default()
+            .max(length);
+
+        let frame_count = (end * LIPSYNC_FPS / 1000.0).ceil() as usize + 1;
+
+        LipSyncFrames {
+            visemes: self.viseme_set.visemes.to_owned(),
+            weights: self.generate_weights(phonemes, frame_count),
+        }
+    }
+
+    pub fn generate_weights(&self, phonemes: &[TimedPhoneme], frame_count: usize) -> Vec<Vec<u8>> {
+        let blend = self.blend_length;
+        let mut first = 0;
+
+        (0..frame_count)
+            .map(|i| {
+                let time = (i as f32 * 1000.0) / LIPSYNC_FPS;
+                let mut weights = vec![0.0f32; self.viseme_set.visemes.len()];
+
+                // Skip phonemes already faded out
+                while first < phonemes.len() && (phonemes[first].end + blend) < time {
+                    first += 1;
+                }
+
+                for timed in phonemes[first..].iter().take_while(|p| (p.start - blend) <= time) {
+                    let amount = match blend > 0.0 {
+                        true if time < timed.start => 1.0 - ((timed.start - time) / blend),
+                        true if time > timed.end => 1.0 - ((time - timed.end) / blend),
+                        _ => 1.0,
+                    }.max(0.0);
+
+                    for (viseme_idx, weight) in self.viseme_set.get_weights(timed.phoneme) {
+                        let w = &mut weights[*viseme_idx];
+                        *w = w.max(amount * weight);
+                    }
+                }
+
+                weights
+                    .into_iter()
+                    .map(|w| (w.clamp(0.0, 1.0) * 255.0).round() as u8)
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+fn clean_syllable(text: &str) -> (String, bool) {
+    // Remove pitch/display markers
+    let text = text
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, '#' | '^' | '$' | '*' | '%'))
+        .collect::<String>();
+
+    // Hyphen means word continues in next syllable
+    match text.strip_suffix(['-', '=']) {
+        Some(text) => (text.to_owned(), true),
+        None => (text, false),
+    }
+}
+
+pub fn get_timed_phonemes(syllables: &[LyricSyllable]) -> Vec<TimedPhoneme> {
+    let mut timed_phonemes: Vec<TimedPhoneme> = Vec::new();
+    let mut word = String::new();
+    let mut word_syllables: Vec<(f32, f32)> = Vec::new();
+
+    for syllable in syllables {
+        let (text, continues) = clean_syllable(&syllable.text);
+
+        if text.eq("+") {
+            // Pitch slide, extend previous syllable
+            if let Some((_, end)) = word_syllables.last_mut() {
+                *end = end.max(syllable.end);
+            } else if let Some(prev) = timed_phonemes.last_mut() {
+                prev.end = prev.end.max(syllable.end);
+            }
+
+            continue;
+        }
+
+        word.push_str(&text);
+        word_syllables.push((syllable.start, syllable.end));
+
+        if !continues {
+            add_word_phonemes(&word, &word_syllables, &mut timed_phonemes);
+            word.clear();
+            word_syllables.clear();
+        }
+    }
+
+    if !word_syllables.is_empty() {
+        add_word_phonemes(&word, &word_syllables, &mut timed_phonemes);
+    }
+
+    timed_phonemes
+}
+
+fn add_word_phonemes(word: &str, syllables: &[(f32, f32)], timed_phonemes: &mut Vec<TimedPhoneme>) {
+    let phonemes = get_word_phonemes(word);
+
+    for (chunk, (start, end)) in split_phonemes(&phonemes, syllables.len()).into_iter().zip(syllables) {
+        add_syllable_phonemes(chunk, *start, *end, timed_phonemes);
+    }
+}
+
+fn split_phonemes(phonemes: &[Phoneme], count: usize) -> Vec<&[Phoneme]> {
+    if count <= 1 {
+        return vec![phonemes];
+    }
+
+    let vowels = phonemes
+        .iter()
+        .enumerate()
+        .filter(|(_, p)| p.is_vowel())
+        .map(|(i, _)| i)
+        .collect::<Vec<_>>();
+
+    // Split consonants between vowels if each syllable has one, otherwise split evenly
+    let bounds = match vowels.len() == count {
+        true => vowels
+            .windows(2)
+            .map(|v| v[0] + 1 + ((v[1] - v[0] - 1) / 2))
+            .collect::<Vec<_>>(),
+        false => (1..count)
+            .map(|i| (i * phonemes.len()) / count)
+            .collect(),
+    };
+
+    let mut chunks = Vec::new();
+    let mut start = 0;
+
+    for bound in bounds {
+        chunks.push(&phonemes[start..bound]);
+        start = bound;
+    }
+
+    chunks.push(&phonemes[start..]);
+    chunks
+}
+
+fn add_syllable_phonemes(phonemes: &[Phoneme], start: f32, end: f32, timed_phonemes: &mut Vec<TimedPhoneme>) {
+    if phonemes.is_empty() {
+        return;
+    }
+
+    let length = (end - start).max(0.0);
+    let vowel_count = phonemes.iter().filter(|p| p.is_vowel()).count();
+    let consonant_count = phonemes.len() - vowel_count;
+
+    // Consonants are short and vowels are held for remaining length
+    let (consonant_length, vowel_length) = match vowel_count {
+        0 => (length / phonemes.len() as f32, 0.0),
+        _ => {
+            let consonant_length = match consonant_count {
+                0 => 0.0,
+                _ => CONSONANT_LENGTH_MS.min((length * 0.5) / consonant_count as f32),
+            };
+
+            (consonant_length, (length - (consonant_length * consonant_count as f32)) / vowel_count as f32)
+        }
+    };
+
+    let mut pos = start;
+
+    for phoneme in phonemes {
+        let length = match phoneme.is_vowel() {
+            true => vowel_length,
+            false => consonant_length,
+        };
+
+        timed_phonemes.push(TimedPhoneme {
+            phoneme: *phoneme,
+            start: pos,
+            end: pos + length,
+        });
+
+        pos += length;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use Phoneme::*;
+
+    fn create_syllable(text: &str, start: f32, end: f32) -> LyricSyllable {
+        LyricSyllable {
+            text: text.to_owned(),
+            start,
+            end,
+        }
+    }
+
+    #[rstest]
+    #[case("Hel-", ("Hel", true))]
+    #[case("lo#", ("lo", false))]
+    #[case("sky=", ("sky", true))]
+    #[case("$oh^", ("oh", false))]
+    fn clean_syllable_test(#[case] text: &str, #[case] expected: (&str, bool)) {
+        let (text, continues) = clean_syllable(text);
+        assert_eq!(expected, (text.as_str(), continues));
+    }
+
+    #[rstest]
+    #[case(&[B, EY, B, IY], 2, vec![&[B, EY][..], &[B, IY]])]
+    #[case(&[R, AH, N, IH, NG], 2, vec![&[R, AH][..], &[N, IH, NG]])]
+    #[case(&[S, T, R], 2, vec![&[S][..], &[T, R]])]
+    #[case(&[L, AH, V], 1, vec![&[L, AH, V][..]])]
+    fn split_phonemes_test(#[case] phonemes: &[Phoneme], #[case] count: usize, #[case] expected: Vec<&[Phoneme]>) {
+        assert_eq!(expected, split_phonemes(phonemes, count));
+    }
+
+    #[rstest]
+    fn get_timed_phonemes_test() {
+        let syllables = vec![
+            create_syllable("love", 0.0, 1000.0),
+            create_syllable("ba-", 1000.0, 1200.0),
+            create_syllable("by", 1200.0, 1400.0),
+            create_syllable("+", 1400.0, 1600.0),
+        ];
+
+        let expected = vec![
+            TimedPhoneme { phoneme: L, start: 0.0, end: 60.0 },
+            TimedPhoneme { phoneme: AH, start: 60.0, end: 940.0 },
+            TimedPhoneme { phoneme: V, start: 940.0, end: 1000.0 },
+            TimedPhoneme { phoneme: B, start: 1000.0, end: 1060.0 },
+            TimedPhoneme { phoneme: EY, start: 1060.0, end: 1200.0 },
+            TimedPhoneme { phoneme: B, start: 1200.0, end: 1260.0 },
+            TimedPhoneme { phoneme: IY, start: 1260.0, end: 1600.0 },
+        ];
+
+        assert_eq!(expected, get_timed_phonemes(&syllables));
+    }
+
+    #[rstest]
+    fn generate_lipsync() {
+        let generator = LipSyncGenerator::default();
+        let syllables = vec![create_syllable("me", 1000.0, 2000.0)];
+
+        let lipsync = generator.generate(&syllables, 3000.0).unwrap();
+        assert_eq!(91, lipsync.frames_count);
+        assert_eq!(generator.viseme_set.visemes, lipsync.visemes);
+
+        let weights = generator.generate_weights(&get_timed_phonemes(&syllables), lipsync.frames_count);
+        let bump_idx = lipsync.visemes.iter().position(|v| v.eq("Bump_hi")).unwrap();
+        let eat_idx = lipsync.visemes.iter().position(|v| v.eq("Eat_hi")).unwrap();
+
+        // Silence, blend in, closed mouth, open mouth, silence
+        assert_eq!(0, weights[0][bump_idx]);
+        assert!(weights[29][bump_idx] > 0 && weights[29][bump_idx] < 255);
+        assert_eq!(255, weights[30][bump_idx]);
+        assert_eq!(255, weights[45][eat_idx]);
+        assert_eq!(0, weights[45][bump_idx]);
+        assert_eq!(0, weights[90][eat_idx]);
+    }
+}
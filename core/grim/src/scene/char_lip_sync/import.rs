@@ -0,0 +1,247 @@
+use super::{LIPSYNC_FPS, LipSyncFrames, LipSyncGenerator, Phoneme, TimedPhoneme};
+use log::warn;
+use thiserror::Error as ThisError;
+
+const PAPAGAYO_HEADER: &str = "MohoSwitch1";
+
+#[derive(Debug, ThisError)]
+pub enum LipSyncImportError {
+    #[error("Papagayo header \"{PAPAGAYO_HEADER}\" not found")]
+    MissingPapagayoHeader,
+    #[error("TSV header should start with \"time\" or \"frame\" column")]
+    InvalidTsvHeader,
+    #[error("Unable to parse line {line}: \"{text}\"")]
+    InvalidLine {
+        line: usize,
+        text: String
+    },
+}
+
+fn get_preston_blair_phoneme(name: &str) -> Option<Phoneme> {
+    // Use closest phoneme for each mouth shape
+    let phoneme = match name.to_ascii_uppercase().as_str() {
+        "AI" => Phoneme::AA,
+        "E" => Phoneme::EH,
+        "O" => Phoneme::OW,
+        "U" => Phoneme::UW,
+        "ETC" => Phoneme::K,
+        "FV" => Phoneme::F,
+        "L" => Phoneme::L,
+        "MBP" => Phoneme::M,
+        "WQ" => Phoneme::W,
+        _ => return None
+    };
+
+    Some(phoneme)
+}
+
+pub fn read_papagayo_dat(text: &str, fps: f32) -> Result<Vec<TimedPhoneme>, LipSyncImportError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, l)| (i + 1, l.trim()))
+        .filter(|(_, l)| !l.is_empty());
+
+    if !matches!(lines.next(), Some((_, PAPAGAYO_HEADER))) {
+        return Err(LipSyncImportError::MissingPapagayoHeader);
+    }
+
+    // Frame + phoneme name
+    let mut keys = Vec::new();
+
+    for (line, text) in lines {
+        let parsed = text
+            .split_once(char::is_whitespace)
+            .and_then(|(frame, name)| frame.parse::<u32>().ok().map(|f| (f, name.trim())));
+
+        let Some((frame, name)) = parsed else {
+            return Err(LipSyncImportError::InvalidLine {
+                line,
+                text: text.to_owned()
+            });
+        };
+
+        // Papagayo can also export CMU phonemes
+        let phoneme = get_preston_blair_phoneme(name)
+            .or_else(|| Phoneme::from_arpabet(name));
+
+        if phoneme.is_none() && !name.eq_ignore_ascii_case("rest") {
+            warn!("Unknown phoneme \"{name}\" on line {line}, using rest");
+        }
+
+        // Frames start at 1
+        let pos = (frame.saturating_sub(1) as f32 * 1000.0) / fps;
+        keys.push((pos, phoneme));
+    }
+
+    // Each key is held until next key
+    let frame_length = 1000.0 / fps;
+
+    let phonemes = keys
+        .iter()
+        .enumerate()
+        .filter_map(|(i, (start, phoneme))| phoneme.map(|p| TimedPhoneme {
+            phoneme: p,
+            start: *start,
+            end: keys
+                .get(i + 1)
+                .map(|(next, _)| *next)
+                .unwrap_or(*start + frame_length),
+        }))
+        .collect();
+
+    Ok(phonemes)
+}
+
+pub fn read_tsv_curves(text: &str) -> Result<LipSyncFrames, LipSyncImportError> {
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .map(|(i, l)| (i + 1, l.trim_end()))
+        .filter(|(_, l)| !l.trim().is_empty());
+
+    let Some((_, header)) = lines.next() else {
+        return Err(LipSyncImportError::InvalidTsvHeader);
+    };
+
+    let mut columns = header.split('\t').map(|c| c.trim());
+
+    // First column is time in seconds or lipsync frames
+    let time_scale = match columns.next().map(|c| c.to_ascii_lowercase()).as_deref() {
+        Some("time") => LIPSYNC_FPS,
+        Some("frame") => 1.0,
+        _ => return Err(LipSyncImportError::InvalidTsvHeader),
+    };
+
+    let visemes = columns
+        .map(|c| c.to_owned())
+        .collect::<Vec<_>>();
+
+    let mut keys: Vec<(f32, Vec<f32>)> = Vec::new();
+
+    for (line, text) in lines {
+        let values = text
+            .split('\t')
+            .map(|v| v.trim().parse::<f32>())
+            .collect::<Result<Vec<_>, _>>();
+
+        let Some((pos, weights)) = values.ok().and_then(|v| v.split_first().map(|(p, w)| (*p, w.to_vec()))) else {
+            return Err(LipSyncImportError::InvalidLine {
+                line,
+                text: text.to_owned()
+            });
+        };
+
+        keys.push((pos * time_scale, weights));
+    }
+
+    keys.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+    let frame_count = keys
+        .last()
+        .map(|(p, _)| p.max(0.0).ceil() as usize + 1)
+        .unwrap_or_default();
+
+    let mut frames = LipSyncFrames::new(visemes, frame_count);
+
+    // Linear interpolation between keys
+    for (i, weights) in frames.weights.iter_mut().enumerate() {
+        let frame = i as f32;
+        let next = keys.partition_point(|(p, _)| *p <= frame);
+
+        let (prev_values, next_values, t) = match next {
+            0 => (&keys[0].1, &keys[0].1, 0.0),
+            n if n >= keys.len() => (&keys[n - 1].1, &keys[n - 1].1, 0.0),
+            n => {
+                let (p1, v1) = &keys[n - 1];
+                let (p2, v2) = &keys[n];
+                (v1, v2, (frame - p1) / (p2 - p1))
+            }
+        };
+
+        for (j, weight) in weights.iter_mut().enumerate() {
+            let a = prev_values.get(j).copied().unwrap_or_default();
+            let b = next_values.get(j).copied().unwrap_or_default();
+            let value = a + (b - a) * t;
+
+            *weight = (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+        }
+    }
+
+    Ok(frames)
+}
+
+impl LipSyncGenerator {
+    pub fn import_papagayo_dat(&self, text: &str, fps: f32) -> Result<LipSyncFrames, LipSyncImportError> {
+        let phonemes = read_papagayo_dat(text, fps)?;
+        Ok(self.generate_frames(&phonemes, 0.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    #[rstest]
+    fn read_papagayo_dat_test() {
+        let text = "MohoSwitch1\n1 rest\n25 MBP\n31 AI\n49 rest\n";
+        let phonemes = read_papagayo_dat(text, 24.0).unwrap();
+
+        let expected = vec![
+            TimedPhoneme { phoneme: Phoneme::M, start: 1000.0, end: 1250.0 },
+            TimedPhoneme { phoneme: Phoneme::AA, start: 1250.0, end: 2000.0 },
+        ];
+
+        assert_eq!(expected, phonemes);
+    }
+
+    #[rstest]
+    #[case("1 rest\n")]
+    #[case("")]
+    fn read_papagayo_dat_missing_header(#[case] text: &str) {
+        let result = read_papagayo_dat(text, 24.0);
+        assert!(matches!(result, Err(LipSyncImportError::MissingPapagayoHeader)));
+    }
+
+    #[rstest]
+    fn read_papagayo_dat_invalid_line() {
+        let result = read_papagayo_dat("MohoSwitch1\n1 rest\nabc MBP\n", 24.0);
+        assert!(matches!(result, Err(LipSyncImportError::InvalidLine { line: 3, .. })));
+    }
+
+    #[rstest]
+    fn import_papagayo_dat() {
+        let generator = LipSyncGenerator::default();
+        let frames = generator.import_papagayo_dat("MohoSwitch1\n1 MBP\n13 rest\n", 24.0).unwrap();
+
+        assert_eq!(255, frames.get_weight(0, "Bump_hi"));
+        assert_eq!(0, frames.get_weight(frames.len() - 1, "Bump_hi"));
+    }
+
+    #[rstest]
+    fn read_tsv_curves_test() {
+        let text = "frame\tEat_hi\tBlink\n0\t0.0\t1.0\n3\t1.0\t0.0\n";
+        let frames = read_tsv_curves(text).unwrap();
+
+        assert_eq!(vec!["Eat_hi", "Blink"], frames.visemes);
+        assert_eq!(vec![vec![0, 255], vec![85, 170], vec![170, 85], vec![255, 0]], frames.weights);
+
+        // Seconds are converted to lipsync frames
+        let frames = read_tsv_curves("time\tEat_hi\n0.0\t0.0\n1.0\t1.0\n").unwrap();
+        assert_eq!(31, frames.len());
+        assert_eq!(255, frames.get_weight(30, "Eat_hi"));
+    }
+
+    #[rstest]
+    #[case("viseme\tEat_hi\n0\t1\n", "header")]
+    #[case("frame\tEat_hi\n0\tabc\n", "line")]
+    fn read_tsv_curves_errors(#[case] text: &str, #[case] error: &str) {
+        let result = read_tsv_curves(text);
+
+        match error {
+            "header" => assert!(matches!(result, Err(LipSyncImportError::InvalidTsvHeader))),
+            _ => assert!(matches!(result, Err(LipSyncImportError::InvalidLine { line: 2, .. }))),
+        }
+    }
+}
@@ -53,13 +53,100 @@ impl ObjectReadWrite for CharLipSync {
         Ok(())
     }
 
-    fn save(&self, _stream: &mut dyn Stream, _info: &SystemInfo) -> Result<(), Box<dyn Error>> {
-        todo!("Implement save() for CharLipSync")
-
-        /*let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
 
         // TODO: Get version from system info
-        let version = 1;
-        Ok(())*/
+        let version = 0;
+        writer.write_uint32(version)?;
+
+        save_object(self, &mut writer, info)?;
+
+        // Write visemes
+        writer.write_uint32(self.visemes.len() as u32)?;
+        for viseme in self.visemes.iter() {
+            writer.write_prefixed_string(viseme)?;
+        }
+
+        writer.write_uint32(self.frames_count as u32)?;
+
+        // Write keyframe data
+        writer.write_uint32(self.data.len() as u32)?;
+        writer.write_bytes(&self.data)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    fn char_lip_sync_round_trip() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let lip_sync = CharLipSync {
+            name: String::from("song.lipsync"),
+            visemes: vec![String::from("Blink"), String::from("Bump_hi")],
+            frames_count: 2,
+            data: vec![1, 0, 255, 2, 0, 128, 1, 64],
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        lip_sync.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = CharLipSync::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(lip_sync.visemes, result.visemes);
+        assert_eq!(lip_sync.frames_count, result.frames_count);
+        assert_eq!(lip_sync.data, result.data);
+    }
+
+    #[rstest]
+    fn load_char_lip_sync_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x00, // Version (0)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x02, // Viseme count
+            0x00, 0x00, 0x00, 0x05, b'B', b'l', b'i', b'n', b'k',
+            0x00, 0x00, 0x00, 0x07, b'B', b'u', b'm', b'p', b'_', b'h', b'i',
+            0x00, 0x00, 0x00, 0x03, // Frame count
+            0x00, 0x00, 0x00, 0x09, // Data size
+            0x01, 0x00, 0xFF,       // Frame 0
+            0x02, 0x00, 0x80, 0x01, 0x40, // Frame 1
+            0x00,                   // Frame 2
+        ];
+
+        let lip_sync: CharLipSync = assert_round_trip_bytes(&data, &info);
+        assert_eq!(vec!["Blink", "Bump_hi"], lip_sync.visemes);
+        assert_eq!(3, lip_sync.frames_count);
+        assert_eq!(vec![1, 0, 255, 2, 0, 128, 1, 64, 0], lip_sync.data);
+
+        let frames = lip_sync.get_frames();
+        assert_eq!(3, frames.len());
+        assert_eq!(vec![("Blink", 255)], frames[0].visemes);
+        assert_eq!(vec![("Blink", 128), ("Bump_hi", 64)], frames[1].visemes);
+        assert!(frames[2].visemes.is_empty());
     }
 }
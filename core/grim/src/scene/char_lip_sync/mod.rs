@@ -1,8 +1,34 @@
+mod frames;
+mod generate;
+mod import;
 mod io;
+mod phoneme;
+#[cfg(feature = "midi")] mod vocals;
 
 use grim_macros::*;
 use grim_traits::scene::*;
+pub use frames::*;
+pub use generate::*;
+pub use import::*;
 pub use io::*;
+pub use phoneme::*;
+#[cfg(feature = "midi")] pub use vocals::*;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum CharLipSyncEncodeError {
+    #[error("Viseme count of {count} exceeds max of {max}")]
+    TooManyVisemes {
+        count: usize,
+        max: usize
+    },
+    #[error("Frame {frame} has {count} weights but {expected} visemes")]
+    WeightCountMismatch {
+        frame: usize,
+        count: usize,
+        expected: usize
+    },
+}
 
 #[milo]
 pub struct CharLipSync {
@@ -68,4 +94,79 @@ impl CharLipSync {
 
         frames
     }
-}
\ No newline at end of file
+
+    pub fn set_weights(&mut self, visemes: Vec<String>, weights: &[Vec<u8>]) -> Result<(), CharLipSyncEncodeError> {
+        // Viseme indices and change counts are both stored as u8
+        if visemes.len() > u8::MAX as usize {
+            return Err(CharLipSyncEncodeError::TooManyVisemes {
+                count: visemes.len(),
+                max: u8::MAX as usize
+            });
+        }
+
+        if let Some((frame, w)) = weights.iter().enumerate().find(|(_, w)| w.len() != visemes.len()) {
+            return Err(CharLipSyncEncodeError::WeightCountMismatch {
+                frame,
+                count: w.len(),
+                expected: visemes.len()
+            });
+        }
+
+        let mut data = Vec::new();
+        let mut prev_weights = vec![0u8; visemes.len()];
+
+        // Only changed weights are written for each frame
+        for frame in weights {
+            let changes = frame
+                .iter()
+                .enumerate()
+                .filter(|(i, w)| prev_weights[*i] != **w)
+                .collect::<Vec<_>>();
+
+            data.push(changes.len() as u8);
+
+            for (i, w) in changes {
+                data.push(i as u8);
+                data.push(*w);
+            }
+
+            prev_weights.clone_from(frame);
+        }
+
+        self.visemes = visemes;
+        self.frames_count = weights.len();
+        self.data = data;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    #[rstest]
+    fn set_weights_too_many_visemes() {
+        let visemes = (0..256).map(|i| format!("viseme_{i}")).collect::<Vec<_>>();
+        let weights = vec![vec![0u8; 256]];
+
+        let mut lipsync = CharLipSync::default();
+        let result = lipsync.set_weights(visemes, &weights);
+
+        assert!(matches!(result, Err(CharLipSyncEncodeError::TooManyVisemes { count: 256, max: 255 })));
+        assert_eq!(0, lipsync.frames_count);
+    }
+
+    #[rstest]
+    fn set_weights_weight_count_mismatch() {
+        let visemes = vec![String::from("Eat_hi"), String::from("Bump_hi")];
+        let weights = vec![vec![100, 0], vec![200]];
+
+        let mut lipsync = CharLipSync::default();
+        let result = lipsync.set_weights(visemes, &weights);
+
+        assert!(matches!(result, Err(CharLipSyncEncodeError::WeightCountMismatch { frame: 1, count: 1, expected: 2 })));
+        assert!(lipsync.data.is_empty());
+    }
+}
@@ -0,0 +1,421 @@
+#[allow(clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Phoneme {
+    // Vowels
+    AA,
+    AE,
+    AH,
+    AO,
+    AW,
+    AY,
+    EH,
+    ER,
+    EY,
+    IH,
+    IY,
+    OW,
+    OY,
+    UH,
+    UW,
+    // Consonants
+    B,
+    CH,
+    D,
+    DH,
+    F,
+    G,
+    HH,
+    JH,
+    K,
+    L,
+    M,
+    N,
+    NG,
+    P,
+    R,
+    S,
+    SH,
+    T,
+    TH,
+    V,
+    W,
+    Y,
+    Z,
+    ZH,
+}
+
+impl Phoneme {
+    pub fn from_arpabet(text: &str) -> Option<Phoneme> {
+        // Ignore stress markers (i.e. AH0, AH1)
+        let phoneme = match text.trim_end_matches(|c: char| c.is_ascii_digit()) {
+            "AA" => Phoneme::AA,
+            "AE" => Phoneme::AE,
+            "AH" => Phoneme::AH,
+            "AO" => Phoneme::AO,
+            "AW" => Phoneme::AW,
+            "AY" => Phoneme::AY,
+            "EH" => Phoneme::EH,
+            "ER" => Phoneme::ER,
+            "EY" => Phoneme::EY,
+            "IH" => Phoneme::IH,
+            "IY" => Phoneme::IY,
+            "OW" => Phoneme::OW,
+            "OY" => Phoneme::OY,
+            "UH" => Phoneme::UH,
+            "UW" => Phoneme::UW,
+            "B" => Phoneme::B,
+            "CH" => Phoneme::CH,
+            "D" => Phoneme::D,
+            "DH" => Phoneme::DH,
+            "F" => Phoneme::F,
+            "G" => Phoneme::G,
+            "HH" => Phoneme::HH,
+            "JH" => Phoneme::JH,
+            "K" => Phoneme::K,
+            "L" => Phoneme::L,
+            "M" => Phoneme::M,
+            "N" => Phoneme::N,
+            "NG" => Phoneme::NG,
+            "P" => Phoneme::P,
+            "R" => Phoneme::R,
+            "S" => Phoneme::S,
+            "SH" => Phoneme::SH,
+            "T" => Phoneme::T,
+            "TH" => Phoneme::TH,
+            "V" => Phoneme::V,
+            "W" => Phoneme::W,
+            "Y" => Phoneme::Y,
+            "Z" => Phoneme::Z,
+            "ZH" => Phoneme::ZH,
+            _ => return None
+        };
+
+        Some(phoneme)
+    }
+
+    pub fn is_vowel(&self) -> bool {
+        matches!(self,
+            Phoneme::AA | Phoneme::AE | Phoneme::AH | Phoneme::AO | Phoneme::AW
+            | Phoneme::AY | Phoneme::EH | Phoneme::ER | Phoneme::EY | Phoneme::IH
+            | Phoneme::IY | Phoneme::OW | Phoneme::OY | Phoneme::UH | Phoneme::UW)
+    }
+}
+
+// Common lyric words where spelling rules don't work well (CMU style)
+const PHONEME_DICTIONARY: [(&str, &str); 96] = [
+    ("a", "AH"),
+    ("about", "AH B AW T"),
+    ("again", "AH G EH N"),
+    ("all", "AO L"),
+    ("alone", "AH L OW N"),
+    ("are", "AA R"),
+    ("away", "AH W EY"),
+    ("baby", "B EY B IY"),
+    ("be", "B IY"),
+    ("because", "B IH K AH Z"),
+    ("been", "B IH N"),
+    ("come", "K AH M"),
+    ("could", "K UH D"),
+    ("day", "D EY"),
+    ("do", "D UW"),
+    ("does", "D AH Z"),
+    ("done", "D AH N"),
+    ("don't", "D OW N T"),
+    ("down", "D AW N"),
+    ("eye", "AY"),
+    ("eyes", "AY Z"),
+    ("find", "F AY N D"),
+    ("fire", "F AY ER"),
+    ("for", "F AO R"),
+    ("from", "F R AH M"),
+    ("gone", "G AO N"),
+    ("gonna", "G AA N AH"),
+    ("have", "HH AE V"),
+    ("he", "HH IY"),
+    ("heart", "HH AA R T"),
+    ("heaven", "HH EH V AH N"),
+    ("here", "HH IY R"),
+    ("home", "HH OW M"),
+    ("i", "AY"),
+    ("i'm", "AY M"),
+    ("is", "IH Z"),
+    ("it's", "IH T S"),
+    ("know", "N OW"),
+    ("life", "L AY F"),
+    ("light", "L AY T"),
+    ("like", "L AY K"),
+    ("live", "L IH V"),
+    ("love", "L AH V"),
+    ("me", "M IY"),
+    ("mind", "M AY N D"),
+    ("more", "M AO R"),
+    ("my", "M AY"),
+    ("never", "N EH V ER"),
+    ("night", "N AY T"),
+    ("no", "N OW"),
+    ("now", "N AW"),
+    ("of", "AH V"),
+    ("oh", "OW"),
+    ("on", "AA N"),
+    ("one", "W AH N"),
+    ("only", "OW N L IY"),
+    ("our", "AW ER"),
+    ("own", "OW N"),
+    ("people", "P IY P AH L"),
+    ("right", "R AY T"),
+    ("said", "S EH D"),
+    ("say", "S EY"),
+    ("see", "S IY"),
+    ("she", "SH IY"),
+    ("so", "S OW"),
+    ("some", "S AH M"),
+    ("the", "DH AH"),
+    ("their", "DH EH R"),
+    ("there", "DH EH R"),
+    ("they", "DH EY"),
+    ("through", "TH R UW"),
+    ("time", "T AY M"),
+    ("to", "T UW"),
+    ("today", "T AH D EY"),
+    ("tonight", "T AH N AY T"),
+    ("two", "T UW"),
+    ("was", "W AA Z"),
+    ("we", "W IY"),
+    ("were", "W ER"),
+    ("what", "W AH T"),
+    ("where", "W EH R"),
+    ("who", "HH UW"),
+    ("whoa", "W OW"),
+    ("why", "W AY"),
+    ("will", "W IH L"),
+    ("with", "W IH DH"),
+    ("without", "W IH DH AW T"),
+    ("woman", "W UH M AH N"),
+    ("world", "W ER L D"),
+    ("would", "W UH D"),
+    ("yeah", "Y AE"),
+    ("yes", "Y EH S"),
+    ("you", "Y UW"),
+    ("you're", "Y UH R"),
+    ("young", "Y AH NG"),
+    ("your", "Y AO R"),
+];
+
+// Spelling rules used for words not in dictionary, longest patterns first
+const LETTER_RULES: [(&str, &[Phoneme]); 43] = [
+    ("tch", &[Phoneme::CH]),
+    ("igh", &[Phoneme::AY]),
+    ("augh", &[Phoneme::AO]),
+    ("ough", &[Phoneme::AO]),
+    ("tion", &[Phoneme::SH, Phoneme::AH, Phoneme::N]),
+    ("th", &[Phoneme::TH]),
+    ("sh", &[Phoneme::SH]),
+    ("ch", &[Phoneme::CH]),
+    ("ph", &[Phoneme::F]),
+    ("wh", &[Phoneme::W]),
+    ("ck", &[Phoneme::K]),
+    ("ng", &[Phoneme::NG]),
+    ("qu", &[Phoneme::K, Phoneme::W]),
+    ("kn", &[Phoneme::N]),
+    ("wr", &[Phoneme::R]),
+    ("ee", &[Phoneme::IY]),
+    ("ea", &[Phoneme::IY]),
+    ("ie", &[Phoneme::IY]),
+    ("ey", &[Phoneme::IY]),
+    ("oo", &[Phoneme::UW]),
+    ("ou", &[Phoneme::AW]),
+    ("ow", &[Phoneme::OW]),
+    ("oa", &[Phoneme::OW]),
+    ("oi", &[Phoneme::OY]),
+    ("oy", &[Phoneme::OY]),
+    ("ai", &[Phoneme::EY]),
+    ("ay", &[Phoneme::EY]),
+    ("au", &[Phoneme::AO]),
+    ("aw", &[Phoneme::AO]),
+    ("er", &[Phoneme::ER]),
+    ("ir", &[Phoneme::ER]),
+    ("ur", &[Phoneme::ER]),
+    ("ar", &[Phoneme::AA, Phoneme::R]),
+    ("or", &[Phoneme::AO, Phoneme::R]),
+    ("a", &[Phoneme::AE]),
+    ("e", &[Phoneme::EH]),
+    ("i", &[Phoneme::IH]),
+    ("o", &[Phoneme::AA]),
+    ("u", &[Phoneme::AH]),
+    ("j", &[Phoneme::JH]),
+    ("q", &[Phoneme::K]),
+    ("x", &[Phoneme::K, Phoneme::S]),
+    ("y", &[Phoneme::Y]),
+];
+
+fn get_consonant_phoneme(c: u8) -> Option<Phoneme> {
+    let phoneme = match c {
+        b'b' => Phoneme::B,
+        b'c' => Phoneme::K,
+        b'd' => Phoneme::D,
+        b'f' => Phoneme::F,
+        b'g' => Phoneme::G,
+        b'h' => Phoneme::HH,
+        b'k' => Phoneme::K,
+        b'l' => Phoneme::L,
+        b'm' => Phoneme::M,
+        b'n' => Phoneme::N,
+        b'p' => Phoneme::P,
+        b'r' => Phoneme::R,
+        b's' => Phoneme::S,
+        b't' => Phoneme::T,
+        b'v' => Phoneme::V,
+        b'w' => Phoneme::W,
+        b'z' => Phoneme::Z,
+        _ => return None
+    };
+
+    Some(phoneme)
+}
+
+fn is_vowel_letter(c: u8) -> bool {
+    matches!(c, b'a' | b'e' | b'i' | b'o' | b'u' | b'y')
+}
+
+fn get_long_vowel_phoneme(c: u8) -> Option<Phoneme> {
+    let phoneme = match c {
+        b'a' => Phoneme::EY,
+        b'e' => Phoneme::IY,
+        b'i' => Phoneme::AY,
+        b'o' => Phoneme::OW,
+        b'u' => Phoneme::UW,
+        _ => return None
+    };
+
+    Some(phoneme)
+}
+
+fn is_magic_e(letters: &[u8], i: usize) -> bool {
+    // Vowel + consonant + trailing e (i.e. "make", "time")
+    i + 3 == letters.len()
+        && letters[i + 2] == b'e'
+        && !is_vowel_letter(letters[i + 1])
+        && (i == 0 || !is_vowel_letter(letters[i - 1]))
+}
+
+fn normalize_word(word: &str) -> String {
+    word
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic() || *c == '\'')
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<String>()
+        .trim_matches('\'')
+        .to_owned()
+}
+
+pub fn guess_phonemes(word: &str) -> Vec<Phoneme> {
+    let word = normalize_word(word);
+    let letters = word
+        .bytes()
+        .filter(|c| *c != b'\'')
+        .collect::<Vec<_>>();
+
+    let mut phonemes = Vec::new();
+    let mut i = 0;
+
+    while i < letters.len() {
+        let c = letters[i];
+        let next = letters.get(i + 1).copied();
+        let is_last = i + 1 == letters.len();
+
+        // Silent trailing e (i.e. "make", "time")
+        if c == b'e' && is_last && i >= 2 && !is_vowel_letter(letters[i - 1]) {
+            break;
+        }
+
+        // Doubled consonants are only pronounced once
+        if i > 0 && letters[i - 1] == c && !is_vowel_letter(c) {
+            i += 1;
+            continue;
+        }
+
+        // Context dependent letters
+        match c {
+            b'a' | b'e' | b'i' | b'o' | b'u' if is_magic_e(&letters, i) => {
+                phonemes.extend(get_long_vowel_phoneme(c));
+                i += 1;
+                continue;
+            },
+            b'c' if matches!(next, Some(b'e' | b'i' | b'y')) => {
+                phonemes.push(Phoneme::S);
+                i += 1;
+                continue;
+            },
+            b'y' if i > 0 => {
+                // Vowel when not at start of word
+                phonemes.push(if is_last { Phoneme::IY } else { Phoneme::IH });
+                i += 1;
+                continue;
+            },
+            b's' if is_last && i > 0 && is_vowel_letter(letters[i - 1]) => {
+                phonemes.push(Phoneme::Z);
+                i += 1;
+                continue;
+            },
+            _ => {}
+        }
+
+        let rule = LETTER_RULES
+            .iter()
+            .find(|(pattern, _)| letters[i..].starts_with(pattern.as_bytes()));
+
+        if let Some((pattern, rule_phonemes)) = rule {
+            phonemes.extend_from_slice(rule_phonemes);
+            i += pattern.len();
+            continue;
+        }
+
+        if let Some(phoneme) = get_consonant_phoneme(c) {
+            phonemes.push(phoneme);
+        }
+
+        i += 1;
+    }
+
+    phonemes
+}
+
+pub fn get_word_phonemes(word: &str) -> Vec<Phoneme> {
+    let word = normalize_word(word);
+
+    PHONEME_DICTIONARY
+        .iter()
+        .find(|(w, _)| word.eq(w))
+        .map(|(_, p)| p
+            .split_whitespace()
+            .filter_map(Phoneme::from_arpabet)
+            .collect())
+        .unwrap_or_else(|| guess_phonemes(&word))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use Phoneme::*;
+
+    #[rstest]
+    #[case("AH0", Some(AH))]
+    #[case("NG", Some(NG))]
+    #[case("XX", None)]
+    fn phoneme_from_arpabet(#[case] text: &str, #[case] expected: Option<Phoneme>) {
+        assert_eq!(expected, Phoneme::from_arpabet(text));
+    }
+
+    #[rstest]
+    #[case("Love", vec![L, AH, V])]
+    #[case("you,", vec![Y, UW])]
+    #[case("shake", vec![SH, EY, K])]
+    #[case("fight", vec![F, AY, T])]
+    #[case("city", vec![S, IH, T, IY])]
+    #[case("running", vec![R, AH, N, IH, NG])]
+    #[case("", vec![])]
+    fn get_word_phonemes_test(#[case] word: &str, #[case] expected: Vec<Phoneme>) {
+        assert_eq!(expected, get_word_phonemes(word));
+    }
+}
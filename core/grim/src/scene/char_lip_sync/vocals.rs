@@ -0,0 +1,174 @@
+use super::{CharLipSync, CharLipSyncEncodeError, LipSyncGenerator, LyricSyllable};
+use crate::midi::{MidiEvent, MidiFile, MidiNote, MidiTrack};
+use std::ops::RangeInclusive;
+use thiserror::Error as ThisError;
+
+const VOCAL_NOTE_RANGE: RangeInclusive<u8> = 36..=84;
+
+#[derive(Debug, ThisError)]
+pub enum LipSyncGenerateError {
+    #[error("Track \"{name}\" not found in midi")]
+    TrackNotFound {
+        name: String
+    },
+    #[error("No lyrics found in track \"{name}\"")]
+    NoLyricsFound {
+        name: String
+    },
+    #[error(transparent)]
+    Encode(#[from] CharLipSyncEncodeError),
+}
+
+// Note: Expects realtime positions to be calculated
+pub fn get_vocal_syllables(track: &MidiTrack) -> Vec<LyricSyllable> {
+    let notes = track
+        .events
+        .iter()
+        .filter_map(|ev| match ev {
+            MidiEvent::Note(note @ MidiNote { pos_realtime: Some(_), length_realtime: Some(_), .. })
+                if VOCAL_NOTE_RANGE.contains(&note.pitch) => Some(note),
+            _ => None
+        })
+        .collect::<Vec<_>>();
+
+    let mut syllables: Vec<LyricSyllable> = Vec::new();
+
+    for ev in track.events.iter() {
+        let MidiEvent::Meta(text) = ev else {
+            continue;
+        };
+
+        // Ignore text events (i.e. "[idle]")
+        let Some(lyric) = text.as_str().filter(|t| !t.starts_with('[')) else {
+            continue;
+        };
+
+        // Lyrics are placed at start of note
+        let Some(note) = notes.iter().find(|n| n.pos == text.pos) else {
+            continue;
+        };
+
+        let start = note.pos_realtime.unwrap_or_default() as f32;
+        let end = start + note.length_realtime.unwrap_or_default() as f32;
+
+        syllables.push(LyricSyllable {
+            text: lyric.to_owned(),
+            start,
+            end,
+        });
+    }
+
+    syllables
+}
+
+impl LipSyncGenerator {
+    pub fn generate_from_midi(&self, mid: &MidiFile, track_name: &str) -> Result<CharLipSync, LipSyncGenerateError> {
+        let Some(track) = mid.tracks.iter().find(|t| t.name.as_deref().eq(&Some(track_name))) else {
+            return Err(LipSyncGenerateError::TrackNotFound {
+                name: track_name.to_owned()
+            });
+        };
+
+        let syllables = get_vocal_syllables(track);
+
+        if syllables.is_empty() {
+            return Err(LipSyncGenerateError::NoLyricsFound {
+                name: track_name.to_owned()
+            });
+        }
+
+        // Lipsync should cover whole song
+        let song_length = mid
+            .tracks
+            .iter()
+            .flat_map(|t| t.events.iter())
+            .filter_map(|ev| ev
+                .get_pos_realtime()
+                .map(|p| p + ev.get_length_realtime().unwrap_or_default()))
+            .fold(0.0f64, f64::max);
+
+        Ok(self.generate(&syllables, song_length as f32)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::midi::{MidiText, MidiTextType};
+
+    fn create_note(pos: u64, length: u64, pitch: u8) -> MidiEvent {
+        // 1 tick = 1 ms
+        MidiEvent::Note(MidiNote {
+            pos,
+            pos_realtime: Some(pos as f64),
+            length,
+            length_realtime: Some(length as f64),
+            pitch,
+            ..Default::default()
+        })
+    }
+
+    fn create_lyric(pos: u64, text: &str) -> MidiEvent {
+        MidiEvent::Meta(MidiText {
+            pos,
+            pos_realtime: Some(pos as f64),
+            text: MidiTextType::Lyric(text.as_bytes().into()),
+        })
+    }
+
+    fn create_midi() -> MidiFile {
+        MidiFile {
+            tracks: vec![
+                MidiTrack {
+                    name: Some(String::from("PART VOCALS")),
+                    events: vec![
+                        create_note(0, 1000, 105), // Phrase marker
+                        create_note(100, 200, 60),
+                        create_lyric(100, "Hel-"),
+                        create_note(300, 200, 62),
+                        create_lyric(300, "lo"),
+                        create_lyric(450, "[idle]"),
+                        create_note(600, 400, 64),
+                        create_lyric(600, "+"),
+                    ]
+                },
+                MidiTrack {
+                    name: Some(String::from("EVENTS")),
+                    events: vec![
+                        create_lyric(3000, "[end]"),
+                    ]
+                },
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn get_vocal_syllables_test() {
+        let mid = create_midi();
+        let syllables = get_vocal_syllables(&mid.tracks[0]);
+
+        let result = syllables
+            .iter()
+            .map(|s| (s.text.as_str(), s.start, s.end))
+            .collect::<Vec<_>>();
+
+        assert_eq!(vec![("Hel-", 100.0, 300.0), ("lo", 300.0, 500.0), ("+", 600.0, 1000.0)], result);
+    }
+
+    #[rstest]
+    fn generate_from_midi() {
+        let mid = create_midi();
+        let generator = LipSyncGenerator::default();
+
+        let lipsync = generator.generate_from_midi(&mid, "PART VOCALS").unwrap();
+        assert_eq!(91, lipsync.frames_count);
+
+        let result = generator.generate_from_midi(&mid, "PART HARM1");
+        assert!(matches!(result, Err(LipSyncGenerateError::TrackNotFound { .. })));
+
+        let result = generator.generate_from_midi(&mid, "EVENTS");
+        assert!(matches!(result, Err(LipSyncGenerateError::NoLyricsFound { .. })));
+    }
+}
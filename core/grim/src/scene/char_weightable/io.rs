@@ -0,0 +1,124 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum CharWeightableLoadError {
+    #[error("CharWeightable version {version} is not supported")]
+    CharWeightableVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+        1 => true, // GH2
+        2 => true, // RB1 and up
+        _ => false
+    }
+}
+
+impl ObjectReadWrite for CharWeightableObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        load_char_weightable(self, &mut reader, info, true)
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        save_char_weightable(self, &mut writer, info, true)
+    }
+}
+
+pub(crate) fn load_char_weightable<T: CharWeightable>(weightable: &mut T, reader: &mut Box<BinaryStream>, info: &SystemInfo, read_meta: bool) -> Result<(), Box<dyn Error>> {
+    let version = reader.read_uint32()?;
+    if !is_version_supported(version) {
+        return Err(Box::new(CharWeightableLoadError::CharWeightableVersionNotSupported {
+            version
+        }));
+    }
+
+    if read_meta {
+        load_object(weightable, reader, info)?;
+    }
+
+    weightable.set_weight(reader.read_float32()?);
+
+    if version > 1 {
+        weightable.set_weight_owner(reader.read_prefixed_string()?);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn save_char_weightable<T: CharWeightable>(weightable: &T, writer: &mut Box<BinaryStream>, info: &SystemInfo, write_meta: bool) -> Result<(), Box<dyn Error>> {
+    let version = if info.version <= 24 { 1 } else { 2 };
+    writer.write_uint32(version)?;
+
+    if write_meta {
+        save_object(weightable, writer, info)?;
+    }
+
+    writer.write_float32(weightable.get_weight())?;
+
+    if version > 1 {
+        writer.write_prefixed_string(weightable.get_weight_owner())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(24, Platform::PS2, vec![
+        0x00, 0x00, 0x00, 0x01, // Version (1)
+        0x00, 0x00, 0x00, 0x00, // Revision
+        0x00, 0x00, 0x00, 0x00, // Type
+        0x00,                   // Props
+        0x3F, 0x40, 0x00, 0x00, // Weight
+    ], "")]
+    #[case(25, Platform::X360, vec![
+        0x00, 0x00, 0x00, 0x02, // Version (2)
+        0x00, 0x00, 0x00, 0x02, // Revision
+        0x00, 0x00, 0x00, 0x00, // Type
+        0x00,                   // Props
+        0x00, 0x00, 0x00, 0x00, // Note
+        0x3F, 0x40, 0x00, 0x00, // Weight
+        0x00, 0x00, 0x00, 0x06, b'o', b'w', b'n', b'e', b'r', b'.', // Weight owner
+    ], "owner.")]
+    fn load_char_weightable_bytes(#[case] version: u32, #[case] platform: Platform, #[case] mut data: Vec<u8>, #[case] weight_owner: &str) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let expected = data.clone();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut weightable = CharWeightableObject::default();
+        weightable.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(0.75, weightable.weight);
+        assert_eq!(weight_owner, weightable.weight_owner);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        weightable.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+}
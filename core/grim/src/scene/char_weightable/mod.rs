@@ -0,0 +1,23 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo(CharWeightable)]
+pub struct CharWeightableObject {}
+
+impl Default for CharWeightableObject {
+    fn default() -> CharWeightableObject {
+        CharWeightableObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // CharWeightable object
+            weight: 1.0,
+            weight_owner: String::default(),
+        }
+    }
+}
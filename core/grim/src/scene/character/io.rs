@@ -0,0 +1,295 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum CharacterLoadError {
+    #[error("Character version {version} is not supported")]
+    CharacterVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+         9 => true, // RB1/RB2
+        12 => true, // TBRB/GDRB
+        _ => false
+    }
+}
+
+fn get_save_version<T: Character>(character: &T, info: &SystemInfo) -> u32 {
+    match (info.version, character.get_char_version()) {
+        (25, Some(v @ (9 | 12))) => v,
+        (25, _) => 9, // RB1/RB2
+        _ => 12,      // TBRB/GDRB (RB3 not supported yet)
+    }
+}
+
+impl ObjectReadWrite for CharacterObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        load_character(self, &mut reader, info)
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        save_character(self, &mut writer, info)
+    }
+}
+
+pub(crate) fn load_character<T: Character>(character: &mut T, reader: &mut Box<BinaryStream>, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+    let version = reader.read_uint32()?;
+    if !is_version_supported(version) {
+        return Err(Box::new(CharacterLoadError::CharacterVersionNotSupported {
+            version
+        }));
+    }
+
+    character.set_char_version(Some(version));
+
+    load_rnd_dir(character, reader, info)?;
+
+    let lods = character.get_lods_mut();
+    lods.clear();
+
+    let lod_count = reader.read_uint32()?;
+    for _ in 0..lod_count {
+        let mut lod = CharLOD {
+            screen_size: reader.read_float32()?,
+            ..Default::default()
+        };
+
+        let group_count = reader.read_uint32()?;
+        for _ in 0..group_count {
+            lod.groups.push(reader.read_prefixed_string()?);
+        }
+
+        if version > 10 {
+            let group_count = reader.read_uint32()?;
+            for _ in 0..group_count {
+                lod.translucent_groups.push(reader.read_prefixed_string()?);
+            }
+        }
+
+        lods.push(lod);
+    }
+
+    character.set_shadow(reader.read_prefixed_string()?);
+    character.set_self_shadow(reader.read_boolean()?);
+    character.set_sphere_base(reader.read_prefixed_string()?);
+
+    if version > 9 {
+        load_sphere(character.get_bounding_mut(), reader)?;
+    }
+
+    if version > 10 {
+        character.set_frozen(reader.read_boolean()?);
+    }
+
+    if version > 11 {
+        character.set_min_lod(reader.read_uint32()?);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn save_character<T: Character>(character: &T, writer: &mut Box<BinaryStream>, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+    let version = get_save_version(character, info);
+    writer.write_uint32(version)?;
+
+    save_rnd_dir(character, writer, info)?;
+
+    writer.write_uint32(character.get_lods().len() as u32)?;
+    for lod in character.get_lods() {
+        writer.write_float32(lod.screen_size)?;
+
+        writer.write_uint32(lod.groups.len() as u32)?;
+        for group in lod.groups.iter() {
+            writer.write_prefixed_string(group)?;
+        }
+
+        if version > 10 {
+            writer.write_uint32(lod.translucent_groups.len() as u32)?;
+            for group in lod.translucent_groups.iter() {
+                writer.write_prefixed_string(group)?;
+            }
+        }
+    }
+
+    writer.write_prefixed_string(character.get_shadow())?;
+    writer.write_boolean(character.get_self_shadow())?;
+    writer.write_prefixed_string(character.get_sphere_base())?;
+
+    if version > 9 {
+        save_sphere(character.get_bounding(), writer)?;
+    }
+
+    if version > 10 {
+        writer.write_boolean(character.get_frozen())?;
+    }
+
+    if version > 11 {
+        writer.write_uint32(character.get_min_lod())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_character_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x09, // Version (9)
+            0x00, 0x00, 0x00, 0x08, // RndDir version (8)
+            0x00, 0x00, 0x00, 0x10, // ObjectDir version (16)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x00, // Viewport count
+            0x00, 0x00, 0x00, 0x00, // Current viewport
+            0x01,                   // Inline proxy
+            0x00, 0x00, 0x00, 0x00, // Proxy file
+            0x00, 0x00, 0x00, 0x00, // Sub dir count
+            0x00, 0x00, 0x00, 0x04, // Anim version (4)
+            0x00, 0x00, 0x00, 0x00, // Frame
+            0x00, 0x00, 0x00, 0x00, // Rate
+            0x00, 0x00, 0x00, 0x09, // Trans version (9)
+        ];
+        data.extend_from_slice(&[0u8; 96]); // Local + world xfm
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, // Constraint
+            0x00, 0x00, 0x00, 0x00, // Target
+            0x00,                   // Preserve scale
+            0x00, 0x00, 0x00, 0x00, // Parent
+            0x00, 0x00, 0x00, 0x03, // Draw version (3)
+            0x01,                   // Showing
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Sphere
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Draw order
+            0x00, 0x00, 0x00, 0x00, // Environ
+            0x00, 0x00, 0x00, 0x02, // LOD count
+            0x41, 0x20, 0x00, 0x00, // Screen size
+            0x00, 0x00, 0x00, 0x01, // Group count
+            0x00, 0x00, 0x00, 0x05, b'a', b'.', b'g', b'r', b'p',
+            0x3F, 0x00, 0x00, 0x00, // Screen size
+            0x00, 0x00, 0x00, 0x00, // Group count
+            0x00, 0x00, 0x00, 0x05, b's', b'.', b'g', b'r', b'p', // Shadow
+            0x01,                   // Self shadow
+            0x00, 0x00, 0x00, 0x06, b'b', b'.', b'm', b'e', b's', b'h', // Sphere base
+        ]);
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut character = CharacterObject::default();
+        character.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(2, character.lods.len());
+        assert_eq!(10.0, character.lods[0].screen_size);
+        assert_eq!(vec![String::from("a.grp")], character.lods[0].groups);
+        assert_eq!(0.5, character.lods[1].screen_size);
+        assert!(character.lods[1].groups.is_empty());
+        assert_eq!("s.grp", character.shadow);
+        assert!(character.self_shadow);
+        assert_eq!("b.mesh", character.sphere_base);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        character.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    #[case(25, 9)]
+    #[case(28, 12)]
+    fn save_load_character(#[case] milo_version: u32, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version: milo_version,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let character = CharacterObject {
+            viewports: (0..2).map(|_| Matrix::default()).collect(),
+            sub_dirs: vec![String::from("head.milo")],
+            environ: String::from("char.env"),
+            test_event: String::from("test"),
+            lods: vec![
+                CharLOD {
+                    screen_size: 0.5,
+                    groups: vec![String::from("lod0.grp")],
+                    translucent_groups: vec![String::from("lod0_hair.grp")],
+                },
+                CharLOD {
+                    screen_size: 0.1,
+                    groups: vec![String::from("lod1.grp"), String::from("lod1_extra.grp")],
+                    ..Default::default()
+                },
+            ],
+            shadow: String::from("shadow.grp"),
+            self_shadow: true,
+            sphere_base: String::from("bone_pelvis.mesh"),
+            bounding: Sphere { x: 1.0, y: 2.0, z: 3.0, r: 4.0 },
+            frozen: true,
+            min_lod: 1,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        character.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut reader = BinaryStream::from_stream_with_endian(&mut stream, info.endian);
+        assert_eq!(expected_version, reader.read_uint32().unwrap());
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_character = CharacterObject::default();
+        loaded_character.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(character.viewports.len(), loaded_character.viewports.len());
+        assert_eq!(character.sub_dirs, loaded_character.sub_dirs);
+        assert_eq!(character.environ, loaded_character.environ);
+        assert_eq!(character.lods.len(), loaded_character.lods.len());
+        assert_eq!(character.lods[1].screen_size, loaded_character.lods[1].screen_size);
+        assert_eq!(character.lods[1].groups, loaded_character.lods[1].groups);
+        assert_eq!(character.shadow, loaded_character.shadow);
+        assert_eq!(character.self_shadow, loaded_character.self_shadow);
+        assert_eq!(character.sphere_base, loaded_character.sphere_base);
+
+        if expected_version > 9 {
+            // TBRB fields
+            assert_eq!(character.test_event, loaded_character.test_event);
+            assert_eq!(character.lods[0].translucent_groups, loaded_character.lods[0].translucent_groups);
+            assert_eq!(character.bounding.r, loaded_character.bounding.r);
+            assert_eq!(character.frozen, loaded_character.frozen);
+            assert_eq!(character.min_lod, loaded_character.min_lod);
+        }
+    }
+}
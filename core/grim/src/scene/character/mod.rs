@@ -0,0 +1,67 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo(Character)]
+#[milo_super(Anim, Draw, ObjDir, RndDir, Trans)]
+pub struct CharacterObject {}
+
+impl Default for CharacterObject {
+    fn default() -> CharacterObject {
+        CharacterObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Anim object
+            anim_objects: Vec::new(),
+            frame: 0.0,
+            rate: AnimRate::default(),
+
+            // Draw object
+            showing: true,
+            draw_objects: Vec::new(),
+            sphere: Sphere::default(),
+            draw_order: 0.0,
+            override_include_in_depth_only_pass: OverrideIncludeInDepthOnlyPass::default(),
+
+            // ObjectDir object
+            viewports: Vec::new(),
+            curr_viewport_index: 0,
+            inline_proxy: true,
+            proxy_file: String::default(),
+            sub_dirs: Vec::new(),
+            obj_dir_version: None,
+
+            // RndDir object
+            environ: String::default(),
+            test_event: String::default(),
+            rnd_dir_version: None,
+
+            // Trans object
+            local_xfm: Matrix::default(),
+            world_xfm: Matrix::default(),
+
+            trans_objects: Vec::new(),
+
+            constraint: TransConstraint::default(),
+            target: String::default(),
+
+            preserve_scale: false,
+            parent: String::default(),
+
+            // Character object
+            lods: Vec::new(),
+            shadow: String::default(),
+            self_shadow: false,
+            sphere_base: String::default(),
+            bounding: Sphere::default(),
+            frozen: false,
+            min_lod: 0,
+            char_version: None,
+        }
+    }
+}
@@ -4,6 +4,15 @@ use crate::texture::Bitmap;
 use crate::SystemInfo;
 use grim_traits::scene::*;
 use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum CubeTexLoadError {
+    #[error("CubeTex version {version} is not supported")]
+    CubeTexVersionNotSupported {
+        version: u32
+    },
+}
 
 fn is_version_supported(version: u32) -> bool {
     match version {
@@ -19,8 +28,9 @@ impl ObjectReadWrite for CubeTexObject {
 
         let version = stream.read_uint32()?;
         if !is_version_supported(version) {
-            // TODO: Switch to custom error
-            panic!("CubeTex version \"{}\" is not supported!", version);
+            return Err(Box::new(CubeTexLoadError::CubeTexVersionNotSupported {
+                version
+            }));
         }
 
         load_object(self, &mut stream, info)?;
@@ -79,15 +89,19 @@ impl ObjectReadWrite for CubeTexObject {
     fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
 
-        // TODO: Get version from system info
-        let version = 1;
+        // GH2 360 uses version 1, everything after uses version 2
+        let version = if info.get_revision() < 2 { 1 } else { 2 };
 
         stream.write_uint32(version)?;
 
         save_object(self, &mut stream, info)?;
 
-        stream.write_uint32(self.some_num_1)?;
-        stream.write_uint32(self.some_num_2)?;
+        if version < 2 {
+            stream.write_uint32(self.some_num_1)?;
+            stream.write_uint32(self.some_num_2)?;
+        } else {
+            save_cubetex_properties(&self.properties, &mut stream)?;
+        }
 
         stream.write_prefixed_string(&self.right_ext_path)?;
         stream.write_prefixed_string(&self.left_ext_path)?;
@@ -96,7 +110,9 @@ impl ObjectReadWrite for CubeTexObject {
         stream.write_prefixed_string(&self.front_ext_path)?;
         stream.write_prefixed_string(&self.back_ext_path)?;
 
-        stream.write_boolean(self.some_bool)?;
+        if version < 2 {
+            stream.write_boolean(self.some_bool)?;
+        }
 
         let textures = [
             &self.right,
@@ -131,3 +147,26 @@ fn load_cubetex_properties(reader: &mut Box<BinaryStream>,) -> Result<Vec<CubeTe
 
     Ok(properties)
 }
+
+fn save_cubetex_properties(properties: &[CubeTexProperties], writer: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+    let empty_props = CubeTexProperties {
+        bpp: 0,
+        width: 0,
+        height: 0,
+        num_mip_maps: 0,
+        bitmap_encoding: 0,
+    };
+
+    // Always write 7 entries
+    for i in 0..7 {
+        let props = properties.get(i).unwrap_or(&empty_props);
+
+        writer.write_uint32(props.bpp)?;
+        writer.write_uint32(props.width)?;
+        writer.write_uint32(props.height)?;
+        writer.write_uint32(props.num_mip_maps)?;
+        writer.write_uint32(props.bitmap_encoding)?;
+    }
+
+    Ok(())
+}
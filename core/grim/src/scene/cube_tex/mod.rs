@@ -1,9 +1,31 @@
 mod io;
 
-use crate::texture::Bitmap;
+use crate::SystemInfo;
+use crate::texture::{Bitmap, BitmapEncodeOptions, DdsFormat, DdsImage, Image, Ktx2Image, write_rgba_to_file};
 use grim_macros::*;
 use grim_traits::scene::*;
 pub use io::*;
+use std::error::Error;
+use std::path::Path;
+use thiserror::Error as ThisError;
+
+pub const CUBE_FACE_NAMES: [&str; 6] = ["right", "left", "top", "bottom", "front", "back"];
+
+#[derive(Debug, ThisError)]
+pub enum CubeTexError {
+    #[error("CubeTex is missing {face} face")]
+    MissingFace {
+        face: &'static str
+    },
+    #[error("CubeTex face {face} is {width}x{height} but expected {expected_width}x{expected_height}")]
+    FaceSizeMismatch {
+        face: &'static str,
+        width: u16,
+        height: u16,
+        expected_width: u16,
+        expected_height: u16,
+    },
+}
 
 pub struct CubeTexProperties {
     pub bpp: u32,
@@ -13,6 +35,13 @@ pub struct CubeTexProperties {
     pub bitmap_encoding: u32, // DXT5, RGBA, etc.
 }
 
+pub struct CubeTexFaces {
+    pub width: u16,
+    pub height: u16,
+    pub mip_maps: u8,
+    pub faces: [Vec<u8>; 6], // Decoded rgba w/ mip maps (right, left, top, bottom, front, back)
+}
+
 #[milo]
 pub struct CubeTexObject {
     pub some_num_1: u32,
@@ -69,3 +98,299 @@ impl Default for CubeTexObject {
         }
     }
 }
+
+impl CubeTexObject {
+    pub fn get_faces(&self) -> [Option<&Bitmap>; 6] {
+        [
+            self.right.as_ref(),
+            self.left.as_ref(),
+            self.top.as_ref(),
+            self.bottom.as_ref(),
+            self.front.as_ref(),
+            self.back.as_ref(),
+        ]
+    }
+
+    pub fn unpack_faces_rgba(&self, info: &SystemInfo) -> Result<CubeTexFaces, Box<dyn Error>> {
+        let mut bitmaps = Vec::new();
+
+        for (face, bitmap) in CUBE_FACE_NAMES.iter().zip(self.get_faces()) {
+            let Some(bitmap) = bitmap else {
+                return Err(Box::new(CubeTexError::MissingFace { face }));
+            };
+
+            bitmaps.push((*face, bitmap));
+        }
+
+        // Every face should match first face
+        let (_, first) = bitmaps[0];
+        let (width, height) = (first.width, first.height);
+
+        for (face, bitmap) in bitmaps.iter() {
+            if bitmap.width != width || bitmap.height != height {
+                return Err(Box::new(CubeTexError::FaceSizeMismatch {
+                    face,
+                    width: bitmap.width,
+                    height: bitmap.height,
+                    expected_width: width,
+                    expected_height: height,
+                }));
+            }
+        }
+
+        // Use lowest mip count between faces
+        let mip_maps = bitmaps_min_mips(&self.get_faces());
+        let face_size = calc_face_size(width, height, mip_maps);
+
+        let mut faces: [Vec<u8>; 6] = Default::default();
+        for (rgba, (_, bitmap)) in faces.iter_mut().zip(bitmaps) {
+            *rgba = bitmap.unpack_rgba(info)?;
+            rgba.truncate(face_size);
+        }
+
+        Ok(CubeTexFaces {
+            width,
+            height,
+            mip_maps,
+            faces
+        })
+    }
+
+    pub fn to_dds(&self, info: &SystemInfo) -> Result<Vec<u8>, Box<dyn Error>> {
+        let CubeTexFaces { width, height, mip_maps, faces } = self.unpack_faces_rgba(info)?;
+
+        // Faces are already in +x, -x, +y, -y, +z, -z order
+        let dds = DdsImage {
+            width: width as u32,
+            height: height as u32,
+            mip_maps: mip_maps as u32,
+            format: DdsFormat::RGBA8,
+            is_cubemap: true,
+            data: faces.concat(),
+        };
+
+        dds.to_bytes()
+    }
+
+    pub fn to_ktx2(&self, info: &SystemInfo) -> Result<Vec<u8>, Box<dyn Error>> {
+        let CubeTexFaces { width, height, mip_maps, faces } = self.unpack_faces_rgba(info)?;
+
+        let ktx = Ktx2Image {
+            width: width as u32,
+            height: height as u32,
+            mip_maps: mip_maps as u32,
+            is_cubemap: true,
+            data: faces.concat(),
+        };
+
+        ktx.to_bytes()
+    }
+
+    pub fn export_faces_to_png<T: AsRef<Path>>(&self, out_dir: T, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let out_dir = out_dir.as_ref();
+        let CubeTexFaces { width, height, faces, .. } = self.unpack_faces_rgba(info)?;
+
+        let base_name = get_base_name(&self.name);
+        std::fs::create_dir_all(out_dir)?;
+
+        for (face, rgba) in CUBE_FACE_NAMES.iter().zip(faces.iter()) {
+            let png_path = out_dir.join(format!("{base_name}_{face}.png"));
+            write_rgba_to_file(width as u32, height as u32, &rgba[..calc_face_size(width, height, 0)], &png_path)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn export_cross_to_png<T: AsRef<Path>>(&self, out_path: T, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let CubeTexFaces { width, height, faces, .. } = self.unpack_faces_rgba(info)?;
+        let (w, h) = (width as usize, height as usize);
+
+        // Horizontal cross layout
+        //       [top]
+        // [left][front][right][back]
+        //       [bottom]
+        const CROSS_POSITIONS: [(usize, usize); 6] = [
+            (2, 1), // Right
+            (0, 1), // Left
+            (1, 0), // Top
+            (1, 2), // Bottom
+            (1, 1), // Front
+            (3, 1), // Back
+        ];
+
+        let cross_width = w * 4;
+        let cross_height = h * 3;
+        let mut cross = vec![0u8; cross_width * cross_height * 4];
+
+        for ((cx, cy), rgba) in CROSS_POSITIONS.iter().zip(faces.iter()) {
+            for y in 0..h {
+                let src = (y * w) << 2;
+                let dst = (((cy * h + y) * cross_width) + (cx * w)) << 2;
+
+                cross[dst..(dst + (w << 2))].copy_from_slice(&rgba[src..(src + (w << 2))]);
+            }
+        }
+
+        crate::io::create_missing_dirs(out_path.as_ref())?;
+        write_rgba_to_file(cross_width as u32, cross_height as u32, &cross, out_path.as_ref())
+    }
+
+    pub fn from_images(name: &str, images: [Image; 6], info: &SystemInfo) -> Result<CubeTexObject, Box<dyn Error>> {
+        let mut bitmaps = Vec::new();
+
+        for image in images.iter() {
+            let (rgba, width, height) = image.to_rgba()?;
+            bitmaps.push(Some(Bitmap::from_rgba(&rgba, width, height, info, &BitmapEncodeOptions::default())?));
+        }
+
+        let [right, left, top, bottom, front, back]: [Option<Bitmap>; 6] = bitmaps.try_into().unwrap();
+
+        let mut cube = CubeTexObject {
+            name: name.to_owned(),
+            type2: String::default(),
+            note: String::default(),
+
+            right,
+            left,
+            top,
+            bottom,
+            front,
+            back,
+            ..Default::default()
+        };
+
+        cube.recompute_properties();
+        Ok(cube)
+    }
+
+    pub fn recompute_properties(&mut self) {
+        let faces = self.get_faces();
+
+        // First entry seems to describe whole cube map, then each face
+        let properties = std::iter::once(faces[0])
+            .chain(faces)
+            .map(|face| match face {
+                Some(bitmap) => CubeTexProperties {
+                    bpp: bitmap.bpp as u32,
+                    width: bitmap.width as u32,
+                    height: bitmap.height as u32,
+                    num_mip_maps: bitmap.mip_maps as u32,
+                    bitmap_encoding: bitmap.encoding,
+                },
+                None => CubeTexProperties {
+                    bpp: 0,
+                    width: 0,
+                    height: 0,
+                    num_mip_maps: 0,
+                    bitmap_encoding: 0,
+                }
+            })
+            .collect();
+
+        self.properties = properties;
+    }
+}
+
+fn bitmaps_min_mips(faces: &[Option<&Bitmap>; 6]) -> u8 {
+    faces
+        .iter()
+        .filter_map(|f| f.map(|b| b.mip_maps))
+        .min()
+        .unwrap_or_default()
+}
+
+fn calc_face_size(width: u16, height: u16, mip_maps: u8) -> usize {
+    (0..=mip_maps)
+        .map(|i| ((width as usize) >> i) * ((height as usize) >> i) * 4)
+        .sum()
+}
+
+fn get_base_name(name: &str) -> &str {
+    match name.rfind('.') {
+        Some(i) => &name[..i],
+        None => name
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::Platform;
+    use crate::texture::BitmapError;
+
+    #[rstest]
+    fn cube_tex_from_images_round_trip() {
+        let info = SystemInfo { platform: Platform::PS2, ..Default::default() };
+        let (width, height) = (4u16, 4u16);
+
+        // Opaque solid color per face
+        let faces = (0..6u8)
+            .map(|i| [i * 40, 255 - i, i, 255].repeat(width as usize * height as usize))
+            .collect::<Vec<_>>();
+
+        let images: [Image; 6] = std::array::from_fn(|i| Image::FromRGBA {
+            rgba: &faces[i],
+            width,
+            height,
+            mips: 0
+        });
+
+        let cube = CubeTexObject::from_images("env.tex", images, &info).unwrap();
+        assert_eq!(7, cube.properties.len());
+        assert!(cube.get_faces().iter().all(|f| f.is_some_and(|b| b.width == width && b.height == height)));
+
+        let unpacked = cube.unpack_faces_rgba(&info).unwrap();
+        assert_eq!((width, height, 0), (unpacked.width, unpacked.height, unpacked.mip_maps));
+
+        for (expected, rgba) in faces.iter().zip(unpacked.faces.iter()) {
+            assert_eq!(expected, rgba);
+        }
+    }
+
+    #[rstest]
+    fn cube_tex_from_images_unsupported_platform() {
+        let info = SystemInfo { platform: Platform::Wii, ..Default::default() };
+        let rgba = vec![0xFFu8; 4 * 4 * 4];
+
+        let images: [Image; 6] = std::array::from_fn(|_| Image::FromRGBA {
+            rgba: &rgba,
+            width: 4,
+            height: 4,
+            mips: 0
+        });
+
+        let result = CubeTexObject::from_images("env.tex", images, &info);
+        assert!(result.is_err_and(|e| e.is::<BitmapError>()));
+    }
+
+    #[rstest]
+    fn cube_tex_to_ktx2() {
+        let info = SystemInfo { platform: Platform::PS2, ..Default::default() };
+        let rgba = [0x10u8, 0x20, 0x30, 0xFF].repeat(4 * 4);
+
+        let images: [Image; 6] = std::array::from_fn(|_| Image::FromRGBA {
+            rgba: &rgba,
+            width: 4,
+            height: 4,
+            mips: 0
+        });
+
+        let cube = CubeTexObject::from_images("env.tex", images, &info).unwrap();
+        let ktx = cube.to_ktx2(&info).unwrap();
+
+        // Header + level index + dfd, then 6 faces
+        assert_eq!(&[0xAB, 0x4B, 0x54, 0x58], &ktx[..4]);
+        assert_eq!(6, u32::from_le_bytes(ktx[36..40].try_into().unwrap()));
+        assert_eq!(80 + 24 + 92 + (6 * 64), ktx.len());
+        assert_eq!(rgba.repeat(6), ktx[(ktx.len() - (6 * 64))..]);
+    }
+
+    #[rstest]
+    fn cube_tex_missing_face() {
+        let cube = CubeTexObject::default();
+        let result = cube.unpack_faces_rgba(&SystemInfo::default());
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,298 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum EnvironLoadError {
+    #[error("Environ version {version} is not supported")]
+    EnvironVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+         2 => true, // GH1
+         5 => true, // GH2/GH2 360
+         9 => true, // RB1/RB2
+        12 => true, // TBRB/GDRB
+        15 => true, // RB3
+        _ => false
+    }
+}
+
+fn get_save_version(environ: &EnvironObject, info: &SystemInfo) -> u32 {
+    match (info.version, environ.version) {
+        (..=10, _) => 2, // GH1
+        (24, _) => 5, // GH2
+        (25, Some(v @ (9 | 12))) => v, // RB1/RB2, TBRB/GDRB
+        (25, _) => 9,
+        _ => 15,
+    }
+}
+
+impl ObjectReadWrite for EnvironObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(EnvironLoadError::EnvironVersionNotSupported {
+                version
+            }));
+        }
+
+        self.version = Some(version);
+
+        load_object(self, &mut reader, info)?;
+
+        // Read lights
+        self.lights.clear();
+        let light_count = reader.read_uint32()?;
+        for _ in 0..light_count {
+            self.lights.push(reader.read_prefixed_string()?);
+        }
+
+        load_color4(&mut self.ambient_color, &mut reader)?;
+
+        self.fog_start = reader.read_float32()?;
+        self.fog_end = reader.read_float32()?;
+        load_color4(&mut self.fog_color, &mut reader)?;
+        self.fog_enable = reader.read_boolean()?;
+
+        if version > 3 {
+            self.animate_from_preset = reader.read_boolean()?;
+        }
+
+        if version > 4 {
+            self.fade_out = reader.read_boolean()?;
+            self.fade_start = reader.read_float32()?;
+            self.fade_end = reader.read_float32()?;
+        }
+
+        if version > 6 {
+            self.fade_max = reader.read_float32()?;
+        }
+
+        if version > 7 {
+            self.fade_ref = reader.read_prefixed_string()?;
+        }
+
+        if version > 8 {
+            self.left_out = reader.read_boolean()?;
+            self.left_out_start = reader.read_float32()?;
+            self.left_out_end = reader.read_float32()?;
+            self.left_out_max = reader.read_float32()?;
+
+            self.right_out = reader.read_boolean()?;
+            self.right_out_start = reader.read_float32()?;
+            self.right_out_end = reader.read_float32()?;
+            self.right_out_max = reader.read_float32()?;
+        }
+
+        if version > 10 {
+            self.use_color_adjust = reader.read_boolean()?;
+            load_color_xfm(&mut self.color_xfm, &mut reader)?;
+        }
+
+        if version > 14 {
+            self.ao_strength = reader.read_float32()?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+
+        // Write lights
+        stream.write_uint32(self.lights.len() as u32)?;
+        for light in self.lights.iter() {
+            stream.write_prefixed_string(light)?;
+        }
+
+        save_color4(&self.ambient_color, &mut stream)?;
+
+        stream.write_float32(self.fog_start)?;
+        stream.write_float32(self.fog_end)?;
+        save_color4(&self.fog_color, &mut stream)?;
+        stream.write_boolean(self.fog_enable)?;
+
+        if version > 3 {
+            stream.write_boolean(self.animate_from_preset)?;
+        }
+
+        if version > 4 {
+            stream.write_boolean(self.fade_out)?;
+            stream.write_float32(self.fade_start)?;
+            stream.write_float32(self.fade_end)?;
+        }
+
+        if version > 6 {
+            stream.write_float32(self.fade_max)?;
+        }
+
+        if version > 7 {
+            stream.write_prefixed_string(&self.fade_ref)?;
+        }
+
+        if version > 8 {
+            stream.write_boolean(self.left_out)?;
+            stream.write_float32(self.left_out_start)?;
+            stream.write_float32(self.left_out_end)?;
+            stream.write_float32(self.left_out_max)?;
+
+            stream.write_boolean(self.right_out)?;
+            stream.write_float32(self.right_out_start)?;
+            stream.write_float32(self.right_out_end)?;
+            stream.write_float32(self.right_out_max)?;
+        }
+
+        if version > 10 {
+            stream.write_boolean(self.use_color_adjust)?;
+            save_color_xfm(&self.color_xfm, &mut stream)?;
+        }
+
+        if version > 14 {
+            stream.write_float32(self.ao_strength)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn load_color_xfm(color_xfm: &mut ColorXfm, reader: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+    color_xfm.hue = reader.read_float32()?;
+    color_xfm.saturation = reader.read_float32()?;
+    color_xfm.lightness = reader.read_float32()?;
+    color_xfm.contrast = reader.read_float32()?;
+    color_xfm.brightness = reader.read_float32()?;
+
+    load_color3(&mut color_xfm.levels_in_lo, reader)?;
+    load_color3(&mut color_xfm.levels_in_hi, reader)?;
+    load_color3(&mut color_xfm.levels_out_lo, reader)?;
+    load_color3(&mut color_xfm.levels_out_hi, reader)?;
+
+    Ok(())
+}
+
+fn save_color_xfm(color_xfm: &ColorXfm, writer: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+    writer.write_float32(color_xfm.hue)?;
+    writer.write_float32(color_xfm.saturation)?;
+    writer.write_float32(color_xfm.lightness)?;
+    writer.write_float32(color_xfm.contrast)?;
+    writer.write_float32(color_xfm.brightness)?;
+
+    save_color3(&color_xfm.levels_in_lo, writer)?;
+    save_color3(&color_xfm.levels_in_hi, writer)?;
+    save_color3(&color_xfm.levels_out_lo, writer)?;
+    save_color3(&color_xfm.levels_out_hi, writer)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(10, Platform::PS2, None, 2)]
+    #[case(24, Platform::PS2, None, 5)]
+    #[case(25, Platform::X360, None, 9)]
+    #[case(25, Platform::X360, Some(12), 12)]
+    #[case(28, Platform::PS3, None, 15)]
+    fn save_load_environ(#[case] version: u32, #[case] platform: Platform, #[case] environ_version: Option<u32>, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let mut environ = EnvironObject {
+            lights: vec![String::from("key.lit"), String::from("fill.lit")],
+            ambient_color: Color4 { r: 0.1, g: 0.2, b: 0.3, a: 1.0 },
+            fog_enable: true,
+            fog_start: 100.0,
+            fog_end: 2000.0,
+            fade_out: true,
+            version: environ_version,
+            ..Default::default()
+        };
+        environ.color_xfm.contrast = 0.5;
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        environ.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_environ = EnvironObject::default();
+        loaded_environ.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(expected_version), loaded_environ.version);
+
+        assert_eq!(environ.lights, loaded_environ.lights);
+        assert_eq!(environ.ambient_color.b, loaded_environ.ambient_color.b);
+        assert_eq!(environ.fog_enable, loaded_environ.fog_enable);
+        assert_eq!(environ.fog_start, loaded_environ.fog_start);
+        assert_eq!(environ.fog_end, loaded_environ.fog_end);
+
+        if version >= 24 {
+            assert_eq!(environ.fade_out, loaded_environ.fade_out);
+        }
+
+        if version >= 28 {
+            assert_eq!(environ.color_xfm.contrast, loaded_environ.color_xfm.contrast);
+        }
+    }
+
+    #[rstest]
+    fn load_environ_bytes() {
+        let info = SystemInfo {
+            version: 24,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let data: Vec<u8> = vec![
+            0x05, 0x00, 0x00, 0x00, // Version (5)
+            0x00, 0x00, 0x00, 0x00, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x01, 0x00, 0x00, 0x00, // Light count
+            0x05, 0x00, 0x00, 0x00, b'a', b'.', b'l', b'i', b't',
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x80, 0x3E, 0x00, 0x00, 0x80, 0x3F, // Ambient color
+            0x00, 0x00, 0xC8, 0x42, // Fog start (100.0)
+            0x00, 0x00, 0xFA, 0x43, // Fog end (500.0)
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F, // Fog color
+            0x01,                   // Fog enable
+            0x00,                   // Animate from preset
+            0x01,                   // Fade out
+            0x00, 0x00, 0xA0, 0x41, // Fade start (20.0)
+            0x00, 0x00, 0xC8, 0x42, // Fade end (100.0)
+        ];
+
+        let environ: EnvironObject = assert_round_trip_bytes(&data, &info);
+        assert_eq!(vec!["a.lit"], environ.lights);
+        assert_eq!((1.0, 0.5, 0.25, 1.0), (environ.ambient_color.r, environ.ambient_color.g, environ.ambient_color.b, environ.ambient_color.a));
+        assert_eq!((100.0, 500.0), (environ.fog_start, environ.fog_end));
+        assert_eq!((0.0, 0.0, 0.0, 1.0), (environ.fog_color.r, environ.fog_color.g, environ.fog_color.b, environ.fog_color.a));
+        assert!(environ.fog_enable);
+        assert!(!environ.animate_from_preset);
+        assert!(environ.fade_out);
+        assert_eq!((20.0, 100.0), (environ.fade_start, environ.fade_end));
+    }
+}
@@ -0,0 +1,106 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo]
+pub struct EnvironObject {
+    pub lights: Vec<String>,
+    pub ambient_color: Color4,
+
+    pub fog_enable: bool,
+    pub fog_start: f32,
+    pub fog_end: f32,
+    pub fog_color: Color4,
+
+    pub animate_from_preset: bool,
+
+    pub fade_out: bool,
+    pub fade_start: f32,
+    pub fade_end: f32,
+    pub fade_max: f32,
+    pub fade_ref: String,
+
+    pub left_out: bool,
+    pub left_out_start: f32,
+    pub left_out_end: f32,
+    pub left_out_max: f32,
+
+    pub right_out: bool,
+    pub right_out_start: f32,
+    pub right_out_end: f32,
+    pub right_out_max: f32,
+
+    pub use_color_adjust: bool,
+    pub color_xfm: ColorXfm,
+
+    pub ao_strength: f32,
+
+    pub version: Option<u32>,
+}
+
+#[derive(Default)]
+pub struct ColorXfm {
+    pub hue: f32,
+    pub saturation: f32,
+    pub lightness: f32,
+    pub contrast: f32,
+    pub brightness: f32,
+
+    pub levels_in_lo: Color3,
+    pub levels_in_hi: Color3,
+    pub levels_out_lo: Color3,
+    pub levels_out_hi: Color3,
+}
+
+impl Default for EnvironObject {
+    fn default() -> EnvironObject {
+        EnvironObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Environ object
+            lights: Vec::new(),
+            ambient_color: Color4 { r: 0.0, g: 0.0, b: 0.0, a: 1.0 },
+
+            fog_enable: false,
+            fog_start: 0.0,
+            fog_end: 1.0,
+            fog_color: Color4::white(),
+
+            animate_from_preset: true,
+
+            fade_out: false,
+            fade_start: 0.0,
+            fade_end: 1000.0,
+            fade_max: 1.0,
+            fade_ref: String::default(),
+
+            left_out: false,
+            left_out_start: 0.0,
+            left_out_end: 1000.0,
+            left_out_max: 1.0,
+
+            right_out: false,
+            right_out_start: 0.0,
+            right_out_end: 1000.0,
+            right_out_max: 1.0,
+
+            use_color_adjust: false,
+            color_xfm: ColorXfm {
+                levels_in_lo: Color3 { r: 0.0, g: 0.0, b: 0.0 },
+                levels_in_hi: Color3::white(),
+                levels_out_lo: Color3 { r: 0.0, g: 0.0, b: 0.0 },
+                levels_out_hi: Color3::white(),
+                ..Default::default()
+            },
+
+            ao_strength: 1.0,
+
+            version: None,
+        }
+    }
+}
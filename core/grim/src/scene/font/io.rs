@@ -0,0 +1,248 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum FontLoadError {
+    #[error("Font version {version} is not supported")]
+    FontVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+         7 => true, // GH1
+        11 => true, // GH2/GH2 360
+        14 => true, // RB1/RB2
+        16 => true, // TBRB/GDRB
+        17 => true, // RB3
+        _ => false
+    }
+}
+
+fn get_save_version(font: &FontObject, info: &SystemInfo) -> u32 {
+    match (info.version, font.version) {
+        (..=10, _) => 7, // GH1
+        (24, _) => 11, // GH2
+        (25, Some(v @ (14 | 16))) => v, // RB1/RB2, TBRB/GDRB
+        (25, _) => 14,
+        _ => 17,
+    }
+}
+
+impl ObjectReadWrite for FontObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(FontLoadError::FontVersionNotSupported {
+                version
+            }));
+        }
+
+        self.version = Some(version);
+
+        load_object(self, &mut reader, info)?;
+
+        self.mat = reader.read_prefixed_string()?;
+
+        if version > 10 {
+            self.font_owner = reader.read_prefixed_string()?;
+        }
+
+        self.base_kerning = reader.read_float32()?;
+        load_vector2(&mut self.cell_size, &mut reader)?;
+        self.deprecated_size = reader.read_float32()?;
+
+        // Older versions only support single byte chars
+        let wide_chars = version > 10;
+
+        self.chars.clear();
+        if wide_chars {
+            let char_count = reader.read_uint32()?;
+            for _ in 0..char_count {
+                self.chars.push(reader.read_uint16()?);
+            }
+        } else {
+            let char_count = reader.read_uint32()?;
+            let chars = reader.read_bytes(char_count as usize)?;
+            self.chars = chars.into_iter().map(|c| c as u16).collect();
+        }
+
+        self.kernings.clear();
+        let kerning_count = reader.read_uint32()?;
+        for _ in 0..kerning_count {
+            let (left, right) = match wide_chars {
+                true => (reader.read_uint16()?, reader.read_uint16()?),
+                false => (reader.read_uint8()? as u16, reader.read_uint8()? as u16),
+            };
+
+            self.kernings.push(FontKerning {
+                left,
+                right,
+                amount: reader.read_float32()?,
+            });
+        }
+
+        if version > 12 {
+            self.monospace = reader.read_boolean()?;
+        }
+
+        if version > 15 {
+            self.packed = reader.read_boolean()?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+
+        stream.write_prefixed_string(&self.mat)?;
+
+        if version > 10 {
+            stream.write_prefixed_string(&self.font_owner)?;
+        }
+
+        stream.write_float32(self.base_kerning)?;
+        save_vector2(&self.cell_size, &mut stream)?;
+        stream.write_float32(self.deprecated_size)?;
+
+        let wide_chars = version > 10;
+
+        if wide_chars {
+            stream.write_uint32(self.chars.len() as u32)?;
+            for c in self.chars.iter() {
+                stream.write_uint16(*c)?;
+            }
+        } else {
+            // Drop anything that can't fit in single byte
+            let chars = self.chars
+                .iter()
+                .filter(|c| **c <= u8::MAX as u16)
+                .map(|c| *c as u8)
+                .collect::<Vec<_>>();
+
+            stream.write_uint32(chars.len() as u32)?;
+            stream.write_bytes(&chars)?;
+        }
+
+        stream.write_uint32(self.kernings.len() as u32)?;
+        for kerning in self.kernings.iter() {
+            if wide_chars {
+                stream.write_uint16(kerning.left)?;
+                stream.write_uint16(kerning.right)?;
+            } else {
+                stream.write_uint8(kerning.left as u8)?;
+                stream.write_uint8(kerning.right as u8)?;
+            }
+
+            stream.write_float32(kerning.amount)?;
+        }
+
+        if version > 12 {
+            stream.write_boolean(self.monospace)?;
+        }
+
+        if version > 15 {
+            stream.write_boolean(self.packed)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(10, Platform::PS2, None, 7)]
+    #[case(24, Platform::PS2, None, 11)]
+    #[case(25, Platform::X360, None, 14)]
+    #[case(25, Platform::X360, Some(16), 16)]
+    #[case(28, Platform::PS3, None, 17)]
+    fn save_load_font(#[case] version: u32, #[case] platform: Platform, #[case] font_version: Option<u32>, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let mut font = FontObject {
+            mat: String::from("font.mat"),
+            base_kerning: 0.1,
+            cell_size: Vector2 { x: 0.5, y: 0.25 },
+            kernings: vec![
+                FontKerning { left: 'A' as u16, right: 'V' as u16, amount: -0.2 }
+            ],
+            version: font_version,
+            ..Default::default()
+        };
+        font.set_chars_from_str("ABCVabc123");
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        font.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_font = FontObject::default();
+        loaded_font.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(expected_version), loaded_font.version);
+
+        assert_eq!(font.mat, loaded_font.mat);
+        assert_eq!(font.base_kerning, loaded_font.base_kerning);
+        assert_eq!(font.cell_size.y, loaded_font.cell_size.y);
+        assert_eq!("ABCVabc123", loaded_font.get_chars_as_string());
+        assert_eq!(1, loaded_font.kernings.len());
+        assert_eq!(font.kernings[0].right, loaded_font.kernings[0].right);
+        assert_eq!(font.kernings[0].amount, loaded_font.kernings[0].amount);
+    }
+
+    #[rstest]
+    fn load_gh1_font_bytes() {
+        let info = SystemInfo {
+            version: 10,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let data: Vec<u8> = vec![
+            0x07, 0x00, 0x00, 0x00, // Version (7)
+            0x05, 0x00, 0x00, 0x00, b'a', b'.', b'm', b'a', b't', // Mat
+            0x00, 0x00, 0x80, 0x3E, // Base kerning (0.25)
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x40, // Cell size
+            0x00, 0x00, 0x40, 0x40, // Deprecated size (3.0)
+            0x03, 0x00, 0x00, 0x00, b'A', b'B', b'C', // Chars
+            0x01, 0x00, 0x00, 0x00, // Kerning count
+            b'A', b'V', 0x00, 0x00, 0x00, 0xBF, // Left, right, amount (-0.5)
+        ];
+
+        let font: FontObject = assert_round_trip_bytes(&data, &info);
+        assert_eq!("a.mat", font.mat);
+        assert_eq!(0.25, font.base_kerning);
+        assert_eq!((1.0, 2.0), (font.cell_size.x, font.cell_size.y));
+        assert_eq!(3.0, font.deprecated_size);
+        assert_eq!(vec![0x41, 0x42, 0x43], font.chars);
+
+        let kernings = font.kernings.iter().map(|k| (k.left, k.right, k.amount)).collect::<Vec<_>>();
+        assert_eq!(vec![(0x41, 0x56, -0.5)], kernings);
+    }
+}
@@ -0,0 +1,67 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo]
+pub struct FontObject {
+    pub mat: String,
+    pub font_owner: String,
+
+    pub base_kerning: f32,
+    pub cell_size: Vector2,
+    pub deprecated_size: f32,
+
+    pub chars: Vec<u16>,
+    pub kernings: Vec<FontKerning>,
+
+    pub monospace: bool,
+    pub packed: bool,
+
+    pub version: Option<u32>,
+}
+
+#[derive(Default)]
+pub struct FontKerning {
+    pub left: u16,
+    pub right: u16,
+    pub amount: f32,
+}
+
+impl FontObject {
+    pub fn get_chars_as_string(&self) -> String {
+        String::from_utf16_lossy(&self.chars)
+    }
+
+    pub fn set_chars_from_str(&mut self, chars: &str) {
+        self.chars = chars.encode_utf16().collect();
+    }
+}
+
+impl Default for FontObject {
+    fn default() -> FontObject {
+        FontObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Font object
+            mat: String::default(),
+            font_owner: String::default(),
+
+            base_kerning: 0.0,
+            cell_size: Vector2 { x: 1.0, y: 1.0 },
+            deprecated_size: 0.0,
+
+            chars: Vec::new(),
+            kernings: Vec::new(),
+
+            monospace: false,
+            packed: false,
+
+            version: None,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 use crate::dta::*;
 use crate::io::{BinaryStream, FileStream, SeekFrom, Stream};
 use crate::SystemInfo;
-use grim_traits::scene::{Color3, Color4, Matrix, Matrix3, MiloObject, Quat, Rect, Sphere, Vector2, Vector3};
+use grim_traits::scene::{Color3, Color4, Matrix, Matrix3, MiloObject, Plane, Quat, Rect, Sphere, Vector2, Vector3};
 use std::error::Error;
 use std::path::Path;
 
@@ -29,6 +29,11 @@ pub(crate) fn load_object<T: MiloObject>(obj: &mut T, reader: &mut Box<BinaryStr
 }
 
 pub(crate) fn save_object<T: MiloObject>(obj: &T, writer: &mut Box<BinaryStream>, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+    if info.version < 24 {
+        // Don't write metadata
+        return Ok(());
+    }
+
     save_object_type(obj, writer, info)?;
     save_object_rest(obj, writer, info)?;
 
@@ -229,6 +234,24 @@ pub (crate) fn save_sphere(sphere: &Sphere, writer: &mut Box<BinaryStream>) -> R
     Ok(())
 }
 
+pub (crate) fn load_plane(plane: &mut Plane, reader: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+    plane.a = reader.read_float32()?;
+    plane.b = reader.read_float32()?;
+    plane.c = reader.read_float32()?;
+    plane.d = reader.read_float32()?;
+
+    Ok(())
+}
+
+pub (crate) fn save_plane(plane: &Plane, writer: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+    writer.write_float32(plane.a)?;
+    writer.write_float32(plane.b)?;
+    writer.write_float32(plane.c)?;
+    writer.write_float32(plane.d)?;
+
+    Ok(())
+}
+
 pub (crate) fn load_vector2(vector: &mut Vector2, reader: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
     vector.x = reader.read_float32()?;
     vector.y = reader.read_float32()?;
@@ -275,4 +298,21 @@ pub (crate) fn save_quat(quat: &Quat, writer: &mut Box<BinaryStream>) -> Result<
     writer.write_float32(quat.w)?;
 
     Ok(())
-}
\ No newline at end of file
+}
+// Loads object from fixture bytes and checks it saves back to the same bytes
+#[cfg(test)]
+pub(crate) fn assert_round_trip_bytes<T: ObjectReadWrite + Default>(data: &[u8], info: &SystemInfo) -> T {
+    let mut input = data.to_vec();
+    let mut stream = crate::io::MemoryStream::from_vector_as_read_write(&mut input);
+
+    let mut obj = T::default();
+    obj.load(&mut stream, info).unwrap();
+    assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+    let mut saved = Vec::new();
+    let mut out_stream = crate::io::MemoryStream::from_vector_as_read_write(&mut saved);
+    obj.save(&mut out_stream, info).unwrap();
+    assert_eq!(data, saved);
+
+    obj
+}
@@ -0,0 +1,294 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum LightLoadError {
+    #[error("Light version {version} is not supported")]
+    LightVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+         0 => true, // Freq/Amp
+         3 => true, // GH1
+         6 => true, // GH2/GH2 360
+         9 => true, // RB1/RB2
+        12 => true, // TBRB/GDRB
+        14 => true, // RB3
+        _ => false
+    }
+}
+
+fn get_save_version(light: &LightObject, info: &SystemInfo) -> u32 {
+    match (info.version, light.version) {
+        (..=10, Some(v @ (0 | 3))) => v, // Freq/Amp/GH1
+        (..=10, _) => 3,
+        (24, _) => 6, // GH2
+        (25, Some(v @ (9 | 12))) => v, // RB1/RB2, TBRB/GDRB
+        (25, _) => 9,
+        _ => 14,
+    }
+}
+
+impl ObjectReadWrite for LightObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(LightLoadError::LightVersionNotSupported {
+                version
+            }));
+        }
+
+        self.version = Some(version);
+
+        load_object(self, &mut reader, info)?;
+        load_trans(self, &mut reader, info, false)?;
+
+        load_color4(&mut self.color, &mut reader)?;
+
+        if version > 10 {
+            self.intensity = reader.read_float32()?;
+        }
+
+        self.range = reader.read_float32()?;
+
+        if version > 1 {
+            self.light_type = reader.read_uint32()?.into();
+        }
+
+        if version > 2 {
+            self.falloff_start = reader.read_float32()?;
+        }
+
+        if version > 5 {
+            self.animate_color_from_preset = reader.read_boolean()?;
+            self.animate_position_from_preset = reader.read_boolean()?;
+        }
+
+        if version > 6 {
+            self.top_radius = reader.read_float32()?;
+            self.bot_radius = reader.read_float32()?;
+        }
+
+        if version > 7 {
+            self.softness = reader.read_float32()?;
+            self.displacement = reader.read_float32()?;
+        }
+
+        if version > 8 {
+            self.texture = reader.read_prefixed_string()?;
+        }
+
+        if version > 9 {
+            self.color_owner = reader.read_prefixed_string()?;
+        }
+
+        if version > 10 {
+            load_matrix(&mut self.texture_xfm, &mut reader)?;
+        }
+
+        self.shadow_objects.clear();
+        if version > 11 {
+            let shadow_count = reader.read_uint32()?;
+            for _ in 0..shadow_count {
+                self.shadow_objects.push(reader.read_prefixed_string()?);
+            }
+        }
+
+        if version > 12 {
+            self.projected_blend = reader.read_uint32()?;
+        }
+
+        if version > 13 {
+            self.only_projection = reader.read_boolean()?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+        save_trans(self, &mut stream, info, false)?;
+
+        save_color4(&self.color, &mut stream)?;
+
+        if version > 10 {
+            stream.write_float32(self.intensity)?;
+        }
+
+        stream.write_float32(self.range)?;
+
+        if version > 1 {
+            stream.write_uint32(self.light_type as u32)?;
+        }
+
+        if version > 2 {
+            stream.write_float32(self.falloff_start)?;
+        }
+
+        if version > 5 {
+            stream.write_boolean(self.animate_color_from_preset)?;
+            stream.write_boolean(self.animate_position_from_preset)?;
+        }
+
+        if version > 6 {
+            stream.write_float32(self.top_radius)?;
+            stream.write_float32(self.bot_radius)?;
+        }
+
+        if version > 7 {
+            stream.write_float32(self.softness)?;
+            stream.write_float32(self.displacement)?;
+        }
+
+        if version > 8 {
+            stream.write_prefixed_string(&self.texture)?;
+        }
+
+        if version > 9 {
+            stream.write_prefixed_string(&self.color_owner)?;
+        }
+
+        if version > 10 {
+            save_matrix(&self.texture_xfm, &mut stream)?;
+        }
+
+        if version > 11 {
+            stream.write_uint32(self.shadow_objects.len() as u32)?;
+            for shadow_object in self.shadow_objects.iter() {
+                stream.write_prefixed_string(shadow_object)?;
+            }
+        }
+
+        if version > 12 {
+            stream.write_uint32(self.projected_blend)?;
+        }
+
+        if version > 13 {
+            stream.write_boolean(self.only_projection)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(10, Platform::PS2, None, 3)]
+    #[case(10, Platform::PS2, Some(0), 0)]
+    #[case(24, Platform::PS2, None, 6)]
+    #[case(25, Platform::X360, None, 9)]
+    #[case(25, Platform::X360, Some(12), 12)]
+    #[case(25, Platform::X360, Some(14), 9)]
+    #[case(28, Platform::PS3, None, 14)]
+    fn save_load_light(#[case] version: u32, #[case] platform: Platform, #[case] light_version: Option<u32>, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let light = LightObject {
+            color: Color4 { r: 1.0, g: 0.5, b: 0.25, a: 1.0 },
+            range: 500.0,
+            light_type: LightType::kDirectional,
+            falloff_start: 10.0,
+            shadow_objects: vec![String::from("shadow.mesh")],
+            version: light_version,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        light.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_light = LightObject::default();
+        loaded_light.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(expected_version), loaded_light.version);
+
+        assert_eq!(light.color.g, loaded_light.color.g);
+        assert_eq!(light.range, loaded_light.range);
+
+        if expected_version > 2 {
+            assert_eq!(light.light_type as u32, loaded_light.light_type as u32);
+            assert_eq!(light.falloff_start, loaded_light.falloff_start);
+        }
+
+        if expected_version > 11 {
+            assert_eq!(light.shadow_objects, loaded_light.shadow_objects);
+        }
+    }
+
+    #[rstest]
+    fn load_light_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x09, // Version (9)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+        ];
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x09]); // Trans version (9)
+        data.extend_from_slice(&[0u8; 96]); // Local + world xfm
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, // Constraint
+            0x00, 0x00, 0x00, 0x00, // Target
+            0x00,                   // Preserve scale
+            0x00, 0x00, 0x00, 0x00, // Parent
+        ]);
+        data.extend_from_slice(&[
+            0x3F, 0x80, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x3E, 0x80, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, // Color
+            0x43, 0xFA, 0x00, 0x00, // Range (500.0)
+            0x00, 0x00, 0x00, 0x01, // Light type (directional)
+            0x41, 0x20, 0x00, 0x00, // Falloff start (10.0)
+            0x01,                   // Animate color from preset
+            0x00,                   // Animate position from preset
+            0x40, 0x00, 0x00, 0x00, // Top radius (2.0)
+            0x40, 0x40, 0x00, 0x00, // Bottom radius (3.0)
+            0x40, 0x80, 0x00, 0x00, // Softness (4.0)
+            0x40, 0xA0, 0x00, 0x00, // Displacement (5.0)
+            0x00, 0x00, 0x00, 0x05, b'a', b'.', b't', b'e', b'x', // Texture
+        ]);
+
+        let light: LightObject = assert_round_trip_bytes(&data, &info);
+        assert_eq!((1.0, 0.5, 0.25, 1.0), (light.color.r, light.color.g, light.color.b, light.color.a));
+        assert_eq!(500.0, light.range);
+        assert_eq!(LightType::kDirectional as u32, light.light_type as u32);
+        assert_eq!(10.0, light.falloff_start);
+        assert!(light.animate_color_from_preset);
+        assert!(!light.animate_position_from_preset);
+        assert_eq!((2.0, 3.0), (light.top_radius, light.bot_radius));
+        assert_eq!((4.0, 5.0), (light.softness, light.displacement));
+        assert_eq!("a.tex", light.texture);
+    }
+}
@@ -0,0 +1,108 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo(Trans)]
+pub struct LightObject {
+    pub color: Color4,
+    pub intensity: f32,
+    pub range: f32,
+    pub light_type: LightType,
+    pub falloff_start: f32,
+
+    pub animate_color_from_preset: bool,
+    pub animate_position_from_preset: bool,
+
+    pub top_radius: f32,
+    pub bot_radius: f32,
+
+    pub softness: f32,
+    pub displacement: f32,
+
+    pub texture: String,
+    pub color_owner: String,
+    pub texture_xfm: Matrix,
+
+    pub shadow_objects: Vec<String>,
+    pub projected_blend: u32,
+    pub only_projection: bool,
+
+    pub version: Option<u32>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+#[repr(u32)]
+pub enum LightType {
+    #[default]
+    kPoint,
+    kDirectional,
+    kFakeSpot,
+    kFloorSpot,
+    kShadowRef,
+}
+
+impl From<u32> for LightType {
+    fn from(num: u32) -> LightType {
+        match num {
+            0 => LightType::kPoint,
+            1 => LightType::kDirectional,
+            2 => LightType::kFakeSpot,
+            3 => LightType::kFloorSpot,
+            4 => LightType::kShadowRef,
+            // Default
+            _ => LightType::default(),
+        }
+    }
+}
+
+impl Default for LightObject {
+    fn default() -> LightObject {
+        LightObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Trans object
+            local_xfm: Matrix::default(),
+            world_xfm: Matrix::default(),
+
+            trans_objects: Vec::new(),
+
+            constraint: TransConstraint::default(),
+            target: String::default(),
+
+            preserve_scale: false,
+            parent: String::default(),
+
+            // Light object
+            color: Color4::white(),
+            intensity: 1.0,
+            range: 1000.0,
+            light_type: LightType::default(),
+            falloff_start: 0.0,
+
+            animate_color_from_preset: true,
+            animate_position_from_preset: true,
+
+            top_radius: 0.0,
+            bot_radius: 0.0,
+
+            softness: 0.0,
+            displacement: 0.0,
+
+            texture: String::default(),
+            color_owner: String::default(),
+            texture_xfm: Matrix::default(),
+
+            shadow_objects: Vec::new(),
+            projected_blend: 0,
+            only_projection: false,
+
+            version: None,
+        }
+    }
+}
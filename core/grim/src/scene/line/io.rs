@@ -0,0 +1,205 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum LineLoadError {
+    #[error("Line version {version} is not supported")]
+    LineVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+        1 => true, // GH1
+        2 => true, // GH2/GH2 360
+        3 => true, // RB1/RB2
+        5 => true, // TBRB/GDRB
+        7 => true, // RB3
+        _ => false
+    }
+}
+
+fn get_save_version(line: &LineObject, info: &SystemInfo) -> u32 {
+    match (info.version, line.version) {
+        (..=10, _) => 1, // GH1
+        (24, _) => 2, // GH2
+        (25, Some(v @ (3 | 5))) => v, // RB1/RB2, TBRB/GDRB
+        (25, _) => 3,
+        _ => 7,
+    }
+}
+
+impl ObjectReadWrite for LineObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(LineLoadError::LineVersionNotSupported {
+                version
+            }));
+        }
+
+        self.version = Some(version);
+
+        load_object(self, &mut reader, info)?;
+        load_trans(self, &mut reader, info, false)?;
+        load_draw(self, &mut reader, info, false)?;
+
+        self.width = reader.read_float32()?;
+
+        self.points.clear();
+        let point_count = reader.read_uint32()?;
+        for _ in 0..point_count {
+            let mut point = LinePoint::default();
+
+            load_vector3(&mut point.point, &mut reader)?;
+            load_color4(&mut point.color, &mut reader)?;
+
+            self.points.push(point);
+        }
+
+        self.mat = reader.read_prefixed_string()?;
+
+        if version > 1 {
+            self.folded = reader.read_boolean()?;
+        }
+
+        if version > 4 {
+            self.line_pairs = reader.read_boolean()?;
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+        save_trans(self, &mut stream, info, false)?;
+        save_draw(self, &mut stream, info, false)?;
+
+        stream.write_float32(self.width)?;
+
+        stream.write_uint32(self.points.len() as u32)?;
+        for point in self.points.iter() {
+            save_vector3(&point.point, &mut stream)?;
+            save_color4(&point.color, &mut stream)?;
+        }
+
+        stream.write_prefixed_string(&self.mat)?;
+
+        if version > 1 {
+            stream.write_boolean(self.folded)?;
+        }
+
+        if version > 4 {
+            stream.write_boolean(self.line_pairs)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(10, Platform::PS2, None, 1)]
+    #[case(25, Platform::X360, None, 3)]
+    #[case(25, Platform::X360, Some(5), 5)]
+    #[case(28, Platform::PS3, None, 7)]
+    fn save_load_line(#[case] version: u32, #[case] platform: Platform, #[case] line_version: Option<u32>, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let line = LineObject {
+            width: 2.0,
+            mat: String::from("line.mat"),
+            version: line_version,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        line.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_line = LineObject::default();
+        loaded_line.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(expected_version), loaded_line.version);
+
+        assert_eq!(line.width, loaded_line.width);
+        assert_eq!(line.mat, loaded_line.mat);
+    }
+
+    #[rstest]
+    fn load_line_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x03, // Version (3)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+        ];
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x09]); // Trans version (9)
+        data.extend_from_slice(&[0u8; 96]); // Local + world xfm
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, // Constraint
+            0x00, 0x00, 0x00, 0x00, // Target
+            0x00,                   // Preserve scale
+            0x00, 0x00, 0x00, 0x00, // Parent
+        ]);
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x03, // Draw version (3)
+            0x01,                   // Showing
+        ]);
+        data.extend_from_slice(&[0u8; 16]); // Sphere
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // Draw order
+        data.extend_from_slice(&[
+            0x40, 0x00, 0x00, 0x00, // Width (2.0)
+            0x00, 0x00, 0x00, 0x01, // Point count
+            0x3F, 0x80, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x40, 0x00, 0x00, // Point
+            0x3F, 0x80, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x3E, 0x80, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, // Color
+            0x00, 0x00, 0x00, 0x05, b'a', b'.', b'm', b'a', b't', // Mat
+            0x01,                   // Folded
+        ]);
+
+        let line: LineObject = assert_round_trip_bytes(&data, &info);
+        assert!(line.showing);
+        assert_eq!(2.0, line.width);
+        assert_eq!(1, line.points.len());
+
+        let point = &line.points[0];
+        assert_eq!((1.0, 2.0, 3.0), (point.point.x, point.point.y, point.point.z));
+        assert_eq!((1.0, 0.5, 0.25, 1.0), (point.color.r, point.color.g, point.color.b, point.color.a));
+        assert_eq!("a.mat", line.mat);
+        assert!(line.folded);
+        assert!(!line.line_pairs);
+    }
+}
@@ -0,0 +1,62 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo]
+#[milo_super(Draw, Trans)]
+pub struct LineObject {
+    pub width: f32,
+    pub points: Vec<LinePoint>,
+    pub mat: String,
+    pub folded: bool,
+    pub line_pairs: bool,
+
+    pub version: Option<u32>,
+}
+
+#[derive(Default)]
+pub struct LinePoint {
+    pub point: Vector3,
+    pub color: Color4,
+}
+
+impl Default for LineObject {
+    fn default() -> LineObject {
+        LineObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Draw object
+            showing: true,
+            draw_objects: Vec::new(),
+            sphere: Sphere::default(),
+            draw_order: 0.0,
+            override_include_in_depth_only_pass: OverrideIncludeInDepthOnlyPass::default(),
+
+            // Trans object
+            local_xfm: Matrix::default(),
+            world_xfm: Matrix::default(),
+
+            trans_objects: Vec::new(),
+
+            constraint: TransConstraint::default(),
+            target: String::default(),
+
+            preserve_scale: false,
+            parent: String::default(),
+
+            // Line object
+            width: 1.0,
+            points: Vec::new(),
+            mat: String::default(),
+            folded: true,
+            line_pairs: false,
+
+            version: None,
+        }
+    }
+}
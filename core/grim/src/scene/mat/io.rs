@@ -26,6 +26,21 @@ fn is_version_supported(version: u32) -> bool {
     }
 }
 
+fn get_save_version(info: &SystemInfo) -> u32 {
+    match (info.version, info.is_next_gen()) {
+        // GH1
+        (..=10, _) => 21,
+        // GH2
+        (24, false) => 27,
+        // TODO: Support saving newer versions
+        _ => 28
+    }
+}
+
+// Point lights bool is stored as u32 for v43-44 mats
+// Other bits are unknown so raw value is preserved
+const POINT_LIGHTS_FLAG: u32 = 1 << 0;
+
 impl ObjectReadWrite for MatObject {
     fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
@@ -72,28 +87,32 @@ impl ObjectReadWrite for MatObject {
         } else if version <= 21 {
             // Read tex entries
             let tex_count = reader.read_uint32()?;
+            self.tex_entries.clear();
 
             for _ in 0..tex_count {
-                reader.read_uint32()?; // Skip unknown
-
-                let map_type = reader.read_uint32()?;
+                let mut entry = MatTexEntry {
+                    unknown: reader.read_uint32()?,
+                    map_type: reader.read_uint32()?,
+                    ..Default::default()
+                };
 
-                if map_type == 0 {
+                if entry.map_type == 0 {
                     load_matrix(&mut self.tex_xfm, &mut reader)?;
                     self.tex_wrap = reader.read_uint32()?.into();
                 } else {
-                    // Skip transform + tex_wrap
-                    reader.seek(SeekFrom::Current(48))?;
-                    reader.seek(SeekFrom::Current(4))?;
+                    load_matrix(&mut entry.xfm, &mut reader)?;
+                    entry.wrap = reader.read_uint32()?.into();
                 }
 
                 // Set name
                 let name = reader.read_prefixed_string()?;
-                match map_type {
+                match entry.map_type {
                     0 => self.diffuse_tex = name,
                     2 => self.environ_map = name,
-                    _ => continue,
+                    _ => entry.name = name,
                 };
+
+                self.tex_entries.push(entry);
             }
 
             if version <= 15 {
@@ -108,6 +127,9 @@ impl ObjectReadWrite for MatObject {
 
         if version <= 21 {
             // TODO: Parse other info
+            let remaining = reader.len()? - reader.pos() as usize;
+            self.raw_v21_data = reader.read_bytes(remaining)?;
+
             return Ok(());
         }
 
@@ -241,8 +263,8 @@ impl ObjectReadWrite for MatObject {
 
         if version > 42 {
             if version < 45 {
-                let bitfield = reader.read_uint32()?;
-                todo!("Parse point_lights from {}", bitfield);
+                self.raw_lights_bitfield = reader.read_uint32()?;
+                self.point_lights = (self.raw_lights_bitfield & POINT_LIGHTS_FLAG) != 0;
             } else {
                 self.point_lights = reader.read_boolean()?;
             }
@@ -310,15 +332,42 @@ impl ObjectReadWrite for MatObject {
     fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
 
-        // TODO: Get version from system info
-        let version = 28;
+        let version = get_save_version(info);
 
         stream.write_uint32(version)?;
 
         save_object(self, &mut stream, info)?;
 
         if version <= 21 {
-            todo!("Saving not implemented for v21 materials");
+            // Write tex entries
+            let mut tex_entries = self.tex_entries
+                .iter()
+                .map(|e| match e.map_type {
+                    0 => (e.unknown, e.map_type, &self.tex_xfm, self.tex_wrap, &self.diffuse_tex),
+                    2 => (e.unknown, e.map_type, &e.xfm, e.wrap, &self.environ_map),
+                    _ => (e.unknown, e.map_type, &e.xfm, e.wrap, &e.name),
+                })
+                .collect::<Vec<_>>();
+
+            // Add diffuse/environ maps missing from loaded entries
+            let identity = Matrix::identity();
+            for (map_type, name, xfm) in [(0, &self.diffuse_tex, &self.tex_xfm), (2, &self.environ_map, &identity)] {
+                if !name.is_empty() && !tex_entries.iter().any(|(_, t, ..)| *t == map_type) {
+                    tex_entries.push((0, map_type, xfm, self.tex_wrap, name));
+                }
+            }
+
+            stream.write_uint32(tex_entries.len() as u32)?;
+
+            for (unknown, map_type, xfm, wrap, name) in tex_entries {
+                stream.write_uint32(unknown)?;
+                stream.write_uint32(map_type)?;
+
+                save_matrix(xfm, &mut stream)?;
+                stream.write_uint32(wrap as u32)?;
+
+                stream.write_prefixed_string(name)?;
+            }
         }
 
         stream.write_uint32(self.blend as u32)?;
@@ -327,6 +376,7 @@ impl ObjectReadWrite for MatObject {
 
         if version <= 21 {
             // TODO: Write other info
+            stream.write_bytes(&self.raw_v21_data)?;
             return Ok(());
         }
 
@@ -447,7 +497,8 @@ impl ObjectReadWrite for MatObject {
         if version > 42 {
             if version < 45 {
                 // Write as bitfield
-                stream.write_uint32(self.point_lights as u32)?;
+                let point_lights = if self.point_lights { POINT_LIGHTS_FLAG } else { 0 };
+                stream.write_uint32((self.raw_lights_bitfield & !POINT_LIGHTS_FLAG) | point_lights)?;
             } else {
                 // Write as boolean
                 stream.write_boolean(self.point_lights)?;
@@ -515,4 +566,128 @@ impl ObjectReadWrite for MatObject {
 
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_v21_mat_bytes() {
+        let info = SystemInfo {
+            version: 10,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x15, 0x00, 0x00, 0x00, // Version (21)
+            0x03, 0x00, 0x00, 0x00, // Tex entry count
+            // Entry 0
+            0x00, 0x00, 0x00, 0x00, // Unknown
+            0x00, 0x00, 0x00, 0x00, // Map type (0)
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F,
+            0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x01, 0x00, 0x00, 0x00, // Tex wrap (repeat)
+            0x05, 0x00, 0x00, 0x00, b'd', b'i', b'f', b'f', b'.',
+            // Entry 1
+            0x00, 0x00, 0x00, 0x00, // Unknown
+            0x02, 0x00, 0x00, 0x00, // Map type (2)
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F,
+            0x00, 0x00, 0x80, 0x3E, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Tex wrap (clamp)
+            0x04, 0x00, 0x00, 0x00, b'e', b'n', b'v', b'.',
+            // Entry 2
+            0x01, 0x00, 0x00, 0x00, // Unknown
+            0x01, 0x00, 0x00, 0x00, // Map type (1)
+            0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x80, 0x3F,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x02, 0x00, 0x00, 0x00, // Tex wrap (border black)
+            0x03, 0x00, 0x00, 0x00, b'a', b'.', b'x',
+            0x02, 0x00, 0x00, 0x00, // Blend (add)
+            0x00, 0x00, 0x80, 0x3E, 0x00, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x40, 0x3F, // Color
+            0x00, 0x00, 0x00, 0x3F, // Alpha
+            0x01, 0x00, 0x00, 0x00, 0xAB, // Remaining data
+        ];
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut mat = MatObject::default();
+        mat.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!("diff.", mat.diffuse_tex);
+        assert_eq!("env.", mat.environ_map);
+        assert_eq!(TexWrap::kTexWrapRepeat as u32, mat.tex_wrap as u32);
+        assert_eq!(0.5, mat.tex_xfm.m41);
+
+        // Non-diffuse entries keep their own transform + wrap
+        assert_eq!(3, mat.tex_entries.len());
+        assert_eq!(0.25, mat.tex_entries[1].xfm.m41);
+        assert_eq!(TexWrap::kTexWrapClamp as u32, mat.tex_entries[1].wrap as u32);
+        assert_eq!((1, 1, "a.x"), (mat.tex_entries[2].unknown, mat.tex_entries[2].map_type, mat.tex_entries[2].name.as_str()));
+        assert_eq!(Blend::kBlendAdd as u32, mat.blend as u32);
+        assert_eq!((0.25, 0.5, 0.75), (mat.color.r, mat.color.g, mat.color.b));
+        assert_eq!(0.5, mat.alpha);
+        assert_eq!(vec![0x01, 0x00, 0x00, 0x00, 0xAB], mat.raw_v21_data);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        mat.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    fn save_load_v21_mat() {
+        let info = SystemInfo {
+            version: 10,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let mut tex_xfm = Matrix::identity();
+        tex_xfm.m41 = 0.5;
+
+        let mat = MatObject {
+            blend: Blend::kBlendAdd,
+            color: Color3 { r: 0.25, g: 0.5, b: 0.75 },
+            alpha: 0.5,
+            tex_wrap: TexWrap::kTexWrapClamp,
+            tex_xfm,
+            diffuse_tex: String::from("diffuse.tex"),
+            environ_map: String::from("environ.tex"),
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        mat.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_mat = MatObject::default();
+        loaded_mat.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(mat.blend as u32, loaded_mat.blend as u32);
+        assert_eq!((mat.color.r, mat.color.g, mat.color.b), (loaded_mat.color.r, loaded_mat.color.g, loaded_mat.color.b));
+        assert_eq!(mat.alpha, loaded_mat.alpha);
+        assert_eq!(mat.tex_wrap as u32, loaded_mat.tex_wrap as u32);
+        assert_eq!(mat.tex_xfm.m41, loaded_mat.tex_xfm.m41);
+        assert_eq!(mat.diffuse_tex, loaded_mat.diffuse_tex);
+        assert_eq!(mat.environ_map, loaded_mat.environ_map);
+    }
+}
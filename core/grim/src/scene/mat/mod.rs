@@ -4,6 +4,16 @@ use grim_macros::*;
 use grim_traits::scene::*;
 pub use io::*;
 
+// GH1 (v21) mats can be linked to many textures
+#[derive(Default)]
+pub struct MatTexEntry {
+    pub unknown: u32,
+    pub map_type: u32,
+    pub xfm: Matrix,
+    pub wrap: TexWrap,
+    pub name: String,
+}
+
 #[milo]
 pub struct MatObject {
     pub blend: Blend,
@@ -49,6 +59,7 @@ pub struct MatObject {
     pub norm_detail_map: String,
 
     pub point_lights: bool,
+    pub raw_lights_bitfield: u32, // v43-44 only, point lights bit is kept separately
     pub proj_lights: bool,
     pub fog: bool,
     pub fade_out: bool,
@@ -73,6 +84,11 @@ pub struct MatObject {
 
     pub alpha_mask: String,
     pub ps3_force_trilinear: bool,
+
+    pub tex_entries: Vec<MatTexEntry>, // Diffuse/environ map entries use fields above
+
+    // Using until remaining GH1 (v21) data is figured out
+    pub raw_v21_data: Vec<u8>,
 }
 
 impl Default for MatObject {
@@ -128,6 +144,7 @@ impl Default for MatObject {
             norm_detail_map: String::default(),
 
             point_lights: false,
+            raw_lights_bitfield: 0,
             proj_lights: false,
             fog: false,
             fade_out: false,
@@ -152,6 +169,9 @@ impl Default for MatObject {
 
             alpha_mask: String::default(),
             ps3_force_trilinear: false,
+
+            tex_entries: Vec::new(),
+            raw_v21_data: Vec::new(),
         }
     }
 }
\ No newline at end of file
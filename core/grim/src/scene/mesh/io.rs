@@ -1,6 +1,7 @@
 use crate::Platform;
-use crate::io::{BinaryStream, SeekFrom, Stream};
+use crate::io::{BinaryStream, f16, SeekFrom, Stream};
 use crate::scene::*;
+use super::MAX_BONES_PER_GROUP;
 use crate::SystemInfo;
 use grim_traits::scene::*;
 use std::error::Error;
@@ -12,8 +13,20 @@ pub enum MeshLoadError {
     MeshVersionNotSupported {
         version: u32
     },
-    #[error("BSP volume geometry is not supported")]
-    BSPVolumeNotSupported,
+}
+
+#[derive(Debug, ThisError)]
+pub enum MeshWriteError {
+    #[error("Mesh version {version} supports up to {max} bones but mesh has {count}")]
+    TooManyBones {
+        version: u32,
+        max: usize,
+        count: usize
+    },
+    #[error("Bone index {index} is too large for packed vert layout")]
+    BoneIndexOutOfRange {
+        index: u16
+    },
 }
 
 fn is_version_supported(version: u32) -> bool {
@@ -30,6 +43,178 @@ fn is_version_supported(version: u32) -> bool {
     }
 }
 
+fn get_save_version(mesh: &MeshObject, info: &SystemInfo) -> u32 {
+    // TODO: Support other versions
+    match (info.version, info.is_next_gen(), mesh.packed_vert_format) {
+        (24, _, _) => 28, // GH2
+        (25, true, Some(PackedVertFormat { version: v @ 36..=37, .. })) => v, // TBRB/GDRB (same milo version as RB1/RB2)
+        (28.., true, _) => 38,
+        _ => 34,
+    }
+}
+
+fn get_packed_vert_format(version: u32) -> (u32, u32) {
+    // Stride + vert type
+    match version {
+        38.. => (40, 2),
+        _ => (36, 1),
+    }
+}
+
+fn pack_unorm8(value: f32) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn unpack_unorm8(value: u8) -> f32 {
+    value as f32 / 255.0
+}
+
+fn pack_snorm8(value: f32) -> i8 {
+    (value.clamp(-1.0, 1.0) * 127.0).round() as i8
+}
+
+fn unpack_snorm8(value: i8) -> f32 {
+    (value as f32 / 127.0).max(-1.0)
+}
+
+fn load_bsp_node(reader: &mut Box<BinaryStream>) -> Result<Option<Box<BSPNode>>, Box<dyn Error>> {
+    if !reader.read_boolean()? {
+        return Ok(None);
+    }
+
+    let mut node = BSPNode::default();
+
+    load_plane(&mut node.plane, reader)?;
+    node.front = load_bsp_node(reader)?;
+    node.back = load_bsp_node(reader)?;
+
+    Ok(Some(Box::new(node)))
+}
+
+fn save_bsp_node(node: &Option<Box<BSPNode>>, stream: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+    let Some(node) = node else {
+        stream.write_boolean(false)?;
+        return Ok(());
+    };
+
+    stream.write_boolean(true)?;
+
+    save_plane(&node.plane, stream)?;
+    save_bsp_node(&node.front, stream)?;
+    save_bsp_node(&node.back, stream)?;
+
+    Ok(())
+}
+
+fn read_packed_vert_layout(reader: &mut Box<BinaryStream>) -> Result<PackedVertLayout, Box<dyn Error>> {
+    // Older layout has -1 marker after position
+    reader.seek(SeekFrom::Current(12))?;
+    let marker = reader.read_int32()?;
+    reader.seek(SeekFrom::Current(-16))?;
+
+    match marker {
+        -1 => Ok(PackedVertLayout::Marked),
+        _ => Ok(PackedVertLayout::Weighted),
+    }
+}
+
+fn read_packed_vert(reader: &mut Box<BinaryStream>, version: u32, layout: PackedVertLayout) -> Result<Vert, Box<dyn Error>> {
+    let mut vec = Vert::default();
+
+    // Position
+    vec.pos.x = reader.read_float32()?;
+    vec.pos.y = reader.read_float32()?;
+    vec.pos.z = reader.read_float32()?;
+
+    if layout == PackedVertLayout::Marked {
+        reader.seek(SeekFrom::Current(4))?; // Always -1
+    }
+
+    // UVs
+    vec.uv.u = reader.read_float16()?.into();
+    vec.uv.v = reader.read_float16()?.into();
+
+    // Normals
+    vec.normals.x = reader.read_float16()?.into();
+    vec.normals.y = reader.read_float16()?.into();
+    vec.normals.z = reader.read_float16()?.into();
+    vec.normals.w = reader.read_float16()?.into();
+
+    // Weights (normalized bytes)
+    vec.weights[0] = unpack_unorm8(reader.read_uint8()?);
+    vec.weights[1] = unpack_unorm8(reader.read_uint8()?);
+    vec.weights[2] = unpack_unorm8(reader.read_uint8()?);
+    vec.weights[3] = unpack_unorm8(reader.read_uint8()?);
+
+    // Bone indices
+    for bone in vec.bones.iter_mut() {
+        *bone = match layout {
+            PackedVertLayout::Weighted => reader.read_uint16()?,
+            PackedVertLayout::Marked => reader.read_uint8()?.into(),
+        };
+    }
+
+    if version >= 38 {
+        // Tangent (normalized signed bytes)
+        vec.tangent.x = unpack_snorm8(reader.read_int8()?);
+        vec.tangent.y = unpack_snorm8(reader.read_int8()?);
+        vec.tangent.z = unpack_snorm8(reader.read_int8()?);
+        vec.tangent.w = unpack_snorm8(reader.read_int8()?);
+    }
+
+    Ok(vec)
+}
+
+fn write_packed_vert(vec: &Vert, stream: &mut Box<BinaryStream>, version: u32, layout: PackedVertLayout) -> Result<(), Box<dyn Error>> {
+    // Position
+    stream.write_float32(vec.pos.x)?;
+    stream.write_float32(vec.pos.y)?;
+    stream.write_float32(vec.pos.z)?;
+
+    if layout == PackedVertLayout::Marked {
+        stream.write_int32(-1)?;
+    }
+
+    // UVs
+    stream.write_float16(f16::from_f32(vec.uv.u))?;
+    stream.write_float16(f16::from_f32(vec.uv.v))?;
+
+    // Normals
+    stream.write_float16(f16::from_f32(vec.normals.x))?;
+    stream.write_float16(f16::from_f32(vec.normals.y))?;
+    stream.write_float16(f16::from_f32(vec.normals.z))?;
+    stream.write_float16(f16::from_f32(vec.normals.w))?;
+
+    // Weights (normalized bytes)
+    stream.write_uint8(pack_unorm8(vec.weights[0]))?;
+    stream.write_uint8(pack_unorm8(vec.weights[1]))?;
+    stream.write_uint8(pack_unorm8(vec.weights[2]))?;
+    stream.write_uint8(pack_unorm8(vec.weights[3]))?;
+
+    // Bone indices
+    for bone in vec.bones.iter() {
+        match layout {
+            PackedVertLayout::Weighted => stream.write_uint16(*bone)?,
+            PackedVertLayout::Marked => {
+                let index = u8::try_from(*bone)
+                    .map_err(|_| MeshWriteError::BoneIndexOutOfRange { index: *bone })?;
+
+                stream.write_uint8(index)?
+            }
+        }
+    }
+
+    if version >= 38 {
+        // Tangent (normalized signed bytes)
+        stream.write_int8(pack_snorm8(vec.tangent.x))?;
+        stream.write_int8(pack_snorm8(vec.tangent.y))?;
+        stream.write_int8(pack_snorm8(vec.tangent.z))?;
+        stream.write_int8(pack_snorm8(vec.tangent.w))?;
+    }
+
+    Ok(())
+}
+
 impl ObjectReadWrite for MeshObject {
     fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
@@ -139,14 +324,10 @@ impl ObjectReadWrite for MeshObject {
             self.volume = reader.read_uint32()?.into();
         }
 
-        // Read bsp value
+        // Read bsp tree
+        self.bsp = None;
         if version > 18 {
-            // Ignored but still validated
-            let bsp = reader.read_uint8()?;
-            if bsp != 0 {
-                //panic!("Expected bsp field to be 0, not \"{}\" in Mesh", bsp);
-                return Err(Box::new(MeshLoadError::BSPVolumeNotSupported));
-            }
+            self.bsp = load_bsp_node(&mut reader)?;
         }
 
         if version == 7 {
@@ -171,8 +352,15 @@ impl ObjectReadWrite for MeshObject {
             }
         }
 
+        self.packed_vert_format = None;
+        if is_ng && vert_count > 0 {
+            self.packed_vert_format = Some(PackedVertFormat {
+                version,
+                layout: read_packed_vert_layout(&mut reader)?,
+            });
+        }
+
         self.vertices.clear();
-        self.raw_vertices.clear();
         for _ in 0..vert_count {
             let mut vec = Vert::default();
 
@@ -239,15 +427,9 @@ impl ObjectReadWrite for MeshObject {
                 continue;
             }
 
-            // TODO: Remove once next gen vertex format is figured out
-            if version >= 36 && is_ng {
-                // Read raw vert data
-                let mut raw_vert = [0u8; 36];
-                let data = reader.read_bytes(36)?;
-                raw_vert.copy_from_slice(data.as_slice());
-
-                reader.seek(SeekFrom::Current(-36))?;
-                self.raw_vertices.push(raw_vert);
+            if let Some(PackedVertFormat { layout, .. }) = self.packed_vert_format {
+                self.vertices.push(read_packed_vert(&mut reader, version, layout)?);
+                continue;
             }
 
             // Position
@@ -258,112 +440,60 @@ impl ObjectReadWrite for MeshObject {
                 vec.pos.w = reader.read_float32()?;
             }
 
-            if version < 35 || !is_ng {
-                if version >= 38 {
-                    // Skip extra bytes
-                    // TODO: Figure out what this data is...
-                    reader.seek(SeekFrom::Current(16))?;
-                }
+            if version >= 38 {
+                // Skip extra bytes
+                // TODO: Figure out what this data is...
+                reader.seek(SeekFrom::Current(16))?;
+            }
 
-                // Normals
-                vec.normals.x = reader.read_float32()?;
-                vec.normals.y = reader.read_float32()?;
-                vec.normals.z = reader.read_float32()?;
-                if version == 34 {
-                    vec.normals.w = reader.read_float32()?;
-                }
+            // Normals
+            vec.normals.x = reader.read_float32()?;
+            vec.normals.y = reader.read_float32()?;
+            vec.normals.z = reader.read_float32()?;
+            if version == 34 {
+                vec.normals.w = reader.read_float32()?;
+            }
 
-                if version >= 38 {
-                    // Packed in different order?
-                    // UVs
-                    vec.uv.u = reader.read_float32()?;
-                    vec.uv.v = reader.read_float32()?;
-
-                    // Weights
-                    vec.weights[0] = reader.read_float32()?;
-                    vec.weights[1] = reader.read_float32()?;
-                    vec.weights[2] = reader.read_float32()?;
-                    vec.weights[3] = reader.read_float32()?;
-                } else {
-                    // Weights
-                    vec.weights[0] = reader.read_float32()?;
-                    vec.weights[1] = reader.read_float32()?;
-                    vec.weights[2] = reader.read_float32()?;
-                    vec.weights[3] = reader.read_float32()?;
-
-                    // UVs
-                    vec.uv.u = reader.read_float32()?;
-                    vec.uv.v = reader.read_float32()?;
-                }
+            if version >= 38 {
+                // Packed in different order?
+                // UVs
+                vec.uv.u = reader.read_float32()?;
+                vec.uv.v = reader.read_float32()?;
 
-                if version >= 34 {
-                    // Bone indices
-                    vec.bones[0] = reader.read_uint16()?;
-                    vec.bones[1] = reader.read_uint16()?;
-                    vec.bones[2] = reader.read_uint16()?;
-                    vec.bones[3] = reader.read_uint16()?;
-
-                    if version >= 38 {
-                        // Skip unknown bytes
-                        // TODO: Figure out what this data is...
-                        reader.seek(SeekFrom::Current(16))?;
-                    } else {
-                        // Tangent?
-                        vec.tangent.x = reader.read_float32()?;
-                        vec.tangent.y = reader.read_float32()?;
-                        vec.tangent.z = reader.read_float32()?;
-                        vec.tangent.w = reader.read_float32()?;
-                    }
-                }
+                // Weights
+                vec.weights[0] = reader.read_float32()?;
+                vec.weights[1] = reader.read_float32()?;
+                vec.weights[2] = reader.read_float32()?;
+                vec.weights[3] = reader.read_float32()?;
             } else {
-                let uv_check = reader.read_int32()?;
-
-                if uv_check == -1 {
-                    // UVs
-                    vec.uv.u = reader.read_float16()?.into();
-                    vec.uv.v = reader.read_float16()?.into();
-
-                    // Normals
-                    vec.normals.x = reader.read_float16()?.into();
-                    vec.normals.y = reader.read_float16()?.into();
-                    vec.normals.z = reader.read_float16()?.into();
-
-                    // Not sure
-                    reader.seek(SeekFrom::Current(6))?;
-
-                    // Bone indices
-                    vec.bones[0] = reader.read_uint8()?.into();
-                    vec.bones[1] = reader.read_uint8()?.into();
-                    vec.bones[2] = reader.read_uint8()?.into();
-                    vec.bones[3] = reader.read_uint8()?.into();
-                } else {
-                    // Read as regular uvs
-                    reader.seek(SeekFrom::Current(-4))?;
-
-                    // UVs
-                    vec.uv.u = reader.read_float16()?.into();
-                    vec.uv.v = reader.read_float16()?.into();
-
-                    // Normals
-                    vec.normals.x = reader.read_float16()?.into();
-                    vec.normals.y = reader.read_float16()?.into();
-                    vec.normals.z = reader.read_float16()?.into();
-                    reader.seek(SeekFrom::Current(2))?; // Not sure
-
-                    // Not sure
-                    reader.seek(SeekFrom::Current(4))?;
-
-                    // Bone indices
-                    vec.bones[0] = reader.read_uint16()?;
-                    vec.bones[1] = reader.read_uint16()?;
-                    vec.bones[2] = reader.read_uint16()?;
-                    vec.bones[3] = reader.read_uint16()?;
-                }
+                // Weights
+                vec.weights[0] = reader.read_float32()?;
+                vec.weights[1] = reader.read_float32()?;
+                vec.weights[2] = reader.read_float32()?;
+                vec.weights[3] = reader.read_float32()?;
+
+                // UVs
+                vec.uv.u = reader.read_float32()?;
+                vec.uv.v = reader.read_float32()?;
+            }
+
+            if version >= 34 {
+                // Bone indices
+                vec.bones[0] = reader.read_uint16()?;
+                vec.bones[1] = reader.read_uint16()?;
+                vec.bones[2] = reader.read_uint16()?;
+                vec.bones[3] = reader.read_uint16()?;
 
                 if version >= 38 {
-                    // Skip extra bytes
+                    // Skip unknown bytes
                     // TODO: Figure out what this data is...
-                    reader.seek(SeekFrom::Current(4))?;
+                    reader.seek(SeekFrom::Current(16))?;
+                } else {
+                    // Tangent?
+                    vec.tangent.x = reader.read_float32()?;
+                    vec.tangent.y = reader.read_float32()?;
+                    vec.tangent.z = reader.read_float32()?;
+                    vec.tangent.w = reader.read_float32()?;
                 }
             }
 
@@ -372,6 +502,7 @@ impl ObjectReadWrite for MeshObject {
 
         let face_count = reader.read_uint32()?;
         self.faces.clear();
+        self.group_partitions.clear();
         for _ in 0..face_count {
             let mut face = [0u16; 3];
 
@@ -444,6 +575,8 @@ impl ObjectReadWrite for MeshObject {
                 });
             }
 
+            let has_bones = !bones.is_empty();
+
             // Read bone transforms
             for mut bone in bones {
                 load_matrix(&mut bone.trans, &mut reader)?;
@@ -453,6 +586,26 @@ impl ObjectReadWrite for MeshObject {
                     self.bones.push(bone);
                 }
             }
+
+            // Read group partitions (only present for skinned meshes)
+            if has_bones {
+                for _ in 0..group_count {
+                    let section_count = reader.read_uint32()?;
+                    let vert_count = reader.read_uint32()?;
+
+                    let mut partition = GroupPartition::default();
+
+                    for _ in 0..section_count {
+                        partition.sections.push(reader.read_uint32()?);
+                    }
+
+                    for _ in 0..vert_count {
+                        partition.vert_indices.push(reader.read_uint16()?);
+                    }
+
+                    self.group_partitions.push(partition);
+                }
+            }
         }
 
         if version >= 36 {
@@ -465,17 +618,14 @@ impl ObjectReadWrite for MeshObject {
             self.has_ao_calculation = reader.read_boolean()?;
         }
 
-        // TODO: Parse extra data from previous gen platforms
-
         Ok(())
     }
 
     fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
 
-        // TODO: Get version from system info
-        let version = 34;
         let is_ng = info.is_next_gen();
+        let version = get_save_version(self, info);
 
         stream.write_uint32(version)?;
 
@@ -489,8 +639,7 @@ impl ObjectReadWrite for MeshObject {
         stream.write_uint32(self.mutable as u32)?;
         stream.write_uint32(self.volume as u32)?;
 
-        // TODO: Figure out what bsp? Although it's not really used at all...
-        stream.write_uint8(0)?;
+        save_bsp_node(&self.bsp, &mut stream)?;
 
         stream.write_uint32(self.vertices.len() as u32)?;
 
@@ -498,67 +647,64 @@ impl ObjectReadWrite for MeshObject {
             stream.write_boolean(is_ng)?;
 
             if is_ng {
-                // TODO: Determine if value changes after v37
-                let vert_stride = 36;
+                let (vert_stride, vert_type) = get_packed_vert_format(version);
 
                 stream.write_uint32(vert_stride)?;
-                stream.write_uint32(1)?; // Some constant
+                stream.write_uint32(vert_type)?;
             }
         }
 
-        // TODO: Remove once next gen vertex format is figured out
-        if version >= 36 && is_ng && self.vertices.len() == self.raw_vertices.len() {
-            // Write raw vertices
-            for raw_vert in self.raw_vertices.iter() {
-                stream.write_bytes(raw_vert)?;
+        // Write back same layout that was read
+        let packed_layout = self.packed_vert_format
+            .map(|f| f.layout)
+            .unwrap_or_default();
+
+        // Write vertices
+        // TODO: Separate into functions and use conditionals before loop iteration
+        for v in &self.vertices {
+            if version >= 36 && is_ng {
+                write_packed_vert(v, &mut stream, version, packed_layout)?;
+                continue;
             }
-        } else {
-            // Write vertices
-            // TODO: Separate into functions and use conditionals before loop iteration
-            for v in &self.vertices {
-                // Position
-                stream.write_float32(v.pos.x)?;
-                stream.write_float32(v.pos.y)?;
-                stream.write_float32(v.pos.z)?;
-                if version == 34 {
-                    stream.write_float32(v.pos.w)?;
-                }
 
-                if version < 35 || !is_ng {
-                    // Normals
-                    stream.write_float32(v.normals.x)?;
-                    stream.write_float32(v.normals.y)?;
-                    stream.write_float32(v.normals.z)?;
-                    if version == 34 {
-                        stream.write_float32(v.normals.w)?;
-                    }
+            // Position
+            stream.write_float32(v.pos.x)?;
+            stream.write_float32(v.pos.y)?;
+            stream.write_float32(v.pos.z)?;
+            if version == 34 {
+                stream.write_float32(v.pos.w)?;
+            }
 
-                    // Weights
-                    stream.write_float32(v.weights[0])?;
-                    stream.write_float32(v.weights[1])?;
-                    stream.write_float32(v.weights[2])?;
-                    stream.write_float32(v.weights[3])?;
-
-                    // UVs
-                    stream.write_float32(v.uv.u)?;
-                    stream.write_float32(v.uv.v)?;
-
-                    if version >= 34 {
-                        // Bone indices
-                        stream.write_uint16(v.bones[0])?;
-                        stream.write_uint16(v.bones[1])?;
-                        stream.write_uint16(v.bones[2])?;
-                        stream.write_uint16(v.bones[3])?;
-
-                        // Tangent?
-                        stream.write_float32(v.tangent.x)?;
-                        stream.write_float32(v.tangent.y)?;
-                        stream.write_float32(v.tangent.z)?;
-                        stream.write_float32(v.tangent.w)?;
-                    }
-                } else {
-                    todo!("Figure out how ng verts are packed in v36 meshes");
-                }
+            // Normals
+            stream.write_float32(v.normals.x)?;
+            stream.write_float32(v.normals.y)?;
+            stream.write_float32(v.normals.z)?;
+            if version == 34 {
+                stream.write_float32(v.normals.w)?;
+            }
+
+            // Weights
+            stream.write_float32(v.weights[0])?;
+            stream.write_float32(v.weights[1])?;
+            stream.write_float32(v.weights[2])?;
+            stream.write_float32(v.weights[3])?;
+
+            // UVs
+            stream.write_float32(v.uv.u)?;
+            stream.write_float32(v.uv.v)?;
+
+            if version >= 34 {
+                // Bone indices
+                stream.write_uint16(v.bones[0])?;
+                stream.write_uint16(v.bones[1])?;
+                stream.write_uint16(v.bones[2])?;
+                stream.write_uint16(v.bones[3])?;
+
+                // Tangent?
+                stream.write_float32(v.tangent.x)?;
+                stream.write_float32(v.tangent.y)?;
+                stream.write_float32(v.tangent.z)?;
+                stream.write_float32(v.tangent.w)?;
             }
         }
 
@@ -585,20 +731,53 @@ impl ObjectReadWrite for MeshObject {
                 // Write 0 bones
                 stream.write_uint32(0)?;
             } else {
-                // Write 4 bones
+                // Bones are shared by every face group
+                if self.bones.len() > MAX_BONES_PER_GROUP {
+                    return Err(Box::new(MeshWriteError::TooManyBones {
+                        version,
+                        max: MAX_BONES_PER_GROUP,
+                        count: self.bones.len(),
+                    }));
+                }
+
+                // Write 4 bone names
+                for i in 0..4 {
+                    match self.bones.get(i) {
+                        Some(b) => stream.write_prefixed_string(&b.name)?,
+                        _ => stream.write_uint32(0)?,
+                    }
+                }
+
+                // Write 4 bone transforms
                 for i in 0..4 {
-                    if let Some(b) = self.bones.get(i) {
-                        // Write bone
-                        stream.write_prefixed_string(&b.name)?;
-                        save_matrix(&b.trans, &mut stream)?;
-                    } else {
-                        // Write empty bone
-                        stream.write_uint32(0)?;
-                        save_matrix(&Matrix::identity(), &mut stream)?;
+                    match self.bones.get(i) {
+                        Some(b) => save_matrix(&b.trans, &mut stream)?,
+                        _ => save_matrix(&Matrix::identity(), &mut stream)?,
                     }
                 }
 
-                todo!("Figure out how to calculate additional group info");
+                // Use existing partitions if still valid for current faces
+                let computed_partitions;
+                let partitions = if self.has_valid_group_partitions() {
+                    &self.group_partitions
+                } else {
+                    computed_partitions = self.compute_group_partitions();
+                    &computed_partitions
+                };
+
+                // Write group partitions
+                for partition in partitions.iter() {
+                    stream.write_uint32(partition.sections.len() as u32)?;
+                    stream.write_uint32(partition.vert_indices.len() as u32)?;
+
+                    for section in partition.sections.iter() {
+                        stream.write_uint32(*section)?;
+                    }
+
+                    for idx in partition.vert_indices.iter() {
+                        stream.write_uint16(*idx)?;
+                    }
+                }
             }
         }
 
@@ -616,3 +795,248 @@ impl ObjectReadWrite for MeshObject {
     }
 }
 
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+
+    const PACKED_VERT_POS: [u8; 12] = [
+        0x3F, 0xC0, 0x00, 0x00, // 1.5
+        0xC0, 0x00, 0x00, 0x00, // -2.0
+        0x41, 0xF2, 0x00, 0x00, // 30.25
+    ];
+
+    const PACKED_VERT_UV_NORMALS_WEIGHTS: [u8; 16] = [
+        0x34, 0x00, 0x3A, 0x00, // 0.25, 0.75
+        0x00, 0x00, 0x3C, 0x00, 0xB8, 0x00, 0x3C, 0x00, // 0.0, 1.0, -0.5, 1.0
+        0x99, 0x66, 0x00, 0x00, // 0.6, 0.4, 0.0, 0.0
+    ];
+
+    fn create_packed_vert_bytes(layout: PackedVertLayout, version: u32) -> Vec<u8> {
+        let mut data = PACKED_VERT_POS.to_vec();
+
+        if layout == PackedVertLayout::Marked {
+            data.extend([0xFF; 4]);
+        }
+
+        data.extend(PACKED_VERT_UV_NORMALS_WEIGHTS);
+
+        match layout {
+            PackedVertLayout::Weighted => data.extend([0x00, 0x01, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00]),
+            PackedVertLayout::Marked => data.extend([0x01, 0x05, 0x00, 0x00]),
+        }
+
+        if version >= 38 {
+            data.extend([0x7F, 0x00, 0x00, 0x81]); // 1.0, 0.0, 0.0, -1.0
+        }
+
+        data
+    }
+
+    #[rstest]
+    #[case(36, PackedVertLayout::Weighted, 36)]
+    #[case(36, PackedVertLayout::Marked, 36)]
+    #[case(37, PackedVertLayout::Weighted, 36)]
+    #[case(38, PackedVertLayout::Weighted, 40)]
+    #[case(38, PackedVertLayout::Marked, 40)]
+    fn packed_vert_read_write(#[case] version: u32, #[case] layout: PackedVertLayout, #[case] expected_size: usize) {
+        let mut data = create_packed_vert_bytes(layout, version);
+        assert_eq!(expected_size, data.len());
+
+        let expected_bytes = data.clone();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(&mut stream, IOEndian::Big));
+
+        assert_eq!(layout, read_packed_vert_layout(&mut reader).unwrap());
+        let vert = read_packed_vert(&mut reader, version, layout).unwrap();
+
+        assert_eq!((1.5, -2.0, 30.25), (vert.pos.x, vert.pos.y, vert.pos.z));
+        assert_eq!((0.25, 0.75), (vert.uv.u, vert.uv.v));
+        assert_eq!((0.0, 1.0, -0.5, 1.0), (vert.normals.x, vert.normals.y, vert.normals.z, vert.normals.w));
+        assert_eq!([0.6, 0.4, 0.0, 0.0], vert.weights);
+        assert_eq!([1, 5, 0, 0], vert.bones);
+
+        if version >= 38 {
+            assert_eq!((1.0, 0.0, 0.0, -1.0), (vert.tangent.x, vert.tangent.y, vert.tangent.z, vert.tangent.w));
+        }
+
+        // Should write back same bytes
+        let mut out_data = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut out_data);
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(&mut out_stream, IOEndian::Big));
+        write_packed_vert(&vert, &mut writer, version, layout).unwrap();
+
+        assert_eq!(expected_bytes, out_data);
+    }
+
+    #[rstest]
+    fn packed_vert_marked_bone_out_of_range() {
+        let vert = Vert {
+            bones: [300, 0, 0, 0],
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(&mut stream, IOEndian::Big));
+
+        let result = write_packed_vert(&vert, &mut writer, 36, PackedVertLayout::Marked);
+        assert!(result.is_err_and(|e| matches!(e.downcast_ref::<MeshWriteError>(), Some(MeshWriteError::BoneIndexOutOfRange { index: 300 }))));
+    }
+
+    #[rstest]
+    #[case(36, PackedVertLayout::Marked, 36)]
+    #[case(37, PackedVertLayout::Weighted, 37)]
+    #[case(38, PackedVertLayout::Marked, 34)] // RB3 verts saved for RB1/RB2
+    fn mesh_packed_verts_round_trip(#[case] version: u32, #[case] layout: PackedVertLayout, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mesh = MeshObject {
+            name: String::from("packed.mesh"),
+            vertices: (0..3).map(|i| Vert { bones: [i, 0, 0, 0], ..Default::default() }).collect(),
+            faces: vec![[0, 1, 2]],
+            face_groups: vec![1],
+            packed_vert_format: Some(PackedVertFormat { version, layout }),
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        mesh.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = MeshObject::default();
+        result.load(&mut stream, &info).unwrap();
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        let expected_format = match expected_version {
+            36.. => Some(PackedVertFormat { version: expected_version, layout }),
+            _ => None,
+        };
+
+        assert_eq!(expected_version.to_be_bytes(), data[..4]);
+        assert_eq!(expected_format, result.packed_vert_format);
+        assert_eq!(vec![[0, 0, 0, 0], [1, 0, 0, 0], [2, 0, 0, 0]], result.vertices.iter().map(|v| v.bones).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    fn mesh_skinned_gh2_round_trip() {
+        let info = SystemInfo {
+            version: 24,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let mut mesh = MeshObject {
+            name: String::from("skinned.mesh"),
+            vertices: (0..4).map(|_| Vert::default()).collect(),
+            faces: vec![[0, 1, 2], [2, 1, 3]],
+            bones: ["bone_a", "bone_b"]
+                .iter()
+                .map(|n| BoneTrans { name: n.to_string(), ..Default::default() })
+                .collect(),
+            ..Default::default()
+        };
+        mesh.recompute_old_gen_face_groups();
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        mesh.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = MeshObject::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(4, result.vertices.len());
+        assert_eq!(mesh.faces, result.faces);
+        assert_eq!(mesh.face_groups, result.face_groups);
+        assert_eq!(vec!["bone_a", "bone_b"], result.bones.iter().map(|b| b.name.as_str()).collect::<Vec<_>>());
+
+        assert_eq!(1, result.group_partitions.len());
+        assert_eq!(vec![4], result.group_partitions[0].sections);
+        assert_eq!(vec![0, 1, 2, 3], result.group_partitions[0].vert_indices);
+    }
+
+    #[rstest]
+    #[case(24, Platform::PS2, IOEndian::Little, false)]
+    #[case(25, Platform::X360, IOEndian::Big, true)]
+    fn mesh_skinned_8_bones_round_trip(#[case] version: u32, #[case] platform: Platform, #[case] endian: IOEndian, #[case] supported: bool) {
+        let info = SystemInfo { version, platform, endian };
+
+        // Each face uses different bone
+        let mut mesh = MeshObject {
+            name: String::from("skinned.mesh"),
+            vertices: (0..8).map(|i| Vert { bones: [i, 0, 0, 0], weights: [1.0, 0.0, 0.0, 0.0], ..Default::default() }).collect(),
+            faces: (0..8).map(|i| [i, i, i]).collect(),
+            bones: (0..8)
+                .map(|i| BoneTrans { name: format!("bone_{i}"), ..Default::default() })
+                .collect(),
+            ..Default::default()
+        };
+        if supported {
+            mesh.recompute_face_groups();
+            assert_eq!(vec![8], mesh.face_groups);
+        } else {
+            mesh.recompute_old_gen_face_groups();
+            assert_eq!(vec![4, 4], mesh.face_groups);
+        }
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        let result = mesh.save(&mut stream, &info);
+
+        if !supported {
+            // Old gen meshes only store 4 bones total
+            assert!(result.is_err_and(|e| matches!(e.downcast_ref::<MeshWriteError>(), Some(MeshWriteError::TooManyBones { version: 28, max: 4, count: 8 }))));
+            return;
+        }
+
+        result.unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = MeshObject::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(mesh.face_groups, result.face_groups);
+        assert_eq!(mesh.bones.iter().map(|b| &b.name).collect::<Vec<_>>(), result.bones.iter().map(|b| &b.name).collect::<Vec<_>>());
+        assert_eq!(mesh.vertices.iter().map(|v| v.bones).collect::<Vec<_>>(), result.vertices.iter().map(|v| v.bones).collect::<Vec<_>>());
+    }
+
+    #[rstest]
+    fn mesh_bsp_round_trip() {
+        let bsp = BSPNode {
+            plane: Plane { a: 0.0, b: 0.0, c: 1.0, d: -5.0 },
+            front: Some(Box::new(BSPNode {
+                plane: Plane { a: 1.0, b: 0.0, c: 0.0, d: 2.0 },
+                ..Default::default()
+            })),
+            back: None,
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(&mut stream, IOEndian::Big));
+        save_bsp_node(&Some(Box::new(bsp)), &mut writer).unwrap();
+
+        // 5 flags + 2 planes
+        assert_eq!(5 + (2 * 16), writer.pos());
+
+        writer.seek(SeekFrom::Start(0)).unwrap();
+        let result = load_bsp_node(&mut writer).unwrap().unwrap();
+
+        assert_eq!((0.0, 0.0, 1.0, -5.0), (result.plane.a, result.plane.b, result.plane.c, result.plane.d));
+        assert!(result.back.is_none());
+
+        let front = result.front.unwrap();
+        assert_eq!((1.0, 0.0, 0.0, 2.0), (front.plane.a, front.plane.b, front.plane.c, front.plane.d));
+        assert!(front.front.is_none());
+        assert!(front.back.is_none());
+    }
+}
@@ -1,14 +1,40 @@
 mod io;
+mod ops;
 
 use grim_macros::*;
 use grim_traits::scene::*;
 pub use io::*;
+pub use ops::*;
+
+const MAX_FACES_PER_GROUP: usize = 255;
+const MAX_BONES_PER_GROUP: usize = 4;
+
+#[derive(Debug, Default)]
+pub struct GroupPartition {
+    pub sections: Vec<u32>,
+    pub vert_indices: Vec<u16>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum PackedVertLayout {
+    #[default]
+    Weighted, // Byte weights + short bone indices
+    Marked,   // -1 marker + byte bone indices
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PackedVertFormat {
+    pub version: u32, // Mesh version verts were read from
+    pub layout: PackedVertLayout,
+}
 
 #[milo(RndMesh)]
 #[milo_super(Draw, Trans)]
 pub struct MeshObject {
-    // Using until packed next gen verts are figured out
-    pub raw_vertices: Vec<[u8; 36]>
+    // Only used by old gen skinned meshes
+    pub group_partitions: Vec<GroupPartition>,
+    // Only used by next gen meshes w/ packed verts (v36+)
+    pub packed_vert_format: Option<PackedVertFormat>,
 }
 
 impl Default for MeshObject {
@@ -45,11 +71,13 @@ impl Default for MeshObject {
             mutable: Mutable::kMutableNone,
             volume: Volume::kVolumeTriangles,
 
+            bsp: None,
             vertices: Vec::new(),
-            raw_vertices: Vec::new(),
             faces: Vec::new(),
 
             face_groups: Vec::new(),
+            group_partitions: Vec::new(),
+            packed_vert_format: None,
             bones: Vec::new(),
             keep_mesh_data: false,
             exclude_from_self_shadow: false,
@@ -60,18 +88,219 @@ impl Default for MeshObject {
 
 impl MeshObject {
     pub fn recompute_face_groups(&mut self) {
-        self.face_groups.clear();
+        self.face_groups = self.compute_face_groups(None);
+        self.recompute_group_partitions();
+    }
+
+    // Old gen skinned meshes (< v34) also limit each group to 4 bones
+    pub fn recompute_old_gen_face_groups(&mut self) {
+        let max_bones = (!self.bones.is_empty()).then_some(MAX_BONES_PER_GROUP);
+
+        self.face_groups = self.compute_face_groups(max_bones);
+        self.recompute_group_partitions();
+    }
+
+    fn compute_face_groups(&self, max_bones: Option<usize>) -> Vec<u8> {
+        let mut face_groups = Vec::new();
+        let mut group_bones = Vec::new();
+        let mut group_size = 0;
+
+        for face in self.faces.iter() {
+            let mut face_bones = group_bones.clone();
+
+            if max_bones.is_some() {
+                for bone in self.get_face_bones(face) {
+                    if !face_bones.contains(&bone) {
+                        face_bones.push(bone);
+                    }
+                }
+            }
+
+            // Start new group if face or bone limit is reached
+            let bone_limit_reached = max_bones.is_some_and(|max| group_size > 0 && face_bones.len() > max);
+
+            if group_size >= MAX_FACES_PER_GROUP || bone_limit_reached {
+                face_groups.push(group_size as u8);
+
+                group_size = 0;
+                face_bones = match max_bones {
+                    Some(_) => self.get_face_bones(face),
+                    None => Vec::new(),
+                };
+            }
+
+            group_bones = face_bones;
+            group_size += 1;
+        }
+
+        if group_size > 0 {
+            face_groups.push(group_size as u8);
+        }
+
+        face_groups
+    }
+
+    pub fn recompute_group_partitions(&mut self) {
+        self.group_partitions = self.compute_group_partitions();
+    }
+
+    // Checks if partitions still cover the same verts as current faces
+    pub(crate) fn has_valid_group_partitions(&self) -> bool {
+        if self.group_partitions.len() != self.face_groups.len() {
+            return false;
+        }
+
+        let mut faces = self.faces.iter();
+
+        self.face_groups
+            .iter()
+            .zip(self.group_partitions.iter())
+            .all(|(group_size, partition)| {
+                let mut face_indices = faces
+                    .by_ref()
+                    .take(*group_size as usize)
+                    .flatten()
+                    .copied()
+                    .collect::<Vec<_>>();
+                face_indices.sort_unstable();
+                face_indices.dedup();
 
-        let mut face_count = self.faces.len() as u32;
+                let mut partition_indices = partition.vert_indices.clone();
+                partition_indices.sort_unstable();
 
-        while face_count > 0 {
-            if face_count < 255 {
-                self.face_groups.push(face_count as u8);
-                break;
+                let section_size = partition.sections.iter().sum::<u32>() as usize;
+                face_indices == partition_indices && section_size == partition.vert_indices.len()
+            })
+    }
+
+    pub(crate) fn compute_group_partitions(&self) -> Vec<GroupPartition> {
+        let mut faces = self.faces.iter();
+
+        self.face_groups
+            .iter()
+            .map(|group_size| {
+                let mut vert_indices = Vec::new();
+
+                for face in faces.by_ref().take(*group_size as usize) {
+                    for idx in face.iter() {
+                        if !vert_indices.contains(idx) {
+                            vert_indices.push(*idx);
+                        }
+                    }
+                }
+
+                GroupPartition {
+                    // TODO: Verify if vert indices can be split into multiple sections
+                    sections: vec![vert_indices.len() as u32],
+                    vert_indices,
+                }
+            })
+            .collect()
+    }
+
+    fn get_face_bones(&self, face: &[u16; 3]) -> Vec<u16> {
+        let mut bones = Vec::new();
+
+        for vert in face.iter().filter_map(|i| self.vertices.get(*i as usize)) {
+            for (bone, weight) in vert.bones.iter().zip(vert.weights.iter()) {
+                if *weight > 0.0 && !bones.contains(bone) {
+                    bones.push(*bone);
+                }
             }
+        }
+
+        bones
+    }
+}
 
-            self.face_groups.push(255);
-            face_count -= 255;
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    fn create_vert(bone: u16) -> Vert {
+        Vert {
+            bones: [bone, 0, 0, 0],
+            weights: [1.0, 0.0, 0.0, 0.0],
+            ..Default::default()
         }
     }
-}
\ No newline at end of file
+
+    #[rstest]
+    #[case(0, vec![])]
+    #[case(1, vec![1])]
+    #[case(255, vec![255])]
+    #[case(256, vec![255, 1])]
+    #[case(600, vec![255, 255, 90])]
+    fn mesh_recompute_face_groups(#[case] face_count: usize, #[case] expected: Vec<u8>) {
+        let mut mesh = MeshObject {
+            vertices: (0..3).map(|_| Vert::default()).collect(),
+            faces: vec![[0, 1, 2]; face_count],
+            ..Default::default()
+        };
+
+        mesh.recompute_face_groups();
+
+        assert_eq!(expected, mesh.face_groups);
+        assert_eq!(expected.len(), mesh.group_partitions.len());
+    }
+
+    #[rstest]
+    #[case(vec![0, 1, 2, 3, 4, 5, 6, 7], vec![4, 4])]
+    #[case(vec![0, 0, 0, 1, 1, 1, 2, 2], vec![8])]
+    #[case(vec![0, 1, 2, 3, 0, 1, 2, 4], vec![7, 1])]
+    #[case(vec![0, 1, 2, 3, 3, 2, 1, 0], vec![8])]
+    fn mesh_recompute_face_groups_skinned(#[case] face_bones: Vec<u16>, #[case] expected: Vec<u8>) {
+        let mut mesh = MeshObject {
+            vertices: face_bones.iter().map(|b| create_vert(*b)).collect(),
+            faces: (0..face_bones.len() as u16).map(|i| [i, i, i]).collect(),
+            bones: (0..8).map(|_| BoneTrans::default()).collect(),
+            ..Default::default()
+        };
+
+        mesh.recompute_old_gen_face_groups();
+        assert_eq!(expected, mesh.face_groups);
+
+        // Bone limit only applies to old gen
+        mesh.recompute_face_groups();
+        assert_eq!(vec![face_bones.len() as u8], mesh.face_groups);
+    }
+
+    #[rstest]
+    fn mesh_recompute_group_partitions() {
+        let mut mesh = MeshObject {
+            vertices: (0..5).map(|_| Vert::default()).collect(),
+            faces: vec![[0, 1, 2], [2, 1, 3], [3, 4, 0]],
+            face_groups: vec![2, 1],
+            ..Default::default()
+        };
+
+        mesh.recompute_group_partitions();
+
+        assert_eq!(2, mesh.group_partitions.len());
+        assert_eq!(vec![4], mesh.group_partitions[0].sections);
+        assert_eq!(vec![0, 1, 2, 3], mesh.group_partitions[0].vert_indices);
+        assert_eq!(vec![3], mesh.group_partitions[1].sections);
+        assert_eq!(vec![3, 4, 0], mesh.group_partitions[1].vert_indices);
+    }
+
+    #[rstest]
+    fn mesh_stale_group_partitions() {
+        let mut mesh = MeshObject {
+            vertices: (0..5).map(|_| Vert::default()).collect(),
+            faces: vec![[0, 1, 2], [2, 1, 3], [3, 4, 0]],
+            face_groups: vec![2, 1],
+            ..Default::default()
+        };
+
+        mesh.recompute_group_partitions();
+        assert!(mesh.has_valid_group_partitions());
+
+        // Order of verts in partition doesn't matter
+        mesh.group_partitions[1].vert_indices.reverse();
+        assert!(mesh.has_valid_group_partitions());
+
+        mesh.faces[2] = [3, 4, 1];
+        assert!(!mesh.has_valid_group_partitions());
+    }
+}
@@ -0,0 +1,650 @@
+use super::MeshObject;
+use grim_traits::scene::*;
+use std::collections::HashMap;
+use thiserror::Error as ThisError;
+
+const VERTEX_CACHE_SIZE: usize = 32;
+
+type Vec3 = [f32; 3];
+
+#[derive(Debug, ThisError)]
+pub enum MeshOpsError {
+    #[error("Mesh would need {count} verts but only {max} can be indexed")]
+    TooManyVerts {
+        count: usize,
+        max: usize
+    },
+}
+
+fn sub(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn add(a: Vec3, b: Vec3) -> Vec3 {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+fn scale(a: Vec3, s: f32) -> Vec3 {
+    [a[0] * s, a[1] * s, a[2] * s]
+}
+
+fn dot(a: Vec3, b: Vec3) -> f32 {
+    (a[0] * b[0]) + (a[1] * b[1]) + (a[2] * b[2])
+}
+
+fn cross(a: Vec3, b: Vec3) -> Vec3 {
+    [
+        (a[1] * b[2]) - (a[2] * b[1]),
+        (a[2] * b[0]) - (a[0] * b[2]),
+        (a[0] * b[1]) - (a[1] * b[0]),
+    ]
+}
+
+fn length(a: Vec3) -> f32 {
+    dot(a, a).sqrt()
+}
+
+fn normalize(a: Vec3) -> Option<Vec3> {
+    let len = length(a);
+
+    match len > f32::EPSILON {
+        true => Some(scale(a, 1.0 / len)),
+        false => None,
+    }
+}
+
+fn get_pos(vert: &Vert) -> Vec3 {
+    [vert.pos.x, vert.pos.y, vert.pos.z]
+}
+
+fn get_normal(vert: &Vert) -> Vec3 {
+    [vert.normals.x, vert.normals.y, vert.normals.z]
+}
+
+fn set_normal(vert: &mut Vert, normal: Vec3) {
+    vert.normals.x = normal[0];
+    vert.normals.y = normal[1];
+    vert.normals.z = normal[2];
+}
+
+fn get_any_perpendicular(n: Vec3) -> Vec3 {
+    // Use axis least aligned with normal
+    let axis = if n[0].abs() < 0.9 { [1.0, 0.0, 0.0] } else { [0.0, 1.0, 0.0] };
+
+    normalize(cross(axis, n))
+        .and_then(|b| normalize(cross(n, b)))
+        .unwrap_or([1.0, 0.0, 0.0])
+}
+
+fn get_vertex_score(cache_pos: Option<usize>, remaining_faces: usize) -> f32 {
+    // Based on Tom Forsyth's linear-speed vertex cache optimisation
+    if remaining_faces == 0 {
+        return -1.0;
+    }
+
+    let cache_score = match cache_pos {
+        Some(pos) if pos < 3 => 0.75,
+        Some(pos) => (1.0 - ((pos - 3) as f32 / (VERTEX_CACHE_SIZE - 3) as f32)).powf(1.5),
+        None => 0.0,
+    };
+
+    cache_score + (2.0 / (remaining_faces as f32).sqrt())
+}
+
+impl MeshObject {
+    fn get_face_positions(&self, face: &[u16; 3]) -> Option<[Vec3; 3]> {
+        let p0 = self.vertices.get(face[0] as usize)?;
+        let p1 = self.vertices.get(face[1] as usize)?;
+        let p2 = self.vertices.get(face[2] as usize)?;
+
+        Some([get_pos(p0), get_pos(p1), get_pos(p2)])
+    }
+
+    pub fn recompute_normals(&mut self) {
+        // Accumulate area weighted face normals by position so uv seams are still smoothed
+        let mut pos_normals: HashMap<[u32; 3], Vec3> = HashMap::new();
+        let get_pos_key = |v: &Vert| [v.pos.x.to_bits(), v.pos.y.to_bits(), v.pos.z.to_bits()];
+
+        for face in self.faces.iter() {
+            let Some([p0, p1, p2]) = self.get_face_positions(face) else {
+                continue;
+            };
+
+            let face_normal = cross(sub(p1, p0), sub(p2, p0));
+
+            for idx in face.iter() {
+                let key = get_pos_key(&self.vertices[*idx as usize]);
+                let normal = pos_normals.entry(key).or_default();
+
+                *normal = add(*normal, face_normal);
+            }
+        }
+
+        for vert in self.vertices.iter_mut() {
+            let normal = pos_normals
+                .get(&get_pos_key(vert))
+                .and_then(|n| normalize(*n))
+                .unwrap_or([0.0, 0.0, 1.0]);
+
+            set_normal(vert, normal);
+        }
+    }
+
+    pub fn recompute_flat_normals(&mut self) -> Result<(), MeshOpsError> {
+        // Every face needs unique verts
+        let max_verts = u16::MAX as usize + 1;
+        if (self.faces.len() * 3) > max_verts {
+            return Err(MeshOpsError::TooManyVerts {
+                count: self.faces.len() * 3,
+                max: max_verts
+            });
+        }
+
+        let mut vertices = Vec::new();
+        let mut faces = Vec::new();
+
+        for face in self.faces.iter() {
+            let Some([p0, p1, p2]) = self.get_face_positions(face) else {
+                continue;
+            };
+
+            let normal = normalize(cross(sub(p1, p0), sub(p2, p0))).unwrap_or([0.0, 0.0, 1.0]);
+            let start_idx = vertices.len() as u16;
+
+            for idx in face.iter() {
+                let v = &self.vertices[*idx as usize];
+
+                let mut vert = Vert {
+                    pos: Vector4 { x: v.pos.x, y: v.pos.y, z: v.pos.z, w: v.pos.w },
+                    normals: Vector4 { w: v.normals.w, ..Default::default() },
+                    uv: UV { u: v.uv.u, v: v.uv.v },
+                    bones: v.bones,
+                    weights: v.weights,
+                    tangent: Vector4 { x: v.tangent.x, y: v.tangent.y, z: v.tangent.z, w: v.tangent.w },
+                };
+
+                set_normal(&mut vert, normal);
+                vertices.push(vert);
+            }
+
+            faces.push([start_idx, start_idx + 1, start_idx + 2]);
+        }
+
+        self.vertices = vertices;
+        self.faces = faces;
+        self.recompute_face_groups();
+
+        Ok(())
+    }
+
+    pub fn recompute_tangents(&mut self) {
+        // Similar to MikkTSpace but without angle weighting or vertex splitting
+        let mut tangents = vec![[0.0f32; 3]; self.vertices.len()];
+        let mut bitangents = vec![[0.0f32; 3]; self.vertices.len()];
+
+        for face in self.faces.iter() {
+            let Some([p0, p1, p2]) = self.get_face_positions(face) else {
+                continue;
+            };
+
+            let [uv0, uv1, uv2] = face.map(|i| {
+                let uv = &self.vertices[i as usize].uv;
+                [uv.u, uv.v]
+            });
+
+            let (e1, e2) = (sub(p1, p0), sub(p2, p0));
+            let (du1, dv1) = (uv1[0] - uv0[0], uv1[1] - uv0[1]);
+            let (du2, dv2) = (uv2[0] - uv0[0], uv2[1] - uv0[1]);
+
+            let det = (du1 * dv2) - (du2 * dv1);
+            if det.abs() <= f32::EPSILON {
+                continue;
+            }
+
+            let r = 1.0 / det;
+            let t = scale(sub(scale(e1, dv2), scale(e2, dv1)), r);
+            let b = scale(sub(scale(e2, du1), scale(e1, du2)), r);
+
+            for idx in face.iter().map(|i| *i as usize) {
+                tangents[idx] = add(tangents[idx], t);
+                bitangents[idx] = add(bitangents[idx], b);
+            }
+        }
+
+        for (vert, (t, b)) in self.vertices.iter_mut().zip(tangents.into_iter().zip(bitangents)) {
+            let n = normalize(get_normal(vert)).unwrap_or([0.0, 0.0, 1.0]);
+
+            // Gram-Schmidt orthogonalize
+            let tangent = normalize(sub(t, scale(n, dot(n, t))))
+                .unwrap_or_else(|| get_any_perpendicular(n));
+
+            let handedness = match dot(cross(n, tangent), b) < 0.0 {
+                true => -1.0,
+                false => 1.0,
+            };
+
+            vert.tangent = Vector4 {
+                x: tangent[0],
+                y: tangent[1],
+                z: tangent[2],
+                w: handedness,
+            };
+        }
+    }
+
+    pub fn weld_vertices(&mut self, epsilon: f32) {
+        let epsilon = epsilon.max(f32::EPSILON);
+        let quantize = |value: f32| (value / epsilon).round() as i64;
+
+        let mut vert_map = HashMap::new();
+        let mut remapped_indices = Vec::with_capacity(self.vertices.len());
+        let mut vertices = Vec::new();
+
+        for vert in self.vertices.drain(..) {
+            let key = (
+                [vert.pos.x, vert.pos.y, vert.pos.z, vert.normals.x, vert.normals.y, vert.normals.z, vert.uv.u, vert.uv.v].map(quantize),
+                vert.weights.map(quantize),
+                vert.bones,
+            );
+
+            let idx = *vert_map
+                .entry(key)
+                .or_insert_with(|| {
+                    vertices.push(vert);
+                    vertices.len() - 1
+                });
+
+            remapped_indices.push(idx as u16);
+        }
+
+        self.vertices = vertices;
+
+        // Remap faces and remove any that became degenerate
+        self.faces = self.faces
+            .iter()
+            .filter_map(|face| {
+                let [a, b, c] = face.map(|i| remapped_indices.get(i as usize).copied());
+                let face = [a?, b?, c?];
+
+                match face[0] != face[1] && face[1] != face[2] && face[0] != face[2] {
+                    true => Some(face),
+                    false => None,
+                }
+            })
+            .collect();
+
+        self.recompute_face_groups();
+    }
+
+    pub fn optimize_faces(&mut self) {
+        let vert_count = self.vertices.len();
+        let face_count = self.faces.len();
+
+        if self.faces.iter().flatten().any(|i| *i as usize >= vert_count) {
+            return;
+        }
+
+        let mut vert_faces = vec![Vec::new(); vert_count];
+        for (i, face) in self.faces.iter().enumerate() {
+            for idx in face.iter() {
+                vert_faces[*idx as usize].push(i);
+            }
+        }
+
+        let mut remaining_faces = vert_faces.iter().map(|f| f.len()).collect::<Vec<_>>();
+        let mut vert_scores = remaining_faces.iter().map(|r| get_vertex_score(None, *r)).collect::<Vec<_>>();
+
+        let get_face_score = |face: &[u16; 3], vert_scores: &Vec<f32>| face
+            .iter()
+            .map(|i| vert_scores[*i as usize])
+            .sum::<f32>();
+
+        let mut face_added = vec![false; face_count];
+        let mut cache: Vec<u16> = Vec::new();
+        let mut faces = Vec::with_capacity(face_count);
+        let mut next_unadded = 0;
+
+        while faces.len() < face_count {
+            // Find best face adjacent to cached verts
+            let best_face = cache
+                .iter()
+                .flat_map(|v| vert_faces[*v as usize].iter())
+                .filter(|f| !face_added[**f])
+                .map(|f| (*f, get_face_score(&self.faces[*f], &vert_scores)))
+                .fold(None, |acc: Option<(usize, f32)>, (f, score)| match acc {
+                    Some((_, best_score)) if best_score >= score => acc,
+                    _ => Some((f, score)),
+                })
+                .map(|(f, _)| f);
+
+            let face_idx = best_face.unwrap_or_else(|| {
+                // Fallback on next face in original order
+                while face_added[next_unadded] {
+                    next_unadded += 1;
+                }
+
+                next_unadded
+            });
+
+            face_added[face_idx] = true;
+            let face = self.faces[face_idx];
+            faces.push(face);
+
+            // Update cache with most recent verts at front
+            for idx in face.iter().rev() {
+                remaining_faces[*idx as usize] -= 1;
+
+                cache.retain(|c| c != idx);
+                cache.insert(0, *idx);
+            }
+
+            let evicted = match cache.len() > VERTEX_CACHE_SIZE {
+                true => cache.split_off(VERTEX_CACHE_SIZE),
+                false => Vec::new(),
+            };
+
+            for (pos, idx) in cache.iter().enumerate() {
+                vert_scores[*idx as usize] = get_vertex_score(Some(pos), remaining_faces[*idx as usize]);
+            }
+
+            for idx in evicted.iter() {
+                vert_scores[*idx as usize] = get_vertex_score(None, remaining_faces[*idx as usize]);
+            }
+        }
+
+        // Re-order verts by first use
+        let mut remapped_indices = vec![None; vert_count];
+        let mut vert_order = Vec::with_capacity(vert_count);
+
+        for idx in faces.iter().flatten() {
+            let remapped = &mut remapped_indices[*idx as usize];
+
+            if remapped.is_none() {
+                *remapped = Some(vert_order.len() as u16);
+                vert_order.push(*idx as usize);
+            }
+        }
+
+        // Keep unused verts at end
+        for (i, remapped) in remapped_indices.iter_mut().enumerate() {
+            if remapped.is_none() {
+                *remapped = Some(vert_order.len() as u16);
+                vert_order.push(i);
+            }
+        }
+
+        let mut old_vertices = self.vertices.drain(..).map(Some).collect::<Vec<_>>();
+        self.vertices = vert_order
+            .iter()
+            .map(|i| old_vertices[*i].take().unwrap())
+            .collect();
+
+        self.faces = faces
+            .iter()
+            .map(|face| face.map(|i| remapped_indices[i as usize].unwrap()))
+            .collect();
+
+        self.recompute_face_groups();
+    }
+
+    pub fn recompute_sphere(&mut self) {
+        // Ritter's bounding sphere
+        let Some(first) = self.vertices.first().map(get_pos) else {
+            self.sphere = Sphere::default();
+            return;
+        };
+
+        let get_furthest = |from: Vec3| self.vertices
+            .iter()
+            .map(get_pos)
+            .fold((from, 0.0f32), |(best, best_dist), p| {
+                let dist = length(sub(p, from));
+
+                match dist > best_dist {
+                    true => (p, dist),
+                    false => (best, best_dist),
+                }
+            })
+            .0;
+
+        let a = get_furthest(first);
+        let b = get_furthest(a);
+
+        let mut center = scale(add(a, b), 0.5);
+        let mut radius = length(sub(b, a)) * 0.5;
+
+        // Grow sphere to include any outside points
+        for p in self.vertices.iter().map(get_pos) {
+            let dist = length(sub(p, center));
+
+            if dist > radius {
+                let new_radius = (radius + dist) * 0.5;
+                center = add(center, scale(sub(p, center), (new_radius - radius) / dist));
+                radius = new_radius;
+            }
+        }
+
+        self.sphere = Sphere {
+            x: center[0],
+            y: center[1],
+            z: center[2],
+            r: radius,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    fn create_vert(pos: Vec3, uv: [f32; 2]) -> Vert {
+        Vert {
+            pos: Vector4 { x: pos[0], y: pos[1], z: pos[2], w: 0.0 },
+            uv: UV { u: uv[0], v: uv[1] },
+            ..Default::default()
+        }
+    }
+
+    fn create_quad_mesh() -> MeshObject {
+        // Quad on xy plane facing +z w/ duplicated corner verts
+        MeshObject {
+            vertices: vec![
+                create_vert([0.0, 0.0, 0.0], [0.0, 0.0]),
+                create_vert([1.0, 0.0, 0.0], [1.0, 0.0]),
+                create_vert([1.0, 1.0, 0.0], [1.0, 1.0]),
+                create_vert([1.0, 1.0, 0.0], [1.0, 1.0]),
+                create_vert([0.0, 1.0, 0.0], [0.0, 1.0]),
+                create_vert([0.0, 0.0, 0.0], [0.0, 0.0]),
+            ],
+            faces: vec![[0, 1, 2], [3, 4, 5]],
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    fn mesh_recompute_normals() {
+        let mut mesh = create_quad_mesh();
+        mesh.recompute_normals();
+
+        for vert in mesh.vertices.iter() {
+            assert_eq!([0.0, 0.0, 1.0], get_normal(vert));
+        }
+    }
+
+    #[rstest]
+    fn mesh_recompute_flat_normals() {
+        let mut mesh = MeshObject {
+            vertices: vec![
+                create_vert([0.0, 0.0, 0.0], [0.0, 0.0]),
+                create_vert([1.0, 0.0, 0.0], [0.0, 0.0]),
+                create_vert([0.0, 1.0, 0.0], [0.0, 0.0]),
+                create_vert([0.0, 0.0, 1.0], [0.0, 0.0]),
+            ],
+            faces: vec![[0, 1, 2], [0, 3, 1]],
+            ..Default::default()
+        };
+
+        mesh.recompute_flat_normals().unwrap();
+
+        assert_eq!(6, mesh.vertices.len());
+        assert_eq!(vec![[0, 1, 2], [3, 4, 5]], mesh.faces);
+        assert_eq!(vec![2], mesh.face_groups);
+
+        for vert in mesh.vertices[..3].iter() {
+            assert_eq!([0.0, 0.0, 1.0], get_normal(vert));
+        }
+
+        for vert in mesh.vertices[3..].iter() {
+            assert_eq!([0.0, 1.0, 0.0], get_normal(vert));
+        }
+    }
+
+    #[rstest]
+    fn mesh_recompute_flat_normals_too_many_verts() {
+        let mut mesh = MeshObject {
+            vertices: vec![
+                create_vert([0.0, 0.0, 0.0], [0.0, 0.0]),
+                create_vert([1.0, 0.0, 0.0], [0.0, 0.0]),
+                create_vert([0.0, 1.0, 0.0], [0.0, 0.0]),
+            ],
+            faces: vec![[0, 1, 2]; 21846],
+            ..Default::default()
+        };
+
+        let result = mesh.recompute_flat_normals();
+
+        assert!(matches!(result, Err(MeshOpsError::TooManyVerts { count: 65538, max: 65536 })));
+        assert_eq!(3, mesh.vertices.len());
+    }
+
+    #[rstest]
+    #[case([0.0, 0.0], [1.0, 0.0], [1.0, 1.0], [1.0, 0.0, 0.0, 1.0])]
+    #[case([1.0, 0.0], [0.0, 0.0], [0.0, 1.0], [-1.0, 0.0, 0.0, -1.0])]
+    fn mesh_recompute_tangents(#[case] uv0: [f32; 2], #[case] uv1: [f32; 2], #[case] uv2: [f32; 2], #[case] expected: [f32; 4]) {
+        let mut mesh = MeshObject {
+            vertices: vec![
+                create_vert([0.0, 0.0, 0.0], uv0),
+                create_vert([1.0, 0.0, 0.0], uv1),
+                create_vert([1.0, 1.0, 0.0], uv2),
+            ],
+            faces: vec![[0, 1, 2]],
+            ..Default::default()
+        };
+
+        mesh.recompute_normals();
+        mesh.recompute_tangents();
+
+        for vert in mesh.vertices.iter() {
+            let Vector4 { x, y, z, w } = vert.tangent;
+            assert_eq!(expected, [x, y, z, w]);
+        }
+    }
+
+    #[rstest]
+    fn mesh_weld_vertices() {
+        let mut mesh = create_quad_mesh();
+        mesh.weld_vertices(0.0001);
+
+        assert_eq!(4, mesh.vertices.len());
+        assert_eq!(vec![[0, 1, 2], [2, 3, 0]], mesh.faces);
+        assert_eq!(vec![2], mesh.face_groups);
+    }
+
+    #[rstest]
+    fn mesh_weld_vertices_removes_degenerate_faces() {
+        let mut mesh = MeshObject {
+            vertices: vec![
+                create_vert([0.0, 0.0, 0.0], [0.0, 0.0]),
+                create_vert([0.0, 0.0, 0.0], [0.0, 0.0]),
+                create_vert([1.0, 0.0, 0.0], [0.0, 0.0]),
+            ],
+            faces: vec![[0, 1, 2]],
+            ..Default::default()
+        };
+
+        mesh.weld_vertices(0.0001);
+
+        assert_eq!(2, mesh.vertices.len());
+        assert!(mesh.faces.is_empty());
+        assert!(mesh.face_groups.is_empty());
+    }
+
+    fn get_sorted_tris(mesh: &MeshObject) -> Vec<[[i32; 3]; 3]> {
+        let mut tris = mesh.faces
+            .iter()
+            .map(|f| {
+                let mut tri = f.map(|i| get_pos(&mesh.vertices[i as usize]).map(|p| p as i32));
+                tri.sort();
+                tri
+            })
+            .collect::<Vec<_>>();
+
+        tris.sort();
+        tris
+    }
+
+    #[rstest]
+    fn mesh_optimize_faces() {
+        // Strip of quads w/ faces in scrambled order
+        let vertices = (0..10)
+            .map(|i| create_vert([(i / 2) as f32, (i % 2) as f32, 0.0], [0.0, 0.0]))
+            .collect::<Vec<_>>();
+
+        let faces = [3, 0, 6, 2, 7, 1, 5, 4]
+            .iter()
+            .map(|q| {
+                let i = (q / 2) * 2;
+
+                match q % 2 {
+                    0 => [i, i + 2, i + 1],
+                    _ => [i + 1, i + 2, i + 3],
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut mesh = MeshObject {
+            vertices,
+            faces,
+            ..Default::default()
+        };
+
+        let expected_tris = get_sorted_tris(&mesh);
+        mesh.optimize_faces();
+
+        assert_eq!(10, mesh.vertices.len());
+        assert_eq!(vec![8], mesh.face_groups);
+        assert_eq!(expected_tris, get_sorted_tris(&mesh));
+
+        // Verts should be ordered by first use
+        let mut max_idx = 0;
+        for idx in mesh.faces.iter().flatten() {
+            assert!(*idx <= max_idx + 1);
+            max_idx = max_idx.max(*idx);
+        }
+    }
+
+    #[rstest]
+    fn mesh_recompute_sphere() {
+        let mut mesh = create_quad_mesh();
+        mesh.recompute_sphere();
+
+        let Sphere { x, y, z, r } = mesh.sphere;
+
+        assert_eq!([0.5, 0.5, 0.0], [x, y, z]);
+        assert!((r - std::f32::consts::SQRT_2 / 2.0).abs() < 0.0001);
+    }
+
+    #[rstest]
+    fn mesh_recompute_sphere_empty() {
+        let mut mesh = MeshObject {
+            sphere: Sphere { x: 1.0, y: 2.0, z: 3.0, r: 4.0 },
+            ..Default::default()
+        };
+
+        mesh.recompute_sphere();
+
+        let Sphere { x, y, z, r } = mesh.sphere;
+        assert_eq!([0.0, 0.0, 0.0, 0.0], [x, y, z, r]);
+    }
+}
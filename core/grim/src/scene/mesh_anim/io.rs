@@ -55,14 +55,25 @@ impl ObjectReadWrite for MeshAnim {
         Ok(())
     }
 
-    fn save(&self, _stream: &mut dyn Stream, _info: &SystemInfo) -> Result<(), Box<dyn Error>> {
-        todo!("Implement save() for MeshAnim")
-
-        /*let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
 
         // TODO: Get version from system info
         let version = 1;
-        Ok(())*/
+        writer.write_uint32(version)?;
+
+        save_object(self, &mut writer, info)?;
+        save_anim(self, &mut writer, info, false)?;
+
+        writer.write_prefixed_string(&self.mesh)?;
+
+        save_keys(&self.vert_point_keys, &mut writer, save_vector3)?;
+        save_keys(&self.vert_text_keys, &mut writer, save_vector2)?;
+        save_keys(&self.vert_color_keys, &mut writer, save_color4)?;
+
+        writer.write_prefixed_string(&self.keys_owner)?;
+
+        Ok(())
     }
 }
 
@@ -90,4 +101,123 @@ fn load_keys<T: std::fmt::Debug + Default>(reader: &mut Box<BinaryStream>, loade
     }
 
     Ok(keys)
-}
\ No newline at end of file
+}
+
+fn save_keys<T: std::fmt::Debug>(keys: &[AnimEvent<Vec<T>>], writer: &mut Box<BinaryStream>, saver: impl Fn(&T, &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>>) -> Result<(), Box<dyn Error>> {
+    writer.write_uint32(keys.len() as u32)?;
+
+    for key in keys.iter() {
+        writer.write_uint32(key.value.len() as u32)?;
+
+        for value in key.value.iter() {
+            saver(value, writer)?;
+        }
+
+        writer.write_float32(key.pos)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    fn mesh_anim_round_trip() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mesh_anim = MeshAnim {
+            name: String::from("face.ma"),
+            frame: 10.0,
+            mesh: String::from("face.mesh"),
+            vert_point_keys: vec![
+                AnimEvent { value: vec![Vector3 { x: 1.0, y: 2.0, z: 3.0 }], pos: 0.0 },
+                AnimEvent { value: vec![Vector3 { x: 4.0, y: 5.0, z: 6.0 }], pos: 10.0 },
+            ],
+            vert_text_keys: vec![AnimEvent { value: vec![Vector2 { x: 0.5, y: 0.25 }], pos: 5.0 }],
+            vert_color_keys: vec![AnimEvent { value: vec![Color4 { r: 1.0, g: 0.5, b: 0.25, a: 1.0 }], pos: 2.0 }],
+            keys_owner: String::from("face.ma"),
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        mesh_anim.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = MeshAnim::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(mesh_anim.frame, result.frame);
+        assert_eq!(mesh_anim.mesh, result.mesh);
+        assert_eq!(mesh_anim.keys_owner, result.keys_owner);
+
+        let points = result.vert_point_keys.iter().map(|k| (k.pos, k.value[0].x, k.value[0].y, k.value[0].z)).collect::<Vec<_>>();
+        assert_eq!(vec![(0.0, 1.0, 2.0, 3.0), (10.0, 4.0, 5.0, 6.0)], points);
+
+        let texts = result.vert_text_keys.iter().map(|k| (k.pos, k.value[0].x, k.value[0].y)).collect::<Vec<_>>();
+        assert_eq!(vec![(5.0, 0.5, 0.25)], texts);
+
+        let colors = result.vert_color_keys.iter().map(|k| (k.pos, k.value[0].r, k.value[0].g, k.value[0].b, k.value[0].a)).collect::<Vec<_>>();
+        assert_eq!(vec![(2.0, 1.0, 0.5, 0.25, 1.0)], colors);
+    }
+
+    #[rstest]
+    fn load_mesh_anim_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x01, // Version (1)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x04, // Anim version (4)
+            0x41, 0x20, 0x00, 0x00, // Frame (10.0)
+            0x00, 0x00, 0x00, 0x02, // Rate (k30_fps_ui)
+            0x00, 0x00, 0x00, 0x06, b'a', b'.', b'm', b'e', b's', b'h', // Mesh
+            // Vert point keys
+            0x00, 0x00, 0x00, 0x01, // Key count
+            0x00, 0x00, 0x00, 0x02, // Value count
+            0x3F, 0x80, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00, 0x40, 0x40, 0x00, 0x00,
+            0x40, 0x80, 0x00, 0x00, 0x40, 0xA0, 0x00, 0x00, 0x40, 0xC0, 0x00, 0x00,
+            0x40, 0xA0, 0x00, 0x00, // Pos (5.0)
+            // Vert text keys
+            0x00, 0x00, 0x00, 0x01, // Key count
+            0x00, 0x00, 0x00, 0x01, // Value count
+            0x3F, 0x00, 0x00, 0x00, 0x3E, 0x80, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Pos (0.0)
+            // Vert color keys
+            0x00, 0x00, 0x00, 0x00, // Key count
+            0x00, 0x00, 0x00, 0x04, b'a', b'.', b'm', b'a', // Keys owner
+        ];
+
+        let mesh_anim: MeshAnim = assert_round_trip_bytes(&data, &info);
+        assert_eq!(10.0, mesh_anim.frame);
+        assert_eq!(AnimRate::k30_fps_ui as u32, mesh_anim.rate as u32);
+        assert_eq!("a.mesh", mesh_anim.mesh);
+        assert_eq!("a.ma", mesh_anim.keys_owner);
+
+        assert_eq!(1, mesh_anim.vert_point_keys.len());
+        assert_eq!(5.0, mesh_anim.vert_point_keys[0].pos);
+
+        let points = mesh_anim.vert_point_keys[0].value.iter().map(|v| (v.x, v.y, v.z)).collect::<Vec<_>>();
+        assert_eq!(vec![(1.0, 2.0, 3.0), (4.0, 5.0, 6.0)], points);
+
+        let texts = mesh_anim.vert_text_keys.iter().map(|k| (k.pos, k.value[0].x, k.value[0].y)).collect::<Vec<_>>();
+        assert_eq!(vec![(0.0, 0.5, 0.25)], texts);
+        assert!(mesh_anim.vert_color_keys.is_empty());
+    }
+}
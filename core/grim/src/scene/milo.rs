@@ -21,8 +21,8 @@ impl Milo {
             let object = self.entries.remove(0);
 
             let new_object = match object.unpack(info) {
-                Some(obj) => obj,
-                None => object
+                Ok(obj) => obj,
+                Err(_) => object
             };
 
             new_entries.push(new_object);
@@ -2,6 +2,7 @@ mod anim;
 mod band_placer;
 mod band_character;
 mod cam;
+mod cam_anim;
 mod char_bones_samples;
 mod char_clip;
 mod char_clip_samples;
@@ -13,8 +14,12 @@ mod character;
 mod color_palette;
 mod cube_tex;
 mod draw;
+mod environ;
+mod font;
 mod group;
 mod io;
+mod light;
+mod line;
 mod mat;
 mod mesh;
 mod mesh_anim;
@@ -28,8 +33,10 @@ mod p9_song_pref;
 mod poll;
 mod prop_anim;
 mod rnd_dir;
+mod set;
 mod synth_sample;
 mod tex;
+mod text;
 mod trans;
 mod trans_anim;
 
@@ -37,6 +44,7 @@ pub use anim::*;
 pub use band_placer::*;
 pub use band_character::*;
 pub use cam::*;
+pub use cam_anim::*;
 pub use char_clip::*;
 pub use char_bones_samples::*;
 pub use char_clip_samples::*;
@@ -48,9 +56,13 @@ pub use character::*;
 pub use color_palette::*;
 pub use cube_tex::*;
 pub use draw::*;
+pub use environ::*;
+pub use font::*;
 pub use group::*;
 pub use grim_traits::scene::*;
 pub use io::*;
+pub use light::*;
+pub use line::*;
 pub use self::mat::*;
 pub use self::meta::*;
 pub use self::mesh::*;
@@ -64,7 +76,9 @@ pub use p9_song_pref::*;
 pub use poll::*;
 pub use prop_anim::*;
 pub use rnd_dir::*;
+pub use set::*;
 pub use synth_sample::*;
 pub use tex::*;
+pub use text::*;
 pub use trans::*;
 pub use trans_anim::*;
\ No newline at end of file
@@ -40,7 +40,7 @@ impl ObjectReadWrite for Morph {
         load_anim(self, &mut reader, info, false)?;
 
         let pose_count = reader.read_uint32()?;
-        reader.seek(SeekFrom::Current(4))?; // Skip 0 data
+        self.target = reader.read_prefixed_string()?;
 
         // Read poses
         self.poses.clear();
@@ -69,7 +69,7 @@ impl ObjectReadWrite for Morph {
         save_anim(self, &mut writer, info, false)?;
 
         writer.write_uint32(self.poses.len() as u32)?;
-        writer.write_uint32(0)?; // Always 0
+        writer.write_prefixed_string(&self.target)?;
 
         for pose in self.poses.iter() {
             save_morph_pose(pose, &mut writer)?;
@@ -8,6 +8,7 @@ pub use io::*;
 #[milo(Anim)]
 pub struct Morph {
     pub poses: Vec<MorphPose>,
+    pub target: String, // Base mesh
     pub normals: bool,
     pub spline: bool,
     pub intensity: f32,
@@ -33,6 +34,7 @@ impl Default for Morph { // RndMorph
 
             // Morph object
             poses: Vec::new(),
+            target: String::default(),
             normals: true,
             spline: true,
             intensity: 1.0
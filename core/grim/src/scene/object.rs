@@ -1,29 +1,41 @@
 use crate::{SystemInfo};
 use crate::io::MemoryStream;
 use crate::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
 
-pub enum Object {
-    Anim(AnimObject),
-    BandPlacer(BandPlacer),
-    Cam(CamObject),
-    CharClipSamples(CharClipSamples),
-    CharHair(CharHair),
-    CharLipSync(CharLipSync),
-    ColorPalette(ColorPalette),
-    CubeTex(CubeTexObject),
-    Draw(DrawObject),
-    Group(GroupObject),
-    Mat(MatObject),
-    Mesh(MeshObject),
-    MeshAnim(MeshAnim),
-    Morph(Morph),
-    P9SongPref(P9SongPref),
-    PropAnim(PropAnim),
-    SynthSample(SynthSample),
-    Tex(Tex),
-    Trans(TransObject),
-    TransAnim(TransAnim),
-    Packed(PackedObject),
+#[derive(Debug, ThisError)]
+pub enum ObjectError {
+    #[error("Object \"{name}\" is already packed")]
+    AlreadyPacked {
+        name: String
+    },
+    #[error("Object \"{name}\" is not packed")]
+    NotPacked {
+        name: String
+    },
+    #[error("Object type \"{object_type}\" is not supported")]
+    UnsupportedType {
+        object_type: String
+    },
+    #[error("Unable to pack \"{name}\" ({object_type}): {source}")]
+    PackFailed {
+        name: String,
+        object_type: String,
+        source: Box<dyn Error>
+    },
+    #[error("Unable to unpack \"{name}\" ({object_type}): {source}")]
+    UnpackFailed {
+        name: String,
+        object_type: String,
+        source: Box<dyn Error>
+    },
+    #[error("Unable to convert \"{name}\" ({object_type}): {source}")]
+    ConvertFailed {
+        name: String,
+        object_type: String,
+        source: Box<dyn Error>
+    },
 }
 
 #[derive(Debug)]
@@ -33,158 +45,227 @@ pub struct PackedObject {
     pub data: Vec<u8>
 }
 
-impl Object {
-    pub fn get_name(&self) -> &str {
-        match self {
-            Object::Anim(anim) => &anim.name,
-            Object::BandPlacer(band_placer) => &band_placer.name,
-            Object::Cam(cam) => &cam.name,
-            Object::CharClipSamples(ccs) => &ccs.name,
-            Object::CharHair(ch) => &ch.name,
-            Object::CharLipSync(cls) => &cls.name,
-            Object::ColorPalette(color_palette) => &color_palette.name,
-            Object::CubeTex(cube) => &cube.name,
-            Object::Draw(draw) => &draw.name,
-            Object::Group(grp) => &grp.name,
-            Object::Mat(mat) => &mat.name,
-            Object::Mesh(mesh) => &mesh.name,
-            Object::MeshAnim(mesh_anim) => &mesh_anim.name,
-            Object::Morph(morph) => &morph.name,
-            Object::P9SongPref(pref) => &pref.name,
-            Object::PropAnim(prop) => &prop.name,
-            Object::SynthSample(synth) => &synth.name,
-            Object::Tex(tex) => &tex.name,
-            Object::Trans(trans) => &trans.name,
-            Object::TransAnim(trans_anim) => &trans_anim.name,
-            Object::Packed(packed) => &packed.name,
+// Registers object types w/ enum variant, struct and milo type name
+macro_rules! define_objects {
+    ($($variant:ident($obj:ty) => $type_name:literal),* $(,)?) => {
+        pub enum Object {
+            $( $variant($obj), )*
+            Packed(PackedObject),
         }
-    }
 
-    pub fn get_type(&self) -> &str {
-        match self {
-            Object::Anim(_) => "Anim",
-            Object::BandPlacer(_) => "BandPlacer",
-            Object::Cam(_) => "Cam",
-            Object::CharClipSamples(_) => "CharClipSamples",
-            Object::CharHair(_) => "CharHair",
-            Object::CharLipSync(_) => "CharLipSync",
-            Object::ColorPalette(_) => "ColorPalette",
-            Object::CubeTex(_) => "CubeTex",
-            Object::Draw(_) => "Draw",
-            Object::Group(_) => "Group",
-            Object::Mat(_) => "Mat",
-            Object::Mesh(_) => "Mesh",
-            Object::MeshAnim(_) => "MeshAnim",
-            Object::Morph(_) => "Morph",
-            Object::P9SongPref(_) => "P9SongPref",
-            Object::PropAnim(_) => "PropAnim",
-            Object::SynthSample(_) => "SynthSample",
-            Object::Tex(_) => "Tex",
-            Object::Trans(_) => "Trans",
-            Object::TransAnim(_) => "TransAnim",
-            Object::Packed(packed) => &packed.object_type,
+        impl Object {
+            pub fn get_name(&self) -> &str {
+                match self {
+                    $( Object::$variant(obj) => obj.get_name(), )*
+                    Object::Packed(packed) => &packed.name,
+                }
+            }
+
+            pub fn get_type(&self) -> &str {
+                match self {
+                    $( Object::$variant(_) => $type_name, )*
+                    Object::Packed(packed) => &packed.object_type,
+                }
+            }
+
+            pub fn is_type_supported(object_type: &str) -> bool {
+                matches!(object_type, $( $type_name )|*)
+            }
+
+            fn as_read_write(&self) -> Option<&dyn ObjectReadWrite> {
+                match self {
+                    $( Object::$variant(obj) => Some(obj), )*
+                    Object::Packed(_) => None,
+                }
+            }
+
+            fn from_packed(packed: &PackedObject, info: &SystemInfo) -> Result<Object, Box<dyn Error>> {
+                match packed.object_type.as_str() {
+                    $( $type_name => unpack_object(packed, info).map(Object::$variant), )*
+                    _ => Err(Box::new(ObjectError::UnsupportedType {
+                        object_type: packed.object_type.to_owned()
+                    }))
+                }
+            }
         }
-    }
+    };
+}
+
+define_objects! {
+    Anim(AnimObject) => "Anim",
+    BandCharacter(BandCharacter) => "BandCharacter",
+    BandPlacer(BandPlacer) => "BandPlacer",
+    Cam(CamObject) => "Cam",
+    CamAnim(CamAnim) => "CamAnim",
+    Character(CharacterObject) => "Character",
+    CharClip(CharClipObject) => "CharClip",
+    CharClipSamples(CharClipSamples) => "CharClipSamples",
+    CharDriver(CharDriver) => "CharDriver",
+    CharHair(CharHair) => "CharHair",
+    CharLipSync(CharLipSync) => "CharLipSync",
+    CharWeightable(CharWeightableObject) => "CharWeightable",
+    ColorPalette(ColorPalette) => "ColorPalette",
+    CubeTex(CubeTexObject) => "CubeTex",
+    Draw(DrawObject) => "Draw",
+    Environ(EnvironObject) => "Environ",
+    Font(FontObject) => "Font",
+    Group(GroupObject) => "Group",
+    Light(LightObject) => "Light",
+    Line(LineObject) => "Line",
+    Mat(MatObject) => "Mat",
+    Mesh(MeshObject) => "Mesh",
+    MeshAnim(MeshAnim) => "MeshAnim",
+    Morph(Morph) => "Morph",
+    P9SongPref(P9SongPref) => "P9SongPref",
+    PropAnim(PropAnim) => "PropAnim",
+    RndDir(RndDirObject) => "RndDir",
+    Set(SetObject) => "Set",
+    SynthSample(SynthSample) => "SynthSample",
+    Tex(Tex) => "Tex",
+    Text(TextObject) => "Text",
+    Trans(TransObject) => "Trans",
+    TransAnim(TransAnim) => "TransAnim",
+}
 
+impl Object {
     pub fn is_packed(&self) -> bool {
-        match self {
-            Object::Packed(_) => true,
-            _ => false
-        }
+        matches!(self, Object::Packed(_))
     }
 
-    pub fn pack(&self, info: &SystemInfo) -> Option<Object> {
-        if self.is_packed() {
-            todo!("Already packed");
-        }
-
-        let obj: &dyn ObjectReadWrite  = match &self {
-            Object::Anim(obj) => obj,
-            Object::BandPlacer(obj) => obj,
-            Object::Cam(obj) => obj,
-            Object::ColorPalette(obj) => obj,
-            Object::CubeTex(obj) => obj,
-            Object::Draw(obj) => obj,
-            Object::Group(obj) => obj,
-            Object::Mat(obj) => obj,
-            Object::Mesh(obj) => obj,
-            Object::Morph(obj) => obj,
-            Object::P9SongPref(obj) => obj,
-            Object::PropAnim(obj) => obj,
-            Object::Tex(obj) => obj,
-            Object::Trans(obj) => obj,
-            Object::TransAnim(obj) => obj,
-            _ => todo!("Test"),
+    pub fn pack(&self, info: &SystemInfo) -> Result<Object, ObjectError> {
+        let Some(obj) = self.as_read_write() else {
+            return Err(ObjectError::AlreadyPacked {
+                name: self.get_name().to_owned()
+            });
         };
 
         let mut data = Vec::new();
         let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
 
-        if obj.save(&mut stream, info).is_err() {
-            println!("ERROR: Unable to pack {} with type {}", self.get_name(), self.get_type());
-            return None;
+        if let Err(source) = obj.save(&mut stream, info) {
+            return Err(ObjectError::PackFailed {
+                name: self.get_name().to_owned(),
+                object_type: self.get_type().to_owned(),
+                source
+            });
         }
 
         // Return packed object
-        Some(Object::Packed(PackedObject {
+        Ok(Object::Packed(PackedObject {
             name: self.get_name().to_owned(),
             object_type: self.get_type().to_owned(),
             data,
         }))
     }
 
-    pub fn unpack(&self, info: &SystemInfo) -> Option<Object> {
-        match self {
-            Object::Packed(packed) => {
-                match packed.object_type.as_str() {
-                    "Anim" => unpack_object(packed, info).map(|o| Object::Anim(o)),
-                    "BandPlacer" => unpack_object(packed, info).map(|o| Object::BandPlacer(o)),
-                    "Cam" => unpack_object(packed, info).map(|o| Object::Cam(o)),
-                    "CharClipSamples" => unpack_object(packed, info).map(|o| Object::CharClipSamples(o)),
-                    "CharHair" => unpack_object(packed, info).map(|o| Object::CharHair(o)),
-                    "CharLipSync" => unpack_object(packed, info).map(|o| Object::CharLipSync(o)),
-                    "ColorPalette" => unpack_object(packed, info).map(|o| Object::ColorPalette(o)),
-                    "CubeTex" => unpack_object(packed, info).map(|o| Object::CubeTex(o)),
-                    "Draw" => unpack_object(packed, info).map(|o| Object::Draw(o)),
-                    "Group" => unpack_object(packed, info).map(|o| Object::Group(o)),
-                    "Mat" => unpack_object(packed, info).map(|o| Object::Mat(o)),
-                    "Mesh" => unpack_object(packed, info).map(|o| Object::Mesh(o)),
-                    "MeshAnim" => unpack_object(packed, info).map(|o| Object::MeshAnim(o)),
-                    "Morph" => unpack_object(packed, info).map(|o| Object::Morph(o)),
-                    "P9SongPref" => unpack_object(packed, info).map(|o| Object::P9SongPref(o)),
-                    "PropAnim" => unpack_object(packed, info).map(|o| Object::PropAnim(o)),
-                    "SynthSample" => unpack_object(packed, info).map(|o| Object::SynthSample(o)),
-                    "Tex" => {
-                        let mut stream = MemoryStream::from_slice_as_read(packed.data.as_slice());
-
-                        // TODO: Update tex to use same io traits
-                        match Tex::from_stream(&mut stream, info) {
-                            Ok(mut tex) => {
-                                tex.name = packed.name.to_owned();
-                                Some(Object::Tex(tex))
-                            },
-                            Err(_) => None,
-                        }
-                    },
-                    "Trans" => unpack_object(packed, info).map(|o| Object::Trans(o)),
-                    "TransAnim" => unpack_object(packed, info).map(|o| Object::TransAnim(o)),
-                    _ => None
-                }
-            },
-            _ => None
+    pub fn unpack(&self, info: &SystemInfo) -> Result<Object, ObjectError> {
+        let Object::Packed(packed) = self else {
+            return Err(ObjectError::NotPacked {
+                name: self.get_name().to_owned()
+            });
+        };
+
+        if !Object::is_type_supported(&packed.object_type) {
+            return Err(ObjectError::UnsupportedType {
+                object_type: packed.object_type.to_owned()
+            });
         }
+
+        Object::from_packed(packed, info)
+            .map_err(|source| ObjectError::UnpackFailed {
+                name: packed.name.to_owned(),
+                object_type: packed.object_type.to_owned(),
+                source
+            })
     }
 }
 
-fn unpack_object<T: Default + MiloObject + ObjectReadWrite>(packed: &PackedObject, info: &SystemInfo) -> Option<T> {
+fn unpack_object<T: Default + MiloObject + ObjectReadWrite>(packed: &PackedObject, info: &SystemInfo) -> Result<T, Box<dyn Error>> {
     let mut stream = MemoryStream::from_slice_as_read(packed.data.as_slice());
 
     let mut obj = T::default();
+    obj.load(&mut stream, info)?;
+    obj.set_name(packed.name.to_owned());
+
+    Ok(obj)
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::IOEndian;
+    use crate::Platform;
+
+    fn get_info() -> SystemInfo {
+        SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        }
+    }
+
+    #[rstest]
+    #[case(Object::Anim(AnimObject::default()))]
+    #[case(Object::CharHair(CharHair::default()))]
+    #[case(Object::CharLipSync(CharLipSync::default()))]
+    #[case(Object::MeshAnim(MeshAnim::default()))]
+    #[case(Object::SynthSample(SynthSample::default()))]
+    #[case(Object::Tex(Tex::default()))]
+    #[case(Object::Trans(TransObject::default()))]
+    fn object_pack_unpack(#[case] mut obj: Object) {
+        let info = get_info();
+
+        if let Object::Tex(tex) = &mut obj {
+            tex.name = String::from("test.tex");
+        }
+
+        let packed = obj.pack(&info).unwrap();
+        assert!(packed.is_packed());
+        assert_eq!(obj.get_name(), packed.get_name());
+        assert_eq!(obj.get_type(), packed.get_type());
 
-    if obj.load(&mut stream, info).is_ok() {
-        obj.set_name(packed.name.to_owned());
-        return Some(obj);
+        let unpacked = packed.unpack(&info).unwrap();
+        assert!(!unpacked.is_packed());
+        assert_eq!(obj.get_name(), unpacked.get_name());
+        assert_eq!(obj.get_type(), unpacked.get_type());
     }
 
-    None
-}
\ No newline at end of file
+    #[rstest]
+    fn object_pack_already_packed() {
+        let packed = Object::Packed(PackedObject {
+            name: String::from("test.tex"),
+            object_type: String::from("Tex"),
+            data: Vec::new(),
+        });
+
+        let result = packed.pack(&get_info());
+        assert!(matches!(result, Err(ObjectError::AlreadyPacked { .. })));
+    }
+
+    #[rstest]
+    fn object_unpack_not_packed() {
+        let obj = Object::Trans(TransObject::default());
+
+        let result = obj.unpack(&get_info());
+        assert!(matches!(result, Err(ObjectError::NotPacked { .. })));
+    }
+
+    #[rstest]
+    #[case("Unknown", Vec::new(), false)]
+    #[case("Tex", vec![0xFF; 4], true)]
+    fn object_unpack_errors(#[case] object_type: &str, #[case] data: Vec<u8>, #[case] is_supported: bool) {
+        let packed = Object::Packed(PackedObject {
+            name: String::from("test"),
+            object_type: object_type.to_owned(),
+            data,
+        });
+
+        let result = packed.unpack(&get_info());
+
+        match is_supported {
+            true => assert!(matches!(result, Err(ObjectError::UnpackFailed { .. }))),
+            false => assert!(matches!(result, Err(ObjectError::UnsupportedType { .. }))),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::SystemInfo;
 use crate::io::{BinaryStream, FileSearchDepth, FileStream, MemoryStream, PathFinder, SeekFrom, Stream};
 use crate::scene::*;
+use crate::texture::{Bitmap, BitmapEncodeOptions};
 use lazy_static::lazy_static;
 use log::warn;
 use regex::Regex;
@@ -100,17 +101,113 @@ impl<'a> ObjectDir {
     pub fn unpack_entries(&'a mut self, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         #[allow(irrefutable_let_patterns)]
         if let ObjectDir::ObjectDir(obj_dir) = self {
-            for entry in obj_dir.entries.iter_mut() {
-                if let Some(new_entry) = entry.unpack(info) {
-                    *entry = new_entry;
-                } else {
-                    warn!("Unable to unpack \"{}\" ({})", entry.get_name(), entry.get_type());
+            for entry in obj_dir.entries.iter_mut().filter(|e| e.is_packed()) {
+                match entry.unpack(info) {
+                    Ok(new_entry) => *entry = new_entry,
+                    Err(err) => warn!("{err}"),
                 }
             }
         }
 
         Ok(())
     }
+
+    pub fn convert_textures(&'a mut self, from: &SystemInfo, to: &SystemInfo, opts: &BitmapEncodeOptions) -> Result<(), Box<dyn Error>> {
+        #[allow(irrefutable_let_patterns)]
+        if let ObjectDir::ObjectDir(obj_dir) = self {
+            let mut changes = Vec::new();
+
+            for (i, entry) in obj_dir.entries.iter().enumerate() {
+                if !entry.is_packed() {
+                    if let Some(change) = convert_texture_object(entry, from, to, opts)? {
+                        changes.push((i, change));
+                    }
+
+                    continue;
+                }
+
+                if !matches!(entry.get_type(), "CubeTex" | "Tex") {
+                    continue;
+                }
+
+                // Unpack to convert, then re-pack using target info
+                let mut unpacked = entry.unpack(from)?;
+                if let Some(change) = convert_texture_object(&unpacked, from, to, opts)? {
+                    apply_texture_change(&mut unpacked, change);
+                }
+
+                changes.push((i, TextureChange::Replace(Box::new(unpacked.pack(to)?))));
+            }
+
+            // Only update entries once every texture is converted
+            for (i, change) in changes {
+                apply_texture_change(&mut obj_dir.entries[i], change);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+enum TextureChange {
+    Tex(Bitmap),
+    CubeTex([Option<Bitmap>; 6]),
+    Replace(Box<Object>),
+}
+
+fn convert_texture_object(obj: &Object, from: &SystemInfo, to: &SystemInfo, opts: &BitmapEncodeOptions) -> Result<Option<TextureChange>, ObjectError> {
+    let result = match obj {
+        Object::Tex(tex) => convert_tex(tex, from, to, opts),
+        Object::CubeTex(cube) => convert_cube_tex(cube, from, to, opts),
+        _ => Ok(None),
+    };
+
+    result.map_err(|source| ObjectError::ConvertFailed {
+        name: obj.get_name().to_owned(),
+        object_type: obj.get_type().to_owned(),
+        source
+    })
+}
+
+fn convert_tex(tex: &Tex, from: &SystemInfo, to: &SystemInfo, opts: &BitmapEncodeOptions) -> Result<Option<TextureChange>, Box<dyn Error>> {
+    let Some(bitmap) = &tex.bitmap else {
+        return Ok(None);
+    };
+
+    Ok(Some(TextureChange::Tex(bitmap.convert(from, to, opts)?)))
+}
+
+fn convert_cube_tex(cube: &CubeTexObject, from: &SystemInfo, to: &SystemInfo, opts: &BitmapEncodeOptions) -> Result<Option<TextureChange>, Box<dyn Error>> {
+    let mut faces: [Option<Bitmap>; 6] = Default::default();
+
+    for (face, bitmap) in faces.iter_mut().zip(cube.get_faces()) {
+        *face = bitmap.map(|b| b.convert(from, to, opts)).transpose()?;
+    }
+
+    Ok(Some(TextureChange::CubeTex(faces)))
+}
+
+fn apply_texture_change(obj: &mut Object, change: TextureChange) {
+    match (obj, change) {
+        (Object::Tex(tex), TextureChange::Tex(bitmap)) => {
+            tex.width = bitmap.width as u32;
+            tex.height = bitmap.height as u32;
+            tex.bpp = bitmap.bpp as u32;
+            tex.bitmap = Some(bitmap);
+        },
+        (Object::CubeTex(cube), TextureChange::CubeTex([right, left, top, bottom, front, back])) => {
+            cube.right = right;
+            cube.left = left;
+            cube.top = top;
+            cube.bottom = bottom;
+            cube.front = front;
+            cube.back = back;
+
+            cube.recompute_properties();
+        },
+        (obj, TextureChange::Replace(new_obj)) => *obj = *new_obj,
+        _ => {}
+    }
 }
 
 impl ObjectDir {
@@ -144,4 +241,102 @@ impl ObjectDir {
             *class_name = name.to_owned();
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::IOEndian;
+    use crate::Platform;
+
+    #[rstest]
+    fn convert_textures_fails_on_bad_bitmap() {
+        let from = SystemInfo { platform: Platform::PS2, ..Default::default() };
+        let to = SystemInfo { platform: Platform::X360, ..Default::default() };
+
+        let valid = Bitmap {
+            bpp: 24,
+            encoding: 3,
+            width: 4,
+            height: 4,
+            bpl: 12,
+            raw_data: vec![0x40; 4 * 4 * 3],
+            ..Bitmap::new()
+        };
+
+        // Zero-sized face can't be decoded
+        let invalid = Bitmap { width: 0, height: 0, ..valid.clone() };
+
+        let cube = CubeTexObject {
+            name: String::from("env.tex"),
+            right: Some(valid.clone()),
+            left: Some(invalid),
+            ..Default::default()
+        };
+
+        let mut obj_dir = ObjectDir::ObjectDir(ObjectDirBase {
+            entries: vec![
+                Object::Tex(Tex {
+                    name: String::from("first.tex"),
+                    bitmap: Some(valid.clone()),
+                    ..Default::default()
+                }),
+                Object::CubeTex(cube),
+            ],
+            ..ObjectDirBase::new()
+        });
+
+        let result = obj_dir.convert_textures(&from, &to, &Default::default());
+        assert!(result.is_err_and(|err| matches!(err.downcast_ref::<ObjectError>(), Some(ObjectError::ConvertFailed { .. }))));
+
+        // Earlier textures are untouched on failure
+        let Object::Tex(tex) = &obj_dir.get_entries()[0] else {
+            panic!("Expected Tex");
+        };
+
+        assert_eq!(Some(3), tex.bitmap.as_ref().map(|b| b.encoding));
+
+        // Faces are untouched on failure
+        let Object::CubeTex(cube) = &obj_dir.get_entries()[1] else {
+            panic!("Expected CubeTex");
+        };
+
+        assert_eq!(Some(valid.raw_data.as_slice()), cube.right.as_ref().map(|b| b.raw_data.as_slice()));
+        assert_eq!(Some(3), cube.right.as_ref().map(|b| b.encoding));
+    }
+
+    #[rstest]
+    fn unpack_entries_skips_unsupported_versions() {
+        let info = SystemInfo { version: 25, platform: Platform::X360, endian: IOEndian::Big };
+
+        let set = Object::Set(SetObject {
+            name: String::from("objects.set"),
+            ..Default::default()
+        });
+
+        let mut obj_dir = ObjectDir::ObjectDir(ObjectDirBase {
+            entries: vec![
+                Object::Packed(PackedObject {
+                    name: String::from("bone.tnm"),
+                    object_type: String::from("TransAnim"),
+                    data: [4u32, 1].iter().flat_map(|v| v.to_be_bytes()).collect(), // Anim v1
+                }),
+                Object::Packed(PackedObject {
+                    name: String::from("env.tex"),
+                    object_type: String::from("CubeTex"),
+                    data: 9u32.to_be_bytes().to_vec(),
+                }),
+                set.pack(&info).unwrap(),
+            ],
+            ..ObjectDirBase::new()
+        });
+
+        obj_dir.unpack_entries(&info).unwrap();
+
+        let entries = obj_dir.get_entries();
+        assert!(entries[0].is_packed());
+        assert!(entries[1].is_packed());
+        assert!(matches!(&entries[2], Object::Set(set) if set.name == "objects.set"));
+    }
+}
@@ -0,0 +1,205 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum ObjectDirLoadError {
+    #[error("ObjectDir version {version} is not supported")]
+    ObjectDirVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+        16 => true, // RB1/RB2
+        17 => true, // TBRB/GDRB
+        _ => false
+    }
+}
+
+fn get_save_version<T: ObjDir>(obj_dir: &T, info: &SystemInfo) -> u32 {
+    match (info.version, obj_dir.get_obj_dir_version()) {
+        (25, Some(v @ (16 | 17))) => v,
+        (25, _) => 16, // RB1/RB2
+        _ => 17,       // TBRB/GDRB (RB3 not supported yet)
+    }
+}
+
+impl ObjectReadWrite for ObjectDirObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        load_obj_dir(self, &mut reader, info)
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        save_obj_dir(self, &mut writer, info)
+    }
+}
+
+pub(crate) fn load_obj_dir<T: ObjDir>(obj_dir: &mut T, reader: &mut Box<BinaryStream>, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+    let version = reader.read_uint32()?;
+    if !is_version_supported(version) {
+        return Err(Box::new(ObjectDirLoadError::ObjectDirVersionNotSupported {
+            version
+        }));
+    }
+
+    obj_dir.set_obj_dir_version(Some(version));
+
+    load_object(obj_dir, reader, info)?;
+
+    let viewports = obj_dir.get_viewports_mut();
+    viewports.clear();
+
+    let viewport_count = reader.read_uint32()?;
+    for _ in 0..viewport_count {
+        let mut viewport = Matrix::default();
+        load_matrix(&mut viewport, reader)?;
+
+        viewports.push(viewport);
+    }
+
+    obj_dir.set_curr_viewport_index(reader.read_uint32()?);
+    obj_dir.set_inline_proxy(reader.read_boolean()?);
+    obj_dir.set_proxy_file(reader.read_prefixed_string()?);
+
+    let sub_dirs = obj_dir.get_sub_dirs_mut();
+    sub_dirs.clear();
+
+    let sub_dir_count = reader.read_uint32()?;
+    for _ in 0..sub_dir_count {
+        sub_dirs.push(reader.read_prefixed_string()?);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn save_obj_dir<T: ObjDir>(obj_dir: &T, writer: &mut Box<BinaryStream>, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+    let version = get_save_version(obj_dir, info);
+    writer.write_uint32(version)?;
+
+    save_object(obj_dir, writer, info)?;
+
+    writer.write_uint32(obj_dir.get_viewports().len() as u32)?;
+    for viewport in obj_dir.get_viewports() {
+        save_matrix(viewport, writer)?;
+    }
+
+    writer.write_uint32(obj_dir.get_curr_viewport_index())?;
+    writer.write_boolean(obj_dir.get_inline_proxy())?;
+    writer.write_prefixed_string(obj_dir.get_proxy_file())?;
+
+    writer.write_uint32(obj_dir.get_sub_dirs().len() as u32)?;
+    for sub_dir in obj_dir.get_sub_dirs() {
+        writer.write_prefixed_string(sub_dir)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_obj_dir_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x10, // Version (16)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x01, // Viewport count
+            0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00,
+            0x41, 0x20, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Current viewport
+            0x00,                   // Inline proxy
+            0x00, 0x00, 0x00, 0x06, b'a', b'.', b'm', b'i', b'l', b'o', // Proxy file
+            0x00, 0x00, 0x00, 0x02, // Sub dir count
+            0x00, 0x00, 0x00, 0x06, b'b', b'.', b'm', b'i', b'l', b'o',
+            0x00, 0x00, 0x00, 0x06, b'c', b'.', b'm', b'i', b'l', b'o',
+        ];
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut obj_dir = ObjectDirObject::default();
+        obj_dir.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(1, obj_dir.viewports.len());
+        assert_eq!(1.0, obj_dir.viewports[0].m11);
+        assert_eq!(10.0, obj_dir.viewports[0].m41);
+        assert_eq!(0, obj_dir.curr_viewport_index);
+        assert!(!obj_dir.inline_proxy);
+        assert_eq!("a.milo", obj_dir.proxy_file);
+        assert_eq!(vec![String::from("b.milo"), String::from("c.milo")], obj_dir.sub_dirs);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        obj_dir.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    #[case(25, 16)]
+    #[case(28, 17)]
+    fn save_load_obj_dir(#[case] milo_version: u32, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version: milo_version,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let obj_dir = ObjectDirObject {
+            viewports: (0..3).map(|_| Matrix::identity()).collect(),
+            curr_viewport_index: 2,
+            inline_proxy: false,
+            proxy_file: String::from("proxy.milo"),
+            sub_dirs: vec![String::from("sub.milo")],
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        obj_dir.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut reader = BinaryStream::from_stream_with_endian(&mut stream, info.endian);
+        assert_eq!(expected_version, reader.read_uint32().unwrap());
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_obj_dir = ObjectDirObject::default();
+        loaded_obj_dir.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(obj_dir.viewports.len(), loaded_obj_dir.viewports.len());
+        assert_eq!(obj_dir.curr_viewport_index, loaded_obj_dir.curr_viewport_index);
+        assert_eq!(obj_dir.inline_proxy, loaded_obj_dir.inline_proxy);
+        assert_eq!(obj_dir.proxy_file, loaded_obj_dir.proxy_file);
+        assert_eq!(obj_dir.sub_dirs, loaded_obj_dir.sub_dirs);
+    }
+}
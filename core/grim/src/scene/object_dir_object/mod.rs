@@ -0,0 +1,27 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo(ObjDir)]
+pub struct ObjectDirObject {}
+
+impl Default for ObjectDirObject {
+    fn default() -> ObjectDirObject {
+        ObjectDirObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // ObjectDir object
+            viewports: Vec::new(),
+            curr_viewport_index: 0,
+            inline_proxy: true,
+            proxy_file: String::default(),
+            sub_dirs: Vec::new(),
+            obj_dir_version: None,
+        }
+    }
+}
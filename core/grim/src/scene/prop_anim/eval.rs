@@ -0,0 +1,169 @@
+use super::{PropInterpolation, PropKeys, PropKeysEvents};
+use crate::scene::trans_anim::sample::{catmull_rom, find_keys, lerp, normalize4, slerp};
+use grim_traits::scene::*;
+
+#[derive(Debug)]
+pub enum PropKeysValue {
+    Float(f32),
+    Color(Color4),
+    Object(String, String),
+    Bool(bool),
+    Quat(Quat),
+    Vector3(Vector3),
+    Symbol(String),
+}
+
+fn interpolate<const N: usize>(values: &[[f32; N]], prev: usize, next: usize, t: f32, interpolation: PropInterpolation) -> [f32; N] {
+    let p1 = values[prev];
+    let p2 = values[next];
+
+    match interpolation {
+        PropInterpolation::kStep => p1,
+        PropInterpolation::kSpline | PropInterpolation::kHermite => {
+            // Catmull-Rom w/ neighbouring keys
+            let p0 = values[prev.saturating_sub(1)];
+            let p3 = values[(next + 1).min(values.len() - 1)];
+
+            catmull_rom(p0, p1, p2, p3, t)
+        },
+        _ => {
+            let t = match interpolation {
+                PropInterpolation::kEaseIn => t * t,
+                PropInterpolation::kEaseOut => 1.0 - ((1.0 - t) * (1.0 - t)),
+                _ => t,
+            };
+
+            lerp(p1, p2, t)
+        }
+    }
+}
+
+fn sample_values<T, const N: usize>(events: &[T], frame: f32, interpolation: PropInterpolation, get_pos: impl Fn(&T) -> f32, get_value: impl Fn(&T) -> [f32; N]) -> [f32; N] {
+    let values = events.iter().map(get_value).collect::<Vec<_>>();
+
+    let (prev, next, t) = find_keys(events, frame, get_pos);
+    interpolate(&values, prev, next, t, interpolation)
+}
+
+impl PropKeys {
+    pub fn get_interpolation(&self) -> PropInterpolation {
+        self.interpolation.into()
+    }
+
+    pub fn sample(&self, frame: f32) -> Option<PropKeysValue> {
+        if self.events.is_empty() {
+            return None;
+        }
+
+        let interpolation = self.get_interpolation();
+
+        let value = match &self.events {
+            PropKeysEvents::Float(events) => {
+                let [v] = sample_values(events, frame, interpolation, |e| e.pos, |e| [e.value]);
+                PropKeysValue::Float(v)
+            },
+            PropKeysEvents::Color(events) => {
+                let [r, g, b, a] = sample_values(events, frame, interpolation, |e| e.pos, |e| [e.value.r, e.value.g, e.value.b, e.value.a]);
+                PropKeysValue::Color(Color4 { r, g, b, a })
+            },
+            PropKeysEvents::Vector3(events) => {
+                let [x, y, z] = sample_values(events, frame, interpolation, |e| e.pos, |e| [e.value.x, e.value.y, e.value.z]);
+                PropKeysValue::Vector3(Vector3 { x, y, z })
+            },
+            PropKeysEvents::Quat(events) => {
+                let (prev, next, t) = find_keys(events, frame, |e| e.pos);
+
+                let q1 = [events[prev].value.x, events[prev].value.y, events[prev].value.z, events[prev].value.w];
+                let q2 = [events[next].value.x, events[next].value.y, events[next].value.z, events[next].value.w];
+
+                let [x, y, z, w] = match interpolation {
+                    PropInterpolation::kStep => normalize4(q1),
+                    _ => normalize4(slerp(q1, q2, t)),
+                };
+
+                PropKeysValue::Quat(Quat { x, y, z, w })
+            },
+            // Discrete values always use previous key
+            PropKeysEvents::Object(events) => {
+                let (prev, _, _) = find_keys(events, frame, |e| e.pos);
+
+                PropKeysValue::Object(events[prev].text1.to_owned(), events[prev].text2.to_owned())
+            },
+            PropKeysEvents::Bool(events) => {
+                let (prev, _, _) = find_keys(events, frame, |e| e.pos);
+
+                PropKeysValue::Bool(events[prev].value)
+            },
+            PropKeysEvents::Symbol(events) => {
+                let (prev, _, _) = find_keys(events, frame, |e| e.pos);
+
+                PropKeysValue::Symbol(events[prev].text.to_owned())
+            },
+        };
+
+        Some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::scene::{AnimEventBool, AnimEventFloat};
+
+    fn create_float_keys(interpolation: PropInterpolation) -> PropKeys {
+        PropKeys {
+            interpolation: interpolation as u32,
+            events: PropKeysEvents::Float(vec![
+                AnimEventFloat { value: 0.0, pos: 0.0 },
+                AnimEventFloat { value: 10.0, pos: 10.0 },
+                AnimEventFloat { value: 20.0, pos: 20.0 },
+                AnimEventFloat { value: 30.0, pos: 30.0 },
+            ]),
+            ..Default::default()
+        }
+    }
+
+    #[rstest]
+    #[case(PropInterpolation::kLinear, -5.0, 0.0)]
+    #[case(PropInterpolation::kLinear, 5.0, 5.0)]
+    #[case(PropInterpolation::kLinear, 35.0, 30.0)]
+    #[case(PropInterpolation::kStep, 15.0, 10.0)]
+    #[case(PropInterpolation::kSpline, 15.0, 15.0)]
+    #[case(PropInterpolation::kEaseIn, 5.0, 2.5)]
+    #[case(PropInterpolation::kEaseOut, 5.0, 7.5)]
+    fn prop_keys_sample_float(#[case] interpolation: PropInterpolation, #[case] frame: f32, #[case] expected: f32) {
+        let keys = create_float_keys(interpolation);
+
+        let Some(PropKeysValue::Float(value)) = keys.sample(frame) else {
+            panic!("Expected float value");
+        };
+
+        assert_eq!(expected, value);
+    }
+
+    #[rstest]
+    #[case(0.5, false)]
+    #[case(1.0, true)]
+    #[case(5.0, true)]
+    fn prop_keys_sample_bool(#[case] frame: f32, #[case] expected: bool) {
+        let keys = PropKeys {
+            events: PropKeysEvents::Bool(vec![
+                AnimEventBool { value: false, pos: 0.0 },
+                AnimEventBool { value: true, pos: 1.0 },
+            ]),
+            ..Default::default()
+        };
+
+        let Some(PropKeysValue::Bool(value)) = keys.sample(frame) else {
+            panic!("Expected bool value");
+        };
+
+        assert_eq!(expected, value);
+    }
+
+    #[rstest]
+    fn prop_keys_sample_empty() {
+        assert!(PropKeys::default().sample(0.0).is_none());
+    }
+}
@@ -4,24 +4,66 @@ use crate::scene::*;
 use crate::SystemInfo;
 use grim_traits::scene::*;
 use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum PropAnimLoadError {
+    #[error("PropAnim version {version} is not supported")]
+    PropAnimVersionNotSupported {
+        version: u32
+    },
+    #[error("PropKeys type of {type_1} doesn't match type {type_2}")]
+    PropKeysTypeMismatch {
+        type_1: u32,
+        type_2: u32
+    },
+    #[error("PropKeys type of {value} is not supported")]
+    PropKeysTypeNotSupported {
+        value: u32
+    },
+}
+
+#[derive(Debug, ThisError)]
+pub enum PropAnimWriteError {
+    #[error("PropAnim can't be saved for milo version {version}")]
+    MiloVersionNotSupported {
+        version: u32
+    },
+}
 
 fn is_version_supported(version: u32) -> bool {
     match version {
-        11 | 12 => true,
+         7 => true, // RB1
+         8 => true, // RB2
+        11 => true, // TBRB
+        12 => true, // GDRB
+        13 => true, // RB3
         _ => false
     }
 }
 
+fn get_save_version(prop_anim: &PropAnim, info: &SystemInfo) -> Option<u32> {
+    match (info.version, prop_anim.version) {
+        (..=24, _) => None,
+        (25, Some(v @ (7 | 8 | 11 | 12))) => Some(v),
+        (25, _) => Some(8), // RB1/RB2
+        _ => Some(13), // RB3
+    }
+}
+
 impl ObjectReadWrite for PropAnim {
     fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
 
         let version = reader.read_uint32()?;
         if !is_version_supported(version) {
-            // TODO: Switch to custom error
-            panic!("PropAnim version \"{}\" is not supported!", version);
+            return Err(Box::new(PropAnimLoadError::PropAnimVersionNotSupported {
+                version
+            }));
         }
 
+        self.version = Some(version);
+
         load_object(self, &mut reader, info)?;
         load_anim(self, &mut reader, info, false)?;
 
@@ -34,7 +76,7 @@ impl ObjectReadWrite for PropAnim {
 
         let prop_keys_count = reader.read_uint32()?;
         for _ in 0..prop_keys_count {
-            let prop_keys = load_prop_keys(&mut reader)?;
+            let prop_keys = load_prop_keys(&mut reader, version)?;
             self.keys.push(prop_keys);
         }
 
@@ -44,8 +86,8 @@ impl ObjectReadWrite for PropAnim {
     fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
         let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
 
-        // TODO: Get version from system info
-        let version = 11;
+        let version = get_save_version(self, info)
+            .ok_or(PropAnimWriteError::MiloVersionNotSupported { version: info.version })?;
 
         writer.write_uint32(version)?;
 
@@ -58,19 +100,22 @@ impl ObjectReadWrite for PropAnim {
 
         writer.write_uint32(self.keys.len() as u32)?;
         for prop_keys in self.keys.iter() {
-            save_prop_keys(prop_keys, &mut writer)?;
-        } 
+            save_prop_keys(prop_keys, &mut writer, version)?;
+        }
 
         Ok(())
     }
 }
 
-fn load_prop_keys(reader: &mut Box<BinaryStream>) -> Result<PropKeys, Box<dyn Error>> {
+fn load_prop_keys(reader: &mut Box<BinaryStream>, version: u32) -> Result<PropKeys, Box<dyn Error>> {
     let type_1 = reader.read_uint32()?;
     let type_2 = reader.read_uint32()?;
 
     if type_1 != type_2 {
-        panic!("Type 1 of {type_1} doesn't match type 2 {type_2} for key in PropAnim")
+        return Err(Box::new(PropAnimLoadError::PropKeysTypeMismatch {
+            type_1,
+            type_2
+        }));
     }
 
     let mut keys: PropKeys = Default::default();
@@ -85,13 +130,21 @@ fn load_prop_keys(reader: &mut Box<BinaryStream>) -> Result<PropKeys, Box<dyn Er
         root_dta.data
     };
 
-    keys.interpolation = reader.read_uint32()?;
-    keys.interp_handler = reader.read_prefixed_string()?;
-    keys.unknown_enum = reader.read_uint32()?;
+    keys.interpolation = if version > 7 {
+        reader.read_uint32()?
+    } else {
+        PropInterpolation::kLinear as u32
+    };
+
+    if version > 10 {
+        keys.interp_handler = reader.read_prefixed_string()?;
+        keys.unknown_enum = reader.read_uint32()?;
+    }
 
     // Read events
     let event_count = reader.read_uint32()?;
-    keys.events = PropKeysEvents::from_enum_value(type_1);
+    keys.events = PropKeysEvents::from_enum_value(type_1)
+        .ok_or(PropAnimLoadError::PropKeysTypeNotSupported { value: type_1 })?;
 
     match &mut keys.events {
         PropKeysEvents::Float(events)   => {
@@ -178,7 +231,7 @@ fn load_prop_keys(reader: &mut Box<BinaryStream>) -> Result<PropKeys, Box<dyn Er
     Ok(keys)
 }
 
-fn save_prop_keys(keys: &PropKeys, writer: &mut Box<BinaryStream>) -> Result<(), Box<dyn Error>> {
+fn save_prop_keys(keys: &PropKeys, writer: &mut Box<BinaryStream>, version: u32) -> Result<(), Box<dyn Error>> {
     let type_value = keys.events.get_enum_value();
 
     // Write twice because milo is weird
@@ -198,9 +251,14 @@ fn save_prop_keys(keys: &PropKeys, writer: &mut Box<BinaryStream>) -> Result<(),
         save_array(&keys.property, writer, &mut id)?;
     }
 
-    writer.write_uint32(keys.interpolation)?;
-    writer.write_prefixed_string(&keys.interp_handler)?;
-    writer.write_uint32(keys.unknown_enum)?;
+    if version > 7 {
+        writer.write_uint32(keys.interpolation)?;
+    }
+
+    if version > 10 {
+        writer.write_prefixed_string(&keys.interp_handler)?;
+        writer.write_uint32(keys.unknown_enum)?;
+    }
 
     // Write events
     writer.write_uint32(keys.events.len() as u32)?;
@@ -252,4 +310,156 @@ fn save_prop_keys(keys: &PropKeys, writer: &mut Box<BinaryStream>) -> Result<(),
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_prop_anim_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x08, // Version (8)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x04, // Anim version (4)
+            0x00, 0x00, 0x00, 0x00, // Frame
+            0x00, 0x00, 0x00, 0x00, // Rate
+            0x00, 0x00, 0x00, 0x02, // Prop keys count
+            // Float keys
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00, 0x00, 0x00, 0x07, b'a', b'.', b't', b'r', b'a', b'n', b's', // Target
+            0x00,                   // Property
+            0x00, 0x00, 0x00, 0x06, // Interpolation (ease out)
+            0x00, 0x00, 0x00, 0x02, // Event count
+            0x3F, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x40, 0x00, 0x00, 0x00, 0x41, 0xF0, 0x00, 0x00,
+            // Bool keys
+            0x00, 0x00, 0x00, 0x03, // Type
+            0x00, 0x00, 0x00, 0x03, // Type
+            0x00, 0x00, 0x00, 0x05, b'b', b'.', b'm', b'a', b't', // Target
+            0x00,                   // Property
+            0x00, 0x00, 0x00, 0x00, // Interpolation (step)
+            0x00, 0x00, 0x00, 0x01, // Event count
+            0x01, 0x40, 0xA0, 0x00, 0x00,
+        ];
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut prop_anim = PropAnim::default();
+        prop_anim.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(2, prop_anim.keys.len());
+
+        let float_keys = &prop_anim.keys[0];
+        assert_eq!("a.trans", float_keys.target);
+        assert_eq!(PropInterpolation::kEaseOut as u32, float_keys.interpolation);
+
+        let PropKeysEvents::Float(events) = &float_keys.events else {
+            panic!("Expected float keys");
+        };
+
+        assert_eq!(vec![(1.0, 0.0), (2.0, 30.0)], events.iter().map(|e| (e.value, e.pos)).collect::<Vec<_>>());
+
+        let bool_keys = &prop_anim.keys[1];
+        assert_eq!("b.mat", bool_keys.target);
+        assert_eq!(PropInterpolation::kStep as u32, bool_keys.interpolation);
+
+        let PropKeysEvents::Bool(events) = &bool_keys.events else {
+            panic!("Expected bool keys");
+        };
+
+        assert_eq!(vec![(true, 5.0)], events.iter().map(|e| (e.value, e.pos)).collect::<Vec<_>>());
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        prop_anim.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    fn save_prop_anim_unsupported_milo_version() {
+        let info = SystemInfo {
+            version: 24,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        let result = PropAnim::default().save(&mut stream, &info);
+
+        assert!(result.is_err());
+    }
+
+    #[rstest]
+    #[case(25, None, 8, PropInterpolation::kEaseOut)]
+    #[case(25, Some(7), 7, PropInterpolation::kLinear)]
+    #[case(25, Some(11), 11, PropInterpolation::kHermite)]
+    #[case(25, Some(12), 12, PropInterpolation::kEaseIn)]
+    #[case(25, Some(13), 8, PropInterpolation::kStep)]
+    #[case(28, None, 13, PropInterpolation::kSpline)]
+    fn save_load_prop_anim(#[case] version: u32, #[case] prop_anim_version: Option<u32>, #[case] expected_version: u32, #[case] interpolation: PropInterpolation) {
+        let info = SystemInfo {
+            version,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let prop_anim = PropAnim {
+            keys: vec![
+                PropKeys {
+                    target: String::from("venue.trans"),
+                    interpolation: interpolation as u32,
+                    events: PropKeysEvents::Float(vec![
+                        AnimEventFloat { value: 1.0, pos: 0.0 },
+                        AnimEventFloat { value: 2.0, pos: 30.0 },
+                    ]),
+                    ..Default::default()
+                }
+            ],
+            unknown_toggle: true,
+            version: prop_anim_version,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        prop_anim.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut reader = BinaryStream::from_stream_with_endian(&mut stream, info.endian);
+        assert_eq!(expected_version, reader.read_uint32().unwrap());
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_prop_anim = PropAnim::default();
+        loaded_prop_anim.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(expected_version), loaded_prop_anim.version);
+        assert_eq!(expected_version >= 12, loaded_prop_anim.unknown_toggle);
+        assert_eq!(1, loaded_prop_anim.keys.len());
+
+        let keys = &loaded_prop_anim.keys[0];
+        assert_eq!("venue.trans", keys.target);
+        assert_eq!(interpolation as u32, keys.interpolation);
+        assert!(matches!(&keys.events, PropKeysEvents::Float(events) if events.len() == 2));
+    }
+}
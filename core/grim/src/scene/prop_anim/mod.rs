@@ -1,4 +1,6 @@
+mod eval;
 mod io;
+pub use eval::*;
 pub use io::*;
 use crate::dta::{DataArray, RootData};
 use grim_macros::*;
@@ -90,16 +92,50 @@ impl PropKeysEvents {
         }
     }
 
-    pub(crate) fn from_enum_value(value: u32) -> PropKeysEvents {
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub(crate) fn from_enum_value(value: u32) -> Option<PropKeysEvents> {
         match value {
-            0 => PropKeysEvents::Float(Vec::new()),
-            1 => PropKeysEvents::Color(Vec::new()),
-            2 => PropKeysEvents::Object(Vec::new()),
-            3 => PropKeysEvents::Bool(Vec::new()),
-            4 => PropKeysEvents::Quat(Vec::new()),
-            5 => PropKeysEvents::Vector3(Vec::new()),
-            6 => PropKeysEvents::Symbol(Vec::new()),
-            _ => panic!("Unsupported prop keys enum value of {value}") // TODO: Use dedicated error?
+            0 => Some(PropKeysEvents::Float(Vec::new())),
+            1 => Some(PropKeysEvents::Color(Vec::new())),
+            2 => Some(PropKeysEvents::Object(Vec::new())),
+            3 => Some(PropKeysEvents::Bool(Vec::new())),
+            4 => Some(PropKeysEvents::Quat(Vec::new())),
+            5 => Some(PropKeysEvents::Vector3(Vec::new())),
+            6 => Some(PropKeysEvents::Symbol(Vec::new())),
+            _ => None
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+#[repr(u32)]
+pub enum PropInterpolation {
+    kStep,
+    #[default]
+    kLinear,
+    kSpline,
+    kSlerp,
+    kHermite,
+    kEaseIn,
+    kEaseOut,
+}
+
+impl From<u32> for PropInterpolation {
+    fn from(num: u32) -> PropInterpolation {
+        match num {
+            0 => PropInterpolation::kStep,
+            1 => PropInterpolation::kLinear,
+            2 => PropInterpolation::kSpline,
+            3 => PropInterpolation::kSlerp,
+            4 => PropInterpolation::kHermite,
+            5 => PropInterpolation::kEaseIn,
+            6 => PropInterpolation::kEaseOut,
+            // Default
+            _ => PropInterpolation::default(),
         }
     }
 }
@@ -109,7 +145,7 @@ pub struct PropKeys {
     pub target: String,
     pub property: Vec<DataArray>,
 
-    pub interpolation: u32, // Some flags?
+    pub interpolation: u32, // See PropInterpolation
     pub interp_handler: String,
 
     pub unknown_enum: u32,
@@ -122,6 +158,8 @@ pub struct PropKeys {
 pub struct PropAnim {
     pub unknown_toggle: bool, // Used in GDRB
     pub keys: Vec<PropKeys>,
+    // Milo 25 is shared by RB1 through GDRB so keep version to write back
+    pub version: Option<u32>,
 }
 
 impl Default for PropAnim {
@@ -139,6 +177,7 @@ impl Default for PropAnim {
 
             unknown_toggle: false,
             keys: Vec::new(),
+            version: None,
         }
     }
 }
\ No newline at end of file
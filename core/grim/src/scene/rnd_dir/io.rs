@@ -0,0 +1,201 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum RndDirLoadError {
+    #[error("RndDir version {version} is not supported")]
+    RndDirVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+        8 => true, // RB1/RB2
+        9 => true, // TBRB/GDRB
+        _ => false
+    }
+}
+
+fn get_save_version<T: RndDir>(rnd_dir: &T, info: &SystemInfo) -> u32 {
+    match (info.version, rnd_dir.get_rnd_dir_version()) {
+        (25, Some(v @ (8 | 9))) => v,
+        (25, _) => 8, // RB1/RB2
+        _ => 9,       // TBRB/GDRB (RB3 not supported yet)
+    }
+}
+
+impl ObjectReadWrite for RndDirObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        load_rnd_dir(self, &mut reader, info)
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        save_rnd_dir(self, &mut writer, info)
+    }
+}
+
+pub(crate) fn load_rnd_dir<T: RndDir>(rnd_dir: &mut T, reader: &mut Box<BinaryStream>, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+    let version = reader.read_uint32()?;
+    if !is_version_supported(version) {
+        return Err(Box::new(RndDirLoadError::RndDirVersionNotSupported {
+            version
+        }));
+    }
+
+    rnd_dir.set_rnd_dir_version(Some(version));
+
+    load_obj_dir(rnd_dir, reader, info)?;
+    load_anim(rnd_dir, reader, info, false)?;
+    load_trans(rnd_dir, reader, info, false)?;
+    load_draw(rnd_dir, reader, info, false)?;
+
+    rnd_dir.set_environ(reader.read_prefixed_string()?);
+
+    if version > 8 {
+        rnd_dir.set_test_event(reader.read_prefixed_string()?);
+    }
+
+    Ok(())
+}
+
+pub(crate) fn save_rnd_dir<T: RndDir>(rnd_dir: &T, writer: &mut Box<BinaryStream>, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+    let version = get_save_version(rnd_dir, info);
+    writer.write_uint32(version)?;
+
+    save_obj_dir(rnd_dir, writer, info)?;
+    save_anim(rnd_dir, writer, info, false)?;
+    save_trans(rnd_dir, writer, info, false)?;
+    save_draw(rnd_dir, writer, info, false)?;
+
+    writer.write_prefixed_string(rnd_dir.get_environ())?;
+
+    if version > 8 {
+        writer.write_prefixed_string(rnd_dir.get_test_event())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    fn load_rnd_dir_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x08, // Version (8)
+            0x00, 0x00, 0x00, 0x10, // ObjectDir version (16)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x00, // Viewport count
+            0x00, 0x00, 0x00, 0x00, // Current viewport
+            0x01,                   // Inline proxy
+            0x00, 0x00, 0x00, 0x00, // Proxy file
+            0x00, 0x00, 0x00, 0x00, // Sub dir count
+            0x00, 0x00, 0x00, 0x04, // Anim version (4)
+            0x41, 0x20, 0x00, 0x00, // Frame
+            0x00, 0x00, 0x00, 0x00, // Rate
+            0x00, 0x00, 0x00, 0x09, // Trans version (9)
+        ];
+        data.extend_from_slice(&[0u8; 96]); // Local + world xfm
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, // Constraint
+            0x00, 0x00, 0x00, 0x00, // Target
+            0x00,                   // Preserve scale
+            0x00, 0x00, 0x00, 0x00, // Parent
+            0x00, 0x00, 0x00, 0x03, // Draw version (3)
+            0x01,                   // Showing
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // Sphere
+            0x00, 0x00, 0x00, 0x00, 0x40, 0x00, 0x00, 0x00,
+            0x00, 0x00, 0x00, 0x00, // Draw order
+            0x00, 0x00, 0x00, 0x08, b'w', b'o', b'r', b'l', b'd', b'.', b'e', b'n', // Environ
+        ]);
+        let expected = data.clone();
+
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut rnd_dir = RndDirObject::default();
+        rnd_dir.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(10.0, rnd_dir.frame);
+        assert!(rnd_dir.showing);
+        assert_eq!(2.0, rnd_dir.sphere.r);
+        assert_eq!("world.en", rnd_dir.environ);
+        assert_eq!("", rnd_dir.test_event);
+
+        let mut saved = Vec::new();
+        let mut out_stream = MemoryStream::from_vector_as_read_write(&mut saved);
+        rnd_dir.save(&mut out_stream, &info).unwrap();
+
+        assert_eq!(expected, saved);
+    }
+
+    #[rstest]
+    #[case(25, 8)]
+    #[case(28, 9)]
+    fn save_load_rnd_dir(#[case] milo_version: u32, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version: milo_version,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let rnd_dir = RndDirObject {
+            sub_dirs: vec![String::from("sub.milo")],
+            frame: 5.0,
+            draw_order: 1.0,
+            environ: String::from("world.env"),
+            test_event: String::from("test"),
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        rnd_dir.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut reader = BinaryStream::from_stream_with_endian(&mut stream, info.endian);
+        assert_eq!(expected_version, reader.read_uint32().unwrap());
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_rnd_dir = RndDirObject::default();
+        loaded_rnd_dir.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(rnd_dir.sub_dirs, loaded_rnd_dir.sub_dirs);
+        assert_eq!(rnd_dir.frame, loaded_rnd_dir.frame);
+        assert_eq!(rnd_dir.draw_order, loaded_rnd_dir.draw_order);
+        assert_eq!(rnd_dir.environ, loaded_rnd_dir.environ);
+
+        if expected_version > 8 {
+            assert_eq!(rnd_dir.test_event, loaded_rnd_dir.test_event);
+        } else {
+            assert_eq!("", loaded_rnd_dir.test_event);
+        }
+    }
+}
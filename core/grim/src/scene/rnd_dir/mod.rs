@@ -0,0 +1,57 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo(RndDir)]
+#[milo_super(Anim, Draw, ObjDir, Trans)]
+pub struct RndDirObject {}
+
+impl Default for RndDirObject {
+    fn default() -> RndDirObject {
+        RndDirObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Anim object
+            anim_objects: Vec::new(),
+            frame: 0.0,
+            rate: AnimRate::default(),
+
+            // Draw object
+            showing: true,
+            draw_objects: Vec::new(),
+            sphere: Sphere::default(),
+            draw_order: 0.0,
+            override_include_in_depth_only_pass: OverrideIncludeInDepthOnlyPass::default(),
+
+            // ObjectDir object
+            viewports: Vec::new(),
+            curr_viewport_index: 0,
+            inline_proxy: true,
+            proxy_file: String::default(),
+            sub_dirs: Vec::new(),
+            obj_dir_version: None,
+
+            // Trans object
+            local_xfm: Matrix::default(),
+            world_xfm: Matrix::default(),
+
+            trans_objects: Vec::new(),
+
+            constraint: TransConstraint::default(),
+            target: String::default(),
+
+            preserve_scale: false,
+            parent: String::default(),
+
+            // RndDir object
+            environ: String::default(),
+            test_event: String::default(),
+            rnd_dir_version: None,
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum SetLoadError {
+    #[error("Set version {version} is not supported")]
+    SetVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+        1 => true, // TBRB/GDRB
+        2 => true, // RB3
+        _ => false
+    }
+}
+
+impl ObjectReadWrite for SetObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(SetLoadError::SetVersionNotSupported {
+                version
+            }));
+        }
+
+        load_object(self, &mut reader, info)?;
+
+        self.objects.clear();
+        let object_count = reader.read_uint32()?;
+        for _ in 0..object_count {
+            self.objects.push(reader.read_prefixed_string()?);
+        }
+
+        self.props.clear();
+        if version > 1 {
+            let prop_count = reader.read_uint32()?;
+            for _ in 0..prop_count {
+                self.props.push(reader.read_prefixed_string()?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        // Sets don't exist before TBRB
+        let version = if info.version >= 28 { 2 } else { 1 };
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+
+        stream.write_uint32(self.objects.len() as u32)?;
+        for object in self.objects.iter() {
+            stream.write_prefixed_string(object)?;
+        }
+
+        if version > 1 {
+            stream.write_uint32(self.props.len() as u32)?;
+            for prop in self.props.iter() {
+                stream.write_prefixed_string(prop)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::IOEndian;
+    use crate::Platform;
+
+    #[rstest]
+    fn load_set_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x01, // Version (1)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x02, // Object count
+            0x00, 0x00, 0x00, 0x06, b'a', b'.', b'm', b'e', b's', b'h',
+            0x00, 0x00, 0x00, 0x06, b'b', b'.', b'm', b'e', b's', b'h',
+        ];
+
+        let set: SetObject = assert_round_trip_bytes(&data, &info);
+        assert_eq!(vec!["a.mesh", "b.mesh"], set.objects);
+        assert!(set.props.is_empty());
+    }
+}
@@ -0,0 +1,12 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo]
+#[derive(Default)]
+pub struct SetObject {
+    pub objects: Vec<String>,
+    pub props: Vec<String>,
+}
@@ -76,7 +76,127 @@ impl ObjectReadWrite for SynthSample {
         Ok(())
     }
 
-    fn save(&self, _stream: &mut dyn Stream, _info: &SystemInfo) -> Result<(), Box<dyn Error>> {
-        todo!()
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut writer = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = 5;
+        writer.write_uint32(version)?;
+
+        save_object(self, &mut writer, info)?;
+
+        writer.write_prefixed_string(&self.file)?;
+        writer.write_boolean(self.looped)?;
+        writer.write_int32(self.loop_start_sample)?;
+        writer.write_int32(self.loop_end_sample)?;
+
+        // Write sample data
+        let sample_data = &self.sample_data;
+        let version = if info.get_revision() < 2 { 11 } else { 13 };
+        writer.write_uint32(version)?;
+
+        writer.write_int32(sample_data.encoding)?;
+        writer.write_int32(sample_data.sample_count)?;
+        writer.write_int32(sample_data.sample_rate)?;
+
+        writer.write_uint32(sample_data.data.len() as u32)?;
+        writer.write_boolean(sample_data.unknown)?;
+        writer.write_bytes(&sample_data.data)?;
+
+        Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(24, Platform::PS2, IOEndian::Little)]
+    #[case(25, Platform::X360, IOEndian::Big)]
+    fn synth_sample_round_trip(#[case] version: u32, #[case] platform: Platform, #[case] endian: IOEndian) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian,
+        };
+
+        let sample = SynthSample {
+            name: String::from("kick.wav"),
+            file: String::from("kick.wav"),
+            looped: true,
+            loop_start_sample: 10,
+            loop_end_sample: 100,
+            sample_data: SampleData {
+                encoding: 3,
+                sample_count: 200,
+                sample_rate: 22050,
+                unknown: true,
+                data: vec![1, 2, 3, 4, 5],
+            },
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        sample.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        let mut result = SynthSample::default();
+        result.load(&mut stream, &info).unwrap();
+
+        assert_eq!(sample.file, result.file);
+        assert_eq!(sample.looped, result.looped);
+        assert_eq!(sample.loop_start_sample, result.loop_start_sample);
+        assert_eq!(sample.loop_end_sample, result.loop_end_sample);
+
+        let (expected, actual) = (&sample.sample_data, &result.sample_data);
+        assert_eq!(expected.encoding, actual.encoding);
+        assert_eq!(expected.sample_count, actual.sample_count);
+        assert_eq!(expected.sample_rate, actual.sample_rate);
+        assert_eq!(expected.unknown, actual.unknown);
+        assert_eq!(expected.data, actual.data);
+    }
+
+    #[rstest]
+    fn load_synth_sample_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x05, // Version (5)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+            0x00, 0x00, 0x00, 0x05, b'a', b'.', b'w', b'a', b'v', // File
+            0x01,                   // Looped
+            0x00, 0x00, 0x00, 0x0A, // Loop start (10)
+            0xFF, 0xFF, 0xFF, 0xFF, // Loop end (-1)
+            0x00, 0x00, 0x00, 0x0D, // Sample data version (13)
+            0x00, 0x00, 0x00, 0x03, // Encoding
+            0x00, 0x00, 0x00, 0xC8, // Sample count (200)
+            0x00, 0x00, 0x56, 0x22, // Sample rate (22050)
+            0x00, 0x00, 0x00, 0x04, // Data size
+            0x00,                   // Unknown
+            0x01, 0x02, 0x03, 0x04, // Data
+        ];
+
+        let sample: SynthSample = assert_round_trip_bytes(&data, &info);
+        assert_eq!("a.wav", sample.file);
+        assert!(sample.looped);
+        assert_eq!((10, -1), (sample.loop_start_sample, sample.loop_end_sample));
+
+        let sample_data = &sample.sample_data;
+        assert_eq!(3, sample_data.encoding);
+        assert_eq!(200, sample_data.sample_count);
+        assert_eq!(22050, sample_data.sample_rate);
+        assert!(!sample_data.unknown);
+        assert_eq!(vec![1, 2, 3, 4], sample_data.data);
+    }
+}
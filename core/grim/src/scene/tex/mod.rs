@@ -19,6 +19,12 @@ pub struct Tex {
     pub bitmap: Option<Bitmap>
 }
 
+impl Default for Tex {
+    fn default() -> Tex {
+        Tex::new()
+    }
+}
+
 impl Tex {
     pub fn new() -> Tex {
         Tex {
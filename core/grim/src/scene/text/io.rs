@@ -0,0 +1,241 @@
+use crate::io::{BinaryStream, Stream};
+use crate::scene::*;
+use crate::SystemInfo;
+use grim_traits::scene::*;
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+#[derive(Debug, ThisError)]
+pub enum TextLoadError {
+    #[error("Text version {version} is not supported")]
+    TextVersionNotSupported {
+        version: u32
+    },
+}
+
+fn is_version_supported(version: u32) -> bool {
+    match version {
+         9 => true, // GH1
+        13 => true, // GH2/GH2 360
+        16 => true, // RB1/RB2
+        19 => true, // TBRB/GDRB
+        21 => true, // RB3
+        _ => false
+    }
+}
+
+fn get_save_version(text: &TextObject, info: &SystemInfo) -> u32 {
+    match (info.version, text.version) {
+        (..=10, _) => 9, // GH1
+        (24, _) => 13, // GH2
+        (25, Some(v @ (16 | 19))) => v, // RB1/RB2, TBRB/GDRB
+        (25, _) => 16,
+        _ => 21,
+    }
+}
+
+impl ObjectReadWrite for TextObject {
+    fn load(&mut self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut reader = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = reader.read_uint32()?;
+        if !is_version_supported(version) {
+            return Err(Box::new(TextLoadError::TextVersionNotSupported {
+                version
+            }));
+        }
+
+        self.version = Some(version);
+
+        load_object(self, &mut reader, info)?;
+        load_trans(self, &mut reader, info, false)?;
+        load_draw(self, &mut reader, info, false)?;
+
+        self.font = reader.read_prefixed_string()?;
+        self.wrap_width = reader.read_float32()?;
+
+        if version > 6 {
+            self.leading = reader.read_float32()?;
+        }
+
+        self.align = reader.read_uint32()?.into();
+        self.text = reader.read_prefixed_string()?;
+
+        if version > 9 {
+            load_color4(&mut self.color, &mut reader)?;
+        }
+
+        if version > 10 {
+            self.fixed_length = reader.read_uint32()?;
+        }
+
+        if version > 13 {
+            self.italics = reader.read_float32()?;
+        }
+
+        if version > 14 {
+            self.size = reader.read_float32()?;
+        }
+
+        if version > 15 {
+            self.markup = reader.read_boolean()?;
+        }
+
+        if version > 16 {
+            self.caps_mode = reader.read_uint32()?.into();
+        }
+
+        Ok(())
+    }
+
+    fn save(&self, stream: &mut dyn Stream, info: &SystemInfo) -> Result<(), Box<dyn Error>> {
+        let mut stream = Box::new(BinaryStream::from_stream_with_endian(stream, info.endian));
+
+        let version = get_save_version(self, info);
+
+        stream.write_uint32(version)?;
+
+        save_object(self, &mut stream, info)?;
+        save_trans(self, &mut stream, info, false)?;
+        save_draw(self, &mut stream, info, false)?;
+
+        stream.write_prefixed_string(&self.font)?;
+        stream.write_float32(self.wrap_width)?;
+
+        if version > 6 {
+            stream.write_float32(self.leading)?;
+        }
+
+        stream.write_uint32(self.align as u32)?;
+        stream.write_prefixed_string(&self.text)?;
+
+        if version > 9 {
+            save_color4(&self.color, &mut stream)?;
+        }
+
+        if version > 10 {
+            stream.write_uint32(self.fixed_length)?;
+        }
+
+        if version > 13 {
+            stream.write_float32(self.italics)?;
+        }
+
+        if version > 14 {
+            stream.write_float32(self.size)?;
+        }
+
+        if version > 15 {
+            stream.write_boolean(self.markup)?;
+        }
+
+        if version > 16 {
+            stream.write_uint32(self.caps_mode as u32)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream, SeekFrom};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(24, Platform::PS2, None, 13)]
+    #[case(25, Platform::X360, None, 16)]
+    #[case(25, Platform::X360, Some(19), 19)]
+    #[case(28, Platform::PS3, None, 21)]
+    fn save_load_text(#[case] version: u32, #[case] platform: Platform, #[case] text_version: Option<u32>, #[case] expected_version: u32) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian: IOEndian::Big,
+        };
+
+        let text = TextObject {
+            font: String::from("default.font"),
+            wrap_width: 200.0,
+            align: TextAlignment::kMiddleCenter,
+            text: String::from("Hello world"),
+            color: Color4 { r: 1.0, g: 0.0, b: 0.0, a: 0.5 },
+            version: text_version,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        text.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_text = TextObject::default();
+        loaded_text.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+        assert_eq!(Some(expected_version), loaded_text.version);
+
+        assert_eq!(text.font, loaded_text.font);
+        assert_eq!(text.wrap_width, loaded_text.wrap_width);
+        assert_eq!(text.align as u32, loaded_text.align as u32);
+        assert_eq!(text.text, loaded_text.text);
+        assert_eq!(text.color.a, loaded_text.color.a);
+    }
+
+    #[rstest]
+    fn load_text_bytes() {
+        let info = SystemInfo {
+            version: 25,
+            platform: Platform::X360,
+            endian: IOEndian::Big,
+        };
+
+        let mut data: Vec<u8> = vec![
+            0x00, 0x00, 0x00, 0x10, // Version (16)
+            0x00, 0x00, 0x00, 0x02, // Revision
+            0x00, 0x00, 0x00, 0x00, // Type
+            0x00,                   // Props
+            0x00, 0x00, 0x00, 0x00, // Note
+        ];
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x09]); // Trans version (9)
+        data.extend_from_slice(&[0u8; 96]); // Local + world xfm
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x00, // Constraint
+            0x00, 0x00, 0x00, 0x00, // Target
+            0x00,                   // Preserve scale
+            0x00, 0x00, 0x00, 0x00, // Parent
+        ]);
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x03, // Draw version (3)
+            0x01,                   // Showing
+        ]);
+        data.extend_from_slice(&[0u8; 16]); // Sphere
+        data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00]); // Draw order
+        data.extend_from_slice(&[
+            0x00, 0x00, 0x00, 0x06, b'a', b'.', b'f', b'o', b'n', b't', // Font
+            0x42, 0xC8, 0x00, 0x00, // Wrap width (100.0)
+            0x3F, 0x80, 0x00, 0x00, // Leading (1.0)
+            0x00, 0x00, 0x00, 0x22, // Align (middle center)
+            0x00, 0x00, 0x00, 0x02, b'h', b'i', // Text
+            0x3F, 0x80, 0x00, 0x00, 0x3F, 0x00, 0x00, 0x00, 0x3E, 0x80, 0x00, 0x00, 0x3F, 0x80, 0x00, 0x00, // Color
+            0x00, 0x00, 0x00, 0x0A, // Fixed length
+            0x3F, 0x00, 0x00, 0x00, // Italics (0.5)
+            0x41, 0x20, 0x00, 0x00, // Size (10.0)
+            0x01,                   // Markup
+        ]);
+
+        let text: TextObject = assert_round_trip_bytes(&data, &info);
+        assert!(text.showing);
+        assert_eq!("a.font", text.font);
+        assert_eq!((100.0, 1.0), (text.wrap_width, text.leading));
+        assert_eq!(TextAlignment::kMiddleCenter as u32, text.align as u32);
+        assert_eq!("hi", text.text);
+        assert_eq!((1.0, 0.5, 0.25, 1.0), (text.color.r, text.color.g, text.color.b, text.color.a));
+        assert_eq!(10, text.fixed_length);
+        assert_eq!((0.5, 10.0), (text.italics, text.size));
+        assert!(text.markup);
+    }
+}
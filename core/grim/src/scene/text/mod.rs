@@ -0,0 +1,126 @@
+mod io;
+
+use grim_macros::*;
+use grim_traits::scene::*;
+pub use io::*;
+
+#[milo]
+#[milo_super(Draw, Trans)]
+pub struct TextObject {
+    pub font: String,
+    pub wrap_width: f32,
+    pub leading: f32,
+    pub align: TextAlignment,
+    pub text: String,
+    pub color: Color4,
+
+    pub fixed_length: u32,
+    pub italics: f32,
+    pub size: f32,
+    pub markup: bool,
+    pub caps_mode: CapsMode,
+
+    pub version: Option<u32>,
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+#[repr(u32)]
+pub enum TextAlignment {
+    #[default]
+    kTopLeft = 0x11,
+    kTopCenter = 0x12,
+    kTopRight = 0x14,
+    kMiddleLeft = 0x21,
+    kMiddleCenter = 0x22,
+    kMiddleRight = 0x24,
+    kBottomLeft = 0x41,
+    kBottomCenter = 0x42,
+    kBottomRight = 0x44,
+}
+
+impl From<u32> for TextAlignment {
+    fn from(num: u32) -> TextAlignment {
+        match num {
+            0x11 => TextAlignment::kTopLeft,
+            0x12 => TextAlignment::kTopCenter,
+            0x14 => TextAlignment::kTopRight,
+            0x21 => TextAlignment::kMiddleLeft,
+            0x22 => TextAlignment::kMiddleCenter,
+            0x24 => TextAlignment::kMiddleRight,
+            0x41 => TextAlignment::kBottomLeft,
+            0x42 => TextAlignment::kBottomCenter,
+            0x44 => TextAlignment::kBottomRight,
+            // Default
+            _ => TextAlignment::default(),
+        }
+    }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone, Default)]
+#[repr(u32)]
+pub enum CapsMode {
+    #[default]
+    kCapsModeNone,
+    kForceLower,
+    kForceUpper,
+}
+
+impl From<u32> for CapsMode {
+    fn from(num: u32) -> CapsMode {
+        match num {
+            0 => CapsMode::kCapsModeNone,
+            1 => CapsMode::kForceLower,
+            2 => CapsMode::kForceUpper,
+            // Default
+            _ => CapsMode::default(),
+        }
+    }
+}
+
+impl Default for TextObject {
+    fn default() -> TextObject {
+        TextObject {
+            // Base object
+            name: String::default(),
+            type2: String::default(),
+            note: String::default(),
+
+            // Draw object
+            showing: true,
+            draw_objects: Vec::new(),
+            sphere: Sphere::default(),
+            draw_order: 0.0,
+            override_include_in_depth_only_pass: OverrideIncludeInDepthOnlyPass::default(),
+
+            // Trans object
+            local_xfm: Matrix::default(),
+            world_xfm: Matrix::default(),
+
+            trans_objects: Vec::new(),
+
+            constraint: TransConstraint::default(),
+            target: String::default(),
+
+            preserve_scale: false,
+            parent: String::default(),
+
+            // Text object
+            font: String::default(),
+            wrap_width: 0.0,
+            leading: 1.0,
+            align: TextAlignment::default(),
+            text: String::default(),
+            color: Color4::white(),
+
+            fixed_length: 0,
+            italics: 0.0,
+            size: 1.0,
+            markup: false,
+            caps_mode: CapsMode::default(),
+
+            version: None,
+        }
+    }
+}
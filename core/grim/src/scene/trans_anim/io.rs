@@ -13,6 +13,8 @@ pub enum TransAnimReadError {
     },
 }
 
+// TODO: Support legacy versions (0-3, 5) once there are sample files to verify
+//  key layouts and trans_anim_owner/follow_path variants against
 fn is_version_supported(version: u32) -> bool {
     match version {
         4 | 6 | 7 => true, // KRPAI, GH2, TBRB
@@ -28,7 +30,6 @@ impl ObjectReadWrite for TransAnim {
 
         // If not valid, return unsupported error
         if !is_version_supported(version) {
-            // TODO: Switch to custom error
             return Err(Box::new(TransAnimReadError::TransAnimVersionNotSupported {
                 version
             }));
@@ -74,41 +75,17 @@ impl ObjectReadWrite for TransAnim {
         self.trans_keys.clear();
         self.scale_keys.clear();
 
-        if version != 2 {
-            self.rot_keys = load_keys_quat(&mut reader)?;
-            self.trans_keys = load_keys_vector3(&mut reader)?;
-        }
+        self.rot_keys = load_keys_quat(&mut reader)?;
+        self.trans_keys = load_keys_vector3(&mut reader)?;
 
         self.trans_anim_owner = reader.read_prefixed_string()?;
-
-        if version < 4 {
-            // TODO: Parse bitfield?
-            self.trans_spline = false;
-            reader.seek(SeekFrom::Current(4))?;
-        } else {
-            self.trans_spline = reader.read_boolean()?;
-        }
-
+        self.trans_spline = reader.read_boolean()?;
         self.repeat_trans = reader.read_boolean()?;
 
-        if version < 4 {
-            self.scale_spline = false;
-            todo!()
-        } else {
-            self.scale_keys = load_keys_vector3(&mut reader)?;
-            self.scale_spline = reader.read_boolean()?;
-        }
-
-        if version < 2 {
-            self.follow_path = false;
-            todo!()
-        } else {
-            self.follow_path = reader.read_boolean()?;
-        }
-
-        if version > 3 {
-            self.rot_slerp = reader.read_boolean()?;
-        }
+        self.scale_keys = load_keys_vector3(&mut reader)?;
+        self.scale_spline = reader.read_boolean()?;
+        self.follow_path = reader.read_boolean()?;
+        self.rot_slerp = reader.read_boolean()?;
 
         if version > 6 {
             self.rot_spline = reader.read_boolean()?;
@@ -226,4 +203,84 @@ fn save_keys_quat(keys: &[AnimEvent<Quat>], writer: &mut Box<BinaryStream>) -> R
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+    use crate::io::{IOEndian, MemoryStream};
+    use crate::Platform;
+
+    #[rstest]
+    #[case(10, Platform::PS2, IOEndian::Little)]
+    #[case(24, Platform::PS2, IOEndian::Little)]
+    #[case(25, Platform::X360, IOEndian::Big)]
+    fn save_load_trans_anim(#[case] version: u32, #[case] platform: Platform, #[case] endian: IOEndian) {
+        let info = SystemInfo {
+            version,
+            platform,
+            endian,
+        };
+
+        let anim = TransAnim {
+            trans_object: String::from("bone.trans"),
+            rot_keys: vec![
+                AnimEvent { value: Quat { x: 0.0, y: 0.0, z: 0.0, w: 1.0 }, pos: 0.0 },
+                AnimEvent { value: Quat { x: 0.0, y: 1.0, z: 0.0, w: 0.0 }, pos: 10.0 },
+            ],
+            trans_keys: vec![
+                AnimEvent { value: Vector3 { x: 1.0, y: 2.0, z: 3.0 }, pos: 5.0 },
+            ],
+            scale_keys: vec![
+                AnimEvent { value: Vector3 { x: 1.0, y: 1.0, z: 1.0 }, pos: 0.0 },
+            ],
+            trans_spline: true,
+            repeat_trans: true,
+            rot_slerp: true,
+            ..Default::default()
+        };
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        anim.save(&mut stream, &info).unwrap();
+
+        stream.seek(SeekFrom::Start(0)).unwrap();
+
+        let mut loaded_anim = TransAnim::default();
+        loaded_anim.load(&mut stream, &info).unwrap();
+
+        assert_eq!(stream.pos(), stream.len().unwrap() as u64);
+
+        assert_eq!(anim.trans_object, loaded_anim.trans_object);
+        assert_eq!(anim.rot_keys.len(), loaded_anim.rot_keys.len());
+        assert_eq!(anim.rot_keys[1].value.y, loaded_anim.rot_keys[1].value.y);
+        assert_eq!(anim.trans_keys[0].value.z, loaded_anim.trans_keys[0].value.z);
+        assert_eq!(anim.trans_keys[0].pos, loaded_anim.trans_keys[0].pos);
+        assert_eq!(anim.scale_keys.len(), loaded_anim.scale_keys.len());
+        assert_eq!(anim.trans_spline, loaded_anim.trans_spline);
+        assert_eq!(anim.repeat_trans, loaded_anim.repeat_trans);
+        assert_eq!(anim.rot_slerp, loaded_anim.rot_slerp);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(2)]
+    #[case(3)]
+    #[case(5)]
+    fn load_trans_anim_unsupported_version(#[case] version: u32) {
+        let info = SystemInfo {
+            version: 10,
+            platform: Platform::PS2,
+            endian: IOEndian::Little,
+        };
+
+        let mut data = version.to_le_bytes().to_vec();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+
+        let mut anim = TransAnim::default();
+        let result = anim.load(&mut stream, &info);
+
+        assert!(result.is_err_and(|e| e.is::<TransAnimReadError>()));
+    }
+}
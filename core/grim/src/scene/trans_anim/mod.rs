@@ -1,4 +1,5 @@
 mod io;
+pub(super) mod sample;
 
 use super::{Quat, Vector3};
 use grim_macros::*;
@@ -0,0 +1,393 @@
+use super::{AnimEvent, TransAnim};
+use grim_traits::scene::*;
+
+type Vec3 = [f32; 3];
+type Vec4 = [f32; 4];
+
+fn to_vec3(v: &Vector3) -> Vec3 {
+    [v.x, v.y, v.z]
+}
+
+fn from_vec3(v: Vec3) -> Vector3 {
+    Vector3 { x: v[0], y: v[1], z: v[2] }
+}
+
+fn to_vec4(q: &Quat) -> Vec4 {
+    [q.x, q.y, q.z, q.w]
+}
+
+fn from_vec4(q: Vec4) -> Quat {
+    Quat { x: q[0], y: q[1], z: q[2], w: q[3] }
+}
+
+pub(crate) fn lerp<const N: usize>(a: [f32; N], b: [f32; N], t: f32) -> [f32; N] {
+    std::array::from_fn(|i| a[i] + (b[i] - a[i]) * t)
+}
+
+pub(crate) fn catmull_rom<const N: usize>(p0: [f32; N], p1: [f32; N], p2: [f32; N], p3: [f32; N], t: f32) -> [f32; N] {
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    std::array::from_fn(|i| 0.5 * ((2.0 * p1[i])
+        + (p2[i] - p0[i]) * t
+        + (2.0 * p0[i] - 5.0 * p1[i] + 4.0 * p2[i] - p3[i]) * t2
+        + (3.0 * p1[i] - p0[i] - 3.0 * p2[i] + p3[i]) * t3))
+}
+
+fn dot4(a: Vec4, b: Vec4) -> f32 {
+    (a[0] * b[0]) + (a[1] * b[1]) + (a[2] * b[2]) + (a[3] * b[3])
+}
+
+pub(crate) fn normalize4(q: Vec4) -> Vec4 {
+    let len = dot4(q, q).sqrt();
+
+    match len > f32::EPSILON {
+        true => [q[0] / len, q[1] / len, q[2] / len, q[3] / len],
+        false => [0.0, 0.0, 0.0, 1.0],
+    }
+}
+
+fn negate4(q: Vec4) -> Vec4 {
+    [-q[0], -q[1], -q[2], -q[3]]
+}
+
+fn conjugate(q: Vec4) -> Vec4 {
+    [-q[0], -q[1], -q[2], q[3]]
+}
+
+fn mul_quat(a: Vec4, b: Vec4) -> Vec4 {
+    [
+        (a[3] * b[0]) + (a[0] * b[3]) + (a[1] * b[2]) - (a[2] * b[1]),
+        (a[3] * b[1]) - (a[0] * b[2]) + (a[1] * b[3]) + (a[2] * b[0]),
+        (a[3] * b[2]) + (a[0] * b[1]) - (a[1] * b[0]) + (a[2] * b[3]),
+        (a[3] * b[3]) - (a[0] * b[0]) - (a[1] * b[1]) - (a[2] * b[2]),
+    ]
+}
+
+fn log_quat(q: Vec4) -> Vec3 {
+    let v_len = ((q[0] * q[0]) + (q[1] * q[1]) + (q[2] * q[2])).sqrt();
+
+    if v_len <= f32::EPSILON {
+        return [0.0; 3];
+    }
+
+    let angle = v_len.atan2(q[3]);
+    let s = angle / v_len;
+
+    [q[0] * s, q[1] * s, q[2] * s]
+}
+
+fn exp_quat(v: Vec3) -> Vec4 {
+    let angle = ((v[0] * v[0]) + (v[1] * v[1]) + (v[2] * v[2])).sqrt();
+
+    if angle <= f32::EPSILON {
+        return [v[0], v[1], v[2], 1.0];
+    }
+
+    let s = angle.sin() / angle;
+    [v[0] * s, v[1] * s, v[2] * s, angle.cos()]
+}
+
+fn nlerp(a: Vec4, b: Vec4, t: f32) -> Vec4 {
+    // Take shortest path
+    let b = if dot4(a, b) < 0.0 { negate4(b) } else { b };
+
+    normalize4([
+        a[0] + (b[0] - a[0]) * t,
+        a[1] + (b[1] - a[1]) * t,
+        a[2] + (b[2] - a[2]) * t,
+        a[3] + (b[3] - a[3]) * t,
+    ])
+}
+
+pub(crate) fn slerp(a: Vec4, b: Vec4, t: f32) -> Vec4 {
+    let mut d = dot4(a, b);
+    let mut b = b;
+
+    // Take shortest path
+    if d < 0.0 {
+        b = negate4(b);
+        d = -d;
+    }
+
+    if d > 0.9995 {
+        // Too close, avoid divide by zero
+        return nlerp(a, b, t);
+    }
+
+    let theta = d.acos();
+    let sin_theta = theta.sin();
+
+    let wa = ((1.0 - t) * theta).sin() / sin_theta;
+    let wb = (t * theta).sin() / sin_theta;
+
+    [
+        (a[0] * wa) + (b[0] * wb),
+        (a[1] * wa) + (b[1] * wb),
+        (a[2] * wa) + (b[2] * wb),
+        (a[3] * wa) + (b[3] * wb),
+    ]
+}
+
+fn squad_control(prev: Vec4, curr: Vec4, next: Vec4) -> Vec4 {
+    let curr_inv = conjugate(curr);
+
+    let log_next = log_quat(mul_quat(curr_inv, next));
+    let log_prev = log_quat(mul_quat(curr_inv, prev));
+
+    let sum = [
+        -(log_next[0] + log_prev[0]) * 0.25,
+        -(log_next[1] + log_prev[1]) * 0.25,
+        -(log_next[2] + log_prev[2]) * 0.25,
+    ];
+
+    mul_quat(curr, exp_quat(sum))
+}
+
+fn squad(q0: Vec4, q1: Vec4, q2: Vec4, q3: Vec4, t: f32) -> Vec4 {
+    // Align neighbours to same hemisphere
+    let q0 = if dot4(q0, q1) < 0.0 { negate4(q0) } else { q0 };
+    let q2 = if dot4(q1, q2) < 0.0 { negate4(q2) } else { q2 };
+    let q3 = if dot4(q2, q3) < 0.0 { negate4(q3) } else { q3 };
+
+    let s1 = squad_control(q0, q1, q2);
+    let s2 = squad_control(q1, q2, q3);
+
+    let a = slerp(q1, q2, t);
+    let b = slerp(s1, s2, t);
+
+    normalize4(slerp(a, b, 2.0 * t * (1.0 - t)))
+}
+
+// Returns indices of surrounding keys + blend amount
+pub(crate) fn find_keys<T>(keys: &[T], frame: f32, get_pos: impl Fn(&T) -> f32) -> (usize, usize, f32) {
+    let last = keys.len() - 1;
+
+    if frame <= get_pos(&keys[0]) {
+        return (0, 0, 0.0);
+    } else if frame >= get_pos(&keys[last]) {
+        return (last, last, 0.0);
+    }
+
+    let next = keys.partition_point(|k| get_pos(k) <= frame).min(last);
+    let prev = next - 1;
+
+    let length = get_pos(&keys[next]) - get_pos(&keys[prev]);
+    let t = match length > f32::EPSILON {
+        true => (frame - get_pos(&keys[prev])) / length,
+        false => 0.0,
+    };
+
+    (prev, next, t)
+}
+
+fn sample_vector3(keys: &[AnimEvent<Vector3>], frame: f32, spline: bool) -> Option<Vector3> {
+    if keys.is_empty() {
+        return None;
+    }
+
+    let (prev, next, t) = find_keys(keys, frame, |k| k.pos);
+
+    let p1 = to_vec3(&keys[prev].value);
+    let p2 = to_vec3(&keys[next].value);
+
+    if !spline || prev == next {
+        return Some(from_vec3(lerp(p1, p2, t)));
+    }
+
+    let p0 = to_vec3(&keys[prev.saturating_sub(1)].value);
+    let p3 = to_vec3(&keys[(next + 1).min(keys.len() - 1)].value);
+
+    Some(from_vec3(catmull_rom(p0, p1, p2, p3, t)))
+}
+
+impl TransAnim {
+    pub fn sample_trans(&self, frame: f32) -> Option<Vector3> {
+        let keys = &self.trans_keys;
+
+        if !self.repeat_trans || keys.len() < 2 {
+            return sample_vector3(keys, frame, self.trans_spline);
+        }
+
+        let first = &keys[0];
+        let last = &keys[keys.len() - 1];
+
+        let length = last.pos - first.pos;
+        if length <= f32::EPSILON {
+            return sample_vector3(keys, frame, self.trans_spline);
+        }
+
+        // Wrap frame and offset by total translation per loop
+        let loops = ((frame - first.pos) / length).floor();
+        let local_frame = frame - (loops * length);
+
+        let delta = [
+            (last.value.x - first.value.x) * loops,
+            (last.value.y - first.value.y) * loops,
+            (last.value.z - first.value.z) * loops,
+        ];
+
+        sample_vector3(keys, local_frame, self.trans_spline)
+            .map(|v| Vector3 {
+                x: v.x + delta[0],
+                y: v.y + delta[1],
+                z: v.z + delta[2],
+            })
+    }
+
+    pub fn sample_rot(&self, frame: f32) -> Option<Quat> {
+        let keys = &self.rot_keys;
+
+        if keys.is_empty() {
+            return None;
+        }
+
+        let (prev, next, t) = find_keys(keys, frame, |k| k.pos);
+
+        let q1 = to_vec4(&keys[prev].value);
+        let q2 = to_vec4(&keys[next].value);
+
+        if prev == next {
+            return Some(from_vec4(normalize4(q1)));
+        }
+
+        let q = if self.rot_spline {
+            let q0 = to_vec4(&keys[prev.saturating_sub(1)].value);
+            let q3 = to_vec4(&keys[(next + 1).min(keys.len() - 1)].value);
+
+            squad(q0, q1, q2, q3, t)
+        } else if self.rot_slerp {
+            slerp(q1, q2, t)
+        } else {
+            nlerp(q1, q2, t)
+        };
+
+        Some(from_vec4(normalize4(q)))
+    }
+
+    pub fn sample_scale(&self, frame: f32) -> Option<Vector3> {
+        sample_vector3(&self.scale_keys, frame, self.scale_spline)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    fn vec3_key(x: f32, y: f32, z: f32, pos: f32) -> AnimEvent<Vector3> {
+        AnimEvent {
+            value: Vector3 { x, y, z },
+            pos
+        }
+    }
+
+    fn quat_key(x: f32, y: f32, z: f32, w: f32, pos: f32) -> AnimEvent<Quat> {
+        AnimEvent {
+            value: Quat { x, y, z, w },
+            pos
+        }
+    }
+
+    fn assert_close(expected: f32, actual: f32) {
+        assert!((expected - actual).abs() < 0.0001, "Expected {expected}, got {actual}");
+    }
+
+    #[rstest]
+    #[case(-5.0, 0.0)]
+    #[case(0.0, 0.0)]
+    #[case(5.0, 5.0)]
+    #[case(10.0, 10.0)]
+    #[case(15.0, 10.0)]
+    fn sample_trans_linear(#[case] frame: f32, #[case] expected_x: f32) {
+        let anim = TransAnim {
+            trans_keys: vec![
+                vec3_key(0.0, 0.0, 0.0, 0.0),
+                vec3_key(10.0, 0.0, 0.0, 10.0),
+            ],
+            ..Default::default()
+        };
+
+        let trans = anim.sample_trans(frame).unwrap();
+        assert_close(expected_x, trans.x);
+    }
+
+    #[rstest]
+    #[case(5.0, 5.0)]
+    #[case(15.0, 15.0)]
+    #[case(25.0, 25.0)]
+    #[case(-5.0, -5.0)]
+    fn sample_trans_repeat(#[case] frame: f32, #[case] expected_x: f32) {
+        let anim = TransAnim {
+            trans_keys: vec![
+                vec3_key(0.0, 0.0, 0.0, 0.0),
+                vec3_key(10.0, 0.0, 0.0, 10.0),
+            ],
+            repeat_trans: true,
+            ..Default::default()
+        };
+
+        let trans = anim.sample_trans(frame).unwrap();
+        assert_close(expected_x, trans.x);
+    }
+
+    #[rstest]
+    fn sample_trans_spline_passes_through_keys() {
+        let anim = TransAnim {
+            trans_keys: vec![
+                vec3_key(0.0, 0.0, 0.0, 0.0),
+                vec3_key(1.0, 4.0, 0.0, 10.0),
+                vec3_key(2.0, 0.0, 0.0, 20.0),
+            ],
+            trans_spline: true,
+            ..Default::default()
+        };
+
+        for key in anim.trans_keys.iter() {
+            let trans = anim.sample_trans(key.pos).unwrap();
+
+            assert_close(key.value.x, trans.x);
+            assert_close(key.value.y, trans.y);
+        }
+
+        // Curve should overshoot linear midpoint
+        let trans = anim.sample_trans(15.0).unwrap();
+        assert!(trans.y > 2.0);
+    }
+
+    #[rstest]
+    #[case(false, false)]
+    #[case(true, false)]
+    #[case(false, true)]
+    fn sample_rot_halfway(#[case] rot_slerp: bool, #[case] rot_spline: bool) {
+        let half = std::f32::consts::FRAC_1_SQRT_2;
+
+        let anim = TransAnim {
+            rot_keys: vec![
+                quat_key(0.0, 0.0, 0.0, 1.0, 0.0),
+                quat_key(0.0, 0.0, half, half, 10.0),
+            ],
+            rot_slerp,
+            rot_spline,
+            ..Default::default()
+        };
+
+        // Should be 45 degrees around z
+        let rot = anim.sample_rot(5.0).unwrap();
+        let expected = (std::f32::consts::PI / 8.0).sin();
+
+        assert_close(0.0, rot.x);
+        assert_close(0.0, rot.y);
+        assert_close(expected, rot.z);
+        assert_close((std::f32::consts::PI / 8.0).cos(), rot.w);
+    }
+
+    #[rstest]
+    fn sample_no_keys() {
+        let anim = TransAnim::default();
+
+        assert!(anim.sample_trans(0.0).is_none());
+        assert!(anim.sample_rot(0.0).is_none());
+        assert!(anim.sample_scale(0.0).is_none());
+    }
+}
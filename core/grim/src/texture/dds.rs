@@ -0,0 +1,325 @@
+use crate::io::{BinaryStream, IOEndian, MemoryStream, SeekFrom, Stream};
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+const DDS_MAGIC: u32 = 0x20534444; // "DDS "
+const DDS_HEADER_SIZE: u32 = 124;
+const DDS_PIXEL_FORMAT_SIZE: u32 = 32;
+
+// Header flags
+const DDSD_CAPS: u32 = 0x1;
+const DDSD_HEIGHT: u32 = 0x2;
+const DDSD_WIDTH: u32 = 0x4;
+const DDSD_PITCH: u32 = 0x8;
+const DDSD_PIXELFORMAT: u32 = 0x1000;
+const DDSD_MIPMAPCOUNT: u32 = 0x20000;
+const DDSD_LINEARSIZE: u32 = 0x80000;
+
+// Pixel format flags
+const DDPF_ALPHAPIXELS: u32 = 0x1;
+const DDPF_FOURCC: u32 = 0x4;
+const DDPF_RGB: u32 = 0x40;
+
+// Caps
+const DDSCAPS_COMPLEX: u32 = 0x8;
+const DDSCAPS_TEXTURE: u32 = 0x1000;
+const DDSCAPS_MIPMAP: u32 = 0x400000;
+const DDSCAPS2_CUBEMAP: u32 = 0x200;
+const DDSCAPS2_CUBEMAP_ALL_FACES: u32 = 0xFE00;
+
+// DX10 header formats
+const DXGI_FORMAT_R8G8B8A8_UNORM: u32 = 28;
+const DXGI_FORMAT_BC1_UNORM: u32 = 71;
+const DXGI_FORMAT_BC3_UNORM: u32 = 77;
+const DXGI_FORMAT_BC5_UNORM: u32 = 83;
+const D3D10_RESOURCE_MISC_TEXTURECUBE: u32 = 0x4;
+
+#[derive(Debug, ThisError)]
+pub enum DdsError {
+    #[error("Invalid DDS magic of {magic:#010x}")]
+    InvalidMagic {
+        magic: u32
+    },
+    #[error("Unsupported DDS pixel format (four cc: {four_cc:?}, dxgi: {dxgi_format})")]
+    UnsupportedFormat {
+        four_cc: [u8; 4],
+        dxgi_format: u32,
+    },
+    #[error("DDS data is {actual} bytes but expected at least {expected}")]
+    NotEnoughData {
+        actual: usize,
+        expected: usize,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DdsFormat {
+    RGBA8,
+    BC1,
+    BC3,
+    BC5,
+}
+
+impl DdsFormat {
+    pub fn get_bpp(&self) -> u32 {
+        match self {
+            DdsFormat::RGBA8 => 32,
+            DdsFormat::BC1 => 4,
+            DdsFormat::BC3 | DdsFormat::BC5 => 8,
+        }
+    }
+
+    pub fn is_compressed(&self) -> bool {
+        !self.eq(&DdsFormat::RGBA8)
+    }
+
+    pub fn calc_image_size(&self, width: u32, height: u32, mip_maps: u32) -> usize {
+        let block_size = match self {
+            DdsFormat::RGBA8 => None,
+            DdsFormat::BC1 => Some(8),
+            DdsFormat::BC3 | DdsFormat::BC5 => Some(16),
+        };
+
+        (0..=mip_maps)
+            .map(|i| {
+                let w = (width >> i).max(1) as usize;
+                let h = (height >> i).max(1) as usize;
+
+                match block_size {
+                    Some(size) => ((w + 3) >> 2) * ((h + 3) >> 2) * size,
+                    None => w * h * 4,
+                }
+            })
+            .sum()
+    }
+
+    fn from_four_cc(four_cc: &[u8; 4]) -> Option<DdsFormat> {
+        match four_cc {
+            b"DXT1" => Some(DdsFormat::BC1),
+            b"DXT5" => Some(DdsFormat::BC3),
+            b"ATI2" | b"BC5U" => Some(DdsFormat::BC5),
+            _ => None,
+        }
+    }
+
+    fn from_dxgi_format(dxgi_format: u32) -> Option<DdsFormat> {
+        match dxgi_format {
+            DXGI_FORMAT_R8G8B8A8_UNORM => Some(DdsFormat::RGBA8),
+            DXGI_FORMAT_BC1_UNORM => Some(DdsFormat::BC1),
+            DXGI_FORMAT_BC3_UNORM => Some(DdsFormat::BC3),
+            DXGI_FORMAT_BC5_UNORM => Some(DdsFormat::BC5),
+            _ => None,
+        }
+    }
+
+    fn get_four_cc(&self) -> Option<&'static [u8; 4]> {
+        match self {
+            DdsFormat::RGBA8 => None,
+            DdsFormat::BC1 => Some(b"DXT1"),
+            DdsFormat::BC3 => Some(b"DXT5"),
+            DdsFormat::BC5 => Some(b"ATI2"),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct DdsImage {
+    pub width: u32,
+    pub height: u32,
+    pub mip_maps: u32, // Additional mip levels (0 = base image only)
+    pub format: DdsFormat,
+    pub is_cubemap: bool,
+    pub data: Vec<u8>, // Every face w/ mip maps, in order
+}
+
+impl DdsImage {
+    pub fn from_bytes(data: &[u8]) -> Result<DdsImage, Box<dyn Error>> {
+        let mut stream = MemoryStream::from_slice_as_read(data);
+        let mut reader = BinaryStream::from_stream_with_endian(&mut stream, IOEndian::Little);
+
+        let magic = reader.read_uint32()?;
+        if magic != DDS_MAGIC {
+            return Err(Box::new(DdsError::InvalidMagic { magic }));
+        }
+
+        reader.read_uint32()?; // Header size
+        let flags = reader.read_uint32()?;
+        let height = reader.read_uint32()?;
+        let width = reader.read_uint32()?;
+        reader.read_uint32()?; // Pitch or linear size
+        reader.read_uint32()?; // Depth
+        let mip_count = reader.read_uint32()?;
+        reader.seek(SeekFrom::Current(44))?; // Reserved
+
+        // Pixel format
+        reader.read_uint32()?; // Pixel format size
+        let pf_flags = reader.read_uint32()?;
+
+        let mut four_cc = [0u8; 4];
+        reader.read_bytes_into_slice(&mut four_cc)?;
+
+        let bit_count = reader.read_uint32()?;
+        let red_mask = reader.read_uint32()?;
+        reader.seek(SeekFrom::Current(12))?; // Green, blue + alpha masks
+
+        reader.read_uint32()?; // Caps
+        let caps2 = reader.read_uint32()?;
+        reader.seek(SeekFrom::Current(12))?; // Caps 3, caps 4 + reserved
+
+        let mut dxgi_format = 0;
+        let mut is_cubemap = (caps2 & DDSCAPS2_CUBEMAP) != 0;
+
+        let format = if (pf_flags & DDPF_FOURCC) != 0 && four_cc.eq(b"DX10") {
+            // Read extended header
+            dxgi_format = reader.read_uint32()?;
+            reader.read_uint32()?; // Resource dimension
+            let misc_flags = reader.read_uint32()?;
+            reader.read_uint32()?; // Array size
+            reader.read_uint32()?; // Misc flags 2
+
+            is_cubemap |= (misc_flags & D3D10_RESOURCE_MISC_TEXTURECUBE) != 0;
+            DdsFormat::from_dxgi_format(dxgi_format)
+        } else if (pf_flags & DDPF_FOURCC) != 0 {
+            DdsFormat::from_four_cc(&four_cc)
+        } else if (pf_flags & DDPF_RGB) != 0 && bit_count == 32 && red_mask == 0x0000_00FF {
+            Some(DdsFormat::RGBA8)
+        } else {
+            None
+        };
+
+        let Some(format) = format else {
+            return Err(Box::new(DdsError::UnsupportedFormat { four_cc, dxgi_format }));
+        };
+
+        let mip_maps = match (flags & DDSD_MIPMAPCOUNT) != 0 {
+            true => mip_count.max(1) - 1,
+            false => 0,
+        };
+
+        // Validate image size
+        let face_count = if is_cubemap { 6 } else { 1 };
+        let expected = format.calc_image_size(width, height, mip_maps) * face_count;
+        let actual = reader.len()? - reader.pos() as usize;
+
+        if actual < expected {
+            return Err(Box::new(DdsError::NotEnoughData { actual, expected }));
+        }
+
+        Ok(DdsImage {
+            width,
+            height,
+            mip_maps,
+            format,
+            is_cubemap,
+            data: reader.read_bytes(expected)?,
+        })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        let mut writer = BinaryStream::from_stream_with_endian(&mut stream, IOEndian::Little);
+
+        let DdsImage { width, height, mip_maps, format, is_cubemap, .. } = self;
+
+        let mut flags = DDSD_CAPS | DDSD_HEIGHT | DDSD_WIDTH | DDSD_PIXELFORMAT;
+        let pitch_or_linear_size = if format.is_compressed() {
+            flags |= DDSD_LINEARSIZE;
+            ((*width).max(4) * (*height).max(4) * format.get_bpp()) / 8
+        } else {
+            flags |= DDSD_PITCH;
+            (*width * format.get_bpp()) / 8
+        };
+
+        let mut caps = DDSCAPS_TEXTURE;
+        if *mip_maps > 0 {
+            flags |= DDSD_MIPMAPCOUNT;
+            caps |= DDSCAPS_COMPLEX | DDSCAPS_MIPMAP;
+        }
+
+        let mut caps2 = 0;
+        if *is_cubemap {
+            caps |= DDSCAPS_COMPLEX;
+            caps2 |= DDSCAPS2_CUBEMAP_ALL_FACES;
+        }
+
+        writer.write_uint32(DDS_MAGIC)?;
+
+        writer.write_uint32(DDS_HEADER_SIZE)?;
+        writer.write_uint32(flags)?;
+        writer.write_uint32(*height)?;
+        writer.write_uint32(*width)?;
+        writer.write_uint32(pitch_or_linear_size)?;
+        writer.write_uint32(0)?; // Depth
+        writer.write_uint32(*mip_maps + 1)?;
+        writer.write_bytes(&[0u8; 44])?; // Reserved
+
+        // Pixel format
+        writer.write_uint32(DDS_PIXEL_FORMAT_SIZE)?;
+        match format.get_four_cc() {
+            Some(four_cc) => {
+                writer.write_uint32(DDPF_FOURCC)?;
+                writer.write_bytes(four_cc)?;
+                writer.write_bytes(&[0u8; 20])?; // Bit count + masks
+            },
+            None => {
+                writer.write_uint32(DDPF_RGB | DDPF_ALPHAPIXELS)?;
+                writer.write_uint32(0)?; // No four cc
+                writer.write_uint32(32)?;
+                writer.write_uint32(0x0000_00FF)?; // Red
+                writer.write_uint32(0x0000_FF00)?; // Green
+                writer.write_uint32(0x00FF_0000)?; // Blue
+                writer.write_uint32(0xFF00_0000)?; // Alpha
+            }
+        }
+
+        writer.write_uint32(caps)?;
+        writer.write_uint32(caps2)?;
+        writer.write_bytes(&[0u8; 12])?; // Caps 3, caps 4 + reserved
+
+        writer.write_bytes(&self.data)?;
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    #[rstest]
+    #[case(DdsFormat::RGBA8, 4, 4, 0, 64)]
+    #[case(DdsFormat::BC1, 64, 64, 0, 2048)]
+    #[case(DdsFormat::BC1, 64, 64, 6, 2744)]
+    #[case(DdsFormat::BC3, 256, 128, 2, 43008)]
+    #[case(DdsFormat::BC5, 2, 2, 0, 16)]
+    fn test_calc_image_size(#[case] format: DdsFormat, #[case] w: u32, #[case] h: u32, #[case] mips: u32, #[case] expected: usize) {
+        assert_eq!(expected, format.calc_image_size(w, h, mips));
+    }
+
+    #[rstest]
+    #[case(DdsFormat::RGBA8, false)]
+    #[case(DdsFormat::BC1, false)]
+    #[case(DdsFormat::BC3, true)]
+    #[case(DdsFormat::BC5, false)]
+    fn test_dds_round_trip(#[case] format: DdsFormat, #[case] is_cubemap: bool) {
+        let (width, height, mip_maps) = (16, 8, 2);
+        let face_count = if is_cubemap { 6 } else { 1 };
+
+        let data = (0..(format.calc_image_size(width, height, mip_maps) * face_count))
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+
+        let dds = DdsImage { width, height, mip_maps, format, is_cubemap, data };
+        let bytes = dds.to_bytes().unwrap();
+        let result = DdsImage::from_bytes(&bytes).unwrap();
+
+        assert_eq!(dds.width, result.width);
+        assert_eq!(dds.height, result.height);
+        assert_eq!(dds.mip_maps, result.mip_maps);
+        assert_eq!(dds.format, result.format);
+        assert_eq!(dds.is_cubemap, result.is_cubemap);
+        assert_eq!(dds.data, result.data);
+    }
+}
@@ -57,7 +57,11 @@ fn encode_dxt_with_lib(rgba: &[u8], dx_img: &mut [u8], width: u32, height: u32,
     let enc = match encoding {
         DXGI_Encoding::DXGI_FORMAT_BC1_UNORM => DxtVariant::DXT1,
         DXGI_Encoding::DXGI_FORMAT_BC3_UNORM => DxtVariant::DXT5,
-        _ => todo!("Implement other encodings")
+        DXGI_Encoding::DXGI_FORMAT_BC5_UNORM => {
+            // Not supported by image lib
+            encode_ati2_image(rgba, dx_img, width);
+            return;
+        }
     };
 
     // Hacky DXT1...
@@ -84,6 +88,69 @@ fn encode_dxt_with_lib(rgba: &[u8], dx_img: &mut [u8], width: u32, height: u32,
     encoder.encode(image, width, height, enc).unwrap();
 }
 
+fn encode_ati2_image(rgba: &[u8], dx_img: &mut [u8], width: u32) {
+    let bpp = get_dx_bpp(&DXGI_Encoding::DXGI_FORMAT_BC5_UNORM) as u32;
+
+    // Get block counts
+    let block_x = width >> 2;
+    let block_size = ((16 * bpp) / 8) as usize;
+
+    dx_img
+        .par_chunks_exact_mut(block_size)
+        .enumerate()
+        .for_each(|(i, block)| {
+            let bx = i % block_x as usize;
+            let by = i / block_x as usize;
+
+            let x = bx << 2;
+            let y = by << 2;
+
+            let mut reds = [0u8; 16];
+            let mut greens = [0u8; 16];
+
+            for (j, (r, g)) in reds.iter_mut().zip(greens.iter_mut()).enumerate() {
+                let offset = (((y + (j >> 2)) * width as usize) + x + (j & 0b11)) << 2;
+
+                *r = rgba[offset];
+                *g = rgba[offset + 1];
+            }
+
+            // Red/green channels are encoded as separate alpha blocks
+            encode_channel_block(&reds, &mut block[..8]);
+            encode_channel_block(&greens, &mut block[8..]);
+        });
+}
+
+fn encode_channel_block(values: &[u8; 16], block: &mut [u8]) {
+    let max = *values.iter().max().unwrap();
+    let min = *values.iter().min().unwrap();
+
+    // Interpolates 6 values between end points
+    block[0] = max;
+    block[1] = min;
+
+    let mut packed = 0u64;
+
+    if max > min {
+        let range = (max - min) as f32;
+
+        for (i, v) in values.iter().enumerate() {
+            // Nearest step from max (0) to min (7)
+            let step = ((((max - v) as f32) / range) * 7.0).round() as u64;
+
+            let idx = match step {
+                0 => 0,
+                7 => 1,
+                s => s + 1,
+            };
+
+            packed |= idx << (i * 3);
+        }
+    }
+
+    block[2..8].copy_from_slice(&packed.to_le_bytes()[..6]);
+}
+
 fn decode_dxt1_image(dx_img: &[u8], rgba: &mut [u8], width: u32, is_360: bool) {
     let bpp = get_dx_bpp(&DXGI_Encoding::DXGI_FORMAT_BC1_UNORM) as u32;
 
@@ -298,4 +365,42 @@ pub fn get_dx_bpp(encoding: &DXGI_Encoding) -> u8 {
         DXGI_Encoding::DXGI_FORMAT_BC3_UNORM => 8,
         DXGI_Encoding::DXGI_FORMAT_BC5_UNORM => 8,
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    #[rstest]
+    #[case([0x80; 16], [0x80, 0x80, 0, 0, 0, 0, 0, 0])]
+    #[case([0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00, 0xFF, 0x00], [0xFF, 0x00, 0x08, 0x82, 0x20, 0x08, 0x82, 0x20])]
+    fn test_encode_channel_block(#[case] values: [u8; 16], #[case] expected: [u8; 8]) {
+        let mut block = [0u8; 8];
+        encode_channel_block(&values, &mut block);
+
+        assert_eq!(expected, block);
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn test_ati2_round_trip(#[case] is_360: bool) {
+        let width = 8;
+
+        // Gradient in red/green channels
+        let rgba = (0..(width * width))
+            .flat_map(|i| [(i * 4) as u8, (255 - (i * 4)) as u8, 0xFF, 0xFF])
+            .collect::<Vec<_>>();
+
+        let mut dx_img = vec![0u8; (width * width) as usize];
+        encode_dx_image(&rgba, &mut dx_img, width, DXGI_Encoding::DXGI_FORMAT_BC5_UNORM, is_360);
+
+        let mut decoded = vec![0u8; rgba.len()];
+        decode_dx_image(&dx_img, &mut decoded, width, DXGI_Encoding::DXGI_FORMAT_BC5_UNORM, is_360);
+
+        for (a, b) in rgba.iter().zip(decoded.iter()) {
+            assert!((*a as i32 - *b as i32).abs() <= 8, "{a} != {b}");
+        }
+    }
+}
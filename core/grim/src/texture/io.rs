@@ -1,8 +1,9 @@
 use crate::io::{BinaryStream, SeekFrom, Stream};
 use crate::scene::ObjectReadWrite;
-use crate::texture::{Bitmap, decode_dx_image, decode_tpl_image, encode_dx_image, get_dx_bpp, DXGI_Encoding, TPLEncoding};
+use crate::texture::{Bitmap, decode_dx_image, decode_tpl_image, encode_dx_image, get_dx_bpp, swap_image_bytes, DdsFormat, DdsImage, DXGI_Encoding, TPLEncoding};
 use crate::system::{Platform, SystemInfo};
 use image::buffer::ConvertBuffer;
+use image::imageops::FilterType;
 use image::{ImageBuffer, ImageEncoder, ImageFormat, open, RgbaImage};
 
 use rayon::prelude::*;
@@ -25,6 +26,43 @@ pub enum BitmapError {
         width: u16,
         height: u16
     },
+    #[error("Unsupported dds format of {format:?} for {platform:?}")]
+    UnsupportedDdsFormat {
+        format: DdsFormat,
+        platform: Platform
+    },
+    #[error("Unsupported target encoding of {encoding:?} for {platform:?}")]
+    UnsupportedTargetEncoding {
+        encoding: BitmapEncoding,
+        platform: Platform
+    },
+    #[error("Image resolution of {width}x{height} is too large")]
+    ResolutionTooLarge {
+        width: u32,
+        height: u32
+    },
+    #[error("Cube map dds can't be used as bitmap")]
+    UnsupportedDdsCubemap,
+    #[error("Bitmap data is {actual} bytes but expected at least {expected}")]
+    NotEnoughData {
+        actual: usize,
+        expected: usize
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BitmapEncoding {
+    DXT1,
+    DXT5,
+    ATI2,
+    RGB,
+    RGBA,
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BitmapEncodeOptions {
+    pub encoding: Option<BitmapEncoding>, // Picked from platform + alpha if not set
+    pub mip_maps: u8, // Additional mip levels to generate
 }
 
 pub enum Image<'a> {
@@ -38,6 +76,23 @@ pub enum Image<'a> {
     },
 }
 
+impl Image<'_> {
+    pub fn to_rgba(&self) -> Result<(Vec<u8>, u16, u16), Box<dyn Error>> {
+        let img = match self {
+            Image::FromRGBA { rgba, width, height, .. } => return Ok((rgba.to_vec(), *width, *height)),
+            Image::FromPath(img_path) => open(img_path)?,
+            Image::FromImageBytes(bytes) => image::load_from_memory(bytes)?,
+        };
+
+        let (width, height) = (img.width(), img.height());
+        if width > u16::MAX as u32 || height > u16::MAX as u32 {
+            return Err(Box::new(BitmapError::ResolutionTooLarge { width, height }));
+        }
+
+        Ok((img.into_rgba8().into_vec(), width as u16, height as u16))
+    }
+}
+
 impl Bitmap {
     pub fn from_image(image: Image, info: &SystemInfo) -> Bitmap {
         // Decode rgba image data
@@ -58,13 +113,15 @@ impl Bitmap {
             _ => todo!()
         };
 
+        let has_alpha = rgba.len() >= 4 && rgba.iter().skip(3).step_by(4).any(|&a| a < u8::MAX);
+
         match info.platform {
             Platform::X360 | Platform::PS3 => {
                 let is_360 = info.platform.eq(&Platform::X360);
                 let mut encoding = DXGI_Encoding::DXGI_FORMAT_BC1_UNORM;
 
                 // Use DXT5 encoding if alpha is used
-                if rgba.len() >= 4 && rgba.iter().skip(3).step_by(4).any(|&a| a < u8::MAX) {
+                if has_alpha {
                     encoding = DXGI_Encoding::DXGI_FORMAT_BC3_UNORM;
                 }
 
@@ -91,10 +148,162 @@ impl Bitmap {
                     raw_data: dx_img
                 }
             },
+            Platform::PS2 => {
+                // Store as raw rgb(a) data
+                let bpp = if has_alpha { 32 } else { 24 };
+
+                let rgba = &rgba[..(width as usize * height as usize * 4)];
+                let raw_data = encode_raw_bitmap(rgba, bpp, info);
+
+                Bitmap {
+                    bpp,
+                    encoding: 3,
+                    mip_maps: 0,
+
+                    width,
+                    height,
+                    bpl: ((width as u32 * bpp as u32) / 8) as u16,
+
+                    raw_data
+                }
+            },
             _ => todo!("Support other platforms")
         }
     }
 
+    pub fn from_rgba(rgba: &[u8], width: u16, height: u16, info: &SystemInfo, opts: &BitmapEncodeOptions) -> Result<Bitmap, Box<dyn Error>> {
+        let rgba_size = width as usize * height as usize * 4;
+        if rgba.len() < rgba_size {
+            return Err(Box::new(BitmapError::NotEnoughData {
+                actual: rgba.len(),
+                expected: rgba_size
+            }));
+        }
+
+        let rgba = &rgba[..rgba_size];
+        let has_alpha = rgba.iter().skip(3).step_by(4).any(|&a| a < u8::MAX);
+
+        let encoding = match (opts.encoding, info.platform) {
+            (Some(enc), _) => enc,
+            (None, Platform::X360 | Platform::PS3) if has_alpha => BitmapEncoding::DXT5,
+            (None, Platform::X360 | Platform::PS3) => BitmapEncoding::DXT1,
+            (None, _) if has_alpha => BitmapEncoding::RGBA,
+            (None, _) => BitmapEncoding::RGB,
+        };
+
+        let (bpp, enc_value, mip_maps) = match (encoding, info.platform) {
+            (BitmapEncoding::DXT1 | BitmapEncoding::DXT5 | BitmapEncoding::ATI2, Platform::X360 | Platform::PS3) => {
+                let dx_enc = match encoding {
+                    BitmapEncoding::DXT1 => DXGI_Encoding::DXGI_FORMAT_BC1_UNORM,
+                    BitmapEncoding::DXT5 => DXGI_Encoding::DXGI_FORMAT_BC3_UNORM,
+                    _ => DXGI_Encoding::DXGI_FORMAT_BC5_UNORM,
+                };
+
+                (get_dx_bpp(&dx_enc), dx_enc as u32, calc_block_mip_maps(width, height, opts.mip_maps))
+            },
+            (BitmapEncoding::RGB | BitmapEncoding::RGBA, Platform::PS2 | Platform::X360 | Platform::PS3) => {
+                let bpp = if encoding == BitmapEncoding::RGB { 24 } else { 32 };
+                let enc_value = if info.platform == Platform::PS2 { 3 } else { 1 };
+
+                // Stop once 1x1
+                let mip_maps = (1..=opts.mip_maps)
+                    .take_while(|i| (width >> i) >= 1 && (height >> i) >= 1)
+                    .count() as u8;
+
+                (bpp, enc_value, mip_maps)
+            },
+            _ => {
+                // TODO: Support wii encoding
+                return Err(Box::new(BitmapError::UnsupportedTargetEncoding {
+                    encoding,
+                    platform: info.platform
+                }));
+            }
+        };
+
+        let mut raw_data = Vec::new();
+
+        for (w, h, mip_rgba) in generate_mip_maps(rgba, width, height, mip_maps) {
+            match enc_value {
+                1 | 3 => raw_data.append(&mut encode_raw_bitmap(&mip_rgba, bpp, info)),
+                _ => {
+                    let mut dx_img = vec![0u8; ((w as usize) * (h as usize) * (bpp as usize)) / 8];
+                    encode_dx_image(&mip_rgba, &mut dx_img, w as u32, DXGI_Encoding::from(enc_value), info.platform == Platform::X360);
+
+                    raw_data.append(&mut dx_img);
+                }
+            }
+        }
+
+        Ok(Bitmap {
+            bpp,
+            encoding: enc_value,
+            mip_maps,
+
+            width,
+            height,
+            bpl: ((width as u32 * bpp as u32) / 8) as u16,
+
+            raw_data
+        })
+    }
+
+    pub fn convert(&self, from: &SystemInfo, to: &SystemInfo, opts: &BitmapEncodeOptions) -> Result<Bitmap, Box<dyn Error>> {
+        let current_encoding = self.get_bitmap_encoding();
+        let keep_encoding = opts.encoding.is_none() || opts.encoding.eq(&current_encoding);
+        let keep_mips = opts.mip_maps <= self.mip_maps;
+
+        match (from.platform, to.platform) {
+            (f, t) if f == t && keep_encoding && keep_mips => {
+                // Nothing to convert
+                return Ok(self.clone());
+            },
+            (Platform::X360 | Platform::PS3, Platform::X360 | Platform::PS3) if keep_encoding && keep_mips && matches!(self.encoding, 8 | 24 | 32) => {
+                // Same dxt blocks, just different byte order
+                let mut bitmap = self.clone();
+                swap_image_bytes(&mut bitmap.raw_data);
+
+                return Ok(bitmap);
+            },
+            (_, Platform::PS2 | Platform::X360 | Platform::PS3) if keep_encoding && keep_mips && self.is_raw() => {
+                // Re-order channels without any loss
+                let rgba = self.unpack_rgba(from)?;
+
+                return Ok(Bitmap {
+                    encoding: if to.platform == Platform::PS2 { 3 } else { 1 },
+                    raw_data: encode_raw_bitmap(&rgba, self.bpp, to),
+                    ..self.clone()
+                });
+            },
+            _ => {}
+        }
+
+        // Transcode w/ rgba (keeps existing mip count if more aren't requested)
+        let rgba = self.unpack_rgba(from)?;
+        let opts = BitmapEncodeOptions {
+            mip_maps: opts.mip_maps.max(self.mip_maps),
+            ..*opts
+        };
+
+        Bitmap::from_rgba(&rgba, self.width, self.height, to, &opts)
+    }
+
+    pub fn get_bitmap_encoding(&self) -> Option<BitmapEncoding> {
+        match (self.encoding, self.bpp) {
+            (8, _) => Some(BitmapEncoding::DXT1),
+            (24, _) => Some(BitmapEncoding::DXT5),
+            (32, _) => Some(BitmapEncoding::ATI2),
+            (1 | 3, 24) => Some(BitmapEncoding::RGB),
+            (1 | 3, 32) => Some(BitmapEncoding::RGBA),
+            _ => None
+        }
+    }
+
+    pub fn is_raw(&self) -> bool {
+        // 1 = ARGB (next gen), 3 = RGBA
+        (self.bpp == 24 || self.bpp == 32) && (self.encoding == 1 || self.encoding == 3)
+    }
+
     pub fn import_from_rgba(&mut self, rgba: &[u8]) {
         let expected_size = self.calc_rgba_size();
         if expected_size.ne(&rgba.len()) {
@@ -115,8 +324,8 @@ impl Bitmap {
             }));
         }
 
-        if info.platform == Platform::PS2 && self.encoding == 3 {
-            // Decode PS2 bitmap
+        if (info.platform == Platform::PS2 && self.encoding == 3) || self.is_raw() {
+            // Decode PS2 or raw bitmap
             let mut rgba = vec![0u8; self.calc_rgba_size()];
             decode_from_bitmap(self, info, &mut rgba[..])?;
 
@@ -222,6 +431,134 @@ impl Bitmap {
         }))
     }
 
+    pub fn to_dds(&self, info: &SystemInfo) -> Result<Vec<u8>, Box<dyn Error>> {
+        let dds = match (info.platform, self.encoding) {
+            (Platform::X360 | Platform::PS3, 8 | 24 | 32) => {
+                let format = match DXGI_Encoding::from(self.encoding) {
+                    DXGI_Encoding::DXGI_FORMAT_BC1_UNORM => DdsFormat::BC1,
+                    DXGI_Encoding::DXGI_FORMAT_BC3_UNORM => DdsFormat::BC3,
+                    DXGI_Encoding::DXGI_FORMAT_BC5_UNORM => DdsFormat::BC5,
+                };
+
+                // Copy raw blocks as-is (no re-encoding)
+                let mip_maps = self.mip_maps;
+                let size = format.calc_image_size(self.width as u32, self.height as u32, mip_maps as u32);
+
+                if self.raw_data.len() < size {
+                    return Err(Box::new(BitmapError::NotEnoughData {
+                        actual: self.raw_data.len(),
+                        expected: size,
+                    }));
+                }
+
+                let mut data = self.raw_data[..size].to_vec();
+                if info.platform == Platform::X360 {
+                    // Un-swap 360 byte order
+                    swap_image_bytes(&mut data);
+                }
+
+                DdsImage {
+                    width: self.width as u32,
+                    height: self.height as u32,
+                    mip_maps: mip_maps as u32,
+                    format,
+                    is_cubemap: false,
+                    data,
+                }
+            },
+            (Platform::PS2, 3) | (Platform::X360 | Platform::PS3, 1 | 3) => {
+                // Palette/raw bitmaps decode without any loss
+                DdsImage {
+                    width: self.width as u32,
+                    height: self.height as u32,
+                    mip_maps: self.mip_maps as u32,
+                    format: DdsFormat::RGBA8,
+                    is_cubemap: false,
+                    data: self.unpack_rgba(info)?,
+                }
+            },
+            _ => {
+                return Err(Box::new(BitmapError::UnsupportedEncoding {
+                    version: self.encoding,
+                }));
+            }
+        };
+
+        dds.to_bytes()
+    }
+
+    pub fn from_dds(data: &[u8], info: &SystemInfo) -> Result<Bitmap, Box<dyn Error>> {
+        let dds = DdsImage::from_bytes(data)?;
+
+        if dds.is_cubemap {
+            return Err(Box::new(BitmapError::UnsupportedDdsCubemap));
+        }
+
+        if dds.width > u16::MAX as u32 || dds.height > u16::MAX as u32 {
+            return Err(Box::new(BitmapError::ResolutionTooLarge {
+                width: dds.width,
+                height: dds.height
+            }));
+        }
+
+        let width = dds.width as u16;
+        let height = dds.height as u16;
+
+        match (dds.format, info.platform) {
+            (DdsFormat::RGBA8, Platform::PS2 | Platform::PS3 | Platform::X360) => {
+                let bpp = 32;
+
+                Ok(Bitmap {
+                    bpp,
+                    encoding: if info.platform == Platform::PS2 { 3 } else { 1 },
+                    mip_maps: dds.mip_maps as u8,
+
+                    width,
+                    height,
+                    bpl: ((width as u32 * bpp as u32) / 8) as u16,
+
+                    raw_data: encode_raw_bitmap(&dds.data, bpp, info)
+                })
+            },
+            (format @ (DdsFormat::BC1 | DdsFormat::BC3 | DdsFormat::BC5), Platform::PS3 | Platform::X360) => {
+                let encoding = match format {
+                    DdsFormat::BC1 => DXGI_Encoding::DXGI_FORMAT_BC1_UNORM,
+                    DdsFormat::BC3 => DXGI_Encoding::DXGI_FORMAT_BC3_UNORM,
+                    _ => DXGI_Encoding::DXGI_FORMAT_BC5_UNORM,
+                };
+
+                // Mips smaller than a block still take up a full block
+                let mip_maps = dds.mip_maps as u8;
+                let size = format.calc_image_size(dds.width, dds.height, mip_maps as u32);
+
+                let mut raw_data = dds.data;
+                raw_data.truncate(size);
+
+                if info.platform == Platform::X360 {
+                    swap_image_bytes(&mut raw_data);
+                }
+
+                let bpp = get_dx_bpp(&encoding);
+
+                Ok(Bitmap {
+                    bpp,
+                    encoding: encoding as u32,
+                    mip_maps,
+
+                    width,
+                    height,
+                    bpl: ((width as u32 * bpp as u32) / 8) as u16,
+
+                    raw_data
+                })
+            },
+            (format, platform) => Err(Box::new(BitmapError::UnsupportedDdsFormat {
+                format,
+                platform
+            }))
+        }
+    }
+
     fn calc_rgba_size(&self) -> usize {
         let Bitmap { width: w, height: h, mip_maps: mips, ..} = self;
         calc_rgba_size(*w, *h, *mips)
@@ -308,8 +645,33 @@ fn calc_rgba_size(mut w: u16, mut h: u16, mut mips: u8) -> usize {
     size
 }
 
+fn generate_mip_maps(rgba: &[u8], width: u16, height: u16, mip_maps: u8) -> Vec<(u16, u16, Vec<u8>)> {
+    let mut mips = vec![(width, height, rgba.to_vec())];
+
+    if mip_maps == 0 {
+        return mips;
+    }
+
+    let image: RgbaImage = ImageBuffer::from_raw(width as u32, height as u32, rgba.to_vec()).unwrap();
+
+    for i in 1..=mip_maps {
+        let (w, h) = (width >> i, height >> i);
+        let mip = image::imageops::resize(&image, w as u32, h as u32, FilterType::Triangle);
+
+        mips.push((w, h, mip.into_raw()));
+    }
+
+    mips
+}
+
+fn calc_block_mip_maps(w: u16, h: u16, mips: u8) -> u8 {
+    // Count mips that are at least 4x4 (single dxt block)
+    (1..=mips)
+        .take_while(|i| (w >> i) >= 4 && (h >> i) >= 4)
+        .count() as u8
+}
 
-pub fn decode_from_bitmap(bitmap: &Bitmap, _info: &SystemInfo, rgba: &mut [u8]) -> Result<(), Box<dyn Error>> {
+pub fn decode_from_bitmap(bitmap: &Bitmap, info: &SystemInfo, rgba: &mut [u8]) -> Result<(), Box<dyn Error>> {
     let Bitmap { bpp, raw_data: data, .. } = bitmap;
 
     if *bpp == 4 || *bpp == 8 {
@@ -356,6 +718,8 @@ pub fn decode_from_bitmap(bitmap: &Bitmap, _info: &SystemInfo, rgba: &mut [u8])
                 });
         }
 
+    } else if *bpp == 24 || *bpp == 32 {
+        decode_raw_bitmap(data, *bpp, info, rgba);
     } else {
         return Err(Box::new(BitmapError::UnsupportedBitmapBpp { bpp: bitmap.bpp}));
     }
@@ -363,6 +727,70 @@ pub fn decode_from_bitmap(bitmap: &Bitmap, _info: &SystemInfo, rgba: &mut [u8])
     Ok(())
 }
 
+pub fn encode_to_bitmap(rgba: &[u8], bpp: u8, info: &SystemInfo) -> Result<Vec<u8>, Box<dyn Error>> {
+    if bpp != 24 && bpp != 32 {
+        // TODO: Support palette encoding
+        return Err(Box::new(BitmapError::UnsupportedBitmapBpp { bpp }));
+    }
+
+    Ok(encode_raw_bitmap(rgba, bpp, info))
+}
+
+fn get_raw_channel_order(bpp: u8, platform: &Platform) -> [Option<usize>; 4] {
+    // Byte offset of r, g, b, a channels in each encoded pixel
+    match (bpp, platform) {
+        (24, Platform::PS3) => [Some(2), Some(1), Some(0), None], // BGR
+        (24, _) => [Some(0), Some(1), Some(2), None], // RGB
+        (_, Platform::X360) => [Some(1), Some(2), Some(3), Some(0)], // ARGB
+        (_, Platform::PS3) => [Some(2), Some(1), Some(0), Some(3)], // BGRA
+        _ => [Some(0), Some(1), Some(2), Some(3)], // RGBA
+    }
+}
+
+fn decode_raw_bitmap(data: &[u8], bpp: u8, info: &SystemInfo, rgba: &mut [u8]) {
+    let order = get_raw_channel_order(bpp, &info.platform);
+
+    rgba
+        .par_chunks_exact_mut(4)
+        .zip(data.par_chunks_exact((bpp >> 3) as usize))
+        .for_each(|(pixel, enc)| {
+            for (p, o) in pixel.iter_mut().zip(order.iter()) {
+                *p = match o {
+                    Some(o) => enc[*o],
+                    _ => u8::MAX, // No alpha channel
+                };
+            }
+        });
+
+    if bpp == 32 && info.platform == Platform::PS2 {
+        update_alpha_channels(rgba, false);
+    }
+}
+
+fn encode_raw_bitmap(rgba: &[u8], bpp: u8, info: &SystemInfo) -> Vec<u8> {
+    let order = get_raw_channel_order(bpp, &info.platform);
+    let pixel_size = (bpp >> 3) as usize;
+
+    let mut data = vec![0u8; (rgba.len() / 4) * pixel_size];
+
+    data
+        .par_chunks_exact_mut(pixel_size)
+        .zip(rgba.par_chunks_exact(4))
+        .for_each(|(enc, pixel)| {
+            for (p, o) in pixel.iter().zip(order.iter()) {
+                if let Some(o) = o {
+                    enc[*o] = *p;
+                }
+            }
+        });
+
+    if bpp == 32 && info.platform == Platform::PS2 {
+        update_alpha_channels(&mut data, true);
+    }
+
+    data
+}
+
 fn update_alpha_channels(data: &mut [u8], reduce: bool) {
     if reduce {
         // 8-bit -> 7-bit alpha
@@ -383,6 +811,15 @@ fn update_alpha_channels(data: &mut [u8], reduce: bool) {
     }
 }
 
+pub fn read_rgba_from_file(path: &Path) -> Result<(u32, u32, Vec<u8>), Box<dyn Error>> {
+    let image = open(path)?;
+
+    let width = image.width();
+    let height = image.height();
+
+    Ok((width, height, image.into_rgba8().into_vec()))
+}
+
 pub fn write_rgba_to_file(width: u32, height: u32, rgba: &[u8], path: &Path) -> Result<(), Box<dyn Error>> {
     let mut image: RgbaImage = ImageBuffer::new(width, height);
     let mut rgba_idx;
@@ -432,4 +869,182 @@ mod tests {
     fn test_calc_rgba_size(#[case] w: u16, #[case] h: u16, #[case] mips: u8, #[case] expected: usize) {
         assert_eq!(expected, calc_rgba_size(w, h, mips));
     }
+
+    #[rstest]
+    #[case(64, 64, 0, 0)]
+    #[case(64, 64, 6, 4)]
+    #[case(256, 64, 8, 4)]
+    #[case(8, 8, 1, 1)]
+    fn test_calc_block_mip_maps(#[case] w: u16, #[case] h: u16, #[case] mips: u8, #[case] expected: u8) {
+        assert_eq!(expected, calc_block_mip_maps(w, h, mips));
+    }
+
+    #[rstest]
+    #[case(Platform::PS2, None, 0, 32, 3, 0, 256)]
+    #[case(Platform::PS2, Some(BitmapEncoding::RGB), 2, 24, 3, 2, 252)]
+    #[case(Platform::PS3, None, 0, 8, 24, 0, 64)]
+    #[case(Platform::PS3, Some(BitmapEncoding::DXT1), 4, 4, 8, 1, 40)]
+    #[case(Platform::X360, Some(BitmapEncoding::ATI2), 1, 8, 32, 1, 80)]
+    #[case(Platform::X360, Some(BitmapEncoding::RGBA), 1, 32, 1, 1, 320)]
+    fn test_bitmap_from_rgba(#[case] platform: Platform, #[case] encoding: Option<BitmapEncoding>, #[case] mip_maps: u8, #[case] expected_bpp: u8, #[case] expected_encoding: u32, #[case] expected_mip_maps: u8, #[case] expected_size: usize) {
+        let info = SystemInfo { platform, ..Default::default() };
+        let opts = BitmapEncodeOptions { encoding, mip_maps };
+
+        // Use transparent pixels
+        let rgba = vec![0x80u8; 8 * 8 * 4];
+        let bitmap = Bitmap::from_rgba(&rgba, 8, 8, &info, &opts).unwrap();
+
+        assert_eq!(expected_bpp, bitmap.bpp);
+        assert_eq!(expected_encoding, bitmap.encoding);
+        assert_eq!(expected_mip_maps, bitmap.mip_maps);
+        assert_eq!(expected_size, bitmap.raw_data.len());
+    }
+
+    #[rstest]
+    #[case(2048, 24, 6144)]
+    #[case(2048, 32, 8192)]
+    #[case(4096, 32, 16384)]
+    fn test_bitmap_from_rgba_wide_bpl(#[case] width: u16, #[case] expected_bpp: u8, #[case] expected_bpl: u16) {
+        let info = SystemInfo { platform: Platform::PS2, ..Default::default() };
+        let encoding = if expected_bpp == 24 { BitmapEncoding::RGB } else { BitmapEncoding::RGBA };
+        let opts = BitmapEncodeOptions { encoding: Some(encoding), mip_maps: 0 };
+
+        let rgba = vec![0x80u8; width as usize * 4];
+        let bitmap = Bitmap::from_rgba(&rgba, width, 1, &info, &opts).unwrap();
+
+        assert_eq!(expected_bpp, bitmap.bpp);
+        assert_eq!(expected_bpl, bitmap.bpl);
+    }
+
+    #[rstest]
+    #[case(Platform::PS3, 8, DdsFormat::BC1, 16, 16, 2, 168)]
+    #[case(Platform::PS3, 32, DdsFormat::BC5, 16, 16, 2, 336)]
+    #[case(Platform::X360, 8, DdsFormat::BC1, 16, 16, 2, 168)]
+    #[case(Platform::X360, 24, DdsFormat::BC3, 16, 16, 2, 336)]
+    #[case(Platform::PS3, 8, DdsFormat::BC1, 256, 256, 8, 43704)]
+    #[case(Platform::X360, 24, DdsFormat::BC3, 256, 64, 8, 21904)]
+    fn test_dds_round_trip(#[case] platform: Platform, #[case] encoding: u32, #[case] format: DdsFormat, #[case] width: u16, #[case] height: u16, #[case] mip_maps: u8, #[case] expected_size: usize) {
+        let info = SystemInfo { platform, ..Default::default() };
+        let bpp = get_dx_bpp(&DXGI_Encoding::from(encoding));
+
+        // Every mip is kept, including ones smaller than a block
+        let size = format.calc_image_size(width as u32, height as u32, mip_maps as u32);
+        assert_eq!(expected_size, size);
+
+        let raw_data = (0..size)
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+
+        let bitmap = Bitmap {
+            bpp,
+            encoding,
+            mip_maps,
+            width,
+            height,
+            bpl: ((width as u32 * bpp as u32) / 8) as u16,
+            raw_data,
+        };
+
+        let dds = bitmap.to_dds(&info).unwrap();
+        let result = Bitmap::from_dds(&dds, &info).unwrap();
+
+        assert_eq!(bitmap.bpp, result.bpp);
+        assert_eq!(bitmap.encoding, result.encoding);
+        assert_eq!(bitmap.mip_maps, result.mip_maps);
+        assert_eq!(bitmap.width, result.width);
+        assert_eq!(bitmap.height, result.height);
+        assert_eq!(bitmap.bpl, result.bpl);
+        assert_eq!(bitmap.raw_data, result.raw_data);
+    }
+
+    #[rstest]
+    fn test_from_dds_resolution_too_large() {
+        let info = SystemInfo { platform: Platform::PS3, ..Default::default() };
+
+        let dds = DdsImage {
+            width: u16::MAX as u32 + 1,
+            height: 4,
+            mip_maps: 0,
+            format: DdsFormat::BC1,
+            is_cubemap: false,
+            data: vec![0u8; (65536 / 4) * 8],
+        };
+
+        let result = Bitmap::from_dds(&dds.to_bytes().unwrap(), &info);
+        assert!(result.is_err_and(|e| matches!(e.downcast_ref::<BitmapError>(), Some(BitmapError::ResolutionTooLarge { .. }))));
+    }
+
+    #[rstest]
+    #[case(Platform::X360, Platform::PS3, 24, None, 24, true)]
+    #[case(Platform::PS3, Platform::X360, 8, None, 8, true)]
+    #[case(Platform::PS3, Platform::X360, 8, Some(BitmapEncoding::DXT5), 24, false)]
+    #[case(Platform::PS3, Platform::PS2, 8, None, 3, false)]
+    fn test_bitmap_convert(#[case] from: Platform, #[case] to: Platform, #[case] encoding: u32, #[case] target_encoding: Option<BitmapEncoding>, #[case] expected_encoding: u32, #[case] expected_swapped: bool) {
+        let from = SystemInfo { platform: from, ..Default::default() };
+        let to = SystemInfo { platform: to, ..Default::default() };
+
+        let bpp = get_dx_bpp(&DXGI_Encoding::from(encoding));
+        let raw_data = (0..(8 * 8 * bpp as usize / 8))
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+
+        let bitmap = Bitmap {
+            bpp,
+            encoding,
+            mip_maps: 0,
+            width: 8,
+            height: 8,
+            bpl: (8 * bpp as u16) / 8,
+            raw_data,
+        };
+
+        let opts = BitmapEncodeOptions { encoding: target_encoding, mip_maps: 0 };
+        let result = bitmap.convert(&from, &to, &opts).unwrap();
+
+        assert_eq!(expected_encoding, result.encoding);
+
+        if expected_swapped {
+            let mut swapped = bitmap.raw_data.clone();
+            swap_image_bytes(&mut swapped);
+
+            assert_eq!(swapped, result.raw_data);
+        }
+    }
+
+    #[rstest]
+    #[case(24, Platform::PS2, &[0x10, 0x20, 0x30])]
+    #[case(24, Platform::PS3, &[0x30, 0x20, 0x10])]
+    #[case(32, Platform::PS2, &[0x10, 0x20, 0x30, 0x40])]
+    #[case(32, Platform::PS3, &[0x30, 0x20, 0x10, 0x80])]
+    #[case(32, Platform::Wii, &[0x10, 0x20, 0x30, 0x80])]
+    #[case(32, Platform::X360, &[0x80, 0x10, 0x20, 0x30])]
+    fn test_encode_raw_bitmap(#[case] bpp: u8, #[case] platform: Platform, #[case] expected: &[u8]) {
+        let info = SystemInfo { platform, ..Default::default() };
+        let rgba = [0x10, 0x20, 0x30, 0x80];
+
+        assert_eq!(expected, encode_raw_bitmap(&rgba, bpp, &info));
+    }
+
+    #[rstest]
+    #[case(24, Platform::PS2, [0x10, 0x20, 0x30, 0xFF])]
+    #[case(24, Platform::X360, [0x10, 0x20, 0x30, 0xFF])]
+    #[case(32, Platform::PS2, [0x10, 0x20, 0x30, 0x80])]
+    #[case(32, Platform::PS3, [0x10, 0x20, 0x30, 0x80])]
+    #[case(32, Platform::X360, [0x10, 0x20, 0x30, 0x80])]
+    fn test_raw_bitmap_round_trip(#[case] bpp: u8, #[case] platform: Platform, #[case] expected: [u8; 4]) {
+        let info = SystemInfo { platform, ..Default::default() };
+        let rgba = [0x10, 0x20, 0x30, 0x80];
+
+        let bitmap = Bitmap {
+            bpp,
+            encoding: 3,
+            mip_maps: 0,
+            width: 1,
+            height: 1,
+            bpl: bpp as u16 / 8,
+            raw_data: encode_raw_bitmap(&rgba, bpp, &info),
+        };
+
+        assert_eq!(expected.to_vec(), bitmap.unpack_rgba(&info).unwrap());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,207 @@
+use crate::io::{BinaryStream, IOEndian, MemoryStream, Stream};
+use std::error::Error;
+use thiserror::Error as ThisError;
+
+const KTX2_IDENTIFIER: [u8; 12] = [0xAB, 0x4B, 0x54, 0x58, 0x20, 0x32, 0x30, 0xBB, 0x0D, 0x0A, 0x1A, 0x0A]; // «KTX 20»\r\n\x1A\n
+const KTX2_HEADER_SIZE: u32 = 80; // Identifier + header + index
+const KTX2_LEVEL_INDEX_SIZE: u32 = 24;
+
+const VK_FORMAT_R8G8B8A8_UNORM: u32 = 37;
+
+// Data format descriptor
+const KHR_DF_MODEL_RGBSDA: u8 = 1;
+const KHR_DF_PRIMARIES_BT709: u8 = 1;
+const KHR_DF_TRANSFER_LINEAR: u8 = 1;
+const KHR_DF_VERSION: u32 = 2;
+const KHR_DF_CHANNEL_ALPHA: u8 = 15;
+const KHR_DF_BASIC_BLOCK_SIZE: u32 = 24;
+const KHR_DF_SAMPLE_SIZE: u32 = 16;
+
+#[derive(Debug, ThisError)]
+pub enum Ktx2Error {
+    #[error("KTX2 data is {actual} bytes but expected at least {expected}")]
+    NotEnoughData {
+        actual: usize,
+        expected: usize,
+    },
+}
+
+// Uncompressed rgba8 only
+#[derive(Debug)]
+pub struct Ktx2Image {
+    pub width: u32,
+    pub height: u32,
+    pub mip_maps: u32, // Additional mip levels (0 = base image only)
+    pub is_cubemap: bool,
+    pub data: Vec<u8>, // Every face w/ mip maps, in order (same as dds)
+}
+
+impl Ktx2Image {
+    pub fn calc_level_size(&self, level: u32) -> usize {
+        let w = (self.width >> level).max(1) as usize;
+        let h = (self.height >> level).max(1) as usize;
+
+        w * h * 4
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Box<dyn Error>> {
+        let face_count = if self.is_cubemap { 6 } else { 1 };
+        let level_count = self.mip_maps + 1;
+
+        let level_sizes = (0..level_count)
+            .map(|i| self.calc_level_size(i))
+            .collect::<Vec<_>>();
+        let face_size = level_sizes.iter().sum::<usize>();
+
+        if self.data.len() < face_size * face_count {
+            return Err(Box::new(Ktx2Error::NotEnoughData {
+                actual: self.data.len(),
+                expected: face_size * face_count
+            }));
+        }
+
+        let channels = [(0, 0), (1, 8), (2, 16), (KHR_DF_CHANNEL_ALPHA, 24)]; // Channel, bit offset
+        let dfd_block_size = KHR_DF_BASIC_BLOCK_SIZE + (KHR_DF_SAMPLE_SIZE * channels.len() as u32);
+        let dfd_size = 4 + dfd_block_size;
+        let dfd_offset = KTX2_HEADER_SIZE + (KTX2_LEVEL_INDEX_SIZE * level_count);
+        let data_offset = (dfd_offset + dfd_size) as u64;
+
+        let mut data = Vec::new();
+        let mut stream = MemoryStream::from_vector_as_read_write(&mut data);
+        let mut writer = BinaryStream::from_stream_with_endian(&mut stream, IOEndian::Little);
+
+        writer.write_bytes(&KTX2_IDENTIFIER)?;
+        writer.write_uint32(VK_FORMAT_R8G8B8A8_UNORM)?;
+        writer.write_uint32(1)?; // Type size
+        writer.write_uint32(self.width)?;
+        writer.write_uint32(self.height)?;
+        writer.write_uint32(0)?; // Depth
+        writer.write_uint32(0)?; // Layer count
+        writer.write_uint32(face_count as u32)?;
+        writer.write_uint32(level_count)?;
+        writer.write_uint32(0)?; // Supercompression scheme
+
+        // Index
+        writer.write_uint32(dfd_offset)?;
+        writer.write_uint32(dfd_size)?;
+        writer.write_uint32(0)?; // Key/value data offset
+        writer.write_uint32(0)?; // Key/value data length
+        writer.write_uint64(0)?; // Supercompression global data offset
+        writer.write_uint64(0)?; // Supercompression global data length
+
+        // Level data is stored smallest mip first
+        let mut level_offsets = vec![0u64; level_count as usize];
+        let mut offset = data_offset;
+
+        for (i, size) in level_sizes.iter().enumerate().rev() {
+            level_offsets[i] = offset;
+            offset += (size * face_count) as u64;
+        }
+
+        for (level_offset, size) in level_offsets.iter().zip(level_sizes.iter()) {
+            let level_size = (size * face_count) as u64;
+
+            writer.write_uint64(*level_offset)?;
+            writer.write_uint64(level_size)?;
+            writer.write_uint64(level_size)?; // Uncompressed size
+        }
+
+        // Data format descriptor (basic block)
+        writer.write_uint32(dfd_size)?;
+        writer.write_uint32(0)?; // Vendor id + descriptor type
+        writer.write_uint32((dfd_block_size << 16) | KHR_DF_VERSION)?;
+        writer.write_bytes(&[KHR_DF_MODEL_RGBSDA, KHR_DF_PRIMARIES_BT709, KHR_DF_TRANSFER_LINEAR, 0])?;
+        writer.write_bytes(&[0u8; 4])?; // Texel block dimensions
+        writer.write_bytes(&[4, 0, 0, 0, 0, 0, 0, 0])?; // Bytes per plane
+
+        for (channel, bit_offset) in channels {
+            writer.write_uint16(bit_offset)?;
+            writer.write_uint8(7)?; // Bit length - 1
+            writer.write_uint8(channel)?;
+            writer.write_bytes(&[0u8; 4])?; // Sample position
+            writer.write_uint32(0)?; // Lower
+            writer.write_uint32(u8::MAX as u32)?; // Upper
+        }
+
+        // Write each level for every face
+        for (i, size) in level_sizes.iter().enumerate().rev() {
+            let level_start = level_sizes[..i].iter().sum::<usize>();
+
+            for face in 0..face_count {
+                let start = (face * face_size) + level_start;
+                writer.write_bytes(&self.data[start..(start + size)])?;
+            }
+        }
+
+        Ok(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::*;
+    use super::*;
+
+    fn read_u32(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes(data[offset..(offset + 4)].try_into().unwrap())
+    }
+
+    fn read_u64(data: &[u8], offset: usize) -> u64 {
+        u64::from_le_bytes(data[offset..(offset + 8)].try_into().unwrap())
+    }
+
+    #[rstest]
+    #[case(false)]
+    #[case(true)]
+    fn test_ktx2_to_bytes(#[case] is_cubemap: bool) {
+        let (width, height, mip_maps) = (4, 2, 1);
+        let face_count = if is_cubemap { 6 } else { 1 };
+
+        // Each face is 40 bytes (4x2 + 2x1 pixels)
+        let data = (0..(40 * face_count))
+            .map(|i| i as u8)
+            .collect::<Vec<_>>();
+
+        let ktx = Ktx2Image { width, height, mip_maps, is_cubemap, data };
+        let bytes = ktx.to_bytes().unwrap();
+
+        assert_eq!(KTX2_IDENTIFIER, bytes[..12]);
+        assert_eq!(VK_FORMAT_R8G8B8A8_UNORM, read_u32(&bytes, 12));
+        assert_eq!((4, 2), (read_u32(&bytes, 20), read_u32(&bytes, 24)));
+        assert_eq!(face_count as u32, read_u32(&bytes, 36));
+        assert_eq!(2, read_u32(&bytes, 40));
+
+        // Dfd follows level index
+        let (dfd_offset, dfd_size) = (read_u32(&bytes, 48) as usize, read_u32(&bytes, 52) as usize);
+        assert_eq!((128, 92), (dfd_offset, dfd_size));
+        assert_eq!(dfd_size as u32, read_u32(&bytes, dfd_offset));
+
+        let level_0 = (read_u64(&bytes, 80) as usize, read_u64(&bytes, 88) as usize);
+        let level_1 = (read_u64(&bytes, 104) as usize, read_u64(&bytes, 112) as usize);
+
+        assert_eq!(32 * face_count, level_0.1);
+        assert_eq!(8 * face_count, level_1.1);
+        assert_eq!(dfd_offset + dfd_size, level_1.0);
+        assert_eq!(level_1.0 + level_1.1, level_0.0);
+        assert_eq!(level_0.0 + level_0.1, bytes.len());
+
+        // Faces of each level are packed together
+        for face in 0..face_count {
+            let src = face * 40;
+
+            let base = level_0.0 + (face * 32);
+            assert_eq!(ktx.data[src..(src + 32)], bytes[base..(base + 32)]);
+
+            let mip = level_1.0 + (face * 8);
+            assert_eq!(ktx.data[(src + 32)..(src + 40)], bytes[mip..(mip + 8)]);
+        }
+    }
+
+    #[rstest]
+    fn test_ktx2_not_enough_data() {
+        let ktx = Ktx2Image { width: 4, height: 4, mip_maps: 0, is_cubemap: true, data: vec![0u8; 64] };
+        let result = ktx.to_bytes();
+
+        assert!(result.is_err_and(|e| e.is::<Ktx2Error>()));
+    }
+}
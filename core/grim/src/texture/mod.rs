@@ -1,11 +1,15 @@
 mod bitmap;
 mod common;
+mod dds;
 mod dxt;
 mod io;
+mod ktx2;
 mod tpl;
 
 pub use bitmap::*;
 pub use common::*;
+pub use dds::*;
 pub use dxt::*;
 pub use io::*;
+pub use ktx2::*;
 pub use tpl::*;
\ No newline at end of file
@@ -0,0 +1,131 @@
+use crate::scene::ObjectTokens;
+use proc_macro::TokenStream;
+use quote::quote;
+
+pub fn get_char_clip_tokens() -> ObjectTokens {
+    let struct_fields = [
+        quote! { pub start_beat: f32 }.into(),
+        quote! { pub end_beat: f32 }.into(),
+        quote! { pub beats_per_sec: f32 }.into(),
+        quote! { pub flags: u32 }.into(),
+        quote! { pub play_flags: u32 }.into(),
+        quote! { pub blend_width: f32 }.into(),
+        quote! { pub range: f32 }.into(),
+        quote! { pub relative: String }.into(),
+        quote! { pub unknown_1: i32 }.into(),
+        quote! { pub do_not_decompress: bool }.into(),
+        quote! { pub nodes: Vec<ClipNode> }.into(),
+        quote! { pub events: Vec<FrameEvent> }.into(),
+        quote! { pub beat_events: Vec<BeatEvent> }.into(),
+    ];
+
+    let trait_impl = quote! {
+        fn get_start_beat(&self) -> f32 {
+            self.start_beat
+        }
+        fn set_start_beat(&mut self, start_beat: f32) {
+            self.start_beat = start_beat;
+        }
+
+        fn get_end_beat(&self) -> f32 {
+            self.end_beat
+        }
+        fn set_end_beat(&mut self, end_beat: f32) {
+            self.end_beat = end_beat;
+        }
+
+        fn get_beats_per_sec(&self) -> f32 {
+            self.beats_per_sec
+        }
+        fn set_beats_per_sec(&mut self, beats_per_sec: f32) {
+            self.beats_per_sec = beats_per_sec;
+        }
+
+        fn get_flags(&self) -> u32 {
+            self.flags
+        }
+        fn set_flags(&mut self, flags: u32) {
+            self.flags = flags;
+        }
+
+        fn get_play_flags(&self) -> u32 {
+            self.play_flags
+        }
+        fn set_play_flags(&mut self, play_flags: u32) {
+            self.play_flags = play_flags;
+        }
+
+        fn get_blend_width(&self) -> f32 {
+            self.blend_width
+        }
+        fn set_blend_width(&mut self, blend_width: f32) {
+            self.blend_width = blend_width;
+        }
+
+        fn get_range(&self) -> f32 {
+            self.range
+        }
+        fn set_range(&mut self, range: f32) {
+            self.range = range;
+        }
+
+        fn get_relative(&self) -> &String {
+            &self.relative
+        }
+        fn get_relative_mut(&mut self) -> &mut String {
+            &mut self.relative
+        }
+        fn set_relative(&mut self, relative: String) {
+            self.relative = relative;
+        }
+
+        fn get_unknown_1(&self) -> i32 {
+            self.unknown_1
+        }
+        fn set_unknown_1(&mut self, unknown_1: i32) {
+            self.unknown_1 = unknown_1;
+        }
+
+        fn get_do_not_decompress(&self) -> bool {
+            self.do_not_decompress
+        }
+        fn set_do_not_decompress(&mut self, do_not_decompress: bool) {
+            self.do_not_decompress = do_not_decompress;
+        }
+
+        fn get_nodes(&self) -> &Vec<ClipNode> {
+            &self.nodes
+        }
+        fn get_nodes_mut(&mut self) -> &mut Vec<ClipNode> {
+            &mut self.nodes
+        }
+        fn set_nodes(&mut self, nodes: Vec<ClipNode>) {
+            self.nodes = nodes;
+        }
+
+        fn get_events(&self) -> &Vec<FrameEvent> {
+            &self.events
+        }
+        fn get_events_mut(&mut self) -> &mut Vec<FrameEvent> {
+            &mut self.events
+        }
+        fn set_events(&mut self, events: Vec<FrameEvent>) {
+            self.events = events;
+        }
+
+        fn get_beat_events(&self) -> &Vec<BeatEvent> {
+            &self.beat_events
+        }
+        fn get_beat_events_mut(&mut self) -> &mut Vec<BeatEvent> {
+            &mut self.beat_events
+        }
+        fn set_beat_events(&mut self, beat_events: Vec<BeatEvent>) {
+            self.beat_events = beat_events;
+        }
+    };
+
+    ObjectTokens::from_tokens(
+        Box::new(struct_fields),
+        trait_impl
+    )
+}
\ No newline at end of file
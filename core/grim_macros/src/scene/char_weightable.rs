@@ -0,0 +1,34 @@
+use crate::scene::ObjectTokens;
+use proc_macro::TokenStream;
+use quote::quote;
+
+pub fn get_char_weightable_tokens() -> ObjectTokens {
+    let struct_fields = [
+        quote! { pub weight: f32 }.into(),
+        quote! { pub weight_owner: String }.into(),
+    ];
+
+    let trait_impl = quote! {
+        fn get_weight(&self) -> f32 {
+            self.weight
+        }
+        fn set_weight(&mut self, weight: f32) {
+            self.weight = weight;
+        }
+
+        fn get_weight_owner(&self) -> &String {
+            &self.weight_owner
+        }
+        fn get_weight_owner_mut(&mut self) -> &mut String {
+            &mut self.weight_owner
+        }
+        fn set_weight_owner(&mut self, weight_owner: String) {
+            self.weight_owner = weight_owner;
+        }
+    };
+
+    ObjectTokens::from_tokens(
+        Box::new(struct_fields),
+        trait_impl
+    )
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use crate::scene::ObjectTokens;
+use proc_macro::TokenStream;
+use quote::quote;
+
+pub fn get_character_tokens() -> ObjectTokens {
+    let struct_fields = [
+        quote! { pub lods: Vec<grim_traits::scene::CharLOD> }.into(),
+        quote! { pub shadow: String }.into(),
+        quote! { pub self_shadow: bool }.into(),
+        quote! { pub sphere_base: String }.into(),
+        quote! { pub bounding: grim_traits::scene::Sphere }.into(),
+        quote! { pub frozen: bool }.into(),
+        quote! { pub min_lod: u32 }.into(),
+        quote! { pub char_version: Option<u32> }.into(),
+    ];
+
+    let trait_impl = quote! {
+        fn get_lods(&self) -> &Vec<grim_traits::scene::CharLOD> {
+            &self.lods
+        }
+        fn get_lods_mut(&mut self) -> &mut Vec<grim_traits::scene::CharLOD> {
+            &mut self.lods
+        }
+        fn set_lods(&mut self, lods: Vec<grim_traits::scene::CharLOD>) {
+            self.lods = lods;
+        }
+
+        fn get_shadow(&self) -> &String {
+            &self.shadow
+        }
+        fn get_shadow_mut(&mut self) -> &mut String {
+            &mut self.shadow
+        }
+        fn set_shadow(&mut self, shadow: String) {
+            self.shadow = shadow;
+        }
+
+        fn get_self_shadow(&self) -> bool {
+            self.self_shadow
+        }
+        fn set_self_shadow(&mut self, self_shadow: bool) {
+            self.self_shadow = self_shadow;
+        }
+
+        fn get_sphere_base(&self) -> &String {
+            &self.sphere_base
+        }
+        fn get_sphere_base_mut(&mut self) -> &mut String {
+            &mut self.sphere_base
+        }
+        fn set_sphere_base(&mut self, sphere_base: String) {
+            self.sphere_base = sphere_base;
+        }
+
+        fn get_bounding(&self) -> &grim_traits::scene::Sphere {
+            &self.bounding
+        }
+        fn get_bounding_mut(&mut self) -> &mut grim_traits::scene::Sphere {
+            &mut self.bounding
+        }
+        fn set_bounding(&mut self, bounding: grim_traits::scene::Sphere) {
+            self.bounding = bounding;
+        }
+
+        fn get_frozen(&self) -> bool {
+            self.frozen
+        }
+        fn set_frozen(&mut self, frozen: bool) {
+            self.frozen = frozen;
+        }
+
+        fn get_min_lod(&self) -> u32 {
+            self.min_lod
+        }
+        fn set_min_lod(&mut self, min_lod: u32) {
+            self.min_lod = min_lod;
+        }
+
+        fn get_char_version(&self) -> Option<u32> {
+            self.char_version
+        }
+        fn set_char_version(&mut self, char_version: Option<u32>) {
+            self.char_version = char_version;
+        }
+    };
+
+    ObjectTokens::from_tokens(
+        Box::new(struct_fields),
+        trait_impl
+    )
+}
\ No newline at end of file
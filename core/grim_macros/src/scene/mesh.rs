@@ -8,6 +8,7 @@ pub fn get_mesh_tokens() -> ObjectTokens {
         quote! { pub geom_owner: String }.into(),
         quote! { pub mutable: grim_traits::scene::Mutable }.into(),
         quote! { pub volume: grim_traits::scene::Volume }.into(),
+        quote! { pub bsp: Option<Box<grim_traits::scene::BSPNode>> }.into(),
         quote! { pub vertices: Vec<grim_traits::scene::Vert> }.into(),
         quote! { pub faces: Vec<[u16; 3]> }.into(),
         quote! { pub face_groups: Vec<u8> }.into(),
@@ -58,6 +59,16 @@ pub fn get_mesh_tokens() -> ObjectTokens {
             self.volume = volume;
         }
 
+        fn get_bsp(&self) -> &Option<Box<grim_traits::scene::BSPNode>> {
+            &self.bsp
+        }
+        fn get_bsp_mut(&mut self) -> &mut Option<Box<grim_traits::scene::BSPNode>> {
+            &mut self.bsp
+        }
+        fn set_bsp(&mut self, bsp: Option<Box<grim_traits::scene::BSPNode>>) {
+            self.bsp = bsp;
+        }
+
         fn get_vertices(&self) -> &Vec<grim_traits::scene::Vert> {
             &self.vertices
         }
@@ -1,9 +1,14 @@
 mod anim;
+mod char_clip;
+mod char_weightable;
+mod character;
 mod draw;
 mod group;
 mod mesh;
 mod milo_object;
+mod obj_dir;
 mod poll;
+mod rnd_dir;
 mod trans;
 
 use crate::*;
@@ -20,10 +25,15 @@ lazy_static! {
     static ref OBJECT_TOKENS: HashMap<&'static str, GetObjectTokensFn> = {
         let mut m: HashMap<&'static str, GetObjectTokensFn> = HashMap::new();
         m.insert("Anim", anim::get_anim_tokens);
+        m.insert("CharClip", char_clip::get_char_clip_tokens);
+        m.insert("CharWeightable", char_weightable::get_char_weightable_tokens);
+        m.insert("Character", character::get_character_tokens);
         m.insert("Draw", draw::get_draw_tokens);
         m.insert("Group", group::get_group_tokens);
+        m.insert("ObjDir", obj_dir::get_obj_dir_tokens);
         m.insert("RndMesh", mesh::get_mesh_tokens);
         m.insert("Poll", poll::get_poll_tokens);
+        m.insert("RndDir", rnd_dir::get_rnd_dir_tokens);
         m.insert("Trans", trans::get_trans_tokens);
         m
     };
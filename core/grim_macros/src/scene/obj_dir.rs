@@ -0,0 +1,72 @@
+use crate::scene::ObjectTokens;
+use proc_macro::TokenStream;
+use quote::quote;
+
+pub fn get_obj_dir_tokens() -> ObjectTokens {
+    let struct_fields = [
+        quote! { pub viewports: Vec<grim_traits::scene::Matrix> }.into(),
+        quote! { pub curr_viewport_index: u32 }.into(),
+        quote! { pub inline_proxy: bool }.into(),
+        quote! { pub proxy_file: String }.into(),
+        quote! { pub sub_dirs: Vec<String> }.into(),
+        quote! { pub obj_dir_version: Option<u32> }.into(),
+    ];
+
+    let trait_impl = quote! {
+        fn get_viewports(&self) -> &Vec<grim_traits::scene::Matrix> {
+            &self.viewports
+        }
+        fn get_viewports_mut(&mut self) -> &mut Vec<grim_traits::scene::Matrix> {
+            &mut self.viewports
+        }
+        fn set_viewports(&mut self, viewports: Vec<grim_traits::scene::Matrix>) {
+            self.viewports = viewports;
+        }
+
+        fn get_curr_viewport_index(&self) -> u32 {
+            self.curr_viewport_index
+        }
+        fn set_curr_viewport_index(&mut self, curr_viewport_index: u32) {
+            self.curr_viewport_index = curr_viewport_index;
+        }
+
+        fn get_inline_proxy(&self) -> bool {
+            self.inline_proxy
+        }
+        fn set_inline_proxy(&mut self, inline_proxy: bool) {
+            self.inline_proxy = inline_proxy;
+        }
+
+        fn get_proxy_file(&self) -> &String {
+            &self.proxy_file
+        }
+        fn get_proxy_file_mut(&mut self) -> &mut String {
+            &mut self.proxy_file
+        }
+        fn set_proxy_file(&mut self, proxy_file: String) {
+            self.proxy_file = proxy_file;
+        }
+
+        fn get_sub_dirs(&self) -> &Vec<String> {
+            &self.sub_dirs
+        }
+        fn get_sub_dirs_mut(&mut self) -> &mut Vec<String> {
+            &mut self.sub_dirs
+        }
+        fn set_sub_dirs(&mut self, sub_dirs: Vec<String>) {
+            self.sub_dirs = sub_dirs;
+        }
+
+        fn get_obj_dir_version(&self) -> Option<u32> {
+            self.obj_dir_version
+        }
+        fn set_obj_dir_version(&mut self, obj_dir_version: Option<u32>) {
+            self.obj_dir_version = obj_dir_version;
+        }
+    };
+
+    ObjectTokens::from_tokens(
+        Box::new(struct_fields),
+        trait_impl
+    )
+}
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::scene::ObjectTokens;
+use proc_macro::TokenStream;
+use quote::quote;
+
+pub fn get_rnd_dir_tokens() -> ObjectTokens {
+    let struct_fields = [
+        quote! { pub environ: String }.into(),
+        quote! { pub test_event: String }.into(),
+        quote! { pub rnd_dir_version: Option<u32> }.into(),
+    ];
+
+    let trait_impl = quote! {
+        fn get_environ(&self) -> &String {
+            &self.environ
+        }
+        fn get_environ_mut(&mut self) -> &mut String {
+            &mut self.environ
+        }
+        fn set_environ(&mut self, environ: String) {
+            self.environ = environ;
+        }
+
+        fn get_test_event(&self) -> &String {
+            &self.test_event
+        }
+        fn get_test_event_mut(&mut self) -> &mut String {
+            &mut self.test_event
+        }
+        fn set_test_event(&mut self, test_event: String) {
+            self.test_event = test_event;
+        }
+
+        fn get_rnd_dir_version(&self) -> Option<u32> {
+            self.rnd_dir_version
+        }
+        fn set_rnd_dir_version(&mut self, rnd_dir_version: Option<u32>) {
+            self.rnd_dir_version = rnd_dir_version;
+        }
+    };
+
+    ObjectTokens::from_tokens(
+        Box::new(struct_fields),
+        trait_impl
+    )
+}
\ No newline at end of file
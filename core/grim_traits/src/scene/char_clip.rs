@@ -18,6 +18,12 @@ pub struct FrameEvent {
     pub script: String,
 }
 
+#[derive(Default)]
+pub struct BeatEvent {
+    pub beat: f32,
+    pub script: String,
+}
+
 pub trait CharClip : MiloObject {
     fn get_start_beat(&self) -> f32;
     fn set_start_beat(&mut self, start_beat: f32);
@@ -57,4 +63,8 @@ pub trait CharClip : MiloObject {
     fn get_events(&self) -> &Vec<FrameEvent>;
     fn get_events_mut(&mut self) -> &mut Vec<FrameEvent>;
     fn set_events(&mut self, events: Vec<FrameEvent>);
+
+    fn get_beat_events(&self) -> &Vec<BeatEvent>;
+    fn get_beat_events_mut(&mut self) -> &mut Vec<BeatEvent>;
+    fn set_beat_events(&mut self, beat_events: Vec<BeatEvent>);
 }
\ No newline at end of file
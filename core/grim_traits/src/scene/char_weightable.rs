@@ -0,0 +1,10 @@
+use super::{MiloObject};
+
+pub trait CharWeightable : MiloObject {
+    fn get_weight(&self) -> f32;
+    fn set_weight(&mut self, weight: f32);
+
+    fn get_weight_owner(&self) -> &String;
+    fn get_weight_owner_mut(&mut self) -> &mut String;
+    fn set_weight_owner(&mut self, weight_owner: String);
+}
\ No newline at end of file
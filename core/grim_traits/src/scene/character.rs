@@ -0,0 +1,38 @@
+use super::{RndDir, Sphere};
+
+#[derive(Debug, Default)]
+pub struct CharLOD {
+    pub screen_size: f32,
+    pub groups: Vec<String>,
+    pub translucent_groups: Vec<String>,
+}
+
+pub trait Character : RndDir {
+    fn get_lods(&self) -> &Vec<CharLOD>;
+    fn get_lods_mut(&mut self) -> &mut Vec<CharLOD>;
+    fn set_lods(&mut self, lods: Vec<CharLOD>);
+
+    fn get_shadow(&self) -> &String;
+    fn get_shadow_mut(&mut self) -> &mut String;
+    fn set_shadow(&mut self, shadow: String);
+
+    fn get_self_shadow(&self) -> bool;
+    fn set_self_shadow(&mut self, self_shadow: bool);
+
+    fn get_sphere_base(&self) -> &String;
+    fn get_sphere_base_mut(&mut self) -> &mut String;
+    fn set_sphere_base(&mut self, sphere_base: String);
+
+    fn get_bounding(&self) -> &Sphere;
+    fn get_bounding_mut(&mut self) -> &mut Sphere;
+    fn set_bounding(&mut self, bounding: Sphere);
+
+    fn get_frozen(&self) -> bool;
+    fn set_frozen(&mut self, frozen: bool);
+
+    fn get_min_lod(&self) -> u32;
+    fn set_min_lod(&mut self, min_lod: u32);
+
+    fn get_char_version(&self) -> Option<u32>;
+    fn set_char_version(&mut self, char_version: Option<u32>);
+}
\ No newline at end of file
@@ -6,6 +6,14 @@ pub struct Sphere {
     pub r: f32,
 }
 
+#[derive(Debug, Default)]
+pub struct Plane {
+    pub a: f32,
+    pub b: f32,
+    pub c: f32,
+    pub d: f32,
+}
+
 #[derive(Debug, Default)]
 pub struct Rect {
     pub x: f32,
@@ -1,4 +1,4 @@
-use super::{Color4, Draw, Matrix, MiloObject, Trans, Vector4};
+use super::{Color4, Draw, Matrix, MiloObject, Plane, Trans, Vector4};
 
 #[allow(non_camel_case_types)]
 #[derive(Clone, Copy)]
@@ -98,6 +98,13 @@ impl Default for Vert {
     }
 }
 
+#[derive(Debug, Default)]
+pub struct BSPNode {
+    pub plane: Plane,
+    pub front: Option<Box<BSPNode>>,
+    pub back: Option<Box<BSPNode>>,
+}
+
 pub trait RndMesh : Draw + MiloObject + Trans {
     fn get_mat(&self) -> &String;
     fn get_mat_mut(&mut self) -> &mut String;
@@ -115,6 +122,10 @@ pub trait RndMesh : Draw + MiloObject + Trans {
     fn get_volume_mut(&mut self) -> &mut Volume;
     fn set_volume(&mut self, volume: Volume);
 
+    fn get_bsp(&self) -> &Option<Box<BSPNode>>;
+    fn get_bsp_mut(&mut self) -> &mut Option<Box<BSPNode>>;
+    fn set_bsp(&mut self, bsp: Option<Box<BSPNode>>);
+
     fn get_vertices(&self) -> &Vec<Vert>;
     fn get_vertices_mut(&mut self) -> &mut Vec<Vert>;
     fn set_vertices(&mut self, vertices: Vec<Vert>);
@@ -1,21 +1,29 @@
 mod anim;
 mod char_clip;
+mod char_weightable;
+mod character;
 mod common;
 mod draw;
 mod group;
 mod mat;
 mod mesh;
 mod milo_object;
+mod obj_dir;
 mod poll;
+mod rnd_dir;
 mod trans;
 
 pub use anim::*;
 pub use char_clip::*;
+pub use char_weightable::*;
+pub use character::*;
 pub use common::*;
 pub use draw::*;
 pub use group::*;
 pub use mat::*;
 pub use mesh::*;
 pub use milo_object::*;
+pub use obj_dir::*;
 pub use poll::*;
+pub use rnd_dir::*;
 pub use trans::*;
\ No newline at end of file
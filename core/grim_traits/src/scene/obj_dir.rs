@@ -0,0 +1,24 @@
+use super::{Matrix, MiloObject};
+
+pub trait ObjDir : MiloObject {
+    fn get_viewports(&self) -> &Vec<Matrix>;
+    fn get_viewports_mut(&mut self) -> &mut Vec<Matrix>;
+    fn set_viewports(&mut self, viewports: Vec<Matrix>);
+
+    fn get_curr_viewport_index(&self) -> u32;
+    fn set_curr_viewport_index(&mut self, curr_viewport_index: u32);
+
+    fn get_inline_proxy(&self) -> bool;
+    fn set_inline_proxy(&mut self, inline_proxy: bool);
+
+    fn get_proxy_file(&self) -> &String;
+    fn get_proxy_file_mut(&mut self) -> &mut String;
+    fn set_proxy_file(&mut self, proxy_file: String);
+
+    fn get_sub_dirs(&self) -> &Vec<String>;
+    fn get_sub_dirs_mut(&mut self) -> &mut Vec<String>;
+    fn set_sub_dirs(&mut self, sub_dirs: Vec<String>);
+
+    fn get_obj_dir_version(&self) -> Option<u32>;
+    fn set_obj_dir_version(&mut self, obj_dir_version: Option<u32>);
+}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use super::{Anim, Draw, MiloObject, ObjDir, Trans};
+
+pub trait RndDir : Anim + Draw + MiloObject + ObjDir + Trans {
+    fn get_environ(&self) -> &String;
+    fn get_environ_mut(&mut self) -> &mut String;
+    fn set_environ(&mut self, environ: String);
+
+    fn get_test_event(&self) -> &String;
+    fn get_test_event_mut(&mut self) -> &mut String;
+    fn set_test_event(&mut self, test_event: String);
+
+    fn get_rnd_dir_version(&self) -> Option<u32>;
+    fn set_rnd_dir_version(&mut self, rnd_dir_version: Option<u32>);
+}
\ No newline at end of file
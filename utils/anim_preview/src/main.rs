@@ -1,15 +1,14 @@
 use std::env;
 use std::error::Error;
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 use std::path::PathBuf;
 
 use keyframe::{CanTween, keyframes, Keyframe, AnimationSequence, functions::Linear, functions::EaseInOut};
 
 use grim::{Platform, SystemInfo};
 use grim::io::*;
-use grim::scene::{Anim, CharBoneSample, Object, ObjectDir, PackedObject, MeshAnim, MiloObject, Trans, Vector3};
-
-use nalgebra as na;
+use grim::model::{Skeleton, SkeletonBone};
+use grim::scene::{Anim, Object, PackedObject, MeshAnim, MiloObject, Vector3};
 
 use rerun::external::glam;
 use rerun::{
@@ -154,9 +153,10 @@ fn main() -> Result<(), Box<dyn Error>> {
     }
 
     // Get bones
-    let root_bone = BoneNode::new(&base_milo_loader.obj_dir);
+    let skeleton = Some(Skeleton::from_object_dir(&base_milo_loader.obj_dir))
+        .filter(|s| !s.is_empty());
 
-    if let Some(mut root_bone) = root_bone {
+    if let Some(mut skeleton) = skeleton {
         /*root_bone = root_bone
             .children
             .into_iter()
@@ -196,6 +196,7 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .iter()
                 .flat_map(|cbs| cbs
                     .decode_samples(info)
+                    .unwrap_or_default()
                     .into_iter()
                     .map(|s| (s, if !cbs.frames.is_empty() { &cbs.frames } else { &default_frames })))
                 .collect::<Vec<_>>();
@@ -210,31 +211,26 @@ fn main() -> Result<(), Box<dyn Error>> {
                 .max()
                 .unwrap_or_default();
 
-            let bone_sample_map = bone_samples
-                .iter()
-                .map(|(cbs, frames)| (cbs.symbol.as_str(), (cbs, *frames)))
-                .collect::<HashMap<_, _>>();
-
             println!("Found {sample_count} samples for {} bones", bone_samples.len());
 
-            /*for (bone, _) in bone_samples.iter() {
-                println!("{}", &bone.symbol);
-            }*/
-
+            let samples = bone_samples
+                .into_iter()
+                .map(|(cbs, _)| cbs)
+                .collect::<Vec<_>>();
 
             for i in 0..sample_count {
                 // If sample not found, use last one?
                 // TODO: Iterpolate from frames
 
-                root_bone.recompute_world_anim_transform(na::Matrix4::identity(), &bone_sample_map, i);
-                add_bones_to_stream(&root_bone, &rec_stream, i);
+                skeleton.apply_samples(&samples, i);
+                add_bones_to_stream(&skeleton, &rec_stream, i);
             }
 
             /*for (char_bone_sample, frames) in bone_samples {
                 //char_bone_sample.
             }*/
         } else {
-            add_bones_to_stream(&root_bone, &rec_stream, 0);
+            add_bones_to_stream(&skeleton, &rec_stream, 0);
 
             // Can probably delete
             /*let (points, lines) = generate_bone_points(&root_bone);
@@ -256,216 +252,21 @@ fn main() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
-pub struct BoneNode<'a> {
-    pub name: &'a str,
-    pub object: Option<&'a dyn Trans>,
-    pub children: Vec<BoneNode<'a>>,
-    pub local_bind_transform: na::Matrix4<f32>,
-    pub inverse_bind_transform: na::Matrix4<f32>,
-    pub anim_transform: na::Matrix4<f32>,
-}
-
-impl<'a> BoneNode<'a> {
-    fn new(obj_dir: &'a ObjectDir) -> Option<Self> {
-        let dir_name = match obj_dir {
-            ObjectDir::ObjectDir(base) => base.name.as_str(),
-        };
-
-        // Get bones
-        let bones = obj_dir
-            .get_entries()
-            .iter()
-            .filter_map(|o| match o {
-                Object::Mesh(m) if m.faces.is_empty() // GH1 bones
-                    => Some(m as &dyn Trans),
-                Object::Trans(t) => Some(t as &dyn Trans),
-                _ => None
-            })
-            .map(|b| (b.get_name().as_str(), b))
-            .collect::<HashMap<_, _>>();
-
-        // Map children
-        let child_map = bones
-            .iter()
-            .fold(HashMap::new(), |mut acc: HashMap<&str, Vec<&'a dyn Trans>>, (_, b)| {
-                if b.get_parent().eq(b.get_name()) {
-                    // If bone references self, ignore
-                    return acc;
-                }
-
-                acc
-                    .entry(b.get_parent().as_str())
-                    .and_modify(|e| e.push(*b))
-                    .or_insert(vec![*b]);
-
-                acc
-            });
-
-        // Create root node
-        let mut root = BoneNode {
-            name: dir_name,
-            object: None,
-            children: Vec::new(),
-            local_bind_transform: na::Matrix4::identity(),
-            inverse_bind_transform: na::Matrix4::identity().try_inverse().unwrap(),
-            anim_transform: na::Matrix4::identity(),
-        };
-
-        // Find bones that belong to object dir
-        root.children = root.find_child_nodes(root.local_bind_transform, &bones, &child_map);
-
-        if root.children.is_empty() {
-            return None;
-        }
-
-        Some(root)
-    }
-
-    fn find_child_nodes(&self, parent_transform: na::Matrix4<f32>, bone_map: &HashMap<&str, &'a dyn Trans>, child_map: &HashMap<&str, Vec<&'a dyn Trans>>) -> Vec<BoneNode<'a>> {
-        let parent_name = self.name;
-
-        let Some(children) = child_map.get(parent_name) else {
-            return Vec::new();
-        };
-
-        children
-            .iter()
-            .map(|c| {
-                let trans_obj = bone_map.get(c.get_name().as_str()).map(|o| *o);
-                let local_transform = trans_obj
-                    .map(|o| {
-                        let m = o.get_local_xfm();
-
-                        na::Matrix4::new(
-                            // Column-major order...
-                            m.m11, m.m21, m.m31, m.m41,
-                            m.m12, m.m22, m.m32, m.m42,
-                            m.m13, m.m23, m.m33, m.m43,
-                            m.m14, m.m24, m.m34, m.m44
-                        )
-
-                        /*na::Matrix4::new(
-                            m.m11, m.m12, m.m13, m.m14,
-                            m.m21, m.m22, m.m23, m.m24,
-                            m.m31, m.m32, m.m33, m.m34,
-                            m.m41, m.m42, m.m43, m.m44
-                        )*/
-                    })
-                    .unwrap_or(na::Matrix4::identity());
-
-                let current_transform = parent_transform * local_transform;
-
-                let mut bone = BoneNode {
-                    name: c.get_name().as_str(),
-                    object: trans_obj,
-                    children: Vec::new(),
-                    local_bind_transform: local_transform,
-                    inverse_bind_transform: current_transform.try_inverse().unwrap(),
-                    anim_transform: current_transform,
-                };
-
-                bone.children = bone.find_child_nodes(current_transform, bone_map, child_map);
-                bone
-            })
-            .collect()
-    }
-
-    fn recompute_world_anim_transform(&mut self, parent_transform: na::Matrix4<f32>, bone_sample_map: &HashMap<&str, (&CharBoneSample, &Vec<f32>)>, i: usize) {
-        let current_transform;
-
-        if let Some((sample, _)) = bone_sample_map.get(self.name) {
-            // Decompose original local transform
-            let (mut trans, mut rotate, scale) = decompose_trs(self.local_bind_transform);
-
-            // TODO: Multiply by bone weight?
-            let pos = sample
-                .pos
-                .as_ref()
-                .and_then(|(_, p)| p.get(i).or_else(|| p.last()))
-                .map(|v| na::Vector3::new(v.x, v.y, v.z));
-                //.unwrap_or_else(|| na::Vector3::zeros());
-
-            let quat = sample
-                .quat
-                .as_ref()
-                .and_then(|(_, q)| q.get(i).or_else(|| q.last()))
-                .map(|q| na::UnitQuaternion::from_quaternion(
-                    na::Quaternion::new(
-                        q.w,
-                        q.x,
-                        q.y,
-                        q.z
-                    )
-                ));
-                //.unwrap_or_else(|| na::UnitQuaternion::identity());
-
-            let rotz = sample
-                .rotz
-                .as_ref()
-                .and_then(|(_, r)| r.get(i).or_else(|| r.last()))
-                .map(|z| na::UnitQuaternion::from_axis_angle(
-                    &na::Vector3::z_axis(),
-                    std::f32::consts::PI * z
-                ));
-                //.unwrap_or_else(|| na::UnitQuaternion::identity());
-
-            // Override values if found
-            if let Some(pos) = pos {
-                trans = pos;
-            }
-
-            if let Some(quat) = quat {
-                rotate = quat;
-            }
-
-            if let Some(rotz) = rotz {
-                let (_roll, _pitch, _yaw) = rotate.euler_angles();
-                //println!("({}, {}, {})", roll, pitch, yaw);
-
-                //rotate = na::UnitQuaternion::from_euler_angles(roll, pitch, std::f32::consts::PI * rotz);
-                //rotate.renormalize();
-
-                /*rotate = na::UnitQuaternion::from_axis_angle(
-                    &na::Vector3::z_axis(),
-                    std::f32::consts::PI * rotz
-                );*/
-
-                rotate *= rotz;
-            }
-
-            // Compute local trs transform
-            let anim_transform = (na::Matrix4::identity()
-                .append_translation(&trans) *
-                rotate.to_homogeneous())
-                .append_nonuniform_scaling(&scale);
-
-
-            current_transform = parent_transform * anim_transform;
-        } else {
-            current_transform = parent_transform * self.local_bind_transform;
-        }
-
-        for ch in self.children.iter_mut() {
-            ch.recompute_world_anim_transform(current_transform, bone_sample_map, i);
-        }
-
-        self.anim_transform = current_transform;
-    }
-
-    fn get_world_anim_pos(&self) -> na::Vector3<f32> {
-        self.anim_transform.column(3).xyz()
+fn add_bones_to_stream(skeleton: &Skeleton, rec_stream: &RecordingStream, i: usize) {
+    for bone in skeleton.get_bones() {
+        add_bone_to_stream(skeleton, bone, rec_stream, i);
     }
 }
 
-fn add_bones_to_stream(bone: &BoneNode, rec_stream: &RecordingStream, i: usize) {
-    let v = bone.get_world_anim_pos();
+fn add_bone_to_stream(skeleton: &Skeleton, bone: &SkeletonBone, rec_stream: &RecordingStream, i: usize) {
+    let v = bone.world_pose.column(3).xyz();
 
     // Generate line strips
     let strips = bone
         .children
         .iter()
         .map(|c| {
-            let cv = c.get_world_anim_pos();
+            let cv = skeleton.get_bones()[*c].world_pose.column(3).xyz();
             vec![[v[0], v[1], v[2]], [cv.x, cv.y, cv.z]].into()
         })
         .collect::<Vec<LineStrip3D>>();
@@ -536,23 +337,4 @@ fn add_bones_to_stream(bone: &BoneNode, rec_stream: &RecordingStream, i: usize)
         .with_time(Timeline::new_sequence("frame"), i as i64)
         .send(rec_stream)
         .unwrap();*/
-
-    for ch in bone.children.iter() {
-        add_bones_to_stream(ch, rec_stream, i);
-    }
 }
-
-// TODO: Move to shared part in lib
-fn decompose_trs(mat: na::Matrix4<f32>) -> (na::Vector3<f32>, na::UnitQuaternion<f32>, na::Vector3<f32>) {
-    // Decompose matrix to T*R*S
-    let translate = mat.column(3).xyz();
-    let rotation = na::UnitQuaternion::from_matrix(&mat.fixed_view::<3, 3>(0, 0).into());
-
-    let scale = na::Vector3::new(
-        mat.column(0).magnitude(),
-        mat.column(1).magnitude(),
-        mat.column(2).magnitude(),
-    );
-
-    (translate, rotation, scale)
-}
\ No newline at end of file
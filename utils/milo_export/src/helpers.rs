@@ -234,6 +234,7 @@ impl GameAnalyzer {
                     .iter()
                     .find_map(|e| match e.get_name() {
                         "song.anim" => e.unpack(&sys_info)
+                            .ok()
                             .and_then(|e| match e {
                                 Object::PropAnim(p) => Some(p),
                                 _ => None